@@ -45,6 +45,7 @@ pub fn register_ensmallen(_py: Python, _m: &PyModule) -> PyResult<()> {
     _m.add_class::<ShortestPathsResultBFS>()?;
     _m.add_class::<Star>()?;
     _m.add_class::<Tendril>()?;
+    _m.add_class::<RandomWalksIterator>()?;
     env_logger::init();
     let submod = PyModule::new(_py, "edge_list_utils")?;
     register_edge_list_utils(_py, submod)?;
@@ -1980,6 +1981,100 @@ impl Graph {
         self.inner.get_adamic_adar_graph().into()
     }
 
+    #[automatically_generated_binding]
+    #[pyo3(text_signature = "($self, candidate_pairs)")]
+    /// Returns preferential attachment coo matrix, that is the product of the node degrees.
+    ///
+    /// Parameters
+    /// ----------
+    /// candidate_pairs: Optional[List[Tuple[int, int]]]
+    ///     The pairs of node IDs to score. By default, all the pairs of distinct nodes at distance at most two.
+    ///
+    ///
+    /// Raises
+    /// -------
+    /// ValueError
+    ///     If any of the provided candidate node IDs does not exist in the current graph.
+    ///
+    pub fn get_preferential_attachment_coo_matrix(
+        &self,
+        candidate_pairs: Option<Vec<(NodeT, NodeT)>>,
+    ) -> PyResult<(Py<PyArray2<NodeT>>, Py<PyArray1<WeightT>>)> {
+        let (subresult_0, subresult_1) = pe!(self
+            .inner
+            .get_preferential_attachment_coo_matrix(candidate_pairs.as_ref().map(|x| x.as_slice())))?;
+        Ok((
+            {
+                // Warning: this copies the array so it uses double the memory.
+                // To avoid this you should directly generate data compatible with a numpy array
+                // Which is a flat vector with row-first or column-first unrolling
+                let gil = pyo3::Python::acquire_gil();
+                let body = subresult_0;
+                let result_array = ThreadDataRaceAware {
+                    t: unsafe { PyArray2::<NodeT>::new(gil.python(), [body.len(), 2], false) },
+                };
+                body.into_par_iter()
+                    .enumerate()
+                    .for_each(|(i, (a, b))| unsafe {
+                        *(result_array.t.uget_mut([i, 0])) = a;
+                        *(result_array.t.uget_mut([i, 1])) = b;
+                    });
+                result_array.t.to_owned()
+            },
+            {
+                let gil = pyo3::Python::acquire_gil();
+                to_ndarray_1d!(gil, subresult_1, WeightT)
+            },
+        ))
+    }
+
+    #[automatically_generated_binding]
+    #[pyo3(text_signature = "($self, candidate_pairs)")]
+    /// Returns common neighbours coo matrix, that is the number of neighbours shared by the node pairs.
+    ///
+    /// Parameters
+    /// ----------
+    /// candidate_pairs: Optional[List[Tuple[int, int]]]
+    ///     The pairs of node IDs to score. By default, all the pairs of distinct nodes sharing at least a neighbour.
+    ///
+    ///
+    /// Raises
+    /// -------
+    /// ValueError
+    ///     If any of the provided candidate node IDs does not exist in the current graph.
+    ///
+    pub fn get_common_neighbours_coo_matrix(
+        &self,
+        candidate_pairs: Option<Vec<(NodeT, NodeT)>>,
+    ) -> PyResult<(Py<PyArray2<NodeT>>, Py<PyArray1<WeightT>>)> {
+        let (subresult_0, subresult_1) = pe!(self
+            .inner
+            .get_common_neighbours_coo_matrix(candidate_pairs.as_ref().map(|x| x.as_slice())))?;
+        Ok((
+            {
+                // Warning: this copies the array so it uses double the memory.
+                // To avoid this you should directly generate data compatible with a numpy array
+                // Which is a flat vector with row-first or column-first unrolling
+                let gil = pyo3::Python::acquire_gil();
+                let body = subresult_0;
+                let result_array = ThreadDataRaceAware {
+                    t: unsafe { PyArray2::<NodeT>::new(gil.python(), [body.len(), 2], false) },
+                };
+                body.into_par_iter()
+                    .enumerate()
+                    .for_each(|(i, (a, b))| unsafe {
+                        *(result_array.t.uget_mut([i, 0])) = a;
+                        *(result_array.t.uget_mut([i, 1])) = b;
+                    });
+                result_array.t.to_owned()
+            },
+            {
+                let gil = pyo3::Python::acquire_gil();
+                to_ndarray_1d!(gil, subresult_1, WeightT)
+            },
+        ))
+    }
+
     #[automatically_generated_binding]
     #[pyo3(text_signature = "($self)")]
     /// Returns Laplacian coo matrix
@@ -2134,6 +2229,38 @@ impl Graph {
         self.inner.get_symmetric_normalized_laplacian_graph().into()
     }
 
+    #[automatically_generated_binding]
+    #[pyo3(text_signature = "($self)")]
+    /// Returns random walk normalized Laplacian coo matrix
+    pub fn get_random_walk_normalized_laplacian_coo_matrix(
+        &self,
+    ) -> (Py<PyArray2<NodeT>>, Py<PyArray1<WeightT>>) {
+        let (subresult_0, subresult_1) = self.inner.get_random_walk_normalized_laplacian_coo_matrix();
+        (
+            {
+                // Warning: this copies the array so it uses double the memory.
+                // To avoid this you should directly generate data compatible with a numpy array
+                // Which is a flat vector with row-first or column-first unrolling
+                let gil = pyo3::Python::acquire_gil();
+                let body = subresult_0;
+                let result_array = ThreadDataRaceAware {
+                    t: unsafe { PyArray2::<NodeT>::new(gil.python(), [body.len(), 2], false) },
+                };
+                body.into_par_iter()
+                    .enumerate()
+                    .for_each(|(i, (a, b))| unsafe {
+                        *(result_array.t.uget_mut([i, 0])) = a;
+                        *(result_array.t.uget_mut([i, 1])) = b;
+                    });
+                result_array.t.to_owned()
+            },
+            {
+                let gil = pyo3::Python::acquire_gil();
+                to_ndarray_1d!(gil, subresult_1, WeightT)
+            },
+        )
+    }
+
     #[automatically_generated_binding]
     #[pyo3(text_signature = "($self)")]
     /// Returns graph with node IDs sorted by increasing outbound node degree
@@ -4973,7 +5100,7 @@ impl Graph {
 
     #[automatically_generated_binding]
     #[pyo3(
-        text_signature = "($self, number_of_negative_samples, random_state, only_from_same_component, minimum_node_degree, maximum_node_degree, source_node_types_names, destination_node_types_names, source_edge_types_names, destination_edge_types_names, source_nodes_prefixes, destination_nodes_prefixes, graph_to_avoid, support, use_scale_free_distribution, sample_edge_types, sample_edge_weights, enforce_node_type_connection_consistency, number_of_sampling_attempts)"
+        text_signature = "($self, number_of_negative_samples, random_state, only_from_same_component, minimum_node_degree, maximum_node_degree, source_node_types_names, destination_node_types_names, source_edge_types_names, destination_edge_types_names, source_nodes_prefixes, destination_nodes_prefixes, graph_to_avoid, support, use_scale_free_distribution, negative_sampling_strategy, degree_exponent, sample_edge_types, sample_edge_weights, enforce_node_type_connection_consistency, number_of_sampling_attempts)"
     )]
     /// Returns Graph with given amount of negative edges as positive edges.
     ///
@@ -5009,6 +5136,10 @@ impl Graph {
     ///     Parent graph of this subgraph, defining the `true` topology of the graph. Node degrees and connected components are sampled from this support graph when provided. Useful when sampling negative edges for a test graph. In this latter case, the support graph should be the training graph.
     /// use_scale_free_distribution: Optional[bool]
     ///     Whether to sample the nodes using scale_free distribution. By default True. Not using this may cause significant biases.
+    /// negative_sampling_strategy: Optional[str]
+    ///     The distribution of the nodes of the negative edges, either `uniform`, `scale_free` or `degree_proportional`. By default, `scale_free` unless `use_scale_free_distribution` is false.
+    /// degree_exponent: Optional[float]
+    ///     The exponent to raise the node degrees to with the `degree_proportional` strategy. By default, 0.75.
     /// sample_edge_types: Optional[bool]
     ///     Whether to sample edge types, following the edge type counts distribution. By default it is true only when the current graph instance has edge types.
     /// sample_edge_weights: Optional[bool]
@@ -5018,6 +5149,18 @@ impl Graph {
     /// number_of_sampling_attempts: Optional[int]
     ///     Number of times to attempt to sample edges before giving up.
     ///
+    ///
+    /// Raises
+    /// -------
+    /// ValueError
+    ///     If the number of negative samples is zero.
+    /// ValueError
+    ///     If both the negative sampling strategy and whether to use the scale free distribution are provided.
+    /// ValueError
+    ///     If the negative sampling strategy is not supported.
+    /// ValueError
+    ///     If the degree exponent is negative or not finite.
+    ///
     pub fn sample_negative_graph(
         &self,
         number_of_negative_samples: EdgeT,
@@ -5034,6 +5177,8 @@ impl Graph {
         graph_to_avoid: Option<&Graph>,
         support: Option<&Graph>,
         use_scale_free_distribution: Option<bool>,
+        negative_sampling_strategy: Option<&str>,
+        degree_exponent: Option<f64>,
         sample_edge_types: Option<bool>,
         sample_edge_weights: Option<bool>,
         enforce_node_type_connection_consistency: Option<bool>,
@@ -5054,6 +5199,8 @@ impl Graph {
             graph_to_avoid.map(|sg| &sg.inner),
             support.map(|sg| &sg.inner),
             use_scale_free_distribution,
+            negative_sampling_strategy,
+            degree_exponent,
             sample_edge_types,
             sample_edge_weights,
             enforce_node_type_connection_consistency,
@@ -5131,7 +5278,7 @@ impl Graph {
 
     #[automatically_generated_binding]
     #[pyo3(
-        text_signature = "($self, train_size, random_state, edge_types, include_all_edge_types, minimum_node_degree, maximum_node_degree, verbose)"
+        text_signature = "($self, train_size, random_state, edge_types, include_all_edge_types, minimum_node_degree, maximum_node_degree, edge_type_stratification, verbose)"
     )]
     /// Returns holdout for training ML algorithms on the graph structure.
     ///
@@ -5146,6 +5293,13 @@ impl Graph {
     /// In the option where a list of edge types has been provided, these
     /// edge types will be those put into the validation set.
     ///
+    /// When the edge type stratification is requested, the train size is
+    /// applied independently within each edge type, so that the validation set
+    /// contains at least one edge of each edge type with edges, and the edges
+    /// with unknown edge type are treated as an additional edge type. The edge
+    /// types with too few edges outside of the spanning tree to reach their
+    /// validation size put all of these edges in the validation set.
+    ///
     /// Parameters
     /// ----------
     /// train_size: float
@@ -5160,6 +5314,8 @@ impl Graph {
     ///     The minimum node degree of either the source or destination node to be sampled. By default 0.
     /// maximum_node_degree: Optional[int]
     ///     The maximum node degree of either the source or destination node to be sampled. By default, the number of nodes.
+    /// edge_type_stratification: Optional[bool]
+    ///     Whether to apply the train size within each edge type. By default, false.
     /// verbose: Optional[bool]
     ///     Whether to show the loading bar.
     ///
@@ -5169,6 +5325,12 @@ impl Graph {
     /// ValueError
     ///     If the edge types have been specified but the graph does not have edge types.
     /// ValueError
+    ///     If the edge type stratification has been requested but the graph does not have edge types.
+    /// ValueError
+    ///     If the edge type stratification has been requested together with the inclusion of all the edges between two nodes.
+    /// ValueError
+    ///     If the edge type stratification has been requested and all the edges of an edge type are required to keep the training graph connected.
+    /// ValueError
     ///     If the required training size is not a real value between 0 and 1.
     /// ValueError
     ///     If the current graph does not allow for the creation of a spanning tree for the requested training size.
@@ -5181,6 +5343,7 @@ impl Graph {
         include_all_edge_types: Option<bool>,
         minimum_node_degree: Option<NodeT>,
         maximum_node_degree: Option<NodeT>,
+        edge_type_stratification: Option<bool>,
         verbose: Option<bool>,
     ) -> PyResult<(Graph, Graph)> {
         Ok({
@@ -5191,6 +5354,7 @@ impl Graph {
                 include_all_edge_types,
                 minimum_node_degree,
                 maximum_node_degree,
+                edge_type_stratification,
                 verbose
             ))?
             .into();
@@ -15531,11 +15695,39 @@ impl Graph {
     }
 
     #[automatically_generated_binding]
-    #[pyo3(text_signature = "($self)")]
-    /// Return parallel iterator over closeness centrality for all nodes.
-    pub fn get_closeness_centrality(&self) -> Py<PyArray1<f32>> {
-        let gil = pyo3::Python::acquire_gil();
-        to_ndarray_1d!(gil, self.inner.get_closeness_centrality(), f32)
+    #[pyo3(text_signature = "($self, use_edge_weights, verbose)")]
+    /// Return closeness centrality for all nodes, corrected for disconnected graphs.
+    ///
+    /// Parameters
+    /// ----------
+    /// use_edge_weights: Optional[bool]
+    ///     Whether to use the edge weights as distances. By default, false.
+    /// verbose: Optional[bool]
+    ///     Whether to show an indicative progress bar. By default, true.
+    ///
+    ///
+    /// Raises
+    /// -------
+    /// ValueError
+    ///     If the edge weights are requested but the graph does not have edge weights.
+    /// ValueError
+    ///     If the edge weights are requested but the graph contains negative edge weights.
+    ///
+    pub fn get_closeness_centrality(
+        &self,
+        use_edge_weights: Option<bool>,
+        verbose: Option<bool>,
+    ) -> PyResult<Py<PyArray1<f64>>> {
+        Ok({
+            let gil = pyo3::Python::acquire_gil();
+            to_ndarray_1d!(
+                gil,
+                pe!(self
+                    .inner
+                    .get_closeness_centrality(use_edge_weights, verbose))?,
+                f64
+            )
+        })
     }
 
     #[automatically_generated_binding]
@@ -18008,6 +18200,8 @@ pub const GRAPH_METHODS_NAMES: &[&str] = &[
     "get_ancestors_jaccard_graph",
     "get_adamic_adar_coo_matrix",
     "get_adamic_adar_graph",
+    "get_preferential_attachment_coo_matrix",
+    "get_common_neighbours_coo_matrix",
     "get_laplacian_coo_matrix",
     "get_laplacian_graph",
     "get_left_normalized_laplacian_coo_matrix",
@@ -18016,6 +18210,7 @@ pub const GRAPH_METHODS_NAMES: &[&str] = &[
     "get_right_normalized_laplacian_graph",
     "get_symmetric_normalized_laplacian_coo_matrix",
     "get_symmetric_normalized_laplacian_graph",
+    "get_random_walk_normalized_laplacian_coo_matrix",
     "sort_by_increasing_outbound_node_degree",
     "sort_by_decreasing_outbound_node_degree",
     "sort_by_node_lexicographic_order",
@@ -24478,8 +24673,8 @@ impl GraphBuilder {
         weight: Option<WeightT>,
     ) -> PyResult<()> {
         Ok(pe!(self.inner.add_edge(
-            src.into(),
-            dst.into(),
+            src,
+            dst,
             edge_type,
             weight
         ))?)
@@ -1,5 +1,6 @@
 use super::*;
-use cpu_models::{BasicEmbeddingModel, BasicSiameseModel};
+use cpu_models::{BasicEmbeddingModel, BasicSiameseModel, TransEEmbedding};
+use numpy::PyArray2;
 
 #[derive(Debug, Clone)]
 pub struct BasicSiameseModelBinding<M>
@@ -165,6 +166,64 @@ impl TransE {
     }
 }
 
+#[pymethods]
+impl Graph {
+    #[pyo3(
+        text_signature = "($self, embedding_size, epochs, learning_rate, margin, number_of_negative_samples, random_state, verbose)"
+    )]
+    /// Returns the TransE node and edge type embeddings of the graph.
+    ///
+    /// The rows of the node embedding are aligned with the node IDs, while
+    /// the rows of the edge type embedding are aligned with the edge type IDs.
+    ///
+    /// Parameters
+    /// ---------
+    /// embedding_size: Optional[int] = 100
+    ///     Size of the embedding.
+    /// epochs: Optional[int] = 10
+    ///     Number of epochs to train the model for.
+    /// learning_rate: Optional[float] = 0.05
+    ///     Learning rate of the model.
+    /// margin: Optional[float] = 1.0
+    ///     The margin of the ranking loss between positive and negative triples. It can be zero.
+    /// number_of_negative_samples: Optional[int] = 1
+    ///     The number of negative samples to draw for each edge at each epoch.
+    /// random_state: Optional[int] = 42
+    ///     random_state to use to reproduce the training.
+    /// verbose: Optional[bool] = True
+    ///     Whether to show the loading bar.
+    ///
+    /// Raises
+    /// ---------
+    /// ValueError
+    ///     If the graph does not have edge types or edges, or if the parameters are not valid.
+    fn compute_transe_embedding(
+        &self,
+        embedding_size: Option<usize>,
+        epochs: Option<usize>,
+        learning_rate: Option<f32>,
+        margin: Option<f32>,
+        number_of_negative_samples: Option<usize>,
+        random_state: Option<u64>,
+        verbose: Option<bool>,
+    ) -> PyResult<(Py<PyArray2<f32>>, Py<PyArray2<f32>>)> {
+        let (node_embedding, edge_type_embedding) = pe!(self.inner.compute_transe_embedding(
+            embedding_size,
+            epochs,
+            learning_rate,
+            margin,
+            number_of_negative_samples,
+            random_state,
+            verbose,
+        ))?;
+        let gil = pyo3::Python::acquire_gil();
+        Ok((
+            to_ndarray_2d!(gil, node_embedding, f32),
+            to_ndarray_2d!(gil, edge_type_embedding, f32),
+        ))
+    }
+}
+
 #[pyclass]
 #[derive(Debug, Clone)]
 #[pyo3(
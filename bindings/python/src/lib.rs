@@ -69,6 +69,7 @@ pub use graph_convolution::*;
 mod weighted_spine;
 pub(crate) use weighted_spine::*;
 mod walks;
+pub use crate::walks::*;
 
 #[cfg(feature = "register_pymodule")]
 #[pymodule]
@@ -106,6 +106,8 @@ pub fn build_walk_parameters_list<'a>(parameters: &[&'a str]) -> Vec<&'a str> {
         "random_state",
         "iterations",
         "normalize_by_degree",
+        "node_mask",
+        "use_edge_weights_as_probabilities",
         "walk_length",
     ];
     default
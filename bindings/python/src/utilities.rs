@@ -27,7 +27,13 @@ pub(crate) fn build_walk_parameters(kwargs: &PyDict) -> PyResult<WalksParameters
         "return_weight",
         WeightT
     )))?
-    .set_random_state(extract_value_rust_result!(kwargs, "random_state", usize))
+    .set_random_state(extract_value_rust_result!(kwargs, "random_state", u64))
+    .set_node_mask(extract_value_rust_result!(kwargs, "node_mask", Vec<bool>))
+    .set_use_edge_weights_as_probabilities(extract_value_rust_result!(
+        kwargs,
+        "use_edge_weights_as_probabilities",
+        bool
+    ))
     .set_max_neighbours(extract_value_rust_result!(
         kwargs,
         "max_neighbours",
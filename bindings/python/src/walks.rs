@@ -5,6 +5,24 @@ use rayon::iter::IndexedParallelIterator;
 use rayon::prelude::*;
 use types::ThreadDataRaceAware;
 
+/// Iterator over random walks, computed one at a time.
+#[pyclass(module = "ensmallen")]
+pub struct RandomWalksIterator {
+    iterator: Box<dyn Iterator<Item = Vec<NodeT>> + Send>,
+}
+
+#[pymethods]
+impl RandomWalksIterator {
+    fn __iter__(slf: PyRef<Self>) -> PyRef<Self> {
+        slf
+    }
+
+    fn __next__(mut slf: PyRefMut<Self>) -> Option<Py<PyArray1<NodeT>>> {
+        let walk = slf.iterator.next()?;
+        Some(PyArray1::from_vec(slf.py(), walk).to_owned())
+    }
+}
+
 #[pymethods]
 impl Graph {
     #[args(py_kwargs = "**")]
@@ -197,4 +215,205 @@ impl Graph {
         }
         Ok(array.t.to_owned())
     }
+
+    #[args(py_kwargs = "**")]
+    #[pyo3(
+        text_signature = "($self, window_size, *, x_max, alpha, node_ids_of_interest, walk_length, return_weight, explore_weight, change_edge_type_weight, change_node_type_weight, random_state, iterations, max_neighbours, normalize_by_degree, node_mask, use_edge_weights_as_probabilities)"
+    )]
+    /// Return the co-occurrence matrix of the complete random walks, with the optional GloVe weighting.
+    ///
+    /// When neither `x_max` nor `alpha` is provided, the counts of each
+    /// random walk are returned separately, so the same pair of nodes may
+    /// appear more than once. Otherwise, each pair of nodes appears once with
+    /// its total count and the GloVe weighting `min(1, (count / x_max)^alpha)`.
+    ///
+    /// Parameters
+    /// ----------
+    /// window_size: int
+    ///     Window size to consider for the sequences.
+    /// x_max: Optional[float] = None
+    ///     The cooccurrence count over which the weighting is one. By default, 100 when `alpha` is provided.
+    /// alpha: Optional[float] = None
+    ///     The exponent of the weighting. By default, 0.75 when `x_max` is provided.
+    /// node_ids_of_interest: Optional[List[int]] = None
+    ///     While the random walks is graph-wide, we only return edges whose source and destination nodes are within this node ID list.
+    /// walk_length: int
+    ///     Maximal length of the random walk.
+    ///     On graphs without traps, all walks have this length.
+    /// return_weight: float = 1.0
+    ///     Weight on the probability of returning to node coming from
+    ///     Having this higher tends the walks to be
+    ///     more like a Breadth-First Search.
+    ///     Having this very high  (> 2) makes search very local.
+    ///     Equal to the inverse of p in the Node2Vec paper.
+    /// explore_weight: float = 1.0
+    ///     Weight on the probability of visiting a neighbor node
+    ///     to the one we're coming from in the random walk
+    ///     Having this higher tends the walks to be
+    ///     more like a Depth-First Search.
+    ///     Having this very high makes search more outward.
+    ///     Having this very low makes search very local.
+    ///     Equal to the inverse of q in the Node2Vec paper.
+    /// change_edge_type_weight: float = 1.0
+    ///     Weight on the probability of visiting a neighbor node of a
+    ///     different type than the previous node. This only applies to
+    ///     colored graphs, otherwise it has no impact.
+    /// change_node_type_weight: float = 1.0
+    ///     Weight on the probability of visiting a neighbor edge of a
+    ///     different type than the previous edge. This only applies to
+    ///     multigraphs, otherwise it has no impact.
+    /// random_state: int = 42
+    ///     random_state to use to reproduce the walks.
+    /// iterations: int = 1
+    ///     Number of cycles on the graphs to execute.
+    /// max_neighbours: Optional[int] = 100
+    ///     Maximum number of randomly sampled neighbours to consider.
+    ///     If this parameter is used, the walks becomes probabilistic in nature
+    ///     and becomes an approximation of an exact walk.
+    /// normalize_by_degree: Optional[bool] = False
+    ///     Whether to normalize the random walks by the node degree.
+    /// node_mask: Optional[List[bool]] = None
+    ///     Mask of the nodes the walks are allowed to visit, with a value for each node.
+    /// use_edge_weights_as_probabilities: Optional[bool] = None
+    ///     Whether to use the edge weights to bias the transitions.
+    ///     By default, the edge weights are used if the graph has them.
+    ///
+    /// Raises
+    /// ------
+    /// ValueError
+    ///     If the given walks parameters are not compatible with the current graph.
+    /// ValueError
+    ///     If the given `x_max` or `alpha` is not a strictly positive finite value.
+    ///
+    /// Returns
+    /// -------
+    /// Tuple with the node ID pairs, their counts and, when the weighting is requested, their weightings.
+    ///
+    fn get_weighted_cooccurence_matrix(
+        &self,
+        window_size: usize,
+        py_kwargs: Option<&PyDict>,
+    ) -> PyResult<(
+        Py<PyArray2<NodeT>>,
+        Py<PyArray1<NodeT>>,
+        Option<Py<PyArray1<f64>>>,
+    )> {
+        let py = pyo3::Python::acquire_gil();
+        let kwargs = normalize_kwargs!(py_kwargs, py.python());
+
+        pe!(validate_kwargs(
+            kwargs,
+            build_walk_parameters_list(&["x_max", "alpha", "node_ids_of_interest"]).as_slice()
+        ))?;
+
+        let parameters = build_walk_parameters(kwargs)?;
+        let node_ids_of_interest =
+            extract_value_rust_result!(kwargs, "node_ids_of_interest", Vec<NodeT>);
+        let (node_ids, counts, weightings) = pe!(self.inner.get_weighted_cooccurence_matrix(
+            &parameters,
+            window_size,
+            node_ids_of_interest.as_deref(),
+            extract_value_rust_result!(kwargs, "x_max", f64),
+            extract_value_rust_result!(kwargs, "alpha", f64),
+        ))?;
+        let node_ids_array = ThreadDataRaceAware {
+            t: unsafe { PyArray2::<NodeT>::new(py.python(), [node_ids.len(), 2], false) },
+        };
+        node_ids
+            .into_par_iter()
+            .enumerate()
+            .for_each(|(i, (src, dst))| unsafe {
+                *(node_ids_array.t.uget_mut([i, 0])) = src;
+                *(node_ids_array.t.uget_mut([i, 1])) = dst;
+            });
+        Ok((
+            node_ids_array.t.to_owned(),
+            to_ndarray_1d!(py, counts, NodeT),
+            weightings.map(|weightings| to_ndarray_1d!(py, weightings, f64)),
+        ))
+    }
+
+    #[args(py_kwargs = "**")]
+    #[pyo3(
+        text_signature = "($self, quantity, *, walk_length, return_weight, explore_weight, change_edge_type_weight, change_node_type_weight, random_state, iterations, max_neighbours, normalize_by_degree, node_mask, use_edge_weights_as_probabilities)"
+    )]
+    /// Return generator of random walks done on the graph using Rust, computed one at a time.
+    ///
+    /// The walks are the same, in the same order, as the rows returned by
+    /// `random_walks` with the same parameters, but only the walk being
+    /// yielded is kept in memory.
+    ///
+    /// Parameters
+    /// ----------
+    /// quantity: int
+    ///     Number of nodes to sample.
+    /// walk_length: int
+    ///     Maximal length of the random walk.
+    ///     On graphs without traps, all walks have this length.
+    /// return_weight: float = 1.0
+    ///     Weight on the probability of returning to node coming from
+    ///     Having this higher tends the walks to be
+    ///     more like a Breadth-First Search.
+    ///     Having this very high  (> 2) makes search very local.
+    ///     Equal to the inverse of p in the Node2Vec paper.
+    /// explore_weight: float = 1.0
+    ///     Weight on the probability of visiting a neighbor node
+    ///     to the one we're coming from in the random walk
+    ///     Having this higher tends the walks to be
+    ///     more like a Depth-First Search.
+    ///     Having this very high makes search more outward.
+    ///     Having this very low makes search very local.
+    ///     Equal to the inverse of q in the Node2Vec paper.
+    /// change_edge_type_weight: float = 1.0
+    ///     Weight on the probability of visiting a neighbor node of a
+    ///     different type than the previous node. This only applies to
+    ///     colored graphs, otherwise it has no impact.
+    /// change_node_type_weight: float = 1.0
+    ///     Weight on the probability of visiting a neighbor edge of a
+    ///     different type than the previous edge. This only applies to
+    ///     multigraphs, otherwise it has no impact.
+    /// random_state: int = 42
+    ///     random_state to use to reproduce the walks.
+    /// iterations: int = 1
+    ///     Number of cycles on the graphs to execute.
+    /// max_neighbours: Optional[int] = 100
+    ///     Maximum number of randomly sampled neighbours to consider.
+    ///     If this parameter is used, the walks becomes probabilistic in nature
+    ///     and becomes an approximation of an exact walk.
+    /// normalize_by_degree: Optional[bool] = False
+    ///     Whether to normalize the random walks by the node degree.
+    /// node_mask: Optional[List[bool]] = None
+    ///     Mask of the nodes the walks are allowed to visit, with a value for each node.
+    ///     Walks starting from a masked-out node are composed only of the starting node.
+    /// use_edge_weights_as_probabilities: Optional[bool] = None
+    ///     Whether to use the edge weights to bias the transitions.
+    ///     By default, the edge weights are used if the graph has them.
+    ///
+    /// Raises
+    /// ------
+    /// ValueError
+    ///     If the given walks parameters are not compatible with the current graph.
+    ///
+    /// Returns
+    /// -------
+    /// Generator of the walks, each one a numpy array of the numeric IDs of nodes.
+    ///
+    fn iter_walks(
+        &self,
+        quantity: NodeT,
+        py_kwargs: Option<&PyDict>,
+    ) -> PyResult<RandomWalksIterator> {
+        let py = pyo3::Python::acquire_gil();
+        let kwargs = normalize_kwargs!(py_kwargs, py.python());
+
+        pe!(validate_kwargs(
+            kwargs,
+            build_walk_parameters_list(&[]).as_slice()
+        ))?;
+
+        let parameters = build_walk_parameters(kwargs)?;
+        Ok(RandomWalksIterator {
+            iterator: Box::new(pe!(self.inner.iter_random_walks(quantity, &parameters))?),
+        })
+    }
 }
@@ -399,6 +399,8 @@ impl GenBinding for BindingsModule {
             mod_names.push("models");
             mod_names.push("express_measures");
 
+            // Classes defined in the handwritten bindings.
+            registrations.push("\t_m.add_class::<RandomWalksIterator>()?;".into());
             registrations.push("\tenv_logger::init();".into());
         }
 
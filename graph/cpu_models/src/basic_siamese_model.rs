@@ -1,4 +1,4 @@
-use crate::{must_not_be_zero, BasicEmbeddingModel};
+use crate::BasicEmbeddingModel;
 
 #[derive(Clone, Debug)]
 pub struct BasicSiameseModel {
@@ -11,12 +11,22 @@ impl BasicSiameseModel {
     ///
     /// # Arguments
     /// * `model`: BasicEmbeddingModel - The basic embedding model.
-    /// * `relu_bias`: Option<f32> - The bias to apply to the relu. By default, 1.0.
+    /// * `relu_bias`: Option<f32> - The bias to apply to the relu, which is the margin of the ranking loss. By default, 1.0.
+    ///
+    /// # Raises
+    /// * If the provided ReLU bias is negative or NaN.
     pub fn new(model: BasicEmbeddingModel, relu_bias: Option<f32>) -> Result<Self, String> {
-        Ok(Self {
-            model,
-            relu_bias: must_not_be_zero(relu_bias, 1.0, "ReLU bias")?,
-        })
+        let relu_bias = relu_bias.unwrap_or(1.0);
+        if relu_bias.is_nan() || relu_bias < 0.0 {
+            return Err(format!(
+                concat!(
+                    "The provided ReLU bias is {}. ",
+                    "The ReLU bias should be greater than or equal to zero."
+                ),
+                relu_bias
+            ));
+        }
+        Ok(Self { model, relu_bias })
     }
 
     pub fn get_embedding_size(&self) -> usize {
@@ -34,7 +34,7 @@ where
         NodeT: AsPrimitive<F>,
     {
         let mut walk_parameters = self.walk_parameters.clone();
-        let mut random_state = splitmix64(self.walk_parameters.get_random_state() as u64);
+        let mut random_state = splitmix64(self.walk_parameters.get_random_state());
         let context_size = (self.window_size as f32 * 2.0).as_();
         let mut learning_rate = self.learning_rate.as_();
         let cv = self.clipping_value.as_();
@@ -135,7 +135,7 @@ where
             // We update the random state used to generate the random walks
             // and the negative samples.
             random_state = splitmix64(random_state);
-            walk_parameters = walk_parameters.set_random_state(Some(random_state as usize));
+            walk_parameters = walk_parameters.set_random_state(Some(random_state));
 
             // We start to compute the new gradients.
             graph
@@ -0,0 +1,261 @@
+use express_measures::dot_product_sequential_unchecked;
+use graph::NodeT;
+use rayon::prelude::*;
+
+/// Maximum number of sweeps of the one-sided Jacobi SVD.
+const MAXIMUM_NUMBER_OF_JACOBI_SWEEPS: usize = 100;
+
+/// Returns the orthogonal matrix closest to the provided square matrix.
+///
+/// # Arguments
+/// * `matrix`: Vec<Vec<f64>> - The square matrix, stored by rows.
+///
+/// # Implementative details
+/// The matrix `M = U S V^T` is decomposed with the one-sided Jacobi SVD, which
+/// rotates the columns of `M` until they are pairwise orthogonal, and the
+/// product `U V^T` is returned. The left singular vectors associated to null
+/// singular values are completed to an orthonormal basis with Gram-Schmidt.
+fn get_closest_orthogonal_matrix(mut matrix: Vec<Vec<f64>>) -> Vec<Vec<f64>> {
+    let dimension = matrix.len();
+    let mut right_vectors = (0..dimension)
+        .map(|i| {
+            (0..dimension)
+                .map(|j| if i == j { 1.0 } else { 0.0 })
+                .collect()
+        })
+        .collect::<Vec<Vec<f64>>>();
+    let norm = matrix
+        .iter()
+        .flat_map(|row| row.iter())
+        .map(|value| value * value)
+        .sum::<f64>()
+        .sqrt();
+
+    for _ in 0..MAXIMUM_NUMBER_OF_JACOBI_SWEEPS {
+        let mut rotated = false;
+        for first in 0..dimension {
+            for second in first + 1..dimension {
+                let (mut alpha, mut beta, mut gamma) = (0.0, 0.0, 0.0);
+                matrix.iter().for_each(|row| {
+                    alpha += row[first] * row[first];
+                    beta += row[second] * row[second];
+                    gamma += row[first] * row[second];
+                });
+                if gamma.abs() <= f64::EPSILON * (alpha * beta).sqrt()
+                    || gamma.abs() <= f64::EPSILON * norm * norm
+                {
+                    continue;
+                }
+                rotated = true;
+                let zeta = (beta - alpha) / (2.0 * gamma);
+                let tangent = zeta.signum() / (zeta.abs() + (1.0 + zeta * zeta).sqrt());
+                let cosine = 1.0 / (1.0 + tangent * tangent).sqrt();
+                let sine = cosine * tangent;
+                matrix
+                    .iter_mut()
+                    .chain(right_vectors.iter_mut())
+                    .for_each(|row| {
+                        let (x, y) = (row[first], row[second]);
+                        row[first] = cosine * x - sine * y;
+                        row[second] = sine * x + cosine * y;
+                    });
+            }
+        }
+        if !rotated {
+            break;
+        }
+    }
+
+    // The columns of the rotated matrix are the left singular vectors scaled
+    // by the singular values, which we normalize, completing the null ones.
+    let mut left_vectors: Vec<Vec<f64>> = Vec::with_capacity(dimension);
+    let mut null_columns = Vec::new();
+    for column in 0..dimension {
+        let singular_value = matrix
+            .iter()
+            .map(|row| row[column] * row[column])
+            .sum::<f64>()
+            .sqrt();
+        if singular_value <= f64::EPSILON * dimension as f64 * norm {
+            null_columns.push(column);
+            left_vectors.push(vec![0.0; dimension]);
+        } else {
+            left_vectors.push(
+                matrix
+                    .iter()
+                    .map(|row| row[column] / singular_value)
+                    .collect(),
+            );
+        }
+    }
+    let mut candidate = 0;
+    for column in null_columns {
+        loop {
+            let mut vector = (0..dimension)
+                .map(|i| if i == candidate { 1.0 } else { 0.0 })
+                .collect::<Vec<f64>>();
+            candidate += 1;
+            left_vectors.iter().for_each(|other| {
+                let projection = other
+                    .iter()
+                    .zip(vector.iter())
+                    .map(|(a, b)| a * b)
+                    .sum::<f64>();
+                vector
+                    .iter_mut()
+                    .zip(other.iter())
+                    .for_each(|(value, other)| *value -= projection * other);
+            });
+            let vector_norm = vector.iter().map(|value| value * value).sum::<f64>().sqrt();
+            if vector_norm > 0.5 {
+                left_vectors[column] = vector
+                    .into_iter()
+                    .map(|value| value / vector_norm)
+                    .collect();
+                break;
+            }
+        }
+    }
+
+    (0..dimension)
+        .map(|i| {
+            (0..dimension)
+                .map(|j| {
+                    (0..dimension)
+                        .map(|k| left_vectors[k][i] * right_vectors[j][k])
+                        .sum()
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// Returns the embedding of the second graph aligned to the embedding of the first one.
+///
+/// # Arguments
+/// * `embedding_a`: &[Vec<f32>] - The node embedding of the first graph, used as reference.
+/// * `embedding_b`: &[Vec<f32>] - The node embedding of the second graph, to be aligned.
+/// * `anchor_node_ids`: &[NodeT] - The node IDs shared by the two graphs, with the same ID in both.
+///
+/// # Implementative details
+/// The rotation is the solution of the orthogonal Procrustes problem on the
+/// anchor rows, that is the orthogonal matrix `R` minimizing the Frobenius norm
+/// of `B R - A`, where `A` and `B` are the anchor rows of the two embeddings.
+/// It is equal to `U V^T`, where `U S V^T` is the SVD of `B^T A`. The rotation
+/// is then applied to all the rows of the second embedding. Note that neither
+/// a translation nor a scaling is applied, hence the embeddings should be
+/// centered beforehand if they are not.
+///
+/// # References
+/// [A generalized solution of the orthogonal Procrustes problem](https://doi.org/10.1007/BF02289451),
+/// by Peter H. Schönemann.
+///
+/// # Raises
+/// * If either of the embeddings is empty.
+/// * If the embeddings do not have the same dimensionality for all the nodes.
+/// * If no anchor node ID is provided.
+/// * If any of the anchor node IDs does not exist in either of the embeddings.
+pub fn align_node_embeddings(
+    embedding_a: &[Vec<f32>],
+    embedding_b: &[Vec<f32>],
+    anchor_node_ids: &[NodeT],
+) -> Result<Vec<Vec<f32>>, String> {
+    for (embedding, name) in [(embedding_a, "first"), (embedding_b, "second")] {
+        if embedding.is_empty() {
+            return Err(format!("The provided {} embedding is empty.", name));
+        }
+    }
+    let dimension = embedding_a[0].len();
+    if dimension == 0 {
+        return Err("The provided embeddings have zero dimensions.".to_string());
+    }
+    for (embedding, name) in [(embedding_a, "first"), (embedding_b, "second")] {
+        if let Some((node_id, node_embedding)) = embedding
+            .iter()
+            .enumerate()
+            .find(|(_, node_embedding)| node_embedding.len() != dimension)
+        {
+            return Err(format!(
+                concat!(
+                    "The node {} of the provided {} embedding has {} dimensions, ",
+                    "while the first node of the first embedding has {} dimensions."
+                ),
+                node_id,
+                name,
+                node_embedding.len(),
+                dimension
+            ));
+        }
+    }
+    if anchor_node_ids.is_empty() {
+        return Err("The provided anchor node IDs are empty.".to_string());
+    }
+    if let Some(&node_id) = anchor_node_ids.iter().find(|&&node_id| {
+        node_id as usize >= embedding_a.len() || node_id as usize >= embedding_b.len()
+    }) {
+        return Err(format!(
+            concat!(
+                "The provided anchor node ID {} does not exist in both embeddings, ",
+                "as the first embedding has {} nodes and the second one has {} nodes."
+            ),
+            node_id,
+            embedding_a.len(),
+            embedding_b.len()
+        ));
+    }
+
+    // We compute the cross-covariance matrix between the anchor rows.
+    let cross_covariance = anchor_node_ids
+        .par_iter()
+        .fold(
+            || vec![vec![0.0; dimension]; dimension],
+            |mut cross_covariance, &node_id| {
+                let row_a = &embedding_a[node_id as usize];
+                let row_b = &embedding_b[node_id as usize];
+                cross_covariance.iter_mut().zip(row_b.iter()).for_each(
+                    |(cross_covariance_row, &b)| {
+                        cross_covariance_row
+                            .iter_mut()
+                            .zip(row_a.iter())
+                            .for_each(|(value, &a)| *value += b as f64 * a as f64);
+                    },
+                );
+                cross_covariance
+            },
+        )
+        .reduce(
+            || vec![vec![0.0; dimension]; dimension],
+            |mut first, second| {
+                first
+                    .iter_mut()
+                    .flat_map(|row| row.iter_mut())
+                    .zip(second.into_iter().flatten())
+                    .for_each(|(first, second)| *first += second);
+                first
+            },
+        );
+
+    let rotation = get_closest_orthogonal_matrix(cross_covariance);
+    // We store the columns of the rotation, so that each aligned feature
+    // is the dot product between the node embedding and one column.
+    let rotation_columns = (0..dimension)
+        .map(|column| {
+            rotation
+                .iter()
+                .map(|rotation_row| rotation_row[column] as f32)
+                .collect::<Vec<f32>>()
+        })
+        .collect::<Vec<Vec<f32>>>();
+
+    Ok(embedding_b
+        .par_iter()
+        .map(|node_embedding| {
+            rotation_columns
+                .iter()
+                .map(|rotation_column| unsafe {
+                    dot_product_sequential_unchecked(node_embedding, rotation_column)
+                })
+                .collect()
+        })
+        .collect())
+}
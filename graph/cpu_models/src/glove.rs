@@ -22,7 +22,7 @@ where
         let embedding_size = self.embedding_size;
         let scale_factor = (self.get_embedding_size() as f32).sqrt().as_();
         let mut walk_parameters = self.walk_parameters.clone();
-        let mut random_state = splitmix64(self.walk_parameters.get_random_state() as u64);
+        let mut random_state = splitmix64(self.walk_parameters.get_random_state());
         let mut learning_rate = self.learning_rate.as_();
         let alpha = self.alpha.as_();
         let maximum_cooccurrence_count_threshold = self.maximum_cooccurrence_count_threshold.as_();
@@ -51,7 +51,7 @@ where
             // We update the random state used to generate the random walks
             // and the negative samples.
             random_state = splitmix64(random_state);
-            walk_parameters = walk_parameters.set_random_state(Some(random_state as usize));
+            walk_parameters = walk_parameters.set_random_state(Some(random_state));
 
             // We start to compute the new gradients.
             graph
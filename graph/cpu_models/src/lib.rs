@@ -16,6 +16,7 @@ mod dag_resnik;
 mod degree_spine;
 mod degree_wine;
 mod edge_prediction_perceptron;
+mod embedding_alignment;
 mod first_order_line;
 mod glove;
 mod hyper_jaccard;
@@ -52,6 +53,7 @@ pub use dag_resnik::*;
 pub use degree_spine::*;
 pub use degree_wine::*;
 pub use edge_prediction_perceptron::*;
+pub use embedding_alignment::*;
 pub use first_order_line::*;
 pub use graph_embedder::*;
 pub use hyper_jaccard::*;
@@ -185,7 +185,7 @@ where
     }
 
     fn get_random_state(&self) -> u64 {
-        self.walk_parameters.get_random_state() as u64
+        self.walk_parameters.get_random_state()
     }
 
     fn _fit_transform<F: AsPrimitive<f32> + ThreadFloat>(
@@ -9,11 +9,152 @@ use vec_rand::splitmix64;
 #[derive(Clone, Debug)]
 pub struct TransE {
     model: BasicSiameseModel,
+    number_of_negative_samples: usize,
 }
 
 impl From<BasicSiameseModel> for TransE {
     fn from(model: BasicSiameseModel) -> Self {
-        Self { model }
+        Self {
+            model,
+            number_of_negative_samples: 1,
+        }
+    }
+}
+
+impl TransE {
+    /// Return new instance of TransE model.
+    ///
+    /// # Arguments
+    /// * `model`: BasicSiameseModel - The basic siamese model.
+    /// * `number_of_negative_samples`: Option<usize> - The number of negative samples to draw for each edge at each epoch. By default, 1.
+    pub fn new(
+        model: BasicSiameseModel,
+        number_of_negative_samples: Option<usize>,
+    ) -> Result<Self, String> {
+        Ok(Self {
+            model,
+            number_of_negative_samples: must_not_be_zero(
+                number_of_negative_samples,
+                1,
+                "number of negative samples",
+            )?,
+        })
+    }
+
+    /// Returns the number of negative samples drawn for each edge at each epoch.
+    pub fn get_number_of_negative_samples(&self) -> usize {
+        self.number_of_negative_samples
+    }
+
+    /// Returns the node and edge type embeddings of the provided graph.
+    ///
+    /// The first returned embedding has a row for each node, aligned
+    /// with the node IDs of the graph, while the second one has a row
+    /// for each edge type, aligned with the edge type IDs of the graph.
+    ///
+    /// # Arguments
+    /// * `graph`: &Graph - The graph to embed.
+    ///
+    /// # Raises
+    /// * If the graph does not have edge types.
+    /// * If the graph does not have edges.
+    pub fn fit_transform_to_vectors(
+        &self,
+        graph: &Graph,
+    ) -> Result<(Vec<Vec<f32>>, Vec<Vec<f32>>), String> {
+        graph.must_have_edge_types()?;
+        let embedding_size = self.model.get_embedding_size();
+        let mut node_embedding = vec![0.0; graph.get_number_of_nodes() as usize * embedding_size];
+        let mut edge_type_embedding =
+            vec![0.0; graph.get_number_of_edge_types()? as usize * embedding_size];
+        self.fit_transform(
+            graph,
+            &mut [
+                node_embedding.as_mut_slice(),
+                edge_type_embedding.as_mut_slice(),
+            ],
+        )?;
+        let to_vectors = |embedding: Vec<f32>| {
+            embedding
+                .chunks(embedding_size)
+                .map(|row| row.to_vec())
+                .collect::<Vec<Vec<f32>>>()
+        };
+        Ok((to_vectors(node_embedding), to_vectors(edge_type_embedding)))
+    }
+}
+
+/// # TransE embedding
+/// Trait to compute the TransE embedding directly from the graph.
+pub trait TransEEmbedding {
+    /// Returns the TransE node and edge type embeddings of the graph.
+    ///
+    /// # Arguments
+    /// * `embedding_size`: Option<usize> - Size of the embedding. By default, 100.
+    /// * `epochs`: Option<usize> - The number of epochs to run the model for. By default, 10.
+    /// * `learning_rate`: Option<f32> - The learning rate to update the gradient. By default, 0.05.
+    /// * `margin`: Option<f32> - The margin of the ranking loss between positive and negative triples. By default, 1.0.
+    /// * `number_of_negative_samples`: Option<usize> - The number of negative samples to draw for each edge at each epoch. By default, 1.
+    /// * `random_state`: Option<u64> - The random state to use to reproduce the training. By default, 42.
+    /// * `verbose`: Option<bool> - Whether to show the loading bar. By default, true.
+    ///
+    /// # Implementative details
+    /// The node embedding rows are aligned with the node IDs of the graph and
+    /// the edge type embedding rows are aligned with its edge type IDs. The negative
+    /// triples are obtained by corrupting the source and destination nodes of
+    /// the positive ones. With a zero margin, only the negative triples that
+    /// are closer than the positive ones contribute to the training.
+    ///
+    /// # References
+    /// The model is described in [Translating Embeddings for Modeling Multi-relational Data](https://papers.nips.cc/paper/2013/hash/1cecc7a77928ca8133fa24680a88d2f9-Abstract.html),
+    /// by Antoine Bordes et al.
+    ///
+    /// # Raises
+    /// * If the graph does not have edge types.
+    /// * If the graph does not have edges.
+    /// * If the embedding size, the number of epochs or the number of negative samples is zero.
+    /// * If the margin is negative.
+    fn compute_transe_embedding(
+        &self,
+        embedding_size: Option<usize>,
+        epochs: Option<usize>,
+        learning_rate: Option<f32>,
+        margin: Option<f32>,
+        number_of_negative_samples: Option<usize>,
+        random_state: Option<u64>,
+        verbose: Option<bool>,
+    ) -> Result<(Vec<Vec<f32>>, Vec<Vec<f32>>), String>;
+}
+
+impl TransEEmbedding for Graph {
+    fn compute_transe_embedding(
+        &self,
+        embedding_size: Option<usize>,
+        epochs: Option<usize>,
+        learning_rate: Option<f32>,
+        margin: Option<f32>,
+        number_of_negative_samples: Option<usize>,
+        random_state: Option<u64>,
+        verbose: Option<bool>,
+    ) -> Result<(Vec<Vec<f32>>, Vec<Vec<f32>>), String> {
+        TransE::new(
+            BasicSiameseModel::new(
+                BasicEmbeddingModel::new(
+                    embedding_size,
+                    epochs,
+                    learning_rate,
+                    None,
+                    None,
+                    None,
+                    random_state,
+                    None,
+                    verbose,
+                )?,
+                margin,
+            )?,
+            number_of_negative_samples,
+        )?
+        .fit_transform_to_vectors(self)
     }
 }
 
@@ -80,100 +221,102 @@ impl GraphEmbedder for TransE {
         (0..self.get_number_of_steps())
             .progress_with(pb)
             .for_each(|_| {
-                // We update the random state used to generate the random walks
-                // and the negative samples.
-                random_state = splitmix64(random_state);
-
-                // We iterate over the graph edges.
-                graph
-                    .par_iter_siamese_mini_batch_with_edge_types(
-                        random_state,
-                        graph.get_number_of_directed_edges() as usize,
-                    )
-                    .for_each(|(_, src, dst, not_src, not_dst, edge_type)| {
-                        let src = src as usize;
-                        let dst = dst as usize;
-                        let not_src = not_src as usize;
-                        let not_dst = not_dst as usize;
-                        let edge_type = edge_type.unwrap() as usize;
-                        let src_embedding = unsafe {
-                            &mut (*shared_embedding.get())[0]
-                                [(src * embedding_size)..((src + 1) * embedding_size)]
-                        };
-                        let not_src_embedding = unsafe {
-                            &mut (*shared_embedding.get())[0]
-                                [(not_src * embedding_size)..((not_src + 1) * embedding_size)]
-                        };
-                        let dst_embedding = unsafe {
-                            &mut (*shared_embedding.get())[0]
-                                [(dst * embedding_size)..((dst + 1) * embedding_size)]
-                        };
-                        let not_dst_embedding = unsafe {
-                            &mut (*shared_embedding.get())[0]
-                                [(not_dst * embedding_size)..((not_dst + 1) * embedding_size)]
-                        };
-                        let edge_type_embedding = unsafe {
-                            &mut (*shared_embedding.get())[1]
-                                [(edge_type * embedding_size)..((edge_type + 1) * embedding_size)]
-                        };
-
-                        let (dst_norm, not_dst_norm, src_norm, not_src_norm) = (
-                            vector_norm(dst_embedding),
-                            vector_norm(not_dst_embedding),
-                            vector_norm(src_embedding),
-                            vector_norm(not_src_embedding),
-                        );
-                        let node_priors: Vec<F> = get_node_priors(
-                            graph,
-                            &[
-                                src as NodeT,
-                                dst as NodeT,
-                                not_src as NodeT,
-                                not_dst as NodeT,
-                            ],
-                            learning_rate,
-                        );
-
-                        let edge_type_prior =
-                            get_edge_type_prior(graph, edge_type as EdgeTypeT, learning_rate);
-
-                        src_embedding
-                            .iter_mut()
-                            .zip(not_src_embedding.iter_mut())
-                            .zip(dst_embedding.iter_mut().zip(not_dst_embedding.iter_mut()))
-                            .zip(edge_type_embedding.iter_mut())
-                            .for_each(
-                                |(
-                                    (
-                                        (src_feature, not_src_feature),
-                                        (dst_feature, not_dst_feature),
-                                    ),
-                                    edge_type_feature,
-                                )| {
-                                    *src_feature /= src_norm;
-                                    *not_src_feature /= not_src_norm;
-                                    *dst_feature /= dst_norm;
-                                    *not_dst_feature /= not_dst_norm;
-
-                                    let positive_distance =
-                                        *src_feature + *edge_type_feature - *dst_feature;
-                                    let negative_distance =
-                                        *not_src_feature + *edge_type_feature - *not_dst_feature;
-                                    let loss = positive_distance.powf(F::one() + F::one())
-                                        - negative_distance.powf(F::one() + F::one());
-
-                                    if loss > -self.model.relu_bias.as_() {
-                                        *src_feature -= positive_distance * node_priors[0];
-                                        *dst_feature += positive_distance * node_priors[1];
-                                        *not_src_feature += negative_distance * node_priors[2];
-                                        *not_dst_feature -= negative_distance * node_priors[3];
-                                        *edge_type_feature -= (positive_distance
-                                            - negative_distance)
-                                            * edge_type_prior;
-                                    }
-                                },
+                // We iterate over the graph edges once for each negative sample.
+                (0..self.number_of_negative_samples).for_each(|_| {
+                    // We update the random state used to generate the negative samples.
+                    random_state = splitmix64(random_state);
+
+                    graph
+                        .par_iter_siamese_mini_batch_with_edge_types(
+                            random_state,
+                            graph.get_number_of_directed_edges() as usize,
+                        )
+                        .for_each(|(_, src, dst, not_src, not_dst, edge_type)| {
+                            let src = src as usize;
+                            let dst = dst as usize;
+                            let not_src = not_src as usize;
+                            let not_dst = not_dst as usize;
+                            let edge_type = edge_type.unwrap() as usize;
+                            let src_embedding = unsafe {
+                                &mut (*shared_embedding.get())[0]
+                                    [(src * embedding_size)..((src + 1) * embedding_size)]
+                            };
+                            let not_src_embedding = unsafe {
+                                &mut (*shared_embedding.get())[0]
+                                    [(not_src * embedding_size)..((not_src + 1) * embedding_size)]
+                            };
+                            let dst_embedding = unsafe {
+                                &mut (*shared_embedding.get())[0]
+                                    [(dst * embedding_size)..((dst + 1) * embedding_size)]
+                            };
+                            let not_dst_embedding = unsafe {
+                                &mut (*shared_embedding.get())[0]
+                                    [(not_dst * embedding_size)..((not_dst + 1) * embedding_size)]
+                            };
+                            let edge_type_embedding = unsafe {
+                                &mut (*shared_embedding.get())[1][(edge_type * embedding_size)
+                                    ..((edge_type + 1) * embedding_size)]
+                            };
+
+                            let (dst_norm, not_dst_norm, src_norm, not_src_norm) = (
+                                vector_norm(dst_embedding),
+                                vector_norm(not_dst_embedding),
+                                vector_norm(src_embedding),
+                                vector_norm(not_src_embedding),
+                            );
+                            let node_priors: Vec<F> = get_node_priors(
+                                graph,
+                                &[
+                                    src as NodeT,
+                                    dst as NodeT,
+                                    not_src as NodeT,
+                                    not_dst as NodeT,
+                                ],
+                                learning_rate,
                             );
-                    });
+
+                            let edge_type_prior =
+                                get_edge_type_prior(graph, edge_type as EdgeTypeT, learning_rate);
+
+                            src_embedding
+                                .iter_mut()
+                                .zip(not_src_embedding.iter_mut())
+                                .zip(dst_embedding.iter_mut().zip(not_dst_embedding.iter_mut()))
+                                .zip(edge_type_embedding.iter_mut())
+                                .for_each(
+                                    |(
+                                        (
+                                            (src_feature, not_src_feature),
+                                            (dst_feature, not_dst_feature),
+                                        ),
+                                        edge_type_feature,
+                                    )| {
+                                        *src_feature /= src_norm;
+                                        *not_src_feature /= not_src_norm;
+                                        *dst_feature /= dst_norm;
+                                        *not_dst_feature /= not_dst_norm;
+
+                                        let positive_distance =
+                                            *src_feature + *edge_type_feature - *dst_feature;
+                                        let negative_distance = *not_src_feature
+                                            + *edge_type_feature
+                                            - *not_dst_feature;
+                                        let loss = positive_distance.powf(F::one() + F::one())
+                                            - negative_distance.powf(F::one() + F::one());
+
+                                        if loss > -self.model.relu_bias.as_() {
+                                            *src_feature -= positive_distance * node_priors[0];
+                                            *dst_feature += positive_distance * node_priors[1];
+                                            *not_src_feature += negative_distance * node_priors[2];
+                                            *not_dst_feature -= negative_distance * node_priors[3];
+                                            *edge_type_feature -= (positive_distance
+                                                - negative_distance)
+                                                * edge_type_prior;
+                                        }
+                                    },
+                                );
+                        });
+                });
                 learning_rate *= self.model.get_learning_rate_decay().as_();
             });
         Ok(())
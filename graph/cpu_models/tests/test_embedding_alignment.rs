@@ -0,0 +1,111 @@
+use cpu_models::*;
+
+const EMBEDDING: &[[f32; 3]] = &[
+    [1.0, 0.0, 0.5],
+    [0.0, 2.0, -1.0],
+    [-1.5, 0.5, 0.0],
+    [0.3, -0.7, 1.2],
+    [2.0, 1.0, -0.5],
+    [-0.4, -1.1, -0.8],
+];
+
+/// Returns the rows of the embedding multiplied by the given matrix.
+fn multiply(embedding: &[[f32; 3]], matrix: &[[f32; 3]; 3]) -> Vec<Vec<f32>> {
+    embedding
+        .iter()
+        .map(|row| {
+            (0..3)
+                .map(|j| (0..3).map(|k| row[k] * matrix[k][j]).sum::<f32>())
+                .collect()
+        })
+        .collect()
+}
+
+fn norm(row: &[f32]) -> f32 {
+    row.iter().map(|value| value * value).sum::<f32>().sqrt()
+}
+
+fn assert_close(first: &[f32], second: &[f32]) {
+    assert!(
+        first
+            .iter()
+            .zip(second.iter())
+            .all(|(a, b)| (a - b).abs() < 1e-4),
+        "{:?} != {:?}",
+        first,
+        second
+    );
+}
+
+#[test]
+fn test_align_node_embeddings() -> Result<(), String> {
+    let identity = [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]];
+    let embedding_a = multiply(EMBEDDING, &identity);
+    let (sine, cosine) = (0.6, 0.8);
+    let rotation = [[cosine, -sine, 0.0], [sine, cosine, 0.0], [0.0, 0.0, 1.0]];
+    let reflection = [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, -1.0]];
+    let rotation_and_reflection = [[0.0, 0.0, 1.0], [cosine, -sine, 0.0], [sine, cosine, 0.0]];
+
+    // The rotations and reflections of the first embedding are recovered
+    // exactly when the anchors span all the dimensions.
+    for matrix in [identity, rotation, reflection, rotation_and_reflection] {
+        let embedding_b = multiply(EMBEDDING, &matrix);
+        let aligned = align_node_embeddings(&embedding_a, &embedding_b, &[0, 1, 2, 3])?;
+        assert_eq!(aligned.len(), embedding_b.len());
+        aligned
+            .iter()
+            .zip(embedding_a.iter())
+            .for_each(|(aligned_row, row)| assert_close(aligned_row, row));
+    }
+
+    // A single anchor is mapped on its counterpart, and the alignment
+    // is still orthogonal, hence it preserves the norms.
+    let embedding_b = multiply(EMBEDDING, &rotation_and_reflection);
+    let aligned = align_node_embeddings(&embedding_a, &embedding_b, &[4])?;
+    assert_close(&aligned[4], &embedding_a[4]);
+    aligned
+        .iter()
+        .zip(embedding_b.iter())
+        .for_each(|(aligned_row, row)| assert!((norm(aligned_row) - norm(row)).abs() < 1e-4));
+
+    // Anchors spanning a plane are aligned, while the remaining
+    // direction is completed to an orthogonal alignment.
+    let planar_embedding = [
+        [1.0, 0.0, 0.0],
+        [0.0, 1.0, 0.0],
+        [1.0, 1.0, 0.0],
+        [0.5, -0.5, 2.0],
+    ];
+    let embedding_a = multiply(&planar_embedding, &identity);
+    let embedding_b = multiply(&planar_embedding, &rotation);
+    let aligned = align_node_embeddings(&embedding_a, &embedding_b, &[0, 1, 2])?;
+    aligned
+        .iter()
+        .zip(embedding_a.iter())
+        .take(3)
+        .for_each(|(aligned_row, row)| assert_close(aligned_row, row));
+    assert!((norm(&aligned[3]) - norm(&embedding_b[3])).abs() < 1e-4);
+
+    // Null anchors carry no information, hence the embedding is left unchanged.
+    let mut embedding_a = multiply(EMBEDDING, &identity);
+    embedding_a[0] = vec![0.0; 3];
+    let embedding_b = multiply(EMBEDDING, &rotation);
+    let aligned = align_node_embeddings(&embedding_a, &embedding_b, &[0])?;
+    aligned
+        .iter()
+        .zip(embedding_b.iter())
+        .for_each(|(aligned_row, row)| assert_close(aligned_row, row));
+
+    Ok(())
+}
+
+#[test]
+fn test_align_node_embeddings_errors() {
+    let embedding = vec![vec![1.0, 0.0], vec![0.0, 1.0]];
+    assert!(align_node_embeddings(&[], &embedding, &[0]).is_err());
+    assert!(align_node_embeddings(&embedding, &[], &[0]).is_err());
+    assert!(align_node_embeddings(&[vec![], vec![]], &embedding, &[0]).is_err());
+    assert!(align_node_embeddings(&embedding, &[vec![1.0, 0.0], vec![1.0]], &[0]).is_err());
+    assert!(align_node_embeddings(&embedding, &embedding, &[]).is_err());
+    assert!(align_node_embeddings(&embedding, &embedding[..1], &[1]).is_err());
+}
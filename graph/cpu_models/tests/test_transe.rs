@@ -0,0 +1,72 @@
+use cpu_models::*;
+use graph::GraphBuilder;
+
+#[test]
+fn test_compute_transe_embedding() -> Result<(), String> {
+    let mut builder = GraphBuilder::new(None, Some(true));
+    for (src, dst, edge_type) in [
+        ("A", "B", "parent"),
+        ("B", "C", "parent"),
+        ("C", "D", "parent"),
+        ("A", "C", "grandparent"),
+        ("B", "D", "grandparent"),
+        ("E", "E", "self"),
+    ] {
+        builder.add_edge(src, dst, Some(edge_type.to_string()), None)?;
+    }
+    let graph = builder.build()?;
+
+    for number_of_negative_samples in [1, 3] {
+        let (node_embedding, edge_type_embedding) = graph.compute_transe_embedding(
+            Some(16),
+            Some(5),
+            None,
+            None,
+            Some(number_of_negative_samples),
+            Some(42),
+            Some(false),
+        )?;
+        // The rows are aligned with the node and edge type vocabularies.
+        assert_eq!(node_embedding.len(), 5);
+        assert_eq!(edge_type_embedding.len(), 3);
+        assert!(node_embedding
+            .iter()
+            .chain(edge_type_embedding.iter())
+            .all(|row| row.len() == 16 && row.iter().all(|value| value.is_finite())));
+    }
+
+    // A zero margin is a valid margin, while a negative one is not.
+    assert!(graph
+        .compute_transe_embedding(None, Some(1), None, Some(0.0), None, None, Some(false))
+        .is_ok());
+    assert!(graph
+        .compute_transe_embedding(None, Some(1), None, Some(-1.0), None, None, Some(false))
+        .is_err());
+
+    for (embedding_size, epochs, number_of_negative_samples) in [
+        (Some(0), None, None),
+        (None, Some(0), None),
+        (None, None, Some(0)),
+    ] {
+        assert!(graph
+            .compute_transe_embedding(
+                embedding_size,
+                epochs,
+                None,
+                None,
+                number_of_negative_samples,
+                None,
+                Some(false),
+            )
+            .is_err());
+    }
+
+    let mut builder = GraphBuilder::new(None, Some(true));
+    builder.add_edge("A", "B", None, None)?;
+    assert!(builder
+        .build()?
+        .compute_transe_embedding(None, None, None, None, None, None, Some(false))
+        .is_err());
+
+    Ok(())
+}
@@ -70,7 +70,7 @@ impl Node2Vec {
             (graph.get_number_of_nodes() as f32 / batch_size as f32).ceil() as usize;
         let learning_rate = learning_rate.unwrap_or(0.025);
         let mut walk_parameters = self.walk_parameters.clone();
-        let mut random_state = splitmix64(self.walk_parameters.get_random_state() as u64);
+        let mut random_state = splitmix64(self.walk_parameters.get_random_state());
         let random_walk_length = walk_parameters.get_random_walk_length() as usize;
         let iterations = walk_parameters.get_iterations() as usize;
         let actual_batch_size =
@@ -203,7 +203,7 @@ impl Node2Vec {
                 // We update the random state used to generate the random walks
                 // and the negative samples.
                 random_state = splitmix64(random_state);
-                walk_parameters = walk_parameters.set_random_state(Some(random_state as usize));
+                walk_parameters = walk_parameters.set_random_state(Some(random_state));
 
                 // We populate the vectors of the current training batch
 
@@ -0,0 +1,240 @@
+use super::*;
+use rayon::prelude::*;
+use std::collections::HashMap;
+
+/// Number of sums required to compute a Pearson correlation.
+const NUMBER_OF_CORRELATION_SUMS: usize = 6;
+
+/// Returns the sums required to compute the Pearson correlation updated with the given pair.
+///
+/// # Arguments
+/// * `sums`: [f64; 6] - The count, the sums, the sum of products and the sums of squares.
+/// * `x`: f64 - The first value of the pair.
+/// * `y`: f64 - The second value of the pair.
+fn update_correlation_sums(
+    mut sums: [f64; NUMBER_OF_CORRELATION_SUMS],
+    x: f64,
+    y: f64,
+) -> [f64; NUMBER_OF_CORRELATION_SUMS] {
+    sums[0] += 1.0;
+    sums[1] += x;
+    sums[2] += y;
+    sums[3] += x * y;
+    sums[4] += x * x;
+    sums[5] += y * y;
+    sums
+}
+
+/// Returns the element-wise sum of the two given correlation sums.
+///
+/// # Arguments
+/// * `first`: [f64; 6] - The first correlation sums.
+/// * `second`: [f64; 6] - The second correlation sums.
+fn merge_correlation_sums(
+    mut first: [f64; NUMBER_OF_CORRELATION_SUMS],
+    second: [f64; NUMBER_OF_CORRELATION_SUMS],
+) -> [f64; NUMBER_OF_CORRELATION_SUMS] {
+    first
+        .iter_mut()
+        .zip(second.iter())
+        .for_each(|(first, second)| *first += second);
+    first
+}
+
+/// Returns the Pearson correlation from the given sums.
+///
+/// # Arguments
+/// * `sums`: [f64; 6] - The count, the sums, the sum of products and the sums of squares.
+///
+/// # Implementative details
+/// When either of the two variables has zero variance, the correlation is not defined and NaN is returned.
+fn get_correlation_from_sums(sums: [f64; NUMBER_OF_CORRELATION_SUMS]) -> f64 {
+    let [count, x_sum, y_sum, product_sum, x_squared_sum, y_squared_sum] = sums;
+    let covariance = product_sum / count - (x_sum / count) * (y_sum / count);
+    let x_variance = x_squared_sum / count - (x_sum / count).powi(2);
+    let y_variance = y_squared_sum / count - (y_sum / count).powi(2);
+    if x_variance <= 0.0 || y_variance <= 0.0 {
+        return f64::NAN;
+    }
+    covariance / (x_variance * y_variance).sqrt()
+}
+
+/// # Assortativity
+impl Graph {
+    /// Returns the degree assortativity of the graph.
+    ///
+    /// # Implementative details
+    /// The degree assortativity is the Pearson correlation between the
+    /// degrees of the source and destination nodes of the directed edges.
+    /// In undirected graphs each edge is considered in both directions,
+    /// making the coefficient symmetric. The degrees used are the outbound
+    /// degrees. When all the nodes with edges have the same degree the
+    /// coefficient is not defined and NaN is returned.
+    ///
+    /// # References
+    /// [Assortative mixing in networks](https://arxiv.org/abs/cond-mat/0205405),
+    /// by M. E. J. Newman.
+    ///
+    /// # Example
+    ///```rust
+    /// # let graph = graph::test_utilities::load_ppi(true, true, true, true, false, false);
+    /// println!("The degree assortativity is {}.", graph.get_degree_assortativity().unwrap());
+    /// ```
+    ///
+    /// # Raises
+    /// * If the graph does not have edges.
+    pub fn get_degree_assortativity(&self) -> Result<f64> {
+        self.must_have_edges()?;
+        Ok(get_correlation_from_sums(
+            self.par_iter_directed_edge_node_ids()
+                .map(|(_, src, dst)| unsafe {
+                    (
+                        self.get_unchecked_node_degree_from_node_id(src) as f64,
+                        self.get_unchecked_node_degree_from_node_id(dst) as f64,
+                    )
+                })
+                .fold(
+                    || [0.0; NUMBER_OF_CORRELATION_SUMS],
+                    |sums, (x, y)| update_correlation_sums(sums, x, y),
+                )
+                .reduce(|| [0.0; NUMBER_OF_CORRELATION_SUMS], merge_correlation_sums),
+        ))
+    }
+
+    /// Returns the degree assortativity restricted to the edges touching each node type.
+    ///
+    /// # Implementative details
+    /// An edge is attributed to every node type of either of its endpoints,
+    /// each node type being counted once per edge even when both endpoints
+    /// share it. Endpoints with unknown node type do not contribute any node
+    /// type, so edges between two nodes with unknown node types are not
+    /// attributed to any node type, while an edge between a typed and an
+    /// untyped node is attributed to the node types of the typed one.
+    ///
+    /// The degrees are always computed on the whole graph, and the coefficient
+    /// of each node type is computed as in `get_degree_assortativity`.
+    /// Node types that are not attributed any edge are not included in the result,
+    /// while those with zero degree variance have a NaN coefficient.
+    ///
+    /// # Example
+    ///```rust
+    /// # let graph = graph::test_utilities::load_ppi(true, true, true, true, false, false);
+    /// for (node_type_id, assortativity) in graph.get_degree_assortativity_per_node_type().unwrap() {
+    ///     println!("Node type {} has degree assortativity {}.", node_type_id, assortativity);
+    /// }
+    /// ```
+    ///
+    /// # Raises
+    /// * If the graph does not have node types.
+    /// * If the graph does not have edges.
+    pub fn get_degree_assortativity_per_node_type(&self) -> Result<HashMap<NodeTypeT, f64>> {
+        self.must_have_node_types()?;
+        self.must_have_edges()?;
+        let number_of_node_types = self.get_number_of_node_types()? as usize;
+        Ok(self
+            .par_iter_directed_edge_node_ids()
+            .fold(
+                || vec![[0.0; NUMBER_OF_CORRELATION_SUMS]; number_of_node_types],
+                |mut sums, (_, src, dst)| unsafe {
+                    let x = self.get_unchecked_node_degree_from_node_id(src) as f64;
+                    let y = self.get_unchecked_node_degree_from_node_id(dst) as f64;
+                    let src_node_type_ids = self
+                        .get_unchecked_node_type_ids_from_node_id(src)
+                        .unwrap_or(&[]);
+                    let dst_node_type_ids = self
+                        .get_unchecked_node_type_ids_from_node_id(dst)
+                        .unwrap_or(&[]);
+                    src_node_type_ids
+                        .iter()
+                        .chain(
+                            dst_node_type_ids
+                                .iter()
+                                .filter(|node_type_id| !src_node_type_ids.contains(node_type_id)),
+                        )
+                        .for_each(|&node_type_id| {
+                            sums[node_type_id as usize] =
+                                update_correlation_sums(sums[node_type_id as usize], x, y);
+                        });
+                    sums
+                },
+            )
+            .reduce(
+                || vec![[0.0; NUMBER_OF_CORRELATION_SUMS]; number_of_node_types],
+                |first, second| {
+                    first
+                        .into_iter()
+                        .zip(second.into_iter())
+                        .map(|(first, second)| merge_correlation_sums(first, second))
+                        .collect()
+                },
+            )
+            .into_iter()
+            .enumerate()
+            .filter(|(_, sums)| sums[0] > 0.0)
+            .map(|(node_type_id, sums)| {
+                (node_type_id as NodeTypeT, get_correlation_from_sums(sums))
+            })
+            .collect())
+    }
+
+    /// Returns the assortativity of the graph with respect to the provided node attribute.
+    ///
+    /// # Arguments
+    /// * `node_attribute`: &[f64] - The scalar attribute of each node.
+    ///
+    /// # Implementative details
+    /// The attribute assortativity is the Pearson correlation between the
+    /// attributes of the source and destination nodes of the directed edges,
+    /// computed in a single pass over the edges. As for the degree assortativity,
+    /// in undirected graphs each edge is considered in both directions, making the
+    /// coefficient symmetric. When all the nodes with edges have the same attribute
+    /// the coefficient is not defined and NaN is returned.
+    ///
+    /// # References
+    /// [Mixing patterns in networks](https://arxiv.org/abs/cond-mat/0209450),
+    /// by M. E. J. Newman.
+    ///
+    /// # Example
+    ///```rust
+    /// # let graph = graph::test_utilities::load_ppi(true, true, true, true, false, false);
+    /// let node_attribute = graph.get_node_degrees().into_iter().map(|degree| degree as f64).collect::<Vec<f64>>();
+    /// let assortativity = graph.get_attribute_assortativity(&node_attribute).unwrap();
+    /// assert!((assortativity - graph.get_degree_assortativity().unwrap()).abs() < 1e-6);
+    /// ```
+    ///
+    /// # Raises
+    /// * If the graph does not have edges.
+    /// * If the provided attribute does not have a value for each node.
+    /// * If any of the provided attribute values is not finite.
+    pub fn get_attribute_assortativity(&self, node_attribute: &[f64]) -> Result<f64> {
+        self.must_have_edges()?;
+        if node_attribute.len() != self.get_number_of_nodes() as usize {
+            return Err(format!(
+                concat!(
+                    "The provided node attribute has length {}, ",
+                    "while the number of nodes in the current graph is {}."
+                ),
+                node_attribute.len(),
+                self.get_number_of_nodes()
+            ));
+        }
+        if let Some(node_id) = node_attribute
+            .par_iter()
+            .position_any(|value| !value.is_finite())
+        {
+            return Err(format!(
+                "The provided attribute of the node {} is not finite: {}.",
+                node_id, node_attribute[node_id]
+            ));
+        }
+        Ok(get_correlation_from_sums(
+            self.par_iter_directed_edge_node_ids()
+                .map(|(_, src, dst)| (node_attribute[src as usize], node_attribute[dst as usize]))
+                .fold(
+                    || [0.0; NUMBER_OF_CORRELATION_SUMS],
+                    |sums, (x, y)| update_correlation_sums(sums, x, y),
+                )
+                .reduce(|| [0.0; NUMBER_OF_CORRELATION_SUMS], merge_correlation_sums),
+        ))
+    }
+}
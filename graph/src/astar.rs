@@ -0,0 +1,158 @@
+use super::*;
+
+/// # A* shortest paths
+impl Graph {
+    /// Returns the shortest path between the given nodes computed with A* and the given heuristic.
+    ///
+    /// # Arguments
+    /// * `src_node_id`: NodeT - The source node ID.
+    /// * `dst_node_id`: NodeT - The destination node ID.
+    /// * `heuristic`: Vec<f64> - The estimate of the distance from each node to the destination node.
+    /// * `use_edge_weights`: Option<bool> - Whether to use the edge weights as distances. By default, false.
+    ///
+    /// # Implementative details
+    /// The nodes are explored by increasing sum of their distance from the source
+    /// and of their heuristic value, using the same queue of Dijkstra, and the
+    /// search stops as soon as the destination node is reached. When the edge
+    /// weights are not used, each edge has unit length, hence the heuristic should
+    /// estimate the number of hops. The path is optimal when the heuristic is
+    /// admissible, that is it never overestimates the distance to the destination
+    /// node, and nodes that are reached through a shorter path after being explored
+    /// are explored again, so the heuristic is not required to be consistent.
+    /// With the zero heuristic, this is equivalent to Dijkstra.
+    ///
+    /// The returned result has the predecessors and the distance of the destination
+    /// node, which is infinite when it cannot be reached. Since the search stops
+    /// early, the distances, the eccentricity and the total distances only refer
+    /// to the nodes reached before the destination node, and the distances of the
+    /// remaining nodes are infinite.
+    ///
+    /// # References
+    /// [A Formal Basis for the Heuristic Determination of Minimum Cost Paths](https://doi.org/10.1109/TSSC.1968.300136),
+    /// by Hart, Nilsson and Raphael.
+    ///
+    /// # Example
+    ///```rust
+    /// # let graph = graph::test_utilities::load_ppi(true, true, true, true, false, false);
+    /// let heuristic = vec![0.0; graph.get_number_of_nodes() as usize];
+    /// let astar = graph.get_astar_from_node_ids(0, 1, heuristic, Some(true)).unwrap();
+    /// let dijkstra = graph.get_dijkstra_from_node_ids(0, Some(1), None, None, None, None).unwrap();
+    /// let distance = dijkstra.get_distance_from_node_id(1).unwrap();
+    /// assert!((astar.get_distance_from_node_id(1).unwrap() - distance).abs() <= 1e-3 * distance);
+    /// ```
+    ///
+    /// # Raises
+    /// * If any of the given node IDs does not exist in the current graph.
+    /// * If the heuristic does not have a value for each node.
+    /// * If any of the heuristic values is negative or not finite.
+    /// * If the edge weights are requested but the graph does not have edge weights.
+    /// * If the edge weights are requested but the graph contains negative edge weights.
+    pub fn get_astar_from_node_ids(
+        &self,
+        src_node_id: NodeT,
+        dst_node_id: NodeT,
+        heuristic: Vec<f64>,
+        use_edge_weights: Option<bool>,
+    ) -> Result<ShortestPathsDjkstra> {
+        let src_node_id = self.validate_node_id(src_node_id)?;
+        let dst_node_id = self.validate_node_id(dst_node_id)?;
+        let number_of_nodes = self.get_number_of_nodes() as usize;
+        if heuristic.len() != number_of_nodes {
+            return Err(format!(
+                concat!(
+                    "The provided heuristic has length {}, ",
+                    "while the number of nodes in the current graph is {}."
+                ),
+                heuristic.len(),
+                number_of_nodes
+            ));
+        }
+        if let Some(node_id) = heuristic
+            .iter()
+            .position(|&value| !(value.is_finite() && value >= 0.0))
+        {
+            return Err(format!(
+                concat!(
+                    "The heuristic of the node {} must be a finite ",
+                    "non-negative value, but {} was provided."
+                ),
+                node_id, heuristic[node_id]
+            ));
+        }
+        let use_edge_weights = use_edge_weights.unwrap_or(false);
+        if use_edge_weights {
+            self.must_have_positive_edge_weights()?;
+        }
+
+        let mut distances = vec![f64::INFINITY; number_of_nodes];
+        let mut predecessors: Vec<Option<NodeT>> = vec![None; number_of_nodes];
+        distances[src_node_id as usize] = 0.0;
+        // The queue is ordered by the distance from the source plus the heuristic.
+        let mut priorities = vec![f64::INFINITY; number_of_nodes];
+        let mut nodes_to_explore: DijkstraQueue<f64> = DijkstraQueue::with_capacity_from_roots(
+            number_of_nodes,
+            vec![src_node_id],
+            &mut priorities,
+        );
+
+        while let Some(closest_node_id) = nodes_to_explore.pop() {
+            if closest_node_id as NodeT == dst_node_id {
+                break;
+            }
+            let closest_node_distance = distances[closest_node_id];
+            unsafe { self.iter_unchecked_edge_ids_from_source_node_id(closest_node_id as NodeT) }
+                .for_each(|edge_id| {
+                    let neighbour_node_id = unsafe {
+                        self.get_unchecked_destination_node_id_from_edge_id(edge_id as EdgeT)
+                    } as usize;
+                    let edge_length = if use_edge_weights {
+                        unsafe { self.get_unchecked_edge_weight_from_edge_id(edge_id as EdgeT) }
+                            .unwrap() as f64
+                    } else {
+                        1.0
+                    };
+                    let neighbour_distance = closest_node_distance + edge_length;
+                    if neighbour_distance < distances[neighbour_node_id] {
+                        distances[neighbour_node_id] = neighbour_distance;
+                        predecessors[neighbour_node_id] = Some(closest_node_id as NodeT);
+                        nodes_to_explore.push(
+                            neighbour_node_id,
+                            neighbour_distance + heuristic[neighbour_node_id],
+                        );
+                    }
+                });
+        }
+
+        let distances = distances
+            .into_iter()
+            .map(|distance| distance as f32)
+            .collect::<Vec<f32>>();
+        let (mut eccentricity, mut most_distant_node) = (0.0, src_node_id);
+        let (mut total_distance, mut total_harmonic_distance) = (0.0, 0.0);
+        distances
+            .iter()
+            .enumerate()
+            .filter(|(_, distance)| distance.is_finite())
+            .for_each(|(node_id, &distance)| {
+                if distance > eccentricity {
+                    eccentricity = distance;
+                    most_distant_node = node_id as NodeT;
+                }
+                total_distance += distance;
+                if distance > 0.0 {
+                    total_harmonic_distance += distance.recip();
+                }
+            });
+        let dst_node_distance = distances[dst_node_id as usize];
+        Ok(ShortestPathsDjkstra::new(
+            distances,
+            most_distant_node,
+            Some(predecessors),
+            Some(dst_node_distance),
+            eccentricity,
+            total_distance,
+            total_distance.ln(),
+            total_harmonic_distance,
+        ))
+    }
+}
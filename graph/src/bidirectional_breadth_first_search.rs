@@ -0,0 +1,98 @@
+use super::*;
+
+/// # Bidirectional breadth first search
+impl Graph {
+    /// Returns the number of hops on the shortest path between the given nodes, if any.
+    ///
+    /// # Arguments
+    /// * `src_node_id`: NodeT - The source node ID.
+    /// * `dst_node_id`: NodeT - The destination node ID.
+    ///
+    /// # Implementative details
+    /// Two breadth first searches are run at the same time from the source and
+    /// from the destination node, each time expanding a whole layer of the side
+    /// with the smaller frontier, and they stop as soon as their visited nodes
+    /// meet. The distance is the minimum, over the edges between the expanded
+    /// layer and the nodes visited by the other search, of the sum of the depths
+    /// of their endpoints plus one. This usually visits far fewer nodes than a
+    /// single breadth first search from the source node.
+    ///
+    /// Since the search from the destination node follows the edges backwards,
+    /// it is only supported on undirected graphs, where the edges can be followed
+    /// in both directions. The edge weights are ignored.
+    ///
+    /// # Example
+    ///```rust
+    /// # let graph = graph::test_utilities::load_ppi(true, true, true, false, false, false);
+    /// let distance = graph.get_bidirectional_distance_from_node_ids(0, 1).unwrap();
+    /// let bfs = graph.get_breadth_first_search_from_node_ids(0, None, None, None).unwrap();
+    /// assert_eq!(distance, Some(bfs.get_distance_from_node_id(1).unwrap()));
+    /// ```
+    ///
+    /// # Raises
+    /// * If any of the given node IDs does not exist in the current graph.
+    /// * If the graph is directed.
+    pub fn get_bidirectional_distance_from_node_ids(
+        &self,
+        src_node_id: NodeT,
+        dst_node_id: NodeT,
+    ) -> Result<Option<NodeT>> {
+        let src_node_id = self.validate_node_id(src_node_id)?;
+        let dst_node_id = self.validate_node_id(dst_node_id)?;
+        if self.is_directed() {
+            return Err(concat!(
+                "The bidirectional breadth first search requires to follow the edges ",
+                "backwards from the destination node, which is only supported on undirected graphs. ",
+                "Consider using the breadth first search from the source node instead."
+            )
+            .to_string());
+        }
+        if src_node_id == dst_node_id {
+            return Ok(Some(0));
+        }
+
+        let number_of_nodes = self.get_number_of_nodes() as usize;
+        // The depths of the nodes visited by the searches from the source and the destination.
+        let mut depths = [
+            vec![NODE_NOT_PRESENT; number_of_nodes],
+            vec![NODE_NOT_PRESENT; number_of_nodes],
+        ];
+        depths[0][src_node_id as usize] = 0;
+        depths[1][dst_node_id as usize] = 0;
+        let mut frontiers = [vec![src_node_id], vec![dst_node_id]];
+        let mut frontier_depths = [0, 0];
+
+        while !frontiers[0].is_empty() && !frontiers[1].is_empty() {
+            let side = if frontiers[0].len() <= frontiers[1].len() {
+                0
+            } else {
+                1
+            };
+            let other_side = 1 - side;
+            let mut distance: Option<NodeT> = None;
+            let mut next_frontier = Vec::new();
+            for &src in frontiers[side].iter() {
+                unsafe { self.iter_unchecked_neighbour_node_ids_from_source_node_id(src) }
+                    .for_each(|dst| {
+                        let other_depth = depths[other_side][dst as usize];
+                        if other_depth != NODE_NOT_PRESENT {
+                            let candidate_distance = frontier_depths[side] + 1 + other_depth;
+                            distance = Some(distance.map_or(candidate_distance, |distance| {
+                                distance.min(candidate_distance)
+                            }));
+                        }
+                        if depths[side][dst as usize] == NODE_NOT_PRESENT {
+                            depths[side][dst as usize] = frontier_depths[side] + 1;
+                            next_frontier.push(dst);
+                        }
+                    });
+            }
+            if distance.is_some() {
+                return Ok(distance);
+            }
+            frontiers[side] = next_frontier;
+            frontier_depths[side] += 1;
+        }
+        Ok(None)
+    }
+}
@@ -0,0 +1,185 @@
+use super::*;
+use crate::constructors::build_graph_from_integers;
+use rayon::prelude::*;
+
+/// # Bipartite projection
+impl Graph {
+    /// Returns the one-mode projection of the graph onto the provided side.
+    ///
+    /// # Arguments
+    /// * `side_node_ids`: &[NodeT] - The node IDs of the side to project the graph onto.
+    /// * `weight_by_shared_neighbours`: bool - Whether to weight each edge by the number of neighbours shared by its nodes.
+    ///
+    /// # Implementative details
+    /// The returned graph contains only the nodes of the provided side, with
+    /// their names and node types, and two of them are connected when they share
+    /// at least one neighbour in the current graph. As the side must be an
+    /// independent set, all the shared neighbours lie outside of it, while the
+    /// rest of the graph is not required to be bipartite. When requested, the
+    /// weight of each edge is the number of shared neighbours, otherwise the
+    /// returned graph is unweighted. Parallel edges are counted once, the edge
+    /// weights and edge types of the current graph are ignored and the returned
+    /// graph has no selfloops.
+    ///
+    /// # Example
+    ///```rust
+    /// # let graph = graph::test_utilities::load_ppi(true, true, true, true, false, false);
+    /// // The PPI graph is not bipartite, hence its nodes are not an independent set.
+    /// assert!(graph.get_bipartite_projection(&[0, 1, 2], false).is_err());
+    /// ```
+    ///
+    /// # Raises
+    /// * If the graph is directed.
+    /// * If no side node ID is provided.
+    /// * If any of the provided node IDs does not exist in the current graph.
+    /// * If any of the provided node IDs is duplicated.
+    /// * If any two of the provided nodes are connected, including through a selfloop.
+    pub fn get_bipartite_projection(
+        &self,
+        side_node_ids: &[NodeT],
+        weight_by_shared_neighbours: bool,
+    ) -> Result<Graph> {
+        self.must_be_undirected()?;
+        if side_node_ids.is_empty() {
+            return Err("The provided side node IDs are empty.".to_string());
+        }
+        let mut is_on_side = vec![false; self.get_number_of_nodes() as usize];
+        for &node_id in side_node_ids {
+            self.validate_node_id(node_id)?;
+            if is_on_side[node_id as usize] {
+                return Err(format!(
+                    "The provided side node ID {} is duplicated.",
+                    node_id
+                ));
+            }
+            is_on_side[node_id as usize] = true;
+        }
+        if let Some((src, dst)) = side_node_ids.par_iter().find_map_any(|&src| {
+            unsafe { self.iter_unchecked_unique_neighbour_node_ids_from_source_node_id(src) }
+                .find(|&dst| is_on_side[dst as usize])
+                .map(|dst| (src, dst))
+        }) {
+            return Err(format!(
+                concat!(
+                    "The provided side is not an independent set, as the nodes {} and {} ",
+                    "are both on the side and they are connected."
+                ),
+                src, dst
+            ));
+        }
+
+        let is_on_side = is_on_side.as_slice();
+        let number_of_nodes = self.get_number_of_nodes() as usize;
+        let projected_graph = build_graph_from_integers(
+            Some(
+                side_node_ids
+                    .par_iter()
+                    .copied()
+                    .map_init(
+                        || (vec![0 as WeightT; number_of_nodes], Vec::new()),
+                        |(shared_neighbours, touched), src| {
+                            unsafe {
+                                self.iter_unchecked_unique_neighbour_node_ids_from_source_node_id(
+                                    src,
+                                )
+                            }
+                            .for_each(|middle| unsafe {
+                                self.iter_unchecked_unique_neighbour_node_ids_from_source_node_id(
+                                    middle,
+                                )
+                                .filter(|&dst| dst != src && is_on_side[dst as usize])
+                                .for_each(|dst| {
+                                    if shared_neighbours[dst as usize] == 0.0 {
+                                        touched.push(dst);
+                                    }
+                                    shared_neighbours[dst as usize] += 1.0;
+                                });
+                            });
+                            touched.sort_unstable();
+                            touched
+                                .drain(..)
+                                .map(|dst| {
+                                    let weight = shared_neighbours[dst as usize];
+                                    shared_neighbours[dst as usize] = 0.0;
+                                    (
+                                        0,
+                                        (
+                                            src,
+                                            dst,
+                                            None,
+                                            if weight_by_shared_neighbours {
+                                                weight
+                                            } else {
+                                                WeightT::NAN
+                                            },
+                                        ),
+                                    )
+                                })
+                                .collect::<Vec<_>>()
+                        },
+                    )
+                    .flat_map_iter(|edges| edges.into_iter()),
+            ),
+            self.nodes.clone(),
+            self.node_types.clone(),
+            None,
+            weight_by_shared_neighbours,
+            false,
+            Some(true),
+            Some(false),
+            Some(false),
+            None,
+            true,
+            false,
+            format!("{} projection", self.get_name()),
+        )?;
+
+        // We only keep the nodes on the projected side.
+        projected_graph.filter_from_ids(
+            Some(side_node_ids.to_vec()),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+    }
+}
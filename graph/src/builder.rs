@@ -1,7 +1,7 @@
 use super::*;
 use rayon::iter::Empty as ParEmpty;
 use std::iter::Empty as SeqEmpty;
-use std::collections::{BTreeSet, BTreeMap};
+use std::collections::BTreeMap;
 use std::io::{BufWriter, Write};
 use std::fs::File;
 
@@ -11,7 +11,7 @@ pub struct Edge{
     pub dst: String, 
     pub edge_type: Option<String>, 
     pub weight: Option<WeightT>,
-};
+}
 
 impl PartialEq for Edge {
     fn eq(&self, other: &Self) -> bool {
@@ -41,14 +41,20 @@ impl Ord for Edge {
     }
 }
 
+/// An edge buffered in the builder, with the IDs of its nodes and edge type in the builder vocabularies.
+pub(crate) type BufferedEdge = (NodeT, NodeT, Option<EdgeTypeT>, Option<WeightT>);
+
 #[derive(Clone, Debug)]
 pub struct GraphBuilder {
-    pub(crate) edges: BTreeSet<Edge>,
+    pub(crate) edges: Vec<BufferedEdge>,
     pub(crate) nodes: BTreeMap<String, Option<Vec<String>>>,
+    pub(crate) nodes_vocabulary: Vocabulary<NodeT>,
+    pub(crate) edge_types_vocabulary: Vocabulary<EdgeTypeT>,
 
     pub(crate) has_node_types: bool,
     pub(crate) has_edge_types: bool,
     pub(crate) has_edge_weights: bool,
+    pub(crate) ignore_duplicated_edges: bool,
     pub(crate) directed: bool,
     pub(crate) name: String,
 
@@ -86,9 +92,12 @@ impl GraphBuilder {
             has_edge_weights: false,
             has_edge_types: false,
             has_node_types: false,
+            ignore_duplicated_edges: true,
 
             nodes: BTreeMap::new(),
-            edges: BTreeSet::new(),
+            edges: Vec::new(),
+            nodes_vocabulary: Vocabulary::new(true, "Nodes".to_string()),
+            edge_types_vocabulary: Vocabulary::new(true, "Edge types".to_string()),
 
             default_weight: 1.0,
         }
@@ -118,17 +127,40 @@ impl GraphBuilder {
         self.default_weight = default_weight;
     }
 
+    /// Set whether to ignore the duplicated edges or to raise an error when the graph is built
+    /// 
+    /// # Arguments
+    /// * `ignore_duplicated_edges`: bool - whether to keep only the first inserted of the duplicated edges, by default it's `true`
+    pub fn set_ignore_duplicated_edges(&mut self, ignore_duplicated_edges: bool) {
+        self.ignore_duplicated_edges = ignore_duplicated_edges;
+    }
+
     /// Add an edge to the graph
     /// 
+    /// The edge is appended to a buffer, and the names of its nodes and of its
+    /// edge type are inserted in the vocabularies of the builder, which assign
+    /// them an ID the first time they are seen, so that new nodes are added
+    /// transparently. Adding an edge therefore has an amortized constant cost.
+    ///
+    /// The IDs of the builder are only used to find the duplicated edges when
+    /// the graph is built: the graph itself is then built from the names of the
+    /// nodes and edge types, which are assigned new IDs and sorted once more,
+    /// so building the graph costs `O(E log E)` and needs the memory of both
+    /// the buffered edges and their names.
+    /// 
+    /// Two edges are duplicated when they have the same source, destination and
+    /// edge type, or the same nodes in the opposite direction in undirected graphs,
+    /// regardless of their weights.
+    /// 
     /// # Arguments
-    /// * `src`: String - The name of the source node
-    /// * `dst`: String - The name of the destination node
+    /// * `src`: S - The name of the source node
+    /// * `dst`: S - The name of the destination node
     /// * `edge_type`: Option<String> - The name of the edge_type, if present
     /// * `weight`: Option<WeightT> - The weight of the edge, if present
-    pub fn add_edge(
+    pub fn add_edge<S: AsRef<str>>(
         &mut self, 
-        src: String,
-        dst: String,
+        src: S,
+        dst: S,
         edge_type: Option<String>,
         weight: Option<WeightT>,
     ) -> Result<()> {
@@ -136,12 +168,16 @@ impl GraphBuilder {
             if !w.is_finite() {
                 return Err(format!("The weight {} is not a finite numnber!", w));
             }
-            self.has_edge_weights = true;
         }
-        if edge_type.is_some() {
-            self.has_edge_types = true;
-        }
-        self.edges.insert(Edge{src, dst, edge_type, weight});
+        let (src, _) = self.nodes_vocabulary.insert(src)?;
+        let (dst, _) = self.nodes_vocabulary.insert(dst)?;
+        let edge_type = match edge_type {
+            Some(edge_type) => Some(self.edge_types_vocabulary.insert(edge_type)?.0),
+            None => None,
+        };
+        self.has_edge_weights |= weight.is_some();
+        self.has_edge_types |= edge_type.is_some();
+        self.edges.push((src, dst, edge_type, weight));
         Ok(())
     }
 
@@ -164,7 +200,19 @@ impl GraphBuilder {
                 return Err(format!("The weight {} is not a finite numnber!", w));
             }
         }
-        self.edges.remove(&Edge{src, dst, edge_type, weight});
+        let edge_type = match edge_type {
+            Some(edge_type) => match self.edge_types_vocabulary.get(&edge_type) {
+                Some(edge_type) => Some(edge_type),
+                None => return Ok(()),
+            },
+            None => None,
+        };
+        if let (Some(src), Some(dst)) = (self.nodes_vocabulary.get(&src), self.nodes_vocabulary.get(&dst)) {
+            self.edges.retain(|&(edge_src, edge_dst, edge_edge_type, edge_weight)| {
+                !(edge_src == src && edge_dst == dst && edge_edge_type == edge_type
+                    && weight.zip(edge_weight).map(|(a, b)| a == b).unwrap_or(true))
+            });
+        }
         Ok(())
     }
 
@@ -193,7 +241,22 @@ impl GraphBuilder {
 
     /// Get a sorted iterator over the edges of the graph
     pub fn iter_edges(&self) -> impl Iterator<Item=Edge> + '_ {
-        self.edges.iter().cloned()
+        let mut edges = self.edges.iter().map(|&edge| self.get_edge_from_buffered_edge(edge)).collect::<Vec<Edge>>();
+        edges.sort();
+        edges.into_iter()
+    }
+
+    /// Returns the edge with the names of the given buffered edge.
+    ///
+    /// # Arguments
+    /// * `edge`: BufferedEdge - The edge with the IDs of its nodes and edge type
+    fn get_edge_from_buffered_edge(&self, (src, dst, edge_type, weight): BufferedEdge) -> Edge {
+        Edge {
+            src: self.nodes_vocabulary.unchecked_translate(src),
+            dst: self.nodes_vocabulary.unchecked_translate(dst),
+            edge_type: edge_type.map(|edge_type| self.edge_types_vocabulary.unchecked_translate(edge_type)),
+            weight,
+        }
     }
 
     /// Get a sorted iterator over the nodes of the graph
@@ -202,9 +265,37 @@ impl GraphBuilder {
     }
 
     /// Consume the edges and nodes to create a new graph.
+    /// 
+    /// # Raises
+    /// * If duplicated edges were added and they are not to be ignored.
     pub fn build(&mut self) -> Result<Graph> {
+        let directed = self.directed;
+        let get_edge_key = |&(src, dst, edge_type, _): &BufferedEdge| {
+            if directed || src <= dst { (src, dst, edge_type) } else { (dst, src, edge_type) }
+        };
+        // The sorting is stable, so the first inserted of the duplicated edges comes first.
+        self.edges.sort_by_key(get_edge_key);
+        if !self.ignore_duplicated_edges {
+            if let Some(duplicated_edges) = self.edges.windows(2).find(|edges| get_edge_key(&edges[0]) == get_edge_key(&edges[1])) {
+                let edge = self.get_edge_from_buffered_edge(duplicated_edges[1]);
+                return Err(format!(
+                    concat!(
+                        "The edge from {} to {} with edge type {:?} was added more than once. ",
+                        "If you want to keep only the first inserted one, ",
+                        "set the builder to ignore the duplicated edges."
+                    ),
+                    edge.src, edge.dst, edge.edge_type
+                ));
+            }
+        }
+        self.edges.dedup_by_key(|edge| get_edge_key(edge));
+
         let nodes = core::mem::replace(&mut self.nodes, BTreeMap::new());
-        let edges = core::mem::replace(&mut self.edges, BTreeSet::new());
+        // The edges are sorted again by the graph constructor, after their names are assigned new IDs.
+        let edges = self.edges.iter().map(|&edge| self.get_edge_from_buffered_edge(edge)).collect::<Vec<Edge>>();
+        self.edges = Vec::new();
+        self.nodes_vocabulary = Vocabulary::new(true, "Nodes".to_string());
+        self.edge_types_vocabulary = Vocabulary::new(true, "Edge types".to_string());
 
         let nodes_iterator = if nodes.is_empty() {
             None
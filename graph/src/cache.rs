@@ -1,4 +1,5 @@
 use super::*;
+use roaring::RoaringBitmap;
 
 #[derive(Debug, Clone)]
 pub(crate) struct PropertyCache {
@@ -23,6 +24,10 @@ pub(crate) struct PropertyCache {
     pub(crate) diameter: Option<Result<f32>>,
     pub(crate) is_connected: Option<bool>,
     pub(crate) is_multigraph: Option<bool>,
+    pub(crate) degree_entropy: Option<Result<f64>>,
+    pub(crate) degree_gini_coefficient: Option<Result<f64>>,
+    pub(crate) number_of_butterflies: Option<Result<EdgeT>>,
+    pub(crate) directed_acyclic_ancestors: Option<Result<Arc<Vec<RoaringBitmap>>>>,
 }
 
 impl Default for PropertyCache {
@@ -49,6 +54,10 @@ impl Default for PropertyCache {
             diameter: None,
             is_connected: None,
             is_multigraph: None,
+            degree_entropy: None,
+            degree_gini_coefficient: None,
+            number_of_butterflies: None,
+            directed_acyclic_ancestors: None,
         }
     }
 }
@@ -1,5 +1,5 @@
 use super::*;
-use atomic_float::AtomicF32;
+use atomic_float::{AtomicF32, AtomicF64};
 use indicatif::ParallelProgressIterator;
 use indicatif::ProgressIterator;
 use itertools::Itertools;
@@ -7,6 +7,7 @@ use num_traits::pow::Pow;
 use num_traits::Zero;
 use parallel_frontier::prelude::*;
 use rayon::iter::IndexedParallelIterator;
+use rayon::iter::IntoParallelIterator;
 use rayon::iter::IntoParallelRefIterator;
 use rayon::iter::{IntoParallelRefMutIterator, ParallelIterator};
 use std::cell::SyncUnsafeCell;
@@ -146,46 +147,96 @@ impl Graph {
         }
     }
 
-    /// Return parallel iterator over closeness centrality for all nodes.
+    /// Return closeness centrality for all nodes, corrected for disconnected graphs.
+    ///
+    /// # Arguments
+    /// * `use_edge_weights`: Option<bool> - Whether to use the edge weights as distances. By default, false.
+    /// * `verbose`: Option<bool> - Whether to show an indicative progress bar. By default, true.
+    ///
+    /// # Implementative details
+    /// The distances from each node are computed with a breadth first search, or
+    /// with Dijkstra when the edge weights are used, running the sources in parallel.
+    /// Since on disconnected graphs the total distance from a node is infinite, we
+    /// only consider the `r` nodes reachable from it and apply the Wasserman-Faust
+    /// correction, that is the closeness centrality is `r^2 / ((n - 1) d)`, where
+    /// `d` is the total distance to the reachable nodes and `n` is the number of
+    /// nodes. On connected graphs this is the usual normalized closeness centrality
+    /// `(n - 1) / d`, and nodes that cannot reach any other node have zero closeness.
     ///
     /// # References
-    /// The metric is described in [Centrality in Social Networks by Freeman](https://www.bebr.ufl.edu/sites/default/files/Centrality%20in%20Social%20Networks.pdf)
-    pub fn get_closeness_centrality(&self) -> Vec<f32> {
-        let visited: SyncUnsafeCell<Vec<Visited<u16>>> = SyncUnsafeCell::from(
-            (0..rayon::current_num_threads().max(1))
-                .map(|_| Visited::zero(self.get_number_of_nodes() as usize))
-                .collect::<Vec<Visited<u16>>>(),
+    /// The metric is described in [Centrality in Social Networks by Freeman](https://www.bebr.ufl.edu/sites/default/files/Centrality%20in%20Social%20Networks.pdf),
+    /// while the correction is described in [Social Network Analysis: Methods and Applications](https://doi.org/10.1017/CBO9780511815478),
+    /// by Wasserman and Faust.
+    ///
+    /// # Example
+    ///```rust
+    /// # let graph = graph::test_utilities::load_ppi(true, true, true, true, false, false);
+    /// let centralities = graph.get_closeness_centrality(None, Some(false)).unwrap();
+    /// assert!(centralities.iter().all(|&centrality| (0.0..=1.0).contains(&centrality)));
+    /// ```
+    ///
+    /// # Raises
+    /// * If the edge weights are requested but the graph does not have edge weights.
+    /// * If the edge weights are requested but the graph contains negative edge weights.
+    pub fn get_closeness_centrality(
+        &self,
+        use_edge_weights: Option<bool>,
+        verbose: Option<bool>,
+    ) -> Result<Vec<f64>> {
+        let use_edge_weights = use_edge_weights.unwrap_or(false);
+        if use_edge_weights {
+            self.must_have_positive_edge_weights()?;
+        }
+        let verbose = verbose.unwrap_or(true);
+        let pb = get_loading_bar(
+            verbose,
+            "Computing closeness centrality",
+            self.get_number_of_nodes() as usize,
         );
-        let mut centralities = vec![0.0; self.get_number_of_nodes() as usize];
-
-        centralities
-            .par_iter_mut()
-            .enumerate()
-            .for_each(move |(root, centrality)| {
-                let mut current_depth = 0;
-                let mut total_distance = 0;
-                let thread_id = rayon::current_thread_index().unwrap_or(0);
-                let mut frontier = vec![root as NodeT];
-                let visited = unsafe { &mut (*visited.get())[thread_id] };
-                visited.set_visited(root);
-                while !frontier.is_empty() {
-                    current_depth += 1;
-                    frontier = frontier
-                        .into_iter()
-                        .flat_map(|src| unsafe {
-                            self.iter_unchecked_neighbour_node_ids_from_source_node_id(src)
-                        })
-                        .filter(|&dst| !visited.set_and_get_visited(dst))
-                        .collect::<Vec<NodeT>>();
-
-                    total_distance += current_depth * frontier.len();
-                }
-                if !total_distance.is_zero() {
-                    *centrality = 1.0 / total_distance as f32;
+        let number_of_other_nodes = self.get_number_of_nodes().saturating_sub(1) as f64;
+        Ok(self
+            .par_iter_node_ids()
+            .progress_with(pb)
+            .map(|node_id| {
+                // The distances include the one from the node to itself, which is zero.
+                let (number_of_reachable_nodes, total_distance) = if use_edge_weights {
+                    unsafe {
+                        self.get_unchecked_dijkstra_from_node_id(
+                            node_id,
+                            None,
+                            None,
+                            Some(false),
+                            None,
+                            None,
+                        )
+                    }
+                    .into_iter_finite_distances()
+                    .fold((0, 0.0), |(count, total), distance| {
+                        (count + 1, total + distance as f64)
+                    })
+                } else {
+                    unsafe {
+                        self.get_unchecked_breadth_first_search_from_node_id(
+                            node_id,
+                            None,
+                            Some(false),
+                            None,
+                        )
+                    }
+                    .into_iter_finite_distances()
+                    .fold((0, 0.0), |(count, total), distance| {
+                        (count + 1, total + distance as f64)
+                    })
+                };
+                let number_of_reachable_nodes = (number_of_reachable_nodes - 1) as f64;
+                if total_distance > 0.0 {
+                    number_of_reachable_nodes * number_of_reachable_nodes
+                        / (number_of_other_nodes * total_distance)
+                } else {
+                    0.0
                 }
-                visited.clear();
-            });
-        centralities
+            })
+            .collect())
     }
 
     /// Return parallel iterator over closeness centrality for all nodes.
@@ -429,6 +480,167 @@ impl Graph {
             .map(|x| x.collect())
     }
 
+    /// Returns the number of nodes reachable from the given node, excluding the node itself.
+    ///
+    /// # Arguments
+    /// * `node_id`: NodeT - The node from which to start the breadth first search.
+    /// * `visited`: &mut Visited<u16> - The visited set to use, which is cleared before returning.
+    ///
+    /// # Safety
+    /// If the given node ID does not exist in the graph the method will panic.
+    pub(crate) unsafe fn get_unchecked_number_of_reachable_nodes_from_node_id(
+        &self,
+        node_id: NodeT,
+        visited: &mut Visited<u16>,
+    ) -> NodeT {
+        let mut number_of_reachable_nodes = 0;
+        let mut frontier = vec![node_id];
+        visited.set_visited(node_id as usize);
+        while !frontier.is_empty() {
+            frontier = frontier
+                .into_iter()
+                .flat_map(|src| self.iter_unchecked_neighbour_node_ids_from_source_node_id(src))
+                .filter(|&dst| !visited.set_and_get_visited(dst))
+                .collect::<Vec<NodeT>>();
+            number_of_reachable_nodes += frontier.len() as NodeT;
+        }
+        visited.clear();
+        number_of_reachable_nodes
+    }
+
+    /// Return vector of local reaching centrality for all nodes.
+    ///
+    /// # Implementative details
+    /// The local reaching centrality of a node is the number of nodes that
+    /// can be reached from it following the edge directions, normalized by the
+    /// number of nodes minus one. In directed acyclic graphs, the nodes with
+    /// high local reaching centrality are the upstream nodes that influence
+    /// most of the graph.
+    ///
+    /// In undirected graphs every node reaches its whole connected component,
+    /// hence the centrality is the size of the component of the node minus one,
+    /// normalized by the number of nodes minus one.
+    ///
+    /// # References
+    /// The metric is described in [Hierarchy Measure for Complex Networks](https://journals.plos.org/plosone/article?id=10.1371/journal.pone.0033799),
+    /// by Mones et al.
+    ///
+    /// # Example
+    ///```rust
+    /// # let graph = graph::test_utilities::load_ppi(true, true, true, true, false, false);
+    /// let centralities = graph.get_local_reaching_centrality();
+    /// assert_eq!(centralities.len(), graph.get_number_of_nodes() as usize);
+    /// ```
+    pub fn get_local_reaching_centrality(&self) -> Vec<f64> {
+        let visited: SyncUnsafeCell<Vec<Visited<u16>>> = SyncUnsafeCell::from(
+            (0..rayon::current_num_threads().max(1))
+                .map(|_| Visited::zero(self.get_number_of_nodes() as usize))
+                .collect::<Vec<Visited<u16>>>(),
+        );
+        let mut centralities = vec![0.0; self.get_number_of_nodes() as usize];
+        if self.get_number_of_nodes() < 2 {
+            return centralities;
+        }
+        let denominator = (self.get_number_of_nodes() - 1) as f64;
+
+        centralities
+            .par_iter_mut()
+            .enumerate()
+            .for_each(move |(node_id, centrality)| {
+                let thread_id = rayon::current_thread_index().unwrap_or(0);
+                let visited = unsafe { &mut (*visited.get())[thread_id] };
+                *centrality = unsafe {
+                    self.get_unchecked_number_of_reachable_nodes_from_node_id(
+                        node_id as NodeT,
+                        visited,
+                    )
+                } as f64
+                    / denominator;
+            });
+        centralities
+    }
+
+    /// Returns the number of ordered pairs of distinct nodes where the second is reachable from the first.
+    ///
+    /// # Arguments
+    /// * `number_of_samples`: Option<usize> - The number of source nodes to sample. By default, all the nodes are used as sources.
+    /// * `random_state`: Option<u64> - The random state to use to sample the source nodes. By default, 42.
+    ///
+    /// # Implementative details
+    /// The number of nodes reachable from each source node is computed with a
+    /// breadth-first search following the edge directions, and summed over the
+    /// source nodes. When the number of samples is provided, the source nodes
+    /// are sampled uniformly with replacement and the sum is scaled by the number
+    /// of nodes divided by the number of samples, which is an unbiased estimate.
+    ///
+    /// In undirected graphs, every node reaches exactly the other nodes of its
+    /// connected component, so the exact count, that is the sum of `s(s - 1)` over
+    /// the sizes `s` of the connected components, is returned without sampling.
+    ///
+    /// # Example
+    ///```rust
+    /// # let graph = graph::test_utilities::load_ppi(true, true, true, true, false, false);
+    /// let number_of_reachable_node_pairs = graph.get_number_of_reachable_node_pairs(None, None).unwrap();
+    /// let number_of_nodes = graph.get_number_of_nodes() as f64;
+    /// assert!(number_of_reachable_node_pairs <= number_of_nodes * (number_of_nodes - 1.0));
+    /// ```
+    ///
+    /// # Raises
+    /// * If the number of samples is zero.
+    pub fn get_number_of_reachable_node_pairs(
+        &self,
+        number_of_samples: Option<usize>,
+        random_state: Option<u64>,
+    ) -> Result<f64> {
+        if number_of_samples == Some(0) {
+            return Err("The number of samples must be strictly positive.".to_string());
+        }
+        if !self.has_nodes() {
+            return Ok(0.0);
+        }
+        if !self.is_directed() {
+            let mut component_sizes = vec![0 as EdgeT; self.get_number_of_nodes() as usize];
+            self.get_node_connected_component_ids(Some(false))
+                .into_iter()
+                .for_each(|component_id| {
+                    component_sizes[component_id as usize] += 1;
+                });
+            return Ok(component_sizes
+                .into_iter()
+                .map(|size| (size * size.saturating_sub(1)) as f64)
+                .sum());
+        }
+
+        let visited: SyncUnsafeCell<Vec<Visited<u16>>> = SyncUnsafeCell::from(
+            (0..rayon::current_num_threads().max(1))
+                .map(|_| Visited::zero(self.get_number_of_nodes() as usize))
+                .collect::<Vec<Visited<u16>>>(),
+        );
+        let count_reachable_nodes = |node_id: NodeT| {
+            let thread_id = rayon::current_thread_index().unwrap_or(0);
+            let visited = unsafe { &mut (*visited.get())[thread_id] };
+            (unsafe { self.get_unchecked_number_of_reachable_nodes_from_node_id(node_id, visited) })
+                as f64
+        };
+
+        Ok(match number_of_samples {
+            Some(number_of_samples) => {
+                self.par_iter_random_node_ids(
+                    number_of_samples,
+                    splitmix64(random_state.unwrap_or(42)),
+                )
+                .map(count_reachable_nodes)
+                .sum::<f64>()
+                    * self.get_number_of_nodes() as f64
+                    / number_of_samples as f64
+            }
+            None => self
+                .par_iter_node_ids()
+                .map(count_reachable_nodes)
+                .sum::<f64>(),
+        })
+    }
+
     /// Returns vector of stress centrality for all nodes.
     ///
     /// # Arguments
@@ -646,6 +858,14 @@ impl Graph {
     /// * `min_max_normalization`: Option<bool> - Whether to normalize the values between 0 and 1. By default, false.
     /// * `verbose`: Option<bool> - Whether to show a loading bar while computing the betweenness centrality. By default, true.
     ///
+    /// # Implementative details
+    /// The centrality is exact, as the dependencies of Brandes' algorithm are
+    /// accumulated from every root. The roots are processed one at a time, while
+    /// each breadth first search layer and the following dependency accumulation
+    /// are processed in parallel. On undirected graphs each pair of nodes is
+    /// counted once, hence the values are halved, and the edges normalization
+    /// divides them by `(n - 1)(n - 2)`, or by half of it on undirected graphs.
+    ///
     /// # References
     /// The algorithm is implemented as described in [Parallel Algorithms for Evaluating Centrality Indices in Real-World Networks](https://ieeexplore.ieee.org/abstract/document/1690659), by Bader et al.
     ///
@@ -885,6 +1105,114 @@ impl Graph {
         Ok(centralities)
     }
 
+    /// Returns vector of weighted betweenness centrality for all nodes.
+    ///
+    /// # Arguments
+    /// * `edges_normalization`: Option<bool> - Whether to normalize the values by the number of edges of the complete graph. By default, false.
+    /// * `verbose`: Option<bool> - Whether to show a loading bar while computing the betweenness centrality. By default, true.
+    ///
+    /// # Implementative details
+    /// The edge weights are treated as distances, and the shortest paths from
+    /// each root are computed with Dijkstra. Two paths are considered of equal
+    /// length when their total weights, accumulated in double precision, are
+    /// exactly equal: in this case both paths contribute to the shortest path
+    /// counts and to the dependencies of their nodes.
+    ///
+    /// # References
+    /// The algorithm is implemented as described in [A faster algorithm for betweenness centrality](https://www.tandfonline.com/doi/abs/10.1080/0022250X.2001.9990249), by Ulrik Brandes.
+    ///
+    /// # Raises
+    /// * If the graph does not have edge weights.
+    /// * If the graph contains non-positive edge weights.
+    /// * If the graph is a multigraph.
+    pub fn get_weighted_betweenness_centrality(
+        &self,
+        edges_normalization: Option<bool>,
+        verbose: Option<bool>,
+    ) -> Result<Vec<f32>> {
+        self.must_have_positive_edge_weights()?;
+        self.must_not_be_multigraph()?;
+        if !self.has_nodes() {
+            return Ok(Vec::new());
+        }
+        let edges_normalization = edges_normalization.unwrap_or(false);
+        let number_of_nodes = self.get_number_of_nodes() as usize;
+
+        let pb = get_loading_bar(
+            verbose.unwrap_or(true),
+            "Computing weighted betweennes centralities",
+            number_of_nodes,
+        );
+
+        let centralities: Vec<AtomicF64> =
+            (0..number_of_nodes).map(|_| AtomicF64::new(0.0)).collect();
+
+        self.par_iter_node_ids()
+            .progress_with(pb)
+            .for_each(|root| unsafe {
+                let mut distances = vec![f64::MAX; number_of_nodes];
+                let mut shortest_path_counts = vec![0.0; number_of_nodes];
+                let mut predecessors: Vec<Vec<NodeT>> = vec![Vec::new(); number_of_nodes];
+                let mut settled_nodes: Vec<NodeT> = Vec::new();
+                shortest_path_counts[root as usize] = 1.0;
+
+                let mut nodes_to_explore: DijkstraQueue<f64> =
+                    DijkstraQueue::with_capacity_from_roots(
+                        number_of_nodes,
+                        vec![root],
+                        &mut distances,
+                    );
+
+                while let Some(src) = nodes_to_explore.pop() {
+                    settled_nodes.push(src as NodeT);
+                    let src_distance = nodes_to_explore[src];
+                    self.iter_unchecked_neighbour_node_ids_from_source_node_id(src as NodeT)
+                        .zip(self.iter_unchecked_edge_weights_from_source_node_id(src as NodeT))
+                        .for_each(|(dst, weight)| {
+                            let dst = dst as usize;
+                            let new_distance = src_distance + weight as f64;
+                            if new_distance < nodes_to_explore[dst] {
+                                shortest_path_counts[dst] = shortest_path_counts[src];
+                                predecessors[dst].clear();
+                                predecessors[dst].push(src as NodeT);
+                                nodes_to_explore.push(dst, new_distance);
+                            } else if new_distance == nodes_to_explore[dst] {
+                                // This is a tie: another shortest path reaches
+                                // the destination node passing through this node.
+                                shortest_path_counts[dst] += shortest_path_counts[src];
+                                predecessors[dst].push(src as NodeT);
+                            }
+                        });
+                }
+
+                // We accumulate the dependencies in order of non-increasing distance.
+                let mut dependencies = vec![0.0; number_of_nodes];
+                settled_nodes.into_iter().rev().for_each(|dst| {
+                    let dst = dst as usize;
+                    let coefficient = (1.0 + dependencies[dst]) / shortest_path_counts[dst];
+                    predecessors[dst].iter().for_each(|&src| {
+                        dependencies[src as usize] +=
+                            shortest_path_counts[src as usize] * coefficient;
+                    });
+                    if dst != root as usize {
+                        centralities[dst].fetch_add(dependencies[dst], Ordering::Relaxed);
+                    }
+                });
+            });
+
+        let mut denominator = if self.is_directed() { 1.0 } else { 2.0 };
+        if edges_normalization {
+            denominator *= (number_of_nodes as f64 - 1.0) * (number_of_nodes as f64 - 2.0)
+                / if self.is_directed() { 1.0 } else { 2.0 };
+        }
+
+        let centralities = unsafe { std::mem::transmute::<Vec<AtomicF64>, Vec<f64>>(centralities) };
+        Ok(centralities
+            .par_iter()
+            .map(|&centrality| (centrality / denominator) as f32)
+            .collect())
+    }
+
     #[no_binding]
     /// Returns the unweighted pair dependency from the given node ID.
     ///
@@ -1470,4 +1798,657 @@ impl Graph {
             maximum_iterations_number
         ))
     }
+
+    /// Returns vector with the PageRank of each node for the given teleport distribution.
+    ///
+    /// # Arguments
+    /// * `damping_factor`: Option<f64> - The probability of following an edge instead of teleporting. By default, 0.85.
+    /// * `teleport_distribution`: Option<Vec<f64>> - The probability of teleporting to each node, summing to one. By default, the uniform distribution.
+    /// * `use_edge_weights`: bool - Whether the transition probabilities are proportional to the edge weights.
+    /// * `maximum_iterations_number`: Option<usize> - The maximum number of iterations to consider. By default, 1000.
+    /// * `tollerance`: Option<f64> - The maximum error tollerance for convergence. By default, 1e-6.
+    ///
+    /// # Raises
+    /// * If the damping factor is not in the interval [0, 1).
+    /// * If the tollerance is not a positive value.
+    /// * If convergence is not reached within the maximum number of iterations.
+    fn get_generic_pagerank(
+        &self,
+        damping_factor: Option<f64>,
+        teleport_distribution: Option<Vec<f64>>,
+        use_edge_weights: bool,
+        maximum_iterations_number: Option<usize>,
+        tollerance: Option<f64>,
+    ) -> Result<Vec<f64>> {
+        let damping_factor = damping_factor.unwrap_or(0.85);
+        let maximum_iterations_number = maximum_iterations_number.unwrap_or(1000);
+        let tollerance = tollerance.unwrap_or(1e-6);
+        if !(0.0..1.0).contains(&damping_factor) {
+            return Err(format!(
+                "The damping factor must be in the interval [0, 1), but {} was provided.",
+                damping_factor
+            ));
+        }
+        if tollerance <= 0.0 {
+            return Err(format!(
+                "The tollerance must be a strictly positive value, but {} was provided.",
+                tollerance
+            ));
+        }
+        let number_of_nodes = self.get_number_of_nodes() as usize;
+        let teleport_distribution = teleport_distribution
+            .unwrap_or_else(|| vec![1.0 / number_of_nodes as f64; number_of_nodes]);
+        let outbound_weights = self
+            .par_iter_node_ids()
+            .map(|node_id| unsafe {
+                if use_edge_weights {
+                    self.iter_unchecked_edge_weights_from_source_node_id(node_id)
+                        .map(|weight| weight as f64)
+                        .sum::<f64>()
+                } else {
+                    self.get_unchecked_node_degree_from_node_id(node_id) as f64
+                }
+            })
+            .collect::<Vec<f64>>();
+        let mut last_scores = teleport_distribution.clone();
+        for _ in 0..maximum_iterations_number {
+            let scores: Vec<AtomicF64> =
+                self.iter_node_ids().map(|_| AtomicF64::new(0.0)).collect();
+            let trap_mass = self
+                .par_iter_node_ids()
+                .map(|src| {
+                    let outbound_weight = outbound_weights[src as usize];
+                    if outbound_weight.is_zero() {
+                        return last_scores[src as usize];
+                    }
+                    let src_score = damping_factor * last_scores[src as usize] / outbound_weight;
+                    if use_edge_weights {
+                        unsafe {
+                            self.iter_unchecked_neighbour_node_ids_from_source_node_id(src)
+                                .zip(self.iter_unchecked_edge_weights_from_source_node_id(src))
+                        }
+                        .for_each(|(dst, weight)| {
+                            scores[dst as usize]
+                                .fetch_add(src_score * weight as f64, Ordering::Relaxed);
+                        });
+                    } else {
+                        unsafe { self.iter_unchecked_neighbour_node_ids_from_source_node_id(src) }
+                            .for_each(|dst| {
+                                scores[dst as usize].fetch_add(src_score, Ordering::Relaxed);
+                            });
+                    }
+                    0.0
+                })
+                .sum::<f64>();
+            let teleport = (1.0 - damping_factor) + damping_factor * trap_mass;
+            let updated_scores = scores
+                .into_par_iter()
+                .zip(teleport_distribution.par_iter())
+                .map(|(score, probability)| score.into_inner() + teleport * probability)
+                .collect::<Vec<f64>>();
+            let differences = updated_scores
+                .par_iter()
+                .zip(last_scores.par_iter())
+                .map(|(score, last_score)| (score - last_score).abs())
+                .sum::<f64>();
+            last_scores = updated_scores;
+            if differences < tollerance {
+                return Ok(last_scores);
+            }
+        }
+        Err(format!(
+            "Unable to reach convergence in {} iterations.",
+            maximum_iterations_number
+        ))
+    }
+
+    #[fuzz_type(maximum_iterations_number: Option<u8>)]
+    /// Returns vector with weighted PageRank.
+    ///
+    /// # Arguments
+    /// * `damping_factor`: Option<f64> - The probability of following an edge instead of teleporting. By default, 0.85.
+    /// * `maximum_iterations_number`: Option<usize> - The maximum number of iterations to consider. By default, 1000.
+    /// * `tollerance`: Option<f64> - The maximum error tollerance for convergence. By default, 1e-6.
+    ///
+    /// # Implementative details
+    /// The probability of moving from a node to one of its neighbours is
+    /// proportional to the weight of the edge connecting them. The teleport
+    /// distribution is uniform, and the mass of the trap nodes, which have no
+    /// outbound edges, is redistributed uniformly to all the nodes. The iteration
+    /// stops when the L1 norm of the difference between two successive score
+    /// vectors is below the tollerance, and the returned scores sum to one.
+    ///
+    /// # References
+    /// [The PageRank Citation Ranking: Bringing Order to the Web](http://ilpubs.stanford.edu:8090/422/),
+    /// by Page, Brin, Motwani and Winograd.
+    ///
+    /// # Raises
+    /// * If the graph does not have edge weights.
+    /// * If the graph contains non-positive edge weights.
+    /// * If the damping factor is not in the interval [0, 1).
+    /// * If the tollerance is not a positive value.
+    /// * If convergence is not reached within the maximum number of iterations.
+    pub fn get_weighted_pagerank(
+        &self,
+        damping_factor: Option<f64>,
+        maximum_iterations_number: Option<usize>,
+        tollerance: Option<f64>,
+    ) -> Result<Vec<f64>> {
+        self.must_have_positive_edge_weights()?;
+        self.get_generic_pagerank(
+            damping_factor,
+            None,
+            true,
+            maximum_iterations_number,
+            tollerance,
+        )
+    }
+
+    #[fuzz_type(maximum_iterations_number: Option<u8>)]
+    /// Returns vector with the PageRank of each node, optionally personalized.
+    ///
+    /// # Arguments
+    /// * `damping_factor`: Option<f64> - The probability of following an edge instead of teleporting. By default, 0.85.
+    /// * `personalization`: Option<Vec<(NodeT, f64)>> - The node IDs and weights of the teleport distribution. By default, the teleport distribution is uniform.
+    /// * `maximum_iterations_number`: Option<usize> - The maximum number of iterations to consider. By default, 1000.
+    /// * `tollerance`: Option<f64> - The maximum error tollerance for convergence. By default, 1e-6.
+    ///
+    /// # Implementative details
+    /// The probability of moving from a node to one of its neighbours is uniform
+    /// over its outbound edges, hence the edge weights are ignored and multi-edges
+    /// are counted according to their multiplicity. When the personalization is
+    /// provided, the teleport distribution is given by its weights normalized to
+    /// sum to one, where the weights of repeated node IDs are summed and the nodes
+    /// that are not specified have zero teleport probability. The mass of the trap
+    /// nodes, which have no outbound edges, is redistributed according to the
+    /// teleport distribution. The iteration stops when the L1 norm of the difference
+    /// between two successive score vectors is below the tollerance, and the
+    /// returned scores sum to one.
+    ///
+    /// # References
+    /// [The PageRank Citation Ranking: Bringing Order to the Web](http://ilpubs.stanford.edu:8090/422/),
+    /// by Page, Brin, Motwani and Winograd.
+    ///
+    /// # Example
+    ///```rust
+    /// # let graph = graph::test_utilities::load_ppi(true, true, true, true, false, false);
+    /// let scores = graph.get_pagerank(None, Some(vec![(0, 1.0)]), None, None).unwrap();
+    /// assert!((scores.iter().sum::<f64>() - 1.0).abs() < 1e-6);
+    /// ```
+    ///
+    /// # Raises
+    /// * If the graph does not have nodes.
+    /// * If any of the personalization node IDs does not exist in the current graph.
+    /// * If any of the personalization weights is negative or not finite.
+    /// * If the personalization weights do not have a strictly positive sum.
+    /// * If the damping factor is not in the interval [0, 1).
+    /// * If the tollerance is not a positive value.
+    /// * If convergence is not reached within the maximum number of iterations.
+    pub fn get_pagerank(
+        &self,
+        damping_factor: Option<f64>,
+        personalization: Option<Vec<(NodeT, f64)>>,
+        maximum_iterations_number: Option<usize>,
+        tollerance: Option<f64>,
+    ) -> Result<Vec<f64>> {
+        self.must_have_nodes()?;
+        let teleport_distribution = personalization
+            .map(|personalization| {
+                let mut teleport_distribution = vec![0.0; self.get_number_of_nodes() as usize];
+                for (node_id, weight) in personalization {
+                    self.validate_node_id(node_id)?;
+                    if !(weight.is_finite() && weight >= 0.0) {
+                        return Err(format!(
+                            concat!(
+                                "The personalization weight of the node {} must be ",
+                                "a finite non-negative value, but {} was provided."
+                            ),
+                            node_id, weight
+                        ));
+                    }
+                    teleport_distribution[node_id as usize] += weight;
+                }
+                let total_weight = teleport_distribution.iter().sum::<f64>();
+                if total_weight <= 0.0 {
+                    return Err(
+                        "The personalization weights must have a strictly positive sum."
+                            .to_string(),
+                    );
+                }
+                teleport_distribution
+                    .iter_mut()
+                    .for_each(|probability| *probability /= total_weight);
+                Ok(teleport_distribution)
+            })
+            .transpose()?;
+        self.get_generic_pagerank(
+            damping_factor,
+            teleport_distribution,
+            false,
+            maximum_iterations_number,
+            tollerance,
+        )
+    }
+
+    #[fuzz_type(maximum_iterations_number: Option<u8>)]
+    /// Returns vector with the Katz centrality of each node.
+    ///
+    /// # Arguments
+    /// * `attenuation_factor`: Option<f64> - The factor by which the contribution of each additional hop is attenuated. By default, half the reciprocal of the maximum node degree.
+    /// * `maximum_iterations_number`: Option<usize> - The maximum number of iterations to consider. By default, 1000.
+    /// * `tollerance`: Option<f64> - The maximum error tollerance for convergence. By default, 1e-6.
+    ///
+    /// # Implementative details
+    /// The Katz centrality is the fixpoint of `x = a A^T x + 1`, where `A` is the
+    /// adjacency matrix and `a` is the attenuation factor, that is the number of
+    /// walks of each length ending in the node, weighted by `a` to the power of
+    /// their length. The fixpoint is computed with the power iteration starting
+    /// from the zero vector, and the iteration stops when the L2 norm of the
+    /// difference between two successive score vectors is below the tollerance.
+    /// The returned scores are not normalized.
+    ///
+    /// The series converges when the attenuation factor is smaller than the
+    /// reciprocal of the spectral radius of the adjacency matrix, which is
+    /// bounded from above by the maximum node degree, hence the attenuation
+    /// factor is required to be smaller than the reciprocal of the maximum node
+    /// degree. The edge weights are ignored, while multi-edges are counted
+    /// according to their multiplicity.
+    ///
+    /// # References
+    /// [A new status index derived from sociometric analysis](https://doi.org/10.1007/BF02289026),
+    /// by Leo Katz.
+    ///
+    /// # Example
+    ///```rust
+    /// # let graph = graph::test_utilities::load_ppi(true, true, true, true, false, false);
+    /// let centralities = graph.get_katz_centrality(None, None, None).unwrap();
+    /// assert!(centralities.iter().all(|&centrality| centrality >= 1.0));
+    /// ```
+    ///
+    /// # Raises
+    /// * If the graph does not have nodes.
+    /// * If the attenuation factor is not strictly positive or not smaller than the reciprocal of the maximum node degree.
+    /// * If the tollerance is not a positive value.
+    /// * If convergence is not reached within the maximum number of iterations.
+    pub fn get_katz_centrality(
+        &self,
+        attenuation_factor: Option<f64>,
+        maximum_iterations_number: Option<usize>,
+        tollerance: Option<f64>,
+    ) -> Result<Vec<f64>> {
+        let maximum_node_degree = self.get_maximum_node_degree()? as f64;
+        let attenuation_factor = attenuation_factor.unwrap_or(0.5 / maximum_node_degree.max(1.0));
+        let maximum_iterations_number = maximum_iterations_number.unwrap_or(1000);
+        let tollerance = tollerance.unwrap_or(1e-6);
+        if !(attenuation_factor > 0.0 && attenuation_factor * maximum_node_degree < 1.0) {
+            return Err(format!(
+                concat!(
+                    "The attenuation factor must be strictly positive and smaller than ",
+                    "the reciprocal of the maximum node degree {}, but {} was provided."
+                ),
+                maximum_node_degree, attenuation_factor
+            ));
+        }
+        if tollerance <= 0.0 {
+            return Err(format!(
+                "The tollerance must be a strictly positive value, but {} was provided.",
+                tollerance
+            ));
+        }
+        let mut last_scores = vec![0.0; self.get_number_of_nodes() as usize];
+        for _ in 0..maximum_iterations_number {
+            let scores: Vec<AtomicF64> =
+                self.iter_node_ids().map(|_| AtomicF64::new(1.0)).collect();
+            self.par_iter_node_ids().for_each(|src| {
+                let src_score = attenuation_factor * last_scores[src as usize];
+                if src_score == 0.0 {
+                    return;
+                }
+                unsafe { self.iter_unchecked_neighbour_node_ids_from_source_node_id(src) }
+                    .for_each(|dst| {
+                        scores[dst as usize].fetch_add(src_score, Ordering::Relaxed);
+                    });
+            });
+            let updated_scores = scores
+                .into_par_iter()
+                .map(|score| score.into_inner())
+                .collect::<Vec<f64>>();
+            let difference = updated_scores
+                .par_iter()
+                .zip(last_scores.par_iter())
+                .map(|(score, last_score)| (score - last_score).powi(2))
+                .sum::<f64>()
+                .sqrt();
+            last_scores = updated_scores;
+            if difference < tollerance {
+                return Ok(last_scores);
+            }
+        }
+        Err(format!(
+            "Unable to reach convergence in {} iterations.",
+            maximum_iterations_number
+        ))
+    }
+
+    /// Returns vector with the subgraph centrality of each node.
+    ///
+    /// # Arguments
+    /// * `number_of_terms`: Option<usize> - The number of terms of the series after the constant one. By default, 20.
+    /// * `verbose`: Option<bool> - Whether to show a loading bar. By default, true.
+    ///
+    /// # Implementative details
+    /// The subgraph centrality of a node is the diagonal entry of the matrix
+    /// exponential of the adjacency matrix `exp(A) = sum_k A^k / k!`, that is
+    /// the number of closed walks of each length starting and ending on the node,
+    /// weighted by the inverse factorial of their length. For each node, the series
+    /// is truncated after `number_of_terms` terms and computed with repeated sparse
+    /// products starting from the node basis vector, which are restricted to the
+    /// nodes within `k` hops at step `k`. The cost is therefore proportional to the
+    /// number of nodes times the number of edges within `number_of_terms` hops.
+    ///
+    /// The truncation error of each centrality is bounded by the remainder of the
+    /// exponential series of the spectral radius `r` of the adjacency matrix, that
+    /// is at most `r^(K + 1) / (K + 1)! * exp(r)` for `K` terms, and the spectral radius
+    /// is at most the maximum node degree. The edge weights are ignored, while
+    /// multi-edges are counted according to their multiplicity.
+    ///
+    /// # References
+    /// [Subgraph centrality in complex networks](https://doi.org/10.1103/PhysRevE.71.056103),
+    /// by Ernesto Estrada and Juan A. Rodríguez-Velázquez.
+    ///
+    /// # Example
+    ///```rust
+    /// # let graph = graph::test_utilities::load_ppi(true, true, true, true, false, false);
+    /// let centralities = graph.get_subgraph_centrality(Some(5), Some(false)).unwrap();
+    /// assert!(centralities.iter().all(|&centrality| centrality >= 1.0));
+    /// ```
+    ///
+    /// # Raises
+    /// * If the graph does not have nodes.
+    pub fn get_subgraph_centrality(
+        &self,
+        number_of_terms: Option<usize>,
+        verbose: Option<bool>,
+    ) -> Result<Vec<f64>> {
+        self.must_have_nodes()?;
+        let number_of_terms = number_of_terms.unwrap_or(20);
+        let verbose = verbose.unwrap_or(true);
+        let number_of_nodes = self.get_number_of_nodes() as usize;
+        let pb = get_loading_bar(verbose, "Computing subgraph centrality", number_of_nodes);
+
+        Ok(self
+            .par_iter_node_ids()
+            .progress_with(pb)
+            .map_init(
+                || {
+                    (
+                        vec![0.0; number_of_nodes],
+                        vec![0.0; number_of_nodes],
+                        Vec::new(),
+                        Vec::new(),
+                    )
+                },
+                |(current, next, current_support, next_support), root| {
+                    current[root as usize] = 1.0;
+                    current_support.push(root);
+                    let mut centrality = 1.0;
+                    for k in 1..=number_of_terms {
+                        // Since all the values are positive, a value of zero
+                        // means that the node is not in the support yet.
+                        for &src in current_support.iter() {
+                            let value = current[src as usize] / k as f64;
+                            current[src as usize] = 0.0;
+                            unsafe {
+                                self.iter_unchecked_neighbour_node_ids_from_source_node_id(src)
+                            }
+                            .for_each(|dst| {
+                                if next[dst as usize] == 0.0 {
+                                    next_support.push(dst);
+                                }
+                                next[dst as usize] += value;
+                            });
+                        }
+                        std::mem::swap(current, next);
+                        std::mem::swap(current_support, next_support);
+                        next_support.clear();
+                        centrality += current[root as usize];
+                        if current_support.is_empty() {
+                            break;
+                        }
+                    }
+                    current_support.drain(..).for_each(|node_id| {
+                        current[node_id as usize] = 0.0;
+                    });
+                    centrality
+                },
+            )
+            .collect())
+    }
+
+    /// Returns the Estrada index of the graph.
+    ///
+    /// # Arguments
+    /// * `number_of_terms`: Option<usize> - The number of terms of the series after the constant one. By default, 20.
+    /// * `verbose`: Option<bool> - Whether to show a loading bar. By default, true.
+    ///
+    /// # Implementative details
+    /// The Estrada index is the trace of the matrix exponential of the adjacency
+    /// matrix, that is the sum of the subgraph centralities of all the nodes,
+    /// and it is subject to the same truncation error, summed over the nodes.
+    ///
+    /// # References
+    /// [Characterization of 3D molecular structure](https://doi.org/10.1016/S0009-2614(00)00158-5),
+    /// by Ernesto Estrada.
+    ///
+    /// # Raises
+    /// * If the graph does not have nodes.
+    pub fn get_estrada_index(
+        &self,
+        number_of_terms: Option<usize>,
+        verbose: Option<bool>,
+    ) -> Result<f64> {
+        Ok(self
+            .get_subgraph_centrality(number_of_terms, verbose)?
+            .into_par_iter()
+            .sum())
+    }
+
+    /// Returns the sums of the distances and of the inverse distances of each node from the sampled landmarks, and the number of landmarks reaching it.
+    ///
+    /// # Arguments
+    /// * `number_of_landmarks`: usize - The number of landmark nodes to sample.
+    /// * `random_state`: Option<u64> - The random state to use to sample the landmarks. By default, 42.
+    ///
+    /// # Implementative details
+    /// The landmarks are sampled uniformly without replacement, and all the nodes
+    /// are used when the number of landmarks is not smaller than the number of nodes.
+    /// The infinite distances are ignored, and the landmarks are not counted as
+    /// reaching themselves. The number of landmarks actually used is returned
+    /// together with the two sums and the counts.
+    ///
+    /// # Raises
+    /// * If the graph does not have nodes.
+    /// * If the graph is directed.
+    /// * If the number of landmarks is zero.
+    fn get_landmark_distance_sums(
+        &self,
+        number_of_landmarks: usize,
+        random_state: Option<u64>,
+    ) -> Result<(Vec<f64>, Vec<f64>, Vec<f64>, usize)> {
+        self.must_have_nodes()?;
+        self.must_be_undirected()?;
+        if number_of_landmarks == 0 {
+            return Err("The number of landmarks must be strictly positive.".to_string());
+        }
+        let number_of_nodes = self.get_number_of_nodes() as usize;
+        let number_of_landmarks = number_of_landmarks.min(number_of_nodes);
+
+        // We sample the landmarks with a partial Fisher-Yates shuffle.
+        let mut random_state = splitmix64(random_state.unwrap_or(42));
+        let mut node_ids = self.get_node_ids();
+        for i in 0..number_of_landmarks {
+            random_state = splitmix64(random_state);
+            let j = i + (random_state % (number_of_nodes - i) as u64) as usize;
+            node_ids.swap(i, j);
+        }
+        node_ids.truncate(number_of_landmarks);
+
+        let new_sums = || {
+            (
+                vec![0.0; number_of_nodes],
+                vec![0.0; number_of_nodes],
+                vec![0.0; number_of_nodes],
+            )
+        };
+        let (distance_sums, harmonic_sums, reachable_counts) = node_ids
+            .into_par_iter()
+            .fold(
+                new_sums,
+                |(mut distance_sums, mut harmonic_sums, mut reachable_counts), landmark_node_id| {
+                    unsafe {
+                        self.get_unchecked_breadth_first_search_from_node_id(
+                            landmark_node_id,
+                            None,
+                            Some(false),
+                            None,
+                        )
+                    }
+                    .into_distances()
+                    .into_iter()
+                    .enumerate()
+                    .filter(|&(_, distance)| distance != NODE_NOT_PRESENT && distance != 0)
+                    .for_each(|(node_id, distance)| {
+                        distance_sums[node_id] += distance as f64;
+                        harmonic_sums[node_id] += 1.0 / distance as f64;
+                        reachable_counts[node_id] += 1.0;
+                    });
+                    (distance_sums, harmonic_sums, reachable_counts)
+                },
+            )
+            .reduce(new_sums, |mut sums, other_sums| {
+                let add = |sums: &mut Vec<f64>, other_sums: Vec<f64>| {
+                    sums.iter_mut()
+                        .zip(other_sums.into_iter())
+                        .for_each(|(sum, other)| *sum += other);
+                };
+                add(&mut sums.0, other_sums.0);
+                add(&mut sums.1, other_sums.1);
+                add(&mut sums.2, other_sums.2);
+                sums
+            });
+        Ok((
+            distance_sums,
+            harmonic_sums,
+            reachable_counts,
+            number_of_landmarks,
+        ))
+    }
+
+    /// Returns the closeness centrality of all nodes approximated from the distances to sampled landmarks.
+    ///
+    /// # Arguments
+    /// * `number_of_landmarks`: usize - The number of landmark nodes from which to run a breadth first search.
+    /// * `random_state`: Option<u64> - The random state to use to sample the landmarks. By default, 42.
+    ///
+    /// # Implementative details
+    /// The closeness centrality is defined as in `get_closeness_centrality`, that is
+    /// `r^2 / ((n - 1) d)` with the Wasserman-Faust correction, where `r` is the number
+    /// of nodes reachable from a node and `d` is the total distance to them. Here both
+    /// `r` and `d` are estimated by running a breadth first search from `k` landmarks
+    /// sampled uniformly at random, and scaling the number of landmarks reaching
+    /// the node and the sum of their distances by `n / k`. This requires `k`
+    /// breadth first searches instead of `n`.
+    ///
+    /// Since each distance is at most the diameter `D`, by Hoeffding's inequality
+    /// using `k = O(log(n) / epsilon^2)` landmarks, the estimated average distance
+    /// of every node is within `epsilon D` of the exact one with high probability.
+    /// When the number of landmarks is not smaller than the number of nodes, all
+    /// the nodes are used and the result of `get_closeness_centrality` is returned.
+    /// The nodes with no finite distance from any landmark other than themselves
+    /// have zero centrality.
+    ///
+    /// # References
+    /// [Fast approximation of centrality](https://doi.org/10.7155/jgaa.00081),
+    /// by David Eppstein and Joseph Wang.
+    ///
+    /// # Example
+    ///```rust
+    /// # let graph = graph::test_utilities::load_ppi(true, true, true, true, false, false);
+    /// let centralities = graph.get_approximate_closeness_centrality(64, None).unwrap();
+    /// assert_eq!(centralities.len(), graph.get_number_of_nodes() as usize);
+    /// ```
+    ///
+    /// # Raises
+    /// * If the graph does not have nodes.
+    /// * If the graph is directed.
+    /// * If the number of landmarks is zero.
+    pub fn get_approximate_closeness_centrality(
+        &self,
+        number_of_landmarks: usize,
+        random_state: Option<u64>,
+    ) -> Result<Vec<f64>> {
+        let (distance_sums, _, reachable_counts, number_of_landmarks) =
+            self.get_landmark_distance_sums(number_of_landmarks, random_state)?;
+        let scale = self.get_number_of_nodes() as f64 / number_of_landmarks as f64;
+        let number_of_other_nodes = self.get_number_of_nodes().saturating_sub(1) as f64;
+        Ok(distance_sums
+            .into_par_iter()
+            .zip(reachable_counts.into_par_iter())
+            .map(|(distance_sum, reachable_count)| {
+                if distance_sum > 0.0 {
+                    let number_of_reachable_nodes = scale * reachable_count;
+                    number_of_reachable_nodes * number_of_reachable_nodes
+                        / (number_of_other_nodes * scale * distance_sum)
+                } else {
+                    0.0
+                }
+            })
+            .collect())
+    }
+
+    /// Returns the harmonic centrality of all nodes approximated from the distances to sampled landmarks.
+    ///
+    /// # Arguments
+    /// * `number_of_landmarks`: usize - The number of landmark nodes from which to run a breadth first search.
+    /// * `random_state`: Option<u64> - The random state to use to sample the landmarks. By default, 42.
+    ///
+    /// # Implementative details
+    /// The harmonic centrality of a node is the sum of the inverse of its distances
+    /// from the other nodes, as in `get_harmonic_centrality`, and it is estimated as
+    /// the sum of the inverse distances from `k` landmarks sampled uniformly at random,
+    /// scaled by `n / k`. Since each inverse distance is at most one, by Hoeffding's
+    /// inequality using `k = O(log(n) / epsilon^2)` landmarks the estimated centrality
+    /// divided by `n` is within `epsilon` of the exact one with high probability.
+    /// Differently from the closeness centrality, the estimate is well defined on
+    /// disconnected graphs. When the number of landmarks is not smaller than the
+    /// number of nodes, the exact harmonic centrality is returned.
+    ///
+    /// # References
+    /// [Fast approximation of centrality](https://doi.org/10.7155/jgaa.00081),
+    /// by David Eppstein and Joseph Wang.
+    ///
+    /// # Example
+    ///```rust
+    /// # let graph = graph::test_utilities::load_ppi(true, true, true, true, false, false);
+    /// let centralities = graph.get_approximate_harmonic_centrality(64, None).unwrap();
+    /// assert_eq!(centralities.len(), graph.get_number_of_nodes() as usize);
+    /// ```
+    ///
+    /// # Raises
+    /// * If the graph does not have nodes.
+    /// * If the graph is directed.
+    /// * If the number of landmarks is zero.
+    pub fn get_approximate_harmonic_centrality(
+        &self,
+        number_of_landmarks: usize,
+        random_state: Option<u64>,
+    ) -> Result<Vec<f64>> {
+        let (_, harmonic_sums, _, number_of_landmarks) =
+            self.get_landmark_distance_sums(number_of_landmarks, random_state)?;
+        let scale = self.get_number_of_nodes() as f64 / number_of_landmarks as f64;
+        Ok(harmonic_sums
+            .into_par_iter()
+            .map(|harmonic_sum| scale * harmonic_sum)
+            .collect())
+    }
 }
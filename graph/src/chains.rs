@@ -205,6 +205,119 @@ impl Chain {
     pub fn get_chain_node_names(&self) -> Vec<String> {
         self.par_iter_chain_node_names().collect()
     }
+
+    /// Return the neighbours of the given endpoint of the chain that anchor it.
+    ///
+    /// # Arguments
+    /// `endpoint_node_id`: NodeT - The endpoint of the chain.
+    /// `adjacent_node_id`: Option<NodeT> - The node of the chain adjacent to the endpoint, if any.
+    ///
+    /// # Safety
+    /// The node IDs must be among the node IDs present in the graph, or the method will panic.
+    unsafe fn get_unchecked_anchor_node_ids(
+        &self,
+        endpoint_node_id: NodeT,
+        adjacent_node_id: Option<NodeT>,
+    ) -> Vec<NodeT> {
+        self.graph
+            .iter_unchecked_unique_neighbour_node_ids_from_source_node_id(endpoint_node_id)
+            .filter(|&neighbour_node_id| {
+                neighbour_node_id != endpoint_node_id
+                    && Some(neighbour_node_id) != adjacent_node_id
+                    && self.graph.get_chain_node_degree(neighbour_node_id) > 2
+            })
+            .collect()
+    }
+
+    /// Return the node IDs of the chain, including the two anchor nodes at its endpoints.
+    ///
+    /// # Implementative details
+    /// The anchors are the nodes with degree higher than 2 that are adjacent to the
+    /// endpoints of the chain. The nodes are returned in order from the anchor with
+    /// the lower node ID to the one with the higher node ID. When the two anchors
+    /// are the same node, the nodes are returned starting from the endpoint with the
+    /// lower node ID. When the chain has a single anchor, it is returned first.
+    /// When the chain has no anchors and it forms an isolated cycle, the nodes are
+    /// returned starting from the minimum node ID and continuing towards its
+    /// neighbour with the lower node ID, while if it forms an isolated path they are
+    /// returned starting from the endpoint with the lower node ID.
+    pub fn get_chain_node_ids_with_endpoints(&self) -> Vec<NodeT> {
+        let mut node_ids = self.get_chain_node_ids();
+        let number_of_nodes = node_ids.len();
+        let first_node_id = node_ids[0];
+        let last_node_id = node_ids[number_of_nodes - 1];
+        let (first_anchor_node_id, last_anchor_node_id) = unsafe {
+            if number_of_nodes == 1 {
+                let anchor_node_ids = self.get_unchecked_anchor_node_ids(first_node_id, None);
+                (
+                    anchor_node_ids.get(0).copied(),
+                    anchor_node_ids.get(1).copied(),
+                )
+            } else {
+                (
+                    self.get_unchecked_anchor_node_ids(first_node_id, Some(node_ids[1]))
+                        .first()
+                        .copied(),
+                    self.get_unchecked_anchor_node_ids(
+                        last_node_id,
+                        Some(node_ids[number_of_nodes - 2]),
+                    )
+                    .first()
+                    .copied(),
+                )
+            }
+        };
+        match (first_anchor_node_id, last_anchor_node_id) {
+            (Some(first_anchor_node_id), Some(last_anchor_node_id)) => {
+                node_ids.insert(0, first_anchor_node_id);
+                node_ids.push(last_anchor_node_id);
+                if last_anchor_node_id < first_anchor_node_id
+                    || (last_anchor_node_id == first_anchor_node_id && last_node_id < first_node_id)
+                {
+                    node_ids.reverse();
+                }
+            }
+            (Some(first_anchor_node_id), None) => {
+                node_ids.insert(0, first_anchor_node_id);
+            }
+            (None, Some(last_anchor_node_id)) => {
+                node_ids.push(last_anchor_node_id);
+                node_ids.reverse();
+            }
+            (None, None) => {
+                if number_of_nodes > 2
+                    && self
+                        .graph
+                        .has_edge_from_node_ids(first_node_id, last_node_id)
+                {
+                    let (minimum_node_id_position, _) = node_ids
+                        .iter()
+                        .enumerate()
+                        .min_by_key(|&(_, &node_id)| node_id)
+                        .unwrap();
+                    node_ids.rotate_left(minimum_node_id_position);
+                    if node_ids[number_of_nodes - 1] < node_ids[1] {
+                        node_ids[1..].reverse();
+                    }
+                } else if last_node_id < first_node_id {
+                    node_ids.reverse();
+                }
+            }
+        }
+        node_ids
+    }
+
+    /// Return the node names of the chain, including the two anchor nodes at its endpoints.
+    ///
+    /// # Implementative details
+    /// The node names follow the same order of the node IDs
+    /// returned by `get_chain_node_ids_with_endpoints`.
+    pub fn get_chain_node_names_with_endpoints(&self) -> Vec<String> {
+        self.get_chain_node_ids_with_endpoints()
+            .into_par_iter()
+            .map(|node_id| unsafe { self.graph.get_unchecked_node_name_from_node_id(node_id) })
+            .collect()
+    }
 }
 
 impl Graph {
@@ -490,6 +490,198 @@ impl Graph {
         Ok(candidate_maximal_clique)
     }
 
+    /// Returns sorted unique neighbours of the given node that are in the given sorted set.
+    ///
+    /// # Arguments
+    /// `node_id`: NodeT - The node whose neighbours are to be filtered.
+    /// `node_ids`: &[NodeT] - The sorted set of nodes to intersect with the neighbours.
+    ///
+    /// # Safety
+    /// If the given node ID does not exist in the graph the method will panic.
+    unsafe fn get_unchecked_neighbours_intersection(
+        &self,
+        node_id: NodeT,
+        node_ids: &[NodeT],
+    ) -> Vec<NodeT> {
+        iter_set::intersection(
+            node_ids.iter().cloned(),
+            self.iter_unchecked_unique_neighbour_node_ids_from_source_node_id(node_id),
+        )
+        .filter(|&neighbour_node_id| neighbour_node_id != node_id)
+        .collect()
+    }
+
+    /// Extends the given clique with the Bron-Kerbosch algorithm with pivoting.
+    ///
+    /// # Arguments
+    /// `clique`: &mut Vec<NodeT> - The nodes in the current clique.
+    /// `candidates`: Vec<NodeT> - The sorted nodes that may extend the current clique.
+    /// `excluded`: Vec<NodeT> - The sorted nodes that may extend the current clique but were already explored.
+    /// `minimum_size`: usize - The minimum size of the cliques to return.
+    /// `maximum_size`: usize - The size over which the cliques are not expanded.
+    /// `cliques`: &mut Vec<Vec<NodeT>> - The vector where to push the found cliques.
+    fn extend_max_cliques(
+        &self,
+        clique: &mut Vec<NodeT>,
+        mut candidates: Vec<NodeT>,
+        mut excluded: Vec<NodeT>,
+        minimum_size: usize,
+        maximum_size: usize,
+        cliques: &mut Vec<Vec<NodeT>>,
+    ) {
+        // If the clique cannot reach the minimum size, we can prune this branch.
+        if clique.len() + candidates.len() < minimum_size {
+            return;
+        }
+        // If the clique is maximal or has reached the maximum size, we store it.
+        if candidates.is_empty() && excluded.is_empty() || clique.len() == maximum_size {
+            let mut clique = clique.clone();
+            clique.sort_unstable();
+            cliques.push(clique);
+            return;
+        }
+        // We choose as pivot the node with most neighbours among the candidates,
+        // as its neighbours do not need to be explored from this branch.
+        let pivot_neighbours = candidates
+            .iter()
+            .chain(excluded.iter())
+            .map(|&node_id| unsafe {
+                self.get_unchecked_neighbours_intersection(node_id, &candidates)
+            })
+            .max_by_key(|neighbours| neighbours.len())
+            .unwrap_or_else(Vec::new);
+        let branch_node_ids =
+            iter_set::difference(candidates.iter().cloned(), pivot_neighbours.into_iter())
+                .collect::<Vec<NodeT>>();
+        for node_id in branch_node_ids {
+            clique.push(node_id);
+            self.extend_max_cliques(
+                clique,
+                unsafe { self.get_unchecked_neighbours_intersection(node_id, &candidates) },
+                unsafe { self.get_unchecked_neighbours_intersection(node_id, &excluded) },
+                minimum_size,
+                maximum_size,
+                cliques,
+            );
+            clique.pop();
+            candidates.retain(|&candidate| candidate != node_id);
+            let position = excluded.binary_search(&node_id).unwrap_or_else(|x| x);
+            excluded.insert(position, node_id);
+        }
+    }
+
+    /// Returns the maximal cliques of the graph with size within the given bounds.
+    ///
+    /// # Arguments
+    /// `minimum_size`: NodeT - The minimum number of nodes of the cliques to return.
+    /// `maximum_size`: Option<NodeT> - The number of nodes over which the cliques are not expanded. By default, the cliques are not bounded.
+    ///
+    /// # Implementative details
+    /// The cliques are enumerated with the Bron-Kerbosch algorithm with pivoting,
+    /// where the branches of the recursion whose clique cannot reach `minimum_size`
+    /// nodes are pruned. The nodes with less than `minimum_size - 1` neighbours
+    /// are dropped before starting, as they cannot be part of such cliques.
+    /// The branches starting from the candidates of the first pivot are explored in parallel.
+    /// When a clique reaches `maximum_size` nodes it is returned without expanding it
+    /// further, hence such cliques may not be maximal, and a larger maximal clique
+    /// is returned as some of its subsets of `maximum_size` nodes.
+    /// Selfloops and multi-edges are ignored, and the node IDs of each clique are sorted.
+    ///
+    /// # References
+    /// [Algorithm 457: finding all cliques of an undirected graph](https://doi.org/10.1145/362342.362367),
+    /// by Bron and Kerbosch.
+    /// [The worst-case time complexity for generating all maximal cliques and computational experiments](https://doi.org/10.1016/j.tcs.2006.06.015),
+    /// by Tomita, Tanaka and Takahashi.
+    ///
+    /// # Example
+    ///```rust
+    /// # let graph = graph::test_utilities::load_ppi(true, true, true, false, false, false);
+    /// let cliques = graph.get_max_cliques(3, Some(5)).unwrap();
+    /// assert!(cliques.iter().all(|clique| clique.len() >= 3 && clique.len() <= 5));
+    /// ```
+    ///
+    /// # Raises
+    /// * If the current graph is directed. Directed graphs can be symmetrized with `to_undirected`.
+    /// * If the maximum size is smaller than the minimum size.
+    pub fn get_max_cliques(
+        &self,
+        minimum_size: NodeT,
+        maximum_size: Option<NodeT>,
+    ) -> Result<Vec<Clique>> {
+        if self.is_directed() {
+            return Err(concat!(
+                "The maximal cliques can only be computed on undirected graphs. ",
+                "You can symmetrize the current directed graph with the `to_undirected` method."
+            )
+            .to_string());
+        }
+        let maximum_size = maximum_size.unwrap_or(NodeT::MAX);
+        if maximum_size < minimum_size {
+            return Err(format!(
+                concat!(
+                    "The given maximum size {} is smaller than ",
+                    "the given minimum size {}."
+                ),
+                maximum_size, minimum_size
+            ));
+        }
+        let (minimum_size, maximum_size) = (minimum_size as usize, maximum_size as usize);
+        // The nodes with less than `minimum_size - 1` neighbours cannot be
+        // part of a clique with at least `minimum_size` nodes.
+        let candidates = self
+            .par_iter_node_ids()
+            .filter(|&node_id| {
+                unsafe {
+                    self.iter_unchecked_unique_neighbour_node_ids_from_source_node_id(node_id)
+                }
+                .filter(|&neighbour_node_id| neighbour_node_id != node_id)
+                .take(minimum_size.saturating_sub(1))
+                .count()
+                    == minimum_size.saturating_sub(1)
+            })
+            .collect::<Vec<NodeT>>();
+        let pivot_neighbours = candidates
+            .par_iter()
+            .map(|&node_id| unsafe {
+                self.get_unchecked_neighbours_intersection(node_id, &candidates)
+            })
+            .max_by_key(|neighbours| neighbours.len())
+            .unwrap_or_else(Vec::new);
+        let branch_node_ids =
+            iter_set::difference(candidates.iter().cloned(), pivot_neighbours.into_iter())
+                .collect::<Vec<NodeT>>();
+        // Each branch excludes the candidates explored by the previous branches,
+        // so that the branches are independent and can be explored in parallel.
+        Ok(branch_node_ids
+            .par_iter()
+            .enumerate()
+            .flat_map(|(i, &node_id)| {
+                let previous_branch_node_ids = &branch_node_ids[..i];
+                let branch_candidates =
+                    unsafe { self.get_unchecked_neighbours_intersection(node_id, &candidates) }
+                        .into_iter()
+                        .filter(|candidate| {
+                            previous_branch_node_ids.binary_search(candidate).is_err()
+                        })
+                        .collect::<Vec<NodeT>>();
+                let excluded = unsafe {
+                    self.get_unchecked_neighbours_intersection(node_id, previous_branch_node_ids)
+                };
+                let mut cliques = Vec::new();
+                self.extend_max_cliques(
+                    &mut vec![node_id],
+                    branch_candidates,
+                    excluded,
+                    minimum_size,
+                    maximum_size,
+                    &mut cliques,
+                );
+                cliques
+            })
+            .map(|clique| Clique::from_node_ids(self, clique))
+            .collect())
+    }
+
     /// Returns number of graph cliques with at least `minimum_degree` nodes.
     ///
     /// # Arguments
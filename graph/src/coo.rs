@@ -1,6 +1,7 @@
 use super::*;
 use hashbrown::HashMap;
 use num_traits::Zero;
+use rayon::iter::Either;
 use rayon::prelude::*;
 
 impl Graph {
@@ -107,6 +108,54 @@ impl Graph {
         self.get_graph_from_coo_iterator(self.par_iter_jaccard_coo_matrix())
     }
 
+    /// Returns parallel iterator on Sørensen-Dice index COO matrix.
+    pub fn par_iter_sorensen_index_coo_matrix(
+        &self,
+    ) -> impl ParallelIterator<Item = (NodeT, NodeT, WeightT)> + '_ {
+        self.par_iter_transformed_coo_matrix(self, |support, src, dst| unsafe {
+            support.get_unchecked_sorensen_index_from_node_ids(src, dst) as WeightT
+        })
+    }
+
+    /// Returns Sørensen-Dice index coo matrix.
+    pub fn get_sorensen_index_coo_matrix(&self) -> (Vec<(NodeT, NodeT)>, Vec<WeightT>) {
+        self.par_iter_sorensen_index_coo_matrix()
+            .map(|(src, dst, weight)| ((src, dst), weight))
+            .unzip()
+    }
+
+    /// Returns parallel iterator on Hub Promoted index COO matrix.
+    pub fn par_iter_hub_promoted_index_coo_matrix(
+        &self,
+    ) -> impl ParallelIterator<Item = (NodeT, NodeT, WeightT)> + '_ {
+        self.par_iter_transformed_coo_matrix(self, |support, src, dst| unsafe {
+            support.get_unchecked_hub_promoted_index_from_node_ids(src, dst) as WeightT
+        })
+    }
+
+    /// Returns Hub Promoted index coo matrix.
+    pub fn get_hub_promoted_index_coo_matrix(&self) -> (Vec<(NodeT, NodeT)>, Vec<WeightT>) {
+        self.par_iter_hub_promoted_index_coo_matrix()
+            .map(|(src, dst, weight)| ((src, dst), weight))
+            .unzip()
+    }
+
+    /// Returns parallel iterator on Hub Depressed index COO matrix.
+    pub fn par_iter_hub_depressed_index_coo_matrix(
+        &self,
+    ) -> impl ParallelIterator<Item = (NodeT, NodeT, WeightT)> + '_ {
+        self.par_iter_transformed_coo_matrix(self, |support, src, dst| unsafe {
+            support.get_unchecked_hub_depressed_index_from_node_ids(src, dst) as WeightT
+        })
+    }
+
+    /// Returns Hub Depressed index coo matrix.
+    pub fn get_hub_depressed_index_coo_matrix(&self) -> (Vec<(NodeT, NodeT)>, Vec<WeightT>) {
+        self.par_iter_hub_depressed_index_coo_matrix()
+            .map(|(src, dst, weight)| ((src, dst), weight))
+            .unzip()
+    }
+
     /// Returns parallel iterator on neighbours intersection size COO matrix.
     pub fn par_iter_neighbours_intersection_size_coo_matrix(
         &self,
@@ -222,6 +271,186 @@ impl Graph {
         self.get_graph_from_coo_iterator(self.par_iter_adamic_adar_coo_matrix())
     }
 
+    /// Returns parallel iterator on the pairs of distinct nodes reached through the neighbours of the source nodes.
+    ///
+    /// # Arguments
+    /// * `extend_destinations`: F - The closure adding to the given vector the destinations reached through the given neighbour.
+    ///
+    /// # Implementative details
+    /// For each source node, the closure is called on each of its unique
+    /// neighbours, and the collected destinations are returned deduplicated,
+    /// sorted by increasing node ID and excluding the source node itself.
+    fn par_iter_node_id_pairs_from_neighbours<'a, F>(
+        &'a self,
+        extend_destinations: F,
+    ) -> impl ParallelIterator<Item = (NodeT, NodeT)> + 'a
+    where
+        F: Fn(NodeT, &mut Vec<NodeT>) + Send + Sync + 'a,
+    {
+        self.par_iter_node_ids()
+            .map(move |src| {
+                let mut destinations = Vec::new();
+                unsafe { self.iter_unchecked_unique_neighbour_node_ids_from_source_node_id(src) }
+                    .for_each(|middle| extend_destinations(middle, &mut destinations));
+                destinations.sort_unstable();
+                destinations.dedup();
+                destinations
+                    .into_iter()
+                    .filter(move |&dst| dst != src)
+                    .map(move |dst| (src, dst))
+                    .collect::<Vec<(NodeT, NodeT)>>()
+            })
+            .flat_map_iter(|pairs| pairs.into_iter())
+    }
+
+    /// Returns parallel iterator on the pairs of distinct nodes at distance at most two.
+    ///
+    /// # Implementative details
+    /// For each source node, the destinations are its neighbours and the
+    /// neighbours of its neighbours, excluding the source node itself, and
+    /// are returned sorted by increasing node ID. In directed graphs the
+    /// distance follows the direction of the edges.
+    fn par_iter_distance_two_node_id_pairs(
+        &self,
+    ) -> impl ParallelIterator<Item = (NodeT, NodeT)> + '_ {
+        self.par_iter_node_id_pairs_from_neighbours(move |middle, destinations| {
+            destinations.push(middle);
+            destinations.extend(unsafe {
+                self.iter_unchecked_unique_neighbour_node_ids_from_source_node_id(middle)
+            });
+        })
+    }
+
+    /// Returns preferential attachment coo matrix, that is the product of the node degrees.
+    ///
+    /// # Arguments
+    /// * `candidate_pairs`: Option<&[(NodeT, NodeT)]> - The pairs of node IDs to score. By default, all the pairs of distinct nodes at distance at most two.
+    ///
+    /// # Implementative details
+    /// When the candidate pairs are not provided, for each source node the
+    /// destinations are its neighbours and the neighbours of its neighbours,
+    /// excluding the source node itself, sorted by increasing node ID. As for
+    /// the other similarity COO matrices, in undirected graphs both the pairs
+    /// `(src, dst)` and `(dst, src)` are returned. In directed graphs the
+    /// distance follows the direction of the edges. The provided candidate
+    /// pairs are instead scored in the given order. The scores are not normalized.
+    ///
+    /// # Example
+    ///```rust
+    /// # let graph = graph::test_utilities::load_ppi(true, true, true, true, false, false);
+    /// let (pairs, scores) = graph.get_preferential_attachment_coo_matrix(Some(&[(1, 2), (3, 4)])).unwrap();
+    /// assert_eq!(pairs, vec![(1, 2), (3, 4)]);
+    /// assert_eq!(scores[0], (graph.get_node_degree_from_node_id(1).unwrap() * graph.get_node_degree_from_node_id(2).unwrap()) as f32);
+    /// ```
+    ///
+    /// # Raises
+    /// * If any of the provided candidate node IDs does not exist in the current graph.
+    pub fn get_preferential_attachment_coo_matrix(
+        &self,
+        candidate_pairs: Option<&[(NodeT, NodeT)]>,
+    ) -> Result<(Vec<(NodeT, NodeT)>, Vec<WeightT>)> {
+        let get_score = |(src, dst): (NodeT, NodeT)| {
+            ((src, dst), unsafe {
+                self.get_unchecked_preferential_attachment_from_node_ids(src, dst, false)
+            })
+        };
+        Ok(match candidate_pairs {
+            Some(candidate_pairs) => {
+                for &(src, dst) in candidate_pairs {
+                    self.validate_node_id(src)?;
+                    self.validate_node_id(dst)?;
+                }
+                candidate_pairs.par_iter().copied().map(get_score).unzip()
+            }
+            None => self
+                .par_iter_distance_two_node_id_pairs()
+                .map(get_score)
+                .unzip(),
+        })
+    }
+
+    /// Returns parallel iterator on the pairs of distinct nodes sharing at least a neighbour.
+    ///
+    /// # Implementative details
+    /// For each source node, the destinations are the other nodes with an edge
+    /// towards any of its neighbours, and are returned sorted by increasing node
+    /// ID. In directed graphs the shared neighbours are the common destinations
+    /// of the outbound edges of the two nodes, hence the inbound neighbours of
+    /// each node are computed first.
+    fn par_iter_shared_neighbour_node_id_pairs(
+        &self,
+    ) -> impl ParallelIterator<Item = (NodeT, NodeT)> + '_ {
+        let inbound_neighbours: Option<Vec<Vec<NodeT>>> = if self.is_directed() {
+            let mut inbound_neighbours = vec![Vec::new(); self.get_number_of_nodes() as usize];
+            self.iter_node_ids().for_each(|src| {
+                unsafe { self.iter_unchecked_unique_neighbour_node_ids_from_source_node_id(src) }
+                    .for_each(|dst| inbound_neighbours[dst as usize].push(src));
+            });
+            Some(inbound_neighbours)
+        } else {
+            None
+        };
+        self.par_iter_node_id_pairs_from_neighbours(move |middle, destinations| {
+            match inbound_neighbours.as_ref() {
+                Some(inbound_neighbours) => {
+                    destinations.extend(inbound_neighbours[middle as usize].iter())
+                }
+                None => destinations.extend(unsafe {
+                    self.iter_unchecked_unique_neighbour_node_ids_from_source_node_id(middle)
+                }),
+            }
+        })
+    }
+
+    /// Returns common neighbours coo matrix, that is the number of neighbours shared by the node pairs.
+    ///
+    /// # Arguments
+    /// * `candidate_pairs`: Option<&[(NodeT, NodeT)]> - The pairs of node IDs to score. By default, all the pairs of distinct nodes sharing at least a neighbour.
+    ///
+    /// # Implementative details
+    /// When the candidate pairs are not provided, only the pairs of distinct
+    /// nodes sharing at least a neighbour are returned, so to avoid the dense
+    /// matrix over all the pairs of nodes. As for the other similarity COO
+    /// matrices, in undirected graphs both the pairs `(src, dst)` and
+    /// `(dst, src)` are returned. In directed graphs the shared neighbours
+    /// are the common destinations of the edges leaving the two nodes. The
+    /// provided candidate pairs are instead all scored in the given order,
+    /// including the ones without shared neighbours.
+    ///
+    /// # Example
+    ///```rust
+    /// # let graph = graph::test_utilities::load_ppi(true, true, true, true, false, false);
+    /// let (pairs, scores) = graph.get_common_neighbours_coo_matrix(Some(&[(1, 2), (3, 4)])).unwrap();
+    /// assert_eq!(pairs, vec![(1, 2), (3, 4)]);
+    /// assert_eq!(scores[0], unsafe { graph.get_unchecked_neighbours_intersection_size_from_node_ids(1, 2) });
+    /// ```
+    ///
+    /// # Raises
+    /// * If any of the provided candidate node IDs does not exist in the current graph.
+    pub fn get_common_neighbours_coo_matrix(
+        &self,
+        candidate_pairs: Option<&[(NodeT, NodeT)]>,
+    ) -> Result<(Vec<(NodeT, NodeT)>, Vec<WeightT>)> {
+        let get_score = |(src, dst): (NodeT, NodeT)| {
+            ((src, dst), unsafe {
+                self.get_unchecked_neighbours_intersection_size_from_node_ids(src, dst)
+            })
+        };
+        Ok(match candidate_pairs {
+            Some(candidate_pairs) => {
+                for &(src, dst) in candidate_pairs {
+                    self.validate_node_id(src)?;
+                    self.validate_node_id(dst)?;
+                }
+                candidate_pairs.par_iter().copied().map(get_score).unzip()
+            }
+            None => self
+                .par_iter_shared_neighbour_node_id_pairs()
+                .map(get_score)
+                .unzip(),
+        })
+    }
+
     /// Returns parallel iterator over the co-occurrence matrix
     ///
     /// # Arguments
@@ -283,6 +512,136 @@ impl Graph {
             }))
     }
 
+    /// Returns parallel iterator over the co-occurrence matrix, with the optional GloVe weighting.
+    ///
+    /// # Arguments
+    /// * `walks_parameters`: &'a WalksParameters - the walks parameters.
+    /// * `window_size`: usize - Window size to consider for the sequences.
+    /// * `node_ids_of_interest`: Option<&[NodeT]> - While the random walks is graph-wide, we only return edges whose source and destination nodes are within this node ID list.
+    /// * `x_max`: Option<f64> - The cooccurrence count over which the weighting is one. By default, 100 when `alpha` is provided.
+    /// * `alpha`: Option<f64> - The exponent of the weighting. By default, 0.75 when `x_max` is provided.
+    ///
+    /// # Implementative details
+    /// The iterator returns the tuples `(src, dst, count, weighting)`. When
+    /// neither `x_max` nor `alpha` is provided, the weighting is None and the
+    /// tuples are the ones of `par_iter_cooccurence_matrix`, that is the counts
+    /// of each random walk separately, so the same pair of nodes may appear more
+    /// than once. When at least one between `x_max` and `alpha` is provided, each
+    /// pair of nodes appears once with the count being the total over all of the
+    /// random walks, and the weighting is the GloVe weighting of the total count,
+    /// that is `min(1, (count / x_max)^alpha)`. Since the counts need to be
+    /// aggregated before the weighting is applied, in this case the cooccurrence
+    /// matrix is kept in memory.
+    ///
+    /// # References
+    /// [GloVe: Global Vectors for Word Representation](https://aclanthology.org/D14-1162/),
+    /// by Pennington, Socher and Manning.
+    ///
+    /// # Raises
+    /// * If the given `x_max` is not a strictly positive finite value.
+    /// * If the given `alpha` is not a strictly positive finite value.
+    pub fn par_iter_weighted_cooccurence_matrix<'a>(
+        &'a self,
+        walks_parameters: &'a WalksParameters,
+        window_size: usize,
+        node_ids_of_interest: Option<&'a [NodeT]>,
+        x_max: Option<f64>,
+        alpha: Option<f64>,
+    ) -> Result<impl ParallelIterator<Item = (NodeT, NodeT, NodeT, Option<f64>)> + 'a> {
+        for (parameter_name, value) in [("x_max", x_max), ("alpha", alpha)] {
+            if let Some(value) = value {
+                if !value.is_finite() || value <= 0.0 {
+                    return Err(format!(
+                        "The given {} must be a strictly positive finite value, but {} was provided.",
+                        parameter_name, value
+                    ));
+                }
+            }
+        }
+        let cooccurence_matrix =
+            self.par_iter_cooccurence_matrix(walks_parameters, window_size, node_ids_of_interest)?;
+        if x_max.is_none() && alpha.is_none() {
+            return Ok(Either::Left(
+                cooccurence_matrix.map(|(src, dst, count)| (src, dst, count, None)),
+            ));
+        }
+        let x_max = x_max.unwrap_or(100.0);
+        let alpha = alpha.unwrap_or(0.75);
+        // The weighting is not linear, so it must be applied to the counts
+        // aggregated over all the walks rather than to the counts of each walk.
+        let cooccurence_matrix = cooccurence_matrix
+            .fold(
+                HashMap::new,
+                |mut cooccurence_matrix: HashMap<(NodeT, NodeT), NodeT>, (src, dst, count)| {
+                    *cooccurence_matrix.entry((src, dst)).or_insert(0) += count;
+                    cooccurence_matrix
+                },
+            )
+            .reduce(HashMap::new, |mut left, mut right| {
+                // We merge the smaller map into the bigger one.
+                if left.len() < right.len() {
+                    std::mem::swap(&mut left, &mut right);
+                }
+                right.into_iter().for_each(|(key, count)| {
+                    *left.entry(key).or_insert(0) += count;
+                });
+                left
+            });
+        Ok(Either::Right(cooccurence_matrix.into_par_iter().map(
+            move |((src, dst), count)| {
+                (
+                    src,
+                    dst,
+                    count,
+                    Some((count as f64 / x_max).powf(alpha).min(1.0)),
+                )
+            },
+        )))
+    }
+
+    /// Returns the co-occurrence matrix, with the optional GloVe weighting.
+    ///
+    /// # Arguments
+    /// * `walks_parameters`: &WalksParameters - the walks parameters.
+    /// * `window_size`: usize - Window size to consider for the sequences.
+    /// * `node_ids_of_interest`: Option<&[NodeT]> - While the random walks is graph-wide, we only return edges whose source and destination nodes are within this node ID list.
+    /// * `x_max`: Option<f64> - The cooccurrence count over which the weighting is one. By default, 100 when `alpha` is provided.
+    /// * `alpha`: Option<f64> - The exponent of the weighting. By default, 0.75 when `x_max` is provided.
+    ///
+    /// # Implementative details
+    /// The returned tuple contains the pairs of nodes, their cooccurrence counts and,
+    /// when at least one between `x_max` and `alpha` is provided, their GloVe weightings,
+    /// as described in `par_iter_weighted_cooccurence_matrix`.
+    ///
+    /// # Raises
+    /// * If the given `x_max` is not a strictly positive finite value.
+    /// * If the given `alpha` is not a strictly positive finite value.
+    pub fn get_weighted_cooccurence_matrix(
+        &self,
+        walks_parameters: &WalksParameters,
+        window_size: usize,
+        node_ids_of_interest: Option<&[NodeT]>,
+        x_max: Option<f64>,
+        alpha: Option<f64>,
+    ) -> Result<(Vec<(NodeT, NodeT)>, Vec<NodeT>, Option<Vec<f64>>)> {
+        let is_weighted = x_max.is_some() || alpha.is_some();
+        let (node_ids, (counts, weightings)): (Vec<(NodeT, NodeT)>, (Vec<NodeT>, Vec<f64>)) = self
+            .par_iter_weighted_cooccurence_matrix(
+                walks_parameters,
+                window_size,
+                node_ids_of_interest,
+                x_max,
+                alpha,
+            )?
+            .map(|(src, dst, count, weighting)| ((src, dst), (count, weighting.unwrap_or(0.0))))
+            .unzip();
+        Ok((
+            node_ids,
+            counts,
+            if is_weighted { Some(weightings) } else { None },
+        ))
+    }
+
     /// Returns unweighted laplacian COO matrix representation of the graph.
     pub fn par_iter_laplacian_coo_matrix(
         &self,
@@ -386,4 +745,134 @@ impl Graph {
     pub fn get_symmetric_normalized_laplacian_graph(&self) -> Graph {
         self.get_graph_from_coo_iterator(self.par_iter_symmetric_normalized_laplacian_coo_matrix())
     }
+
+    /// Returns unweighted random walk normalized laplacian COO matrix representation of the graph.
+    ///
+    /// # Implementative details
+    /// The matrix is `I - D^{-1}A`, hence each edge has weight minus the reciprocal
+    /// of the degree of its source node, and the diagonal of the nodes with a selfloop
+    /// is one minus the reciprocal of their degree. The nodes with zero degree have
+    /// a zero row, and their diagonal value is not returned.
+    pub fn par_iter_random_walk_normalized_laplacian_coo_matrix(
+        &self,
+    ) -> impl ParallelIterator<Item = (NodeT, NodeT, WeightT)> + '_ {
+        self.par_iter_laplacian_like_coo_matrix(
+            |graph, src, _| {
+                -1.0 / unsafe { graph.get_unchecked_node_degree_from_node_id(src) as WeightT }
+            },
+            |graph, node_id| {
+                let degree = unsafe { graph.get_unchecked_node_degree_from_node_id(node_id) };
+                if degree == 0 {
+                    0.0
+                } else if graph.has_selfloop_from_node_id(node_id) {
+                    1.0 - 1.0 / degree as WeightT
+                } else {
+                    1.0
+                }
+            },
+        )
+    }
+
+    /// Returns random walk normalized Laplacian coo matrix.
+    pub fn get_random_walk_normalized_laplacian_coo_matrix(
+        &self,
+    ) -> (Vec<(NodeT, NodeT)>, Vec<WeightT>) {
+        self.par_iter_random_walk_normalized_laplacian_coo_matrix()
+            .map(|(src, dst, weight)| ((src, dst), weight))
+            .unzip()
+    }
+
+    /// Returns parallel iterator on the common neighbours counts of the pairs of nodes at distance two.
+    ///
+    /// # Arguments
+    /// * `top_k`: Option<NodeT> - The maximum number of pairs to return for each source node. By default, all pairs are returned.
+    ///
+    /// # Implementative details
+    /// The candidate pairs are generated by expanding the neighbours of the
+    /// neighbours of each source node, so only the pairs of distinct nodes sharing
+    /// at least one neighbour are returned, including pairs that are also directly
+    /// connected. Parallel edges are counted once and selfloops are ignored.
+    /// In undirected graphs each pair is returned once, with the smaller node ID
+    /// first, while in directed graphs the count of the pair `(src, dst)` is the number of
+    /// nodes `m` such that the graph has the edges `(src, m)` and `(m, dst)`.
+    ///
+    /// When `top_k` is provided, for each source node only the pairs with
+    /// the highest counts are kept, breaking ties by destination node ID.
+    /// In undirected graphs the symmetric pairs are dropped before this selection,
+    /// so that the source node is the one with the smaller node ID.
+    pub fn par_iter_two_hop_common_neighbour_counts(
+        &self,
+        top_k: Option<NodeT>,
+    ) -> impl ParallelIterator<Item = (NodeT, NodeT, NodeT)> + '_ {
+        let number_of_nodes = self.get_number_of_nodes() as usize;
+        self.par_iter_node_ids()
+            .map_init(
+                || (vec![0 as NodeT; number_of_nodes], Vec::new()),
+                move |(counts, touched_node_ids), src| {
+                    unsafe {
+                        self.iter_unchecked_unique_neighbour_node_ids_from_source_node_id(src)
+                    }
+                    .filter(|&middle| middle != src)
+                    .for_each(|middle| {
+                        unsafe {
+                            self.iter_unchecked_unique_neighbour_node_ids_from_source_node_id(
+                                middle,
+                            )
+                        }
+                        // In undirected graphs, each pair is only counted from its
+                        // smaller node ID, so that the symmetric pairs are dropped
+                        // before the top pairs are selected.
+                        .filter(|&dst| {
+                            dst != middle && dst != src && (self.is_directed() || src < dst)
+                        })
+                        .for_each(|dst| {
+                            if counts[dst as usize] == 0 {
+                                touched_node_ids.push(dst);
+                            }
+                            counts[dst as usize] += 1;
+                        });
+                    });
+                    let mut pairs = touched_node_ids
+                        .drain(..)
+                        .map(|dst| {
+                            let count = counts[dst as usize];
+                            counts[dst as usize] = 0;
+                            (src, dst, count)
+                        })
+                        .collect::<Vec<(NodeT, NodeT, NodeT)>>();
+                    if let Some(top_k) = top_k {
+                        pairs.sort_unstable_by(|(_, a_dst, a_count), (_, b_dst, b_count)| {
+                            b_count.cmp(a_count).then(a_dst.cmp(b_dst))
+                        });
+                        pairs.truncate(top_k as usize);
+                    }
+                    pairs.sort_unstable();
+                    pairs
+                },
+            )
+            .flat_map_iter(|pairs| pairs.into_iter())
+    }
+
+    /// Returns the common neighbours counts of the pairs of nodes at distance two as a COO matrix.
+    ///
+    /// # Arguments
+    /// * `top_k`: Option<NodeT> - The maximum number of pairs to return for each source node. By default, all pairs are returned.
+    ///
+    /// # Implementative details
+    /// See `par_iter_two_hop_common_neighbour_counts` for the definition of the returned pairs.
+    ///
+    /// # Example
+    ///```rust
+    /// # let graph = graph::test_utilities::load_ppi(true, true, true, true, false, false);
+    /// let (pairs, counts) = graph.get_two_hop_common_neighbour_counts(Some(10));
+    /// assert_eq!(pairs.len(), counts.len());
+    /// ```
+    pub fn get_two_hop_common_neighbour_counts(
+        &self,
+        top_k: Option<NodeT>,
+    ) -> (Vec<(NodeT, NodeT)>, Vec<NodeT>) {
+        self.par_iter_two_hop_common_neighbour_counts(top_k)
+            .map(|(src, dst, count)| ((src, dst), count))
+            .unzip()
+    }
 }
@@ -0,0 +1,386 @@
+use super::*;
+use indicatif::ProgressIterator;
+use rayon::prelude::*;
+
+/// # K-core decomposition
+impl Graph {
+    /// Returns the core number of each node.
+    ///
+    /// # Arguments
+    /// * `verbose`: Option<bool> - Whether to show a loading bar while peeling the nodes. By default, true.
+    ///
+    /// # Implementative details
+    /// The core number of a node is the largest `k` such that the node
+    /// belongs to the `k`-core, the maximal subgraph in which every node has
+    /// degree at least `k`. Parallel edges are counted once and selfloops are ignored.
+    ///
+    /// # References
+    /// The algorithm is described in [An O(m) Algorithm for Cores Decomposition of Networks](https://arxiv.org/abs/cs/0310049),
+    /// by Batagelj and Zaversnik.
+    ///
+    /// # Example
+    ///```rust
+    /// # let graph = graph::test_utilities::load_ppi(true, true, true, true, false, false);
+    /// let coreness = graph.get_coreness(Some(false)).unwrap();
+    /// assert_eq!(coreness.len(), graph.get_number_of_nodes() as usize);
+    /// ```
+    ///
+    /// # Raises
+    /// * If the graph is directed.
+    pub fn get_coreness(&self, verbose: Option<bool>) -> Result<Vec<NodeT>> {
+        self.must_be_undirected()?;
+        let number_of_nodes = self.get_number_of_nodes() as usize;
+        let mut degrees = self
+            .par_iter_node_ids()
+            .map(|node_id| unsafe {
+                self.iter_unchecked_unique_neighbour_node_ids_from_source_node_id(node_id)
+                    .filter(|&dst| dst != node_id)
+                    .count() as NodeT
+            })
+            .collect::<Vec<NodeT>>();
+        let maximum_degree = degrees.iter().copied().max().unwrap_or(0) as usize;
+
+        // We sort the nodes by degree using a bucket sort.
+        let mut bin_starts = vec![0; maximum_degree + 1];
+        degrees
+            .iter()
+            .for_each(|&degree| bin_starts[degree as usize] += 1);
+        let mut start = 0;
+        bin_starts.iter_mut().for_each(|bin_start| {
+            let number_of_nodes_in_bin = *bin_start;
+            *bin_start = start;
+            start += number_of_nodes_in_bin;
+        });
+        let mut sorted_nodes = vec![0; number_of_nodes];
+        let mut positions = vec![0; number_of_nodes];
+        let mut next_positions = bin_starts.clone();
+        degrees.iter().enumerate().for_each(|(node_id, &degree)| {
+            let position = next_positions[degree as usize];
+            positions[node_id] = position;
+            sorted_nodes[position] = node_id as NodeT;
+            next_positions[degree as usize] += 1;
+        });
+
+        // We peel the nodes in order of increasing degree, moving their
+        // neighbours with larger degree one bucket down.
+        let pb = get_loading_bar(
+            verbose.unwrap_or(true),
+            "Computing core numbers",
+            number_of_nodes,
+        );
+        for i in (0..number_of_nodes).progress_with(pb) {
+            let src = sorted_nodes[i];
+            let src_degree = degrees[src as usize];
+            unsafe { self.iter_unchecked_unique_neighbour_node_ids_from_source_node_id(src) }
+                .for_each(|dst| {
+                    let dst_degree = degrees[dst as usize];
+                    if dst_degree > src_degree {
+                        let dst_position = positions[dst as usize];
+                        let bin_start = bin_starts[dst_degree as usize];
+                        let first_node_in_bin = sorted_nodes[bin_start];
+                        if first_node_in_bin != dst {
+                            sorted_nodes.swap(dst_position, bin_start);
+                            positions[dst as usize] = bin_start;
+                            positions[first_node_in_bin as usize] = dst_position;
+                        }
+                        bin_starts[dst_degree as usize] += 1;
+                        degrees[dst as usize] -= 1;
+                    }
+                });
+        }
+        Ok(degrees)
+    }
+
+    /// Returns the core-periphery layer of each node, which is equal to its core number.
+    ///
+    /// # Implementative details
+    /// The innermost layer contains the nodes with the highest core number,
+    /// while the outermost layer, the periphery, contains the nodes with the
+    /// lowest one. Parallel edges are counted once and selfloops are ignored.
+    ///
+    /// # Example
+    ///```rust
+    /// # let graph = graph::test_utilities::load_ppi(true, true, true, true, false, false);
+    /// let layers = graph.get_core_periphery_layers().unwrap();
+    /// assert_eq!(layers.len(), graph.get_number_of_nodes() as usize);
+    /// ```
+    ///
+    /// # Raises
+    /// * If the graph is directed.
+    pub fn get_core_periphery_layers(&self) -> Result<Vec<NodeT>> {
+        self.get_coreness(Some(false))
+    }
+
+    /// Returns the subgraph induced by the nodes with core number at least `k`.
+    ///
+    /// # Arguments
+    /// * `k`: NodeT - The minimum core number of the nodes to keep.
+    ///
+    /// # Implementative details
+    /// The node IDs of the returned graph are remapped compactly, preserving
+    /// the relative order of the kept nodes, while their node types and the
+    /// types and weights of the edges between them are preserved.
+    ///
+    /// # Example
+    ///```rust
+    /// # let graph = graph::test_utilities::load_ppi(true, true, true, true, false, false);
+    /// let core = graph.get_k_core_subgraph(2).unwrap();
+    /// assert!(core.get_number_of_nodes() <= graph.get_number_of_nodes());
+    /// ```
+    ///
+    /// # Raises
+    /// * If the graph is directed.
+    pub fn get_k_core_subgraph(&self, k: NodeT) -> Result<Graph> {
+        let coreness = self.get_coreness(Some(false))?;
+        let node_ids_to_keep = coreness
+            .par_iter()
+            .enumerate()
+            .filter_map(|(node_id, &core_number)| {
+                if core_number >= k {
+                    Some(node_id as NodeT)
+                } else {
+                    None
+                }
+            })
+            .collect::<Vec<NodeT>>();
+        self.filter_from_ids(
+            Some(node_ids_to_keep),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+    }
+
+    /// Returns the number of nodes in each core-periphery layer.
+    ///
+    /// # Implementative details
+    /// The returned vector is indexed by the core number, hence layers that
+    /// do not contain any node have a zero count.
+    ///
+    /// # Raises
+    /// * If the graph is directed.
+    pub fn get_number_of_nodes_per_core_periphery_layer(&self) -> Result<Vec<NodeT>> {
+        let layers = self.get_core_periphery_layers()?;
+        let number_of_layers = layers
+            .iter()
+            .copied()
+            .max()
+            .map_or(0, |layer| layer as usize + 1);
+        let mut counts = vec![0; number_of_layers];
+        layers
+            .into_iter()
+            .for_each(|layer| counts[layer as usize] += 1);
+        Ok(counts)
+    }
+
+    /// Returns the node IDs in the given layer.
+    ///
+    /// # Arguments
+    /// * `layers`: &[NodeT] - The core-periphery layer of each node.
+    /// * `layer`: NodeT - The layer whose nodes are to be returned.
+    fn get_node_ids_from_core_periphery_layer(layers: &[NodeT], layer: NodeT) -> Vec<NodeT> {
+        layers
+            .par_iter()
+            .enumerate()
+            .filter_map(|(node_id, &node_layer)| {
+                if node_layer == layer {
+                    Some(node_id as NodeT)
+                } else {
+                    None
+                }
+            })
+            .collect()
+    }
+
+    /// Returns the node IDs in the innermost core-periphery layer, that is the nodes with maximum core number.
+    ///
+    /// # Raises
+    /// * If the graph is directed.
+    pub fn get_core_node_ids(&self) -> Result<Vec<NodeT>> {
+        let layers = self.get_core_periphery_layers()?;
+        Ok(layers.iter().copied().max().map_or_else(Vec::new, |layer| {
+            Self::get_node_ids_from_core_periphery_layer(&layers, layer)
+        }))
+    }
+
+    /// Returns the node IDs in the outermost core-periphery layer, that is the nodes with minimum core number.
+    ///
+    /// # Raises
+    /// * If the graph is directed.
+    pub fn get_periphery_node_ids(&self) -> Result<Vec<NodeT>> {
+        let layers = self.get_core_periphery_layers()?;
+        Ok(layers.iter().copied().min().map_or_else(Vec::new, |layer| {
+            Self::get_node_ids_from_core_periphery_layer(&layers, layer)
+        }))
+    }
+
+    /// Returns the continuous coreness of each node and the fit of the core-periphery model.
+    ///
+    /// # Arguments
+    /// * `maximum_iterations`: Option<usize> - The maximum number of sweeps over the nodes. By default, 100.
+    /// * `tolerance`: Option<f64> - The maximum change of the coreness, relative to the largest one, under which the sweeps are stopped. By default, 1e-6.
+    ///
+    /// # Implementative details
+    /// In the continuous Borgatti-Everett model, the ideal strength of the
+    /// connection between two distinct nodes is the product of their coreness,
+    /// so that the core nodes are densely connected to each other and the
+    /// periphery nodes are loosely connected to anything.
+    ///
+    /// The coreness vector `c` is fitted by minimizing the sum, over all the pairs
+    /// of distinct nodes, of the squared differences between the adjacency matrix
+    /// and `c c^T`. The coreness is seeded with the node degrees, which is the
+    /// solution expected when the graph is a configuration model, and is then
+    /// optimized with sequential coordinate descent sweeps over the nodes in
+    /// increasing node ID order: each coreness is set to the value minimizing the
+    /// objective given all the others, that is the sum of the coreness of its
+    /// neighbours divided by the sum of the squared coreness of all the other nodes.
+    /// The returned coreness is scaled so that its maximum is one.
+    ///
+    /// The fit is the Pearson correlation between the adjacency matrix and the
+    /// ideal pattern `c c^T` over all the pairs of distinct nodes, which is computed
+    /// in linear time from the sums of the coreness. When either of the two has no
+    /// variance, as in complete graphs, the correlation is not defined and the fit is zero.
+    /// The edge weights are ignored, parallel edges are counted once and selfloops are ignored.
+    ///
+    /// # References
+    /// [Models of core/periphery structures](https://doi.org/10.1016/S0378-8733(99)00019-2),
+    /// by Stephen P. Borgatti and Martin G. Everett.
+    ///
+    /// # Example
+    ///```rust
+    /// # let graph = graph::test_utilities::load_ppi(true, true, true, true, false, false);
+    /// let (coreness, fit) = graph.get_core_periphery_score(None, None).unwrap();
+    /// assert_eq!(coreness.len(), graph.get_number_of_nodes() as usize);
+    /// assert!(fit.abs() <= 1.0 + 1e-6);
+    /// ```
+    ///
+    /// # Raises
+    /// * If the graph is directed.
+    /// * If the graph does not have edges.
+    pub fn get_core_periphery_score(
+        &self,
+        maximum_iterations: Option<usize>,
+        tolerance: Option<f64>,
+    ) -> Result<(Vec<f64>, f64)> {
+        self.must_be_undirected()?;
+        self.must_have_edges()?;
+        let maximum_iterations = maximum_iterations.unwrap_or(100);
+        let tolerance = tolerance.unwrap_or(1e-6);
+        let neighbours = |node_id: NodeT| unsafe {
+            self.iter_unchecked_unique_neighbour_node_ids_from_source_node_id(node_id)
+                .filter(move |&dst| dst != node_id)
+        };
+
+        let mut coreness = self
+            .par_iter_node_ids()
+            .map(|node_id| neighbours(node_id).count() as f64)
+            .collect::<Vec<f64>>();
+        let number_of_directed_edges = coreness.iter().sum::<f64>();
+        if number_of_directed_edges > 0.0 {
+            coreness
+                .iter_mut()
+                .for_each(|value| *value /= number_of_directed_edges.sqrt());
+        }
+        let mut squared_coreness_sum = coreness.iter().map(|value| value * value).sum::<f64>();
+
+        for _ in 0..maximum_iterations {
+            let mut maximum_change: f64 = 0.0;
+            let mut maximum_coreness: f64 = 0.0;
+            for node_id in 0..coreness.len() {
+                let old_coreness = coreness[node_id];
+                let other_squared_coreness_sum = squared_coreness_sum - old_coreness * old_coreness;
+                let new_coreness = if other_squared_coreness_sum > 0.0 {
+                    neighbours(node_id as NodeT)
+                        .map(|dst| coreness[dst as usize])
+                        .sum::<f64>()
+                        / other_squared_coreness_sum
+                } else {
+                    0.0
+                };
+                coreness[node_id] = new_coreness;
+                squared_coreness_sum = other_squared_coreness_sum + new_coreness * new_coreness;
+                maximum_change = maximum_change.max((new_coreness - old_coreness).abs());
+                maximum_coreness = maximum_coreness.max(new_coreness);
+            }
+            if maximum_change <= tolerance * maximum_coreness {
+                break;
+            }
+        }
+
+        // We compute the correlation between the adjacency matrix and the
+        // ideal pattern from their sums over the pairs of distinct nodes.
+        let number_of_pairs = (coreness.len() * (coreness.len() - 1)) as f64;
+        let coreness_sum = coreness.iter().sum::<f64>();
+        let squared_coreness_sum = coreness.iter().map(|value| value * value).sum::<f64>();
+        let pattern_sum = coreness_sum * coreness_sum - squared_coreness_sum;
+        let squared_pattern_sum = squared_coreness_sum * squared_coreness_sum
+            - coreness.iter().map(|value| value.powi(4)).sum::<f64>();
+        let adjacency_pattern_sum = self
+            .par_iter_node_ids()
+            .map(|node_id| {
+                coreness[node_id as usize]
+                    * neighbours(node_id)
+                        .map(|dst| coreness[dst as usize])
+                        .sum::<f64>()
+            })
+            .sum::<f64>();
+        let covariance =
+            number_of_pairs * adjacency_pattern_sum - number_of_directed_edges * pattern_sum;
+        let adjacency_variance =
+            number_of_pairs * number_of_directed_edges - number_of_directed_edges.powi(2);
+        let pattern_variance = number_of_pairs * squared_pattern_sum - pattern_sum.powi(2);
+        let fit = if adjacency_variance > 0.0 && pattern_variance > 0.0 {
+            covariance / (adjacency_variance * pattern_variance).sqrt()
+        } else {
+            0.0
+        };
+
+        let maximum_coreness = coreness.iter().cloned().fold(0.0, f64::max);
+        if maximum_coreness > 0.0 {
+            coreness
+                .par_iter_mut()
+                .for_each(|value| *value /= maximum_coreness);
+        }
+        Ok((coreness, fit))
+    }
+}
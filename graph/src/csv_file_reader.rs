@@ -7,9 +7,10 @@ use nix::fcntl::*;
 #[cfg(target_os = "linux")]
 use std::os::unix::io::AsRawFd;
 
+use flate2::read::GzDecoder;
 use num_traits::Zero;
-use rayon::iter::ParallelIterator;
-use std::{collections::HashMap, fs::File, io::prelude::*, io::BufReader};
+use rayon::iter::{Either, ParallelBridge, ParallelIterator};
+use std::{collections::HashMap, fs::File, io::prelude::*, io::BufReader, io::SeekFrom};
 
 use crate::utils::get_loading_bar;
 
@@ -76,6 +77,9 @@ pub struct CSVFileReader {
 
     /// Whether to trim spaces from the elements, that is change read value from `  VALUE ` to `VALUE`
     pub(crate) remove_spaces: bool,
+
+    /// The compression of the file, which when not provided is detected from the file extension.
+    pub(crate) compression: Option<String>,
 }
 
 /// # Builder methods
@@ -112,6 +116,7 @@ impl CSVFileReader {
                     support_balanced_quotes: false,
                     remove_chevrons: false,
                     remove_spaces: false,
+                    compression: None,
                 }
             }),
             Err(_) => Err(format!("Cannot open the file at {}", path)),
@@ -169,6 +174,45 @@ impl CSVFileReader {
         self
     }
 
+    /// Set the compression of the file.
+    ///
+    /// # Arguments
+    /// * `compression`: Option<String> - The compression of the file, either `gzip` or `none`. By default, files with the `.gz` extension are read as gzip compressed.
+    ///
+    /// # Implementative details
+    /// Gzip compressed files are decompressed as a stream. When the parallel reader
+    /// is requested, the decompressed lines are distributed to the threads as they
+    /// are read, instead of splitting the memory mapped file into chunks as it
+    /// happens for plain files, so that the file is never decompressed whole in memory.
+    ///
+    /// # Raises
+    /// * If the separator was already set before calling this method.
+    /// * If the given compression is not supported.
+    pub fn set_compression(mut self, compression: Option<String>) -> Result<CSVFileReader> {
+        if let Some(compression) = compression {
+            self.separator_must_not_already_be_set()?;
+            if !["gzip", "none"].contains(&compression.as_str()) {
+                return Err(format!(
+                    concat!(
+                        "The given compression `{}` is not supported. ",
+                        "The supported compressions are `gzip` and `none`."
+                    ),
+                    compression
+                ));
+            }
+            self.compression = Some(compression);
+        }
+        Ok(self)
+    }
+
+    /// Return whether the file is read as gzip compressed.
+    pub fn is_gzip_compressed(&self) -> bool {
+        match self.compression.as_deref() {
+            Some(compression) => compression == "gzip",
+            None => self.path.ends_with(".gz"),
+        }
+    }
+
     /// Set separator to the provided value.
     ///
     /// # Arguments
@@ -331,14 +375,14 @@ impl CSVFileReader {
             .0)
     }
 
-    fn get_buffer_reader(&self) -> Result<BufReader<File>> {
+    fn get_buffer_reader(&self) -> Result<BufReader<Box<dyn Read + Send>>> {
         let file = File::open(&self.path);
 
         if file.is_err() {
             return Err(format!("Cannot open the file at {}", self.path));
         }
 
-        let file = file.unwrap();
+        let mut file = file.unwrap();
 
         #[cfg(target_os = "linux")]
         let _ = posix_fadvise(
@@ -347,6 +391,30 @@ impl CSVFileReader {
             0,
             PosixFadviseAdvice::POSIX_FADV_SEQUENTIAL,
         );
+
+        let file: Box<dyn Read + Send> = if self.is_gzip_compressed() {
+            // We check the gzip magic bytes, so that plain files read as
+            // gzip compressed get a clear error instead of a generic I/O one.
+            let mut magic_bytes = [0; 2];
+            if file.read_exact(&mut magic_bytes).is_err() || magic_bytes != [0x1f, 0x8b] {
+                return Err(format!(
+                    concat!(
+                        "The file at {} is not a valid gzip file, ",
+                        "as it does not start with the gzip magic bytes."
+                    ),
+                    self.path
+                ));
+            }
+            if file.seek(SeekFrom::Start(0)).is_err() {
+                return Err(format!(
+                    "Unable to move file pointer to beginning of the file {}",
+                    self.path
+                ));
+            }
+            Box::new(GzDecoder::new(file))
+        } else {
+            Box::new(file)
+        };
         Ok(BufReader::with_capacity(8 * 1024 * 1024, file))
     }
 
@@ -480,7 +548,18 @@ impl CSVFileReader {
         >,
     > {
         Ok(if self.parallel {
-            ItersWrapper::Parallel(self.get_parallell_lines_iterator(skip_header)?)
+            ItersWrapper::Parallel(if self.is_gzip_compressed() {
+                // The parallel reader splits the memory mapped file among the threads,
+                // which a compressed stream does not allow without decompressing it
+                // whole in memory, hence the decompressed lines are streamed and
+                // handed to the threads as they are read.
+                Either::Right(
+                    self.get_sequential_lines_iterator(skip_header, false)?
+                        .par_bridge(),
+                )
+            } else {
+                Either::Left(self.get_parallell_lines_iterator(skip_header)?)
+            })
         } else {
             ItersWrapper::Sequential(self.get_sequential_lines_iterator(skip_header, verbose)?)
         })
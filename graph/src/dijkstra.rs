@@ -1960,6 +1960,153 @@ impl Graph {
         })
     }
 
+    /// Returns the number of reconstructed shortest paths from the given source to the given targets passing through each edge.
+    ///
+    /// # Arguments
+    /// * `src_node_id`: NodeT - Node ID root of the tree of minimum paths.
+    /// * `dst_node_ids`: &[NodeT] - Node IDs of the targets of the shortest paths.
+    /// * `use_edge_weights`: Option<bool> - Whether to use the edge weights, running Dijkstra instead of a breadth first search. By default, false.
+    ///
+    /// # Implementative details
+    /// A single breadth first search, or Dijkstra when the edge weights are used, is
+    /// run from the source node, and the shortest path towards each target is
+    /// reconstructed from the resulting vector of predecessors. Only the shortest path
+    /// described by the predecessors is considered for each target, and the targets
+    /// that are not reachable from the source, including the source itself, are ignored.
+    /// Targets provided multiple times are counted multiple times.
+    /// The returned vector has a count for each directed edge ID: in undirected graphs,
+    /// the edges are oriented from the source towards the targets. In multigraphs, the
+    /// lightest of the parallel edges is used when the edge weights are used, and the
+    /// first one otherwise.
+    ///
+    /// # Raises
+    /// * If the given source node ID or any of the target node IDs do not exist in the current graph.
+    /// * If the edge weights are requested but the graph does not have positive edge weights.
+    pub fn get_shortest_path_edge_usage_counts_from_node_id(
+        &self,
+        src_node_id: NodeT,
+        dst_node_ids: &[NodeT],
+        use_edge_weights: Option<bool>,
+    ) -> Result<Vec<EdgeT>> {
+        self.validate_node_id(src_node_id)?;
+        for &dst_node_id in dst_node_ids {
+            self.validate_node_id(dst_node_id)?;
+        }
+        let use_edge_weights = use_edge_weights.unwrap_or(false);
+        let predecessors: Vec<Option<NodeT>> = if use_edge_weights {
+            self.must_have_positive_edge_weights()?;
+            unsafe {
+                self.get_unchecked_dijkstra_from_node_id(
+                    src_node_id,
+                    None,
+                    None,
+                    Some(true),
+                    None,
+                    None,
+                )
+            }
+            .predecessors
+            .unwrap()
+        } else {
+            unsafe {
+                self.get_unchecked_breadth_first_search_from_node_id(
+                    src_node_id,
+                    None,
+                    Some(true),
+                    None,
+                )
+            }
+            .get_predecessors()?
+            .into_iter()
+            .map(|predecessor| {
+                if predecessor == NODE_NOT_PRESENT {
+                    None
+                } else {
+                    Some(predecessor)
+                }
+            })
+            .collect()
+        };
+
+        let mut edge_usage_counts = vec![0 as EdgeT; self.get_number_of_directed_edges() as usize];
+        for &dst_node_id in dst_node_ids {
+            let mut node_id = dst_node_id;
+            while node_id != src_node_id {
+                let predecessor = match predecessors[node_id as usize] {
+                    Some(predecessor) => predecessor,
+                    None => break,
+                };
+                let edge_id = unsafe {
+                    if use_edge_weights {
+                        self.iter_unchecked_edge_ids_from_node_ids(predecessor, node_id)
+                            .min_by(|&first, &second| {
+                                self.get_unchecked_edge_weight_from_edge_id(first)
+                                    .unwrap()
+                                    .partial_cmp(
+                                        &self
+                                            .get_unchecked_edge_weight_from_edge_id(second)
+                                            .unwrap(),
+                                    )
+                                    .unwrap()
+                            })
+                            .unwrap()
+                    } else {
+                        self.get_unchecked_edge_id_from_node_ids(predecessor, node_id)
+                    }
+                };
+                edge_usage_counts[edge_id as usize] += 1;
+                node_id = predecessor;
+            }
+        }
+        Ok(edge_usage_counts)
+    }
+
+    /// Returns the sorted edge IDs lying on the reconstructed shortest paths from the given source to the given targets.
+    ///
+    /// # Arguments
+    /// * `src_node_id`: NodeT - Node ID root of the tree of minimum paths.
+    /// * `dst_node_ids`: &[NodeT] - Node IDs of the targets of the shortest paths.
+    /// * `use_edge_weights`: Option<bool> - Whether to use the edge weights, running Dijkstra instead of a breadth first search. By default, false.
+    ///
+    /// # Implementative details
+    /// This is the set of edges with a non-zero count in the vector returned by
+    /// `get_shortest_path_edge_usage_counts_from_node_id`.
+    ///
+    /// # Example
+    ///```rust
+    /// # let graph = graph::test_utilities::load_ppi(true, true, true, true, false, false);
+    /// let edge_ids = graph.get_shortest_path_edge_usage_from_node_id(0, &[10, 20, 30], None).unwrap();
+    /// // The edge IDs are sorted and unique.
+    /// assert!(edge_ids.windows(2).all(|window| window[0] < window[1]));
+    /// ```
+    ///
+    /// # Raises
+    /// * If the given source node ID or any of the target node IDs do not exist in the current graph.
+    /// * If the edge weights are requested but the graph does not have positive edge weights.
+    pub fn get_shortest_path_edge_usage_from_node_id(
+        &self,
+        src_node_id: NodeT,
+        dst_node_ids: &[NodeT],
+        use_edge_weights: Option<bool>,
+    ) -> Result<Vec<EdgeT>> {
+        Ok(self
+            .get_shortest_path_edge_usage_counts_from_node_id(
+                src_node_id,
+                dst_node_ids,
+                use_edge_weights,
+            )?
+            .into_par_iter()
+            .enumerate()
+            .filter_map(|(edge_id, count)| {
+                if count > 0 {
+                    Some(edge_id as EdgeT)
+                } else {
+                    None
+                }
+            })
+            .collect())
+    }
+
     /// Returns approximated diameter and tentative low eccentricity node for an UNDIRECTED graph.
     /// This method returns a lowerbound of the diameter by doing the following steps:
     /// * Find the most central node
@@ -1972,35 +2119,60 @@ impl Graph {
     ///
     /// This basically creates a "cross" that spans the graph.
     pub fn get_four_sweep(&self) -> (NodeT, NodeT) {
-        let most_central_node_id = unsafe { self.get_unchecked_most_central_node_id() };
-        let first_candidate_most_eccentric_node_id = unsafe {
-            self.get_unchecked_eccentricity_and_most_distant_node_id_from_node_id(
-                most_central_node_id,
-            )
-            .1
+        let (tentative_diameter, low_eccentricity_node, _) = unsafe {
+            self.get_unchecked_four_sweep_from_node_id(self.get_unchecked_most_central_node_id())
         };
+        (tentative_diameter, low_eccentricity_node)
+    }
 
-        let bfs1 = unsafe {
-            self.get_unchecked_breadth_first_search_predecessors_parallel_from_node_id(
-                first_candidate_most_eccentric_node_id,
-            )
-        };
+    /// Returns approximated diameter, tentative low eccentricity node and the endpoints of the approximated diameter.
+    ///
+    /// # Arguments
+    /// * `most_central_node_id`: NodeT - The node from which to start the sweeps, usually the one with maximum degree.
+    ///
+    /// # Implementative details
+    /// See `get_four_sweep`. The returned endpoints are the extremes of the
+    /// shortest path whose length is the returned diameter lowerbound.
+    ///
+    /// # Safety
+    /// If the given node ID does not exist in the graph the method will panic.
+    unsafe fn get_unchecked_four_sweep_from_node_id(
+        &self,
+        most_central_node_id: NodeT,
+    ) -> (NodeT, NodeT, (NodeT, NodeT)) {
+        let first_candidate_most_eccentric_node_id = self
+            .get_unchecked_eccentricity_and_most_distant_node_id_from_node_id(most_central_node_id)
+            .1;
+
+        let bfs1 = self.get_unchecked_breadth_first_search_predecessors_parallel_from_node_id(
+            first_candidate_most_eccentric_node_id,
+        );
 
-        let second_candidate_most_eccentric_node_id = unsafe {
-            self.get_unchecked_eccentricity_and_most_distant_node_id_from_node_id(
+        let second_candidate_most_eccentric_node_id = self
+            .get_unchecked_eccentricity_and_most_distant_node_id_from_node_id(
                 bfs1.get_median_point_to_most_distant_node().unwrap(),
             )
-            .1
-        };
-        let bfs2 = unsafe {
-            self.get_unchecked_breadth_first_search_predecessors_parallel_from_node_id(
+            .1;
+        let bfs2 = self.get_unchecked_breadth_first_search_predecessors_parallel_from_node_id(
+            second_candidate_most_eccentric_node_id,
+        );
+
+        let endpoints = if bfs1.get_eccentricity() >= bfs2.get_eccentricity() {
+            (
+                first_candidate_most_eccentric_node_id,
+                bfs1.get_most_distant_node(),
+            )
+        } else {
+            (
                 second_candidate_most_eccentric_node_id,
+                bfs2.get_most_distant_node(),
             )
         };
 
         (
             bfs1.get_eccentricity().max(bfs2.get_eccentricity()),
             bfs2.get_median_point_to_most_distant_node().unwrap(),
+            endpoints,
         )
     }
 
@@ -2020,16 +2192,32 @@ impl Graph {
             return Ok(0.0);
         }
 
+        Ok(unsafe { self.get_unchecked_ifub_from_node_id(most_central_node_id) }.0 as f32)
+    }
+
+    /// Returns diameter and its endpoints of the UNDIRECTED connected component containing the given node.
+    ///
+    /// # Arguments
+    /// * `most_central_node_id`: NodeT - The node from which to start the sweeps, usually the one with maximum degree.
+    ///
+    /// # Implementative details
+    /// See `get_ifub`. The given node must not be a disconnected node.
+    ///
+    /// # Safety
+    /// If the given node ID does not exist in the graph the method will panic.
+    unsafe fn get_unchecked_ifub_from_node_id(
+        &self,
+        most_central_node_id: NodeT,
+    ) -> (NodeT, (NodeT, NodeT)) {
         // get the lowerbound of the diameter
-        let (mut tentative_diameter, low_eccentricity_node) = self.get_four_sweep();
+        let (mut tentative_diameter, low_eccentricity_node, mut endpoints) =
+            self.get_unchecked_four_sweep_from_node_id(most_central_node_id);
         // find the distances of all the nodes from the node with low eccentricty,
         // and thus with high centrality
-        let bfs = unsafe {
-            self.get_unchecked_breadth_first_search_distances_parallel_from_node_id(
-                low_eccentricity_node,
-                None,
-            )
-        };
+        let bfs = self.get_unchecked_breadth_first_search_distances_parallel_from_node_id(
+            low_eccentricity_node,
+            None,
+        );
         assert!(
             tentative_diameter != NodeT::MAX,
             "The central node eccentricity cannot be infinite!"
@@ -2050,7 +2238,7 @@ impl Graph {
         // If all the test cases are empty, it means
         // that our tentative diameter is already the actual diameter.
         if node_ids_and_distances.is_empty() {
-            return Ok(tentative_diameter as f32);
+            return (tentative_diameter, endpoints);
         }
 
         // sort the nodes by distance, so that we will start checking from the
@@ -2073,15 +2261,15 @@ impl Graph {
 
             // Alternatively, we compute for another node ID
             // its eccentricity.
-            tentative_diameter = tentative_diameter.max(
-                unsafe {
-                    self.get_unchecked_eccentricity_and_most_distant_node_id_from_node_id(node_id)
-                }
-                .0,
-            );
+            let (eccentricity, most_distant_node_id) =
+                self.get_unchecked_eccentricity_and_most_distant_node_id_from_node_id(node_id);
+            if eccentricity > tentative_diameter {
+                tentative_diameter = eccentricity;
+                endpoints = (node_id, most_distant_node_id);
+            }
         }
 
-        Ok(tentative_diameter as f32)
+        (tentative_diameter, endpoints)
     }
 
     /// Returns diameter of the graph using naive method.
@@ -2161,6 +2349,144 @@ impl Graph {
         }
     }
 
+    /// Returns the node IDs of a shortest path whose length is equal to the diameter.
+    ///
+    /// # Arguments
+    /// * `use_edge_weights`: Option<bool> - Whether to use the edge weights as distances. By default, false.
+    ///
+    /// # Implementative details
+    /// The path is computed within the largest connected component of the graph,
+    /// which for directed graphs is the largest weakly connected component:
+    /// when the graph is disconnected, the returned path is therefore the diameter
+    /// path of the largest component. When multiple components share the largest
+    /// size, the one containing the node with the smallest node ID is used.
+    ///
+    /// For undirected graphs without edge weights, the endpoints of the diameter
+    /// are found with the same iFUB sweep used to compute the diameter, started
+    /// from the node with maximum degree in the largest component. Otherwise, the
+    /// eccentricity of every node of the largest component is computed, using
+    /// Dijkstra when the edge weights are used. The path is then reconstructed
+    /// from the predecessors of a BFS, or of a Dijkstra, from the first endpoint.
+    ///
+    /// When the largest component is composed of a single node, the path contains only that node.
+    ///
+    /// # Example
+    ///```rust
+    /// # let graph = graph::test_utilities::load_ppi(true, true, true, true, false, false);
+    /// let path = graph.get_diameter_path(None).unwrap();
+    /// println!("The diameter path has {} nodes.", path.len());
+    /// ```
+    ///
+    /// # Raises
+    /// * If the graph does not contain edges.
+    /// * If the edge weights are to be used and the graph does not have edge weights.
+    /// * If the edge weights are to be used and the graph contains non-positive edge weights.
+    pub fn get_diameter_path(&self, use_edge_weights: Option<bool>) -> Result<Vec<NodeT>> {
+        self.must_have_edges()?;
+        let use_edge_weights = use_edge_weights.unwrap_or(false);
+        if use_edge_weights {
+            self.must_have_positive_edge_weights()?;
+        }
+
+        // We identify the nodes of the largest component.
+        let component_ids = self.get_node_connected_component_ids(Some(false));
+        let mut component_sizes = vec![0 as NodeT; self.get_number_of_nodes() as usize];
+        component_ids
+            .iter()
+            .for_each(|&component_id| component_sizes[component_id as usize] += 1);
+        let largest_component_size = component_sizes.iter().copied().max().unwrap();
+        let largest_component_id = component_ids
+            .iter()
+            .copied()
+            .find(|&component_id| component_sizes[component_id as usize] == largest_component_size)
+            .unwrap();
+        let component_node_ids = component_ids
+            .par_iter()
+            .enumerate()
+            .filter_map(|(node_id, &component_id)| {
+                if component_id == largest_component_id {
+                    Some(node_id as NodeT)
+                } else {
+                    None
+                }
+            })
+            .collect::<Vec<NodeT>>();
+
+        if component_node_ids.len() == 1 {
+            return Ok(component_node_ids);
+        }
+
+        let (src_node_id, dst_node_id) = if !use_edge_weights && !self.is_directed() {
+            let most_central_node_id = component_node_ids
+                .par_iter()
+                .copied()
+                .max_by_key(|&node_id| unsafe {
+                    (
+                        self.get_unchecked_node_degree_from_node_id(node_id),
+                        std::cmp::Reverse(node_id),
+                    )
+                })
+                .unwrap();
+            unsafe { self.get_unchecked_ifub_from_node_id(most_central_node_id) }.1
+        } else if !use_edge_weights {
+            component_node_ids
+                .par_iter()
+                .map(|&node_id| unsafe {
+                    let (eccentricity, most_distant_node_id) = self
+                        .get_unchecked_eccentricity_and_most_distant_node_id_from_node_id(node_id);
+                    (eccentricity, node_id, most_distant_node_id)
+                })
+                .max_by(|a, b| a.0.cmp(&b.0).then(b.1.cmp(&a.1)))
+                .map(|(_, src, dst)| (src, dst))
+                .unwrap()
+        } else {
+            component_node_ids
+                .par_iter()
+                .map(|&node_id| unsafe {
+                    let dijkstra = self.get_unchecked_dijkstra_from_node_id(
+                        node_id,
+                        None,
+                        None,
+                        Some(false),
+                        None,
+                        None,
+                    );
+                    (
+                        dijkstra.get_eccentricity(),
+                        node_id,
+                        dijkstra.get_most_distant_node(),
+                    )
+                })
+                .max_by(|a, b| a.0.total_cmp(&b.0).then(b.1.cmp(&a.1)))
+                .map(|(_, src, dst)| (src, dst))
+                .unwrap()
+        };
+
+        if src_node_id == dst_node_id {
+            return Ok(vec![src_node_id]);
+        }
+
+        Ok(if use_edge_weights {
+            unsafe {
+                self.get_unchecked_weighted_shortest_path_node_ids_from_node_ids(
+                    src_node_id,
+                    dst_node_id,
+                    None,
+                    None,
+                )
+            }
+            .1
+        } else {
+            unsafe {
+                self.get_unchecked_shortest_path_node_ids_from_node_ids(
+                    src_node_id,
+                    dst_node_id,
+                    None,
+                )
+            }?
+        })
+    }
+
     /// Returns vector of minimum paths distances and vector of nodes predecessors from given source node name and optional destination node name.
     ///
     /// # Arguments
@@ -1,4 +1,5 @@
 use super::*;
+use rayon::prelude::*;
 
 /// Implementation of methods relative to statistical tools.
 impl Graph {
@@ -35,4 +36,98 @@ impl Graph {
             numerator / (1.0 - 1.0 / mean_node_degree)
         }
     }
+
+    /// Returns the average branching factor at each hop distance from the sampled source nodes.
+    ///
+    /// # Arguments
+    /// * `maximal_hops`: NodeT - The maximal number of hops to consider.
+    /// * `number_of_samples`: Option<usize> - The number of source nodes to sample. By default, all the nodes are used as sources.
+    /// * `random_state`: Option<u64> - The random state to use to sample the source nodes. By default, 42.
+    ///
+    /// # Implementative details
+    /// From each source node a BFS truncated at the maximal number of hops is
+    /// executed, counting the nodes at exactly `k` hops. The value at position
+    /// `k - 1` of the returned vector is the average number of nodes at `k` hops
+    /// divided by the average number of nodes at `k - 1` hops, for `k` from one up
+    /// to the maximal number of hops. The source nodes are sampled uniformly with
+    /// replacement. Source nodes whose BFS saturates early, having exhausted the
+    /// nodes reachable from them, are kept in the averages with zero nodes at the
+    /// following hops, so the profile decays once the typical source has reached
+    /// the boundary of its component. When no sampled source reaches `k - 1` hops,
+    /// the branching factor at `k` hops is zero.
+    ///
+    /// # Example
+    ///```rust
+    /// # let graph = graph::test_utilities::load_ppi(true, true, true, true, false, false);
+    /// let profile = graph.get_neighbourhood_density_profile(4, Some(100), None).unwrap();
+    /// assert_eq!(profile.len(), 4);
+    /// ```
+    ///
+    /// # Raises
+    /// * If the graph does not contain nodes.
+    /// * If the maximal number of hops is zero.
+    /// * If the number of samples is zero.
+    pub fn get_neighbourhood_density_profile(
+        &self,
+        maximal_hops: NodeT,
+        number_of_samples: Option<usize>,
+        random_state: Option<u64>,
+    ) -> Result<Vec<f64>> {
+        self.must_have_nodes()?;
+        if maximal_hops == 0 {
+            return Err("The maximal number of hops must be strictly positive.".to_string());
+        }
+        if number_of_samples == Some(0) {
+            return Err("The number of samples must be strictly positive.".to_string());
+        }
+        let random_state = random_state.unwrap_or(42);
+        let source_node_ids = number_of_samples.map_or_else(
+            || self.get_node_ids(),
+            |number_of_samples| {
+                self.par_iter_random_node_ids(number_of_samples, random_state)
+                    .collect()
+            },
+        );
+
+        let number_of_hops = maximal_hops as usize + 1;
+        let counts = source_node_ids
+            .into_par_iter()
+            .fold(
+                || vec![0; number_of_hops],
+                |mut counts: Vec<u64>, src| {
+                    let bfs = unsafe {
+                        self.get_unchecked_breadth_first_search_from_node_ids(
+                            vec![src],
+                            None,
+                            Some(false),
+                            Some(maximal_hops),
+                        )
+                    };
+                    bfs.into_iter_finite_distances()
+                        .for_each(|distance| counts[distance as usize] += 1);
+                    counts
+                },
+            )
+            .reduce(
+                || vec![0; number_of_hops],
+                |mut first, second| {
+                    first
+                        .iter_mut()
+                        .zip(second.into_iter())
+                        .for_each(|(first, second)| *first += second);
+                    first
+                },
+            );
+
+        Ok(counts
+            .windows(2)
+            .map(|window| {
+                if window[0] == 0 {
+                    0.0
+                } else {
+                    window[1] as f64 / window[0] as f64
+                }
+            })
+            .collect())
+    }
 }
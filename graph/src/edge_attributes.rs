@@ -0,0 +1,225 @@
+use super::*;
+
+/// # Edge attributes
+/// The edge attributes are an optional matrix associated to the edges, with
+/// the values of the additional edge attribute columns of the edge list.
+impl Graph {
+    /// Returns whether the graph has edge attributes.
+    pub fn has_edge_attributes(&self) -> bool {
+        self.edge_attributes.is_some()
+    }
+
+    /// Returns the additional edge attributes of the graph, with a row for each edge ID.
+    ///
+    /// # Implementative details
+    /// The edge attributes are read when the graph is loaded from an edge file
+    /// reader with the additional edge attribute columns set, and each row
+    /// contains the values of these columns in the order they were provided.
+    ///
+    /// # Raises
+    /// * If the graph was not loaded with additional edge attribute columns.
+    pub fn get_edge_attributes(&self) -> Result<Vec<Vec<f64>>> {
+        self.edge_attributes.as_ref().clone().ok_or_else(|| {
+            concat!(
+                "The current graph instance does not have edge attributes, ",
+                "as it was not loaded with additional edge attribute columns."
+            )
+            .to_string()
+        })
+    }
+
+    /// Set the edge attributes of the graph from the edge list read by the given edge file reader.
+    ///
+    /// This happens INPLACE, that is edits the current graph instance.
+    ///
+    /// # Arguments
+    /// * `edge_file_reader`: &EdgeFileReader - The edge file reader used to load the graph, with the additional edge attribute columns set.
+    ///
+    /// # Implementative details
+    /// Each line of the edge list is matched to the edge of the graph with the
+    /// same source node, destination node and edge type, so that the rows are
+    /// aligned with the edge IDs independently of the order of the edge list.
+    /// In multigraphs, the lines of the parallel edges are matched to distinct
+    /// edges, choosing among them the ones with the same weight when the edge
+    /// list has weights. In undirected graphs, the values of a line are assigned
+    /// to both the directions of the edge. When an edge appears in more lines
+    /// than the graph has edges for it, as the duplicated edges were dropped,
+    /// the values of the first of these lines are kept. The values are read with
+    /// the same separator, quotes and spaces handling used for the other columns.
+    ///
+    /// # Raises
+    /// * If the additional edge attribute columns were not set in the edge file reader.
+    /// * If any of the additional edge attribute values is missing or cannot be parsed as a float.
+    /// * If any of the lines of the edge list refers to an edge that does not exist in the graph.
+    /// * If any of the edges of the graph does not appear in the edge list.
+    pub(crate) fn attach_inplace_edge_attributes_from_edge_file_reader(
+        &mut self,
+        edge_file_reader: &EdgeFileReader,
+    ) -> Result<()> {
+        let column_numbers = match edge_file_reader
+            .additional_edge_attribute_column_numbers
+            .as_ref()
+        {
+            Some(column_numbers) => column_numbers,
+            None => {
+                return Err(concat!(
+                    "The additional edge attribute columns were not set, ",
+                    "hence there are no edge attributes to read."
+                )
+                .to_string())
+            }
+        };
+        let has_edge_types_column = edge_file_reader.edge_types_column_number.is_some();
+        // The weights are only needed to tell apart the parallel edges.
+        let weights_column_number = if self.has_edge_weights() {
+            edge_file_reader.weights_column_number
+        } else {
+            None
+        };
+        let mut lines = edge_file_reader
+            .reader
+            .read_lines(Some(
+                [
+                    Some(edge_file_reader.sources_column_number),
+                    Some(edge_file_reader.destinations_column_number),
+                    edge_file_reader.edge_types_column_number,
+                    weights_column_number,
+                ]
+                .iter()
+                .filter_map(|&column_number| column_number)
+                .chain(column_numbers.iter().copied())
+                .collect(),
+            ))?
+            .map(|line| {
+                let (line_number, elements) = line?;
+                let mut elements = elements.into_iter();
+                let src_name = elements.next().unwrap();
+                let dst_name = elements.next().unwrap();
+                let edge_type_name = if has_edge_types_column {
+                    elements.next().unwrap()
+                } else {
+                    None
+                }
+                .or_else(|| edge_file_reader.default_edge_type.clone());
+                let weight = if weights_column_number.is_some() {
+                    elements
+                        .next()
+                        .unwrap()
+                        .and_then(|weight| weight.trim().parse::<WeightT>().ok())
+                } else {
+                    None
+                }
+                .or(edge_file_reader.default_weight);
+                let (src_name, dst_name) = match (src_name, dst_name) {
+                    (Some(src_name), Some(dst_name)) => (src_name, dst_name),
+                    _ => {
+                        return Err(format!(
+                            concat!(
+                                "The source or destination node of the edge is missing. ",
+                                "The current line number is {}."
+                            ),
+                            line_number
+                        ))
+                    }
+                };
+                let add_line_number = |error: String| {
+                    format!("{} The current line number is {}.", error, line_number)
+                };
+                let src = self
+                    .get_node_id_from_node_name(&src_name)
+                    .map_err(add_line_number)?;
+                let dst = self
+                    .get_node_id_from_node_name(&dst_name)
+                    .map_err(add_line_number)?;
+                let edge_type = if self.has_edge_types() {
+                    self.get_edge_type_id_from_edge_type_name(edge_type_name.as_deref())
+                        .map_err(add_line_number)?
+                } else {
+                    None
+                };
+                let attributes = elements
+                    .zip(column_numbers.iter())
+                    .map(|(element, column_number)| {
+                        let element = element.ok_or_else(|| {
+                            format!(
+                                concat!(
+                                    "The value of the additional edge attribute in the column {} ",
+                                    "is missing. The current line number is {}."
+                                ),
+                                column_number, line_number
+                            )
+                        })?;
+                        element.trim().parse::<f64>().map_err(|_| {
+                            format!(
+                                concat!(
+                                    "Cannot parse the value `{}` of the additional edge attribute ",
+                                    "in the column {} as a float. The current line number is {}."
+                                ),
+                                element, column_number, line_number
+                            )
+                        })
+                    })
+                    .collect::<Result<Vec<f64>>>()?;
+                Ok((line_number, src, dst, edge_type, weight, attributes))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        // The lines may be read in parallel, so we restore the order of the
+        // edge list to keep the values of the first occurrence of each edge.
+        lines.sort_unstable_by_key(|(line_number, _, _, _, _, _)| *line_number);
+
+        let mut edge_attributes: Vec<Option<Vec<f64>>> =
+            vec![None; self.get_number_of_directed_edges() as usize];
+        for (line_number, src, dst, edge_type, weight, attributes) in lines {
+            let directions = if self.is_directed() || src == dst {
+                vec![(src, dst)]
+            } else {
+                vec![(src, dst), (dst, src)]
+            };
+            for (src, dst) in directions {
+                // The edges with the same source, destination and edge type,
+                // among which we look for one that has not been assigned yet.
+                let mut edge_ids = unsafe { self.iter_unchecked_edge_ids_from_node_ids(src, dst) }
+                    .filter(|&edge_id| unsafe {
+                        self.get_unchecked_edge_type_id_from_edge_id(edge_id) == edge_type
+                    })
+                    .peekable();
+                if edge_ids.peek().is_none() {
+                    return Err(format!(
+                        concat!(
+                            "The edge from {} to {} does not exist in the graph. ",
+                            "The current line number is {}."
+                        ),
+                        unsafe { self.get_unchecked_node_name_from_node_id(src) },
+                        unsafe { self.get_unchecked_node_name_from_node_id(dst) },
+                        line_number
+                    ));
+                }
+                if let Some(edge_id) = edge_ids.find(|&edge_id| {
+                    edge_attributes[edge_id as usize].is_none()
+                        && weight.map_or(true, |weight| {
+                            Some(weight)
+                                == unsafe { self.get_unchecked_edge_weight_from_edge_id(edge_id) }
+                        })
+                }) {
+                    edge_attributes[edge_id as usize] = Some(attributes.clone());
+                }
+            }
+        }
+
+        self.edge_attributes = Arc::new(Some(
+            edge_attributes
+                .into_iter()
+                .enumerate()
+                .map(|(edge_id, attributes)| {
+                    attributes.ok_or_else(|| {
+                        format!(
+                            "The edge with ID {} does not appear in the edge list.",
+                            edge_id
+                        )
+                    })
+                })
+                .collect::<Result<Vec<Vec<f64>>>>()?,
+        ));
+        Ok(())
+    }
+}
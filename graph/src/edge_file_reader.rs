@@ -15,6 +15,7 @@ pub struct EdgeFileReader {
     pub(crate) default_edge_type: Option<String>,
     pub(crate) weights_column_number: Option<usize>,
     pub(crate) default_weight: Option<WeightT>,
+    pub(crate) additional_edge_attribute_column_numbers: Option<Vec<usize>>,
     pub(crate) numeric_edge_type_ids: bool,
     pub(crate) numeric_node_ids: bool,
     pub(crate) skip_weights_if_unavailable: bool,
@@ -41,6 +42,7 @@ impl EdgeFileReader {
             default_edge_type: None,
             weights_column_number: None,
             default_weight: None,
+            additional_edge_attribute_column_numbers: None,
             numeric_edge_type_ids: false,
             numeric_node_ids: false,
             skip_weights_if_unavailable: false,
@@ -342,6 +344,69 @@ impl EdgeFileReader {
         self.weights_column_number
     }
 
+    /// Set the columns of the additional numeric edge attributes.
+    ///
+    /// # Arguments
+    /// * `additional_edge_attribute_columns`: Option<Vec<String>> - The additional edge attribute columns to use for the file.
+    ///
+    pub fn set_additional_edge_attribute_columns<S: Into<String>>(
+        mut self,
+        additional_edge_attribute_columns: Option<Vec<S>>,
+    ) -> Result<EdgeFileReader> {
+        if let Some(columns) = additional_edge_attribute_columns {
+            let column_numbers = columns
+                .into_iter()
+                .map(|column| {
+                    let column = column.into();
+                    if column.is_empty() {
+                        return Err(
+                            "One of the given additional edge attribute columns is empty."
+                                .to_owned(),
+                        );
+                    }
+                    self.reader.get_column_number(column)
+                })
+                .collect::<Result<Vec<usize>>>()?;
+            self = self.set_additional_edge_attribute_column_numbers(Some(column_numbers))?;
+        }
+        Ok(self)
+    }
+
+    /// Set the column numbers of the additional numeric edge attributes.
+    ///
+    /// # Arguments
+    /// * `additional_edge_attribute_column_numbers`: Option<Vec<usize>> - The additional edge attribute column numbers to use for the file.
+    ///
+    pub fn set_additional_edge_attribute_column_numbers(
+        mut self,
+        additional_edge_attribute_column_numbers: Option<Vec<usize>>,
+    ) -> Result<EdgeFileReader> {
+        if let Some(column_numbers) = additional_edge_attribute_column_numbers {
+            if column_numbers.is_empty() {
+                return Err("The given additional edge attribute columns are empty.".to_owned());
+            }
+            let expected_elements = self.reader.get_elements_per_line()?;
+            for &column in column_numbers.iter() {
+                if column >= expected_elements {
+                    return Err(format!(
+                        concat!(
+                            "The additional edge attribute column number passed was {} but ",
+                            "the first parsable line has {} values."
+                        ),
+                        column, expected_elements
+                    ));
+                }
+            }
+            self.additional_edge_attribute_column_numbers = Some(column_numbers);
+        }
+        Ok(self)
+    }
+
+    /// Return the additional edge attribute column numbers.
+    pub fn get_additional_edge_attribute_column_numbers(&self) -> Option<Vec<usize>> {
+        self.additional_edge_attribute_column_numbers.clone()
+    }
+
     /// Set whether the current edge list is complete.
     ///
     /// # Arguments
@@ -492,6 +557,18 @@ impl EdgeFileReader {
         Ok(self)
     }
 
+    /// Set the compression of the edge list.
+    ///
+    /// # Arguments
+    /// * compression: Option<String> - The compression of the edge list, either `gzip` or `none`. By default, edge lists with the `.gz` extension are read as gzip compressed.
+    ///
+    /// Gzip compressed edge lists are decompressed as a stream, whose lines are processed in parallel when the parallel reader is requested.
+    ///
+    pub fn set_compression(mut self, compression: Option<String>) -> Result<EdgeFileReader> {
+        self.reader = self.reader.set_compression(compression)?;
+        Ok(self)
+    }
+
     /// Set the verbose.
     ///
     /// # Arguments
@@ -525,3 +525,108 @@ impl EdgeFileWriter {
         )
     }
 }
+
+/// Write the given edge list iterator to file, without building a graph.
+///
+/// # Arguments
+/// * `path`: S - Path where to write the edge list.
+/// * `separator`: Option<char> - The separator to use for the file. By default, tab.
+/// * `header`: Option<bool> - Whether to write out an header. By default, true.
+/// * `edge_types_column`: Option<String> - The name of the edge types column. By default, no edge types column is written.
+/// * `weights_column`: Option<String> - The name of the weights column. By default, no weights column is written.
+/// * `iterator`: impl Iterator<Item = (NodeT, NodeT, Option<String>, Option<WeightT>)> - The iterator with the source node IDs, destination node IDs, edge type names and weights to write.
+///
+/// # Implementative details
+/// The edges are streamed to disk as they are produced by the iterator, using
+/// the same line composition of the `EdgeFileWriter`, so that the edge list
+/// of a graph too large to be kept in memory can be written out. The node IDs
+/// are written as numeric node IDs in the `subject` and `object` columns, followed
+/// by the edge types and the weights columns, when requested. Edges without an
+/// edge type or a weight are written with an empty value in the respective column.
+/// When an error is encountered, the partially written file is removed.
+///
+/// # Example
+///```rust
+/// # use graph::write_edge_iterator;
+/// let path = std::env::temp_dir().join("write_edge_iterator_example.tsv");
+/// let path = path.to_str().unwrap();
+/// write_edge_iterator(
+///     path,
+///     None,
+///     None,
+///     None,
+///     Some("weight".to_string()),
+///     (0..10).map(|src| (src, src + 1, None, Some(1.0))),
+/// ).unwrap();
+/// # std::fs::remove_file(path).unwrap();
+/// ```
+///
+/// # Raises
+/// * If an edge has an edge type but the edge types column was not provided.
+/// * If an edge has a weight but the weights column was not provided.
+/// * If some I/O error is encountered.
+pub fn write_edge_iterator<S: Into<String>>(
+    path: S,
+    separator: Option<char>,
+    header: Option<bool>,
+    edge_types_column: Option<String>,
+    weights_column: Option<String>,
+    iterator: impl Iterator<Item = (NodeT, NodeT, Option<String>, Option<WeightT>)>,
+) -> Result<()> {
+    let has_edge_types = edge_types_column.is_some();
+    let has_weights = weights_column.is_some();
+    let writer = EdgeFileWriter::new(path)
+        .set_separator(separator)?
+        .set_header(header)
+        .set_numeric_node_ids(Some(true))
+        .set_edge_types_column(edge_types_column)
+        .set_edge_types_column_number(if has_edge_types { Some(2) } else { None })
+        .set_weights_column(weights_column)
+        .set_weights_column_number(if has_weights {
+            Some(2 + has_edge_types as usize)
+        } else {
+            None
+        });
+
+    let mut stream = writer.start_writer()?;
+    let result = (|| {
+        for (edge_id, (src, dst, edge_type_name, weight)) in iterator.enumerate() {
+            if !has_edge_types && edge_type_name.is_some() {
+                return Err(format!(
+                    concat!(
+                        "The edge with ID {} has edge type {:?}, ",
+                        "but the edge types column was not provided."
+                    ),
+                    edge_id, edge_type_name
+                ));
+            }
+            if !has_weights && weight.is_some() {
+                return Err(format!(
+                    concat!(
+                        "The edge with ID {} has weight {:?}, ",
+                        "but the weights column was not provided."
+                    ),
+                    edge_id, weight
+                ));
+            }
+            writer.write_line(
+                &mut stream,
+                edge_id as EdgeT,
+                src,
+                String::new(),
+                dst,
+                String::new(),
+                None,
+                edge_type_name,
+                weight,
+            )?;
+        }
+        writer.close_writer(stream)
+    })();
+    // We remove the partially written file, so that no truncated
+    // edge list is left on disk.
+    if result.is_err() {
+        let _ = std::fs::remove_file(&writer.writer.path);
+    }
+    result
+}
@@ -0,0 +1,122 @@
+use crate::{
+    utils::ItersWrapper, EdgeFileReader, EdgeFileWriter, EdgeT, NodeFileWriter, NodeT, Result,
+    Vocabulary,
+};
+
+/// Create a new numeric edge list with dense node IDs and the file mapping the node names to them.
+///
+/// # Arguments
+/// * `original_edge_path`: &str - The path from where to load the original edge list.
+/// * `target_edge_path`: &str - The path where to write the numeric edge list. This must be different from the original edge list path.
+/// * `target_mapping_path`: &str - The path where to write the mapping from the node names to the node IDs.
+/// * `separator`: Option<char> - Separator to use for the original edge list, the target edge list and the mapping.
+/// * `header`: Option<bool> - Whether the original edge list has an header. The target files will have one accordingly.
+/// * `verbose`: Option<bool> - Whether to show the loading bar while processing the file.
+///
+/// # Implementative details
+/// The original edge list is read in a single sequential pass, and each line
+/// is written to the target edge list as soon as it is parsed, with the node
+/// IDs assigned densely in order of first appearance. Analogously, each new node
+/// is written to the mapping file when it is first encountered. Therefore, only
+/// the node names vocabulary is kept in memory, while the edges are streamed,
+/// so that edge lists that are larger than the available memory can be converted.
+/// Only the source and destination columns are written to the target edge list.
+///
+/// # Returns
+/// Tuple with the number of nodes and the number of edges written.
+///
+/// # Raises
+/// * If there are problems with opening the original or target files.
+/// * If the original, target and mapping paths are not distinct.
+/// * If any line of the original edge list cannot be parsed.
+pub fn convert_edge_list_to_numeric_with_mapping(
+    original_edge_path: &str,
+    target_edge_path: &str,
+    target_mapping_path: &str,
+    separator: Option<char>,
+    header: Option<bool>,
+    verbose: Option<bool>,
+) -> Result<(NodeT, EdgeT)> {
+    if original_edge_path == target_edge_path
+        || original_edge_path == target_mapping_path
+        || target_edge_path == target_mapping_path
+    {
+        return Err(format!(
+            concat!(
+                "The original edge list path {:?}, the target edge list path {:?} ",
+                "and the target mapping path {:?} must be distinct.\n",
+                "It is not possible to write these files inplace, ",
+                "as the lines would overwrite the ones yet to be read."
+            ),
+            original_edge_path, target_edge_path, target_mapping_path
+        ));
+    }
+    let file_reader = EdgeFileReader::new(original_edge_path)?
+        .set_header(header)?
+        .set_separator(separator)?
+        .set_parallel(Some(false))
+        .set_verbose(verbose);
+    let file_writer = EdgeFileWriter::new(target_edge_path)
+        .set_separator(Some(file_reader.get_separator()))?
+        .set_numeric_node_ids(Some(true))
+        .set_verbose(verbose)
+        .set_header(Some(file_reader.has_header()));
+    let mapping_writer = NodeFileWriter::new(target_mapping_path)
+        .set_node_ids_column(Some("node_id".to_string()))
+        .set_node_ids_column_number(Some(1))
+        .set_separator(Some(file_reader.get_separator()))?
+        .set_header(Some(file_reader.has_header()));
+
+    let lines_iterator = match file_reader.read_lines()? {
+        ItersWrapper::Parallel(_) => unreachable!("This is not meant to run in parallel."),
+        ItersWrapper::Sequential(i) => i,
+    };
+
+    let mut nodes: Vocabulary<NodeT> = Vocabulary::new(false, "Nodes".to_string());
+    let mut edge_file_stream = file_writer.start_writer()?;
+    let mut mapping_file_stream = mapping_writer.start_writer()?;
+    let mut number_of_edges: EdgeT = 0;
+
+    for line in lines_iterator {
+        let (_, (src_name, dst_name, _, _)) = line?;
+        let (src_id, src_was_already_present) = nodes.insert(&src_name)?;
+        if !src_was_already_present {
+            mapping_writer.write_line(
+                &mut mapping_file_stream,
+                src_id,
+                src_name,
+                None,
+                None,
+                None,
+            )?;
+        }
+        let (dst_id, dst_was_already_present) = nodes.insert(&dst_name)?;
+        if !dst_was_already_present {
+            mapping_writer.write_line(
+                &mut mapping_file_stream,
+                dst_id,
+                dst_name,
+                None,
+                None,
+                None,
+            )?;
+        }
+        file_writer.write_line(
+            &mut edge_file_stream,
+            number_of_edges,
+            src_id,
+            "".to_string(),
+            dst_id,
+            "".to_string(),
+            None,
+            None,
+            None,
+        )?;
+        number_of_edges += 1;
+    }
+
+    file_writer.close_writer(edge_file_stream)?;
+    mapping_writer.close_writer(mapping_file_stream)?;
+
+    Ok((nodes.len() as NodeT, number_of_edges))
+}
@@ -1,5 +1,7 @@
 mod convert_edge_list_to_numeric;
 pub use convert_edge_list_to_numeric::*;
+mod convert_edge_list_to_numeric_with_mapping;
+pub use convert_edge_list_to_numeric_with_mapping::*;
 mod get_minmax_node_from_numeric_edge_list;
 pub use get_minmax_node_from_numeric_edge_list::*;
 mod get_number_of_selfloops_from_edge_list;
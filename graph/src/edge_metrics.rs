@@ -352,31 +352,21 @@ impl Graph {
         .count() as f32
     }
 
-    /// Returns the Jaccard index for the two given nodes from the given node IDs.
+    /// Returns the number of shared neighbours and the degrees of the two given nodes from the given node IDs.
     ///
     /// # Arguments
     ///
     /// * `source_node_id`: NodeT - Node ID of the first node.
     /// * `destination_node_id`: NodeT - Node ID of the second node.
     ///
-    /// # References
-    /// [D. Liben-Nowell, J. Kleinberg.
-    /// The Link Prediction Problem for Social Networks (2004).](http://www.cs.cornell.edu/home/kleinber/link-pred.pdf)
-    ///
-    /// # Example
-    ///```rust
-    /// # let graph = graph::test_utilities::load_ppi(true, true, true, true, false, false);
-    /// println!("The Jaccard Index between node 1 and node 2 is {}", unsafe{ graph.get_unchecked_jaccard_coefficient_from_node_ids(1, 2) });
-    /// ```
-    ///
     /// # Safety
     /// If either of the provided one and two node IDs are higher than the
     /// number of nodes in the graph.
-    pub unsafe fn get_unchecked_jaccard_coefficient_from_node_ids(
+    unsafe fn get_unchecked_neighbours_intersection_and_degrees_from_node_ids(
         &self,
         source_node_id: NodeT,
         destination_node_id: NodeT,
-    ) -> f32 {
+    ) -> (usize, usize, usize) {
         let src_neighbours = self
             .edges
             .get_unchecked_neighbours_node_ids_from_src_node_id(source_node_id);
@@ -401,6 +391,40 @@ impl Graph {
             intersection_count += (src_neighbour == dst_neighbour) as usize;
         }
 
+        (intersection_count, src_count, dst_count)
+    }
+
+    /// Returns the Jaccard index for the two given nodes from the given node IDs.
+    ///
+    /// # Arguments
+    ///
+    /// * `source_node_id`: NodeT - Node ID of the first node.
+    /// * `destination_node_id`: NodeT - Node ID of the second node.
+    ///
+    /// # References
+    /// [D. Liben-Nowell, J. Kleinberg.
+    /// The Link Prediction Problem for Social Networks (2004).](http://www.cs.cornell.edu/home/kleinber/link-pred.pdf)
+    ///
+    /// # Example
+    ///```rust
+    /// # let graph = graph::test_utilities::load_ppi(true, true, true, true, false, false);
+    /// println!("The Jaccard Index between node 1 and node 2 is {}", unsafe{ graph.get_unchecked_jaccard_coefficient_from_node_ids(1, 2) });
+    /// ```
+    ///
+    /// # Safety
+    /// If either of the provided one and two node IDs are higher than the
+    /// number of nodes in the graph.
+    pub unsafe fn get_unchecked_jaccard_coefficient_from_node_ids(
+        &self,
+        source_node_id: NodeT,
+        destination_node_id: NodeT,
+    ) -> f32 {
+        let (intersection_count, src_count, dst_count) = self
+            .get_unchecked_neighbours_intersection_and_degrees_from_node_ids(
+                source_node_id,
+                destination_node_id,
+            );
+
         let union_count = src_count + dst_count - intersection_count;
 
         if intersection_count == 0 {
@@ -468,6 +492,289 @@ impl Graph {
         })
     }
 
+    /// Returns the Jaccard index between the given reference node and each of the candidate nodes.
+    ///
+    /// # Arguments
+    /// * `reference_node_id`: NodeT - Node ID of the reference node.
+    /// * `candidate_node_ids`: Option<&[NodeT]> - Node IDs of the candidate nodes. By default, all the nodes of the graph.
+    ///
+    /// # Implementative details
+    /// The returned vector is aligned with the candidate node IDs, and
+    /// the Jaccard indices are computed in parallel over the candidates
+    /// with the same sorted neighbourhood intersection used by
+    /// `get_unchecked_jaccard_coefficient_from_node_ids`, in double precision.
+    ///
+    /// # Example
+    ///```rust
+    /// # let graph = graph::test_utilities::load_ppi(true, true, true, true, false, false);
+    /// let similarities = graph.get_jaccard_similarity_to_node(1, None).unwrap();
+    /// assert_eq!(similarities.len(), graph.get_number_of_nodes() as usize);
+    /// ```
+    ///
+    /// # Raises
+    /// * If the reference node ID or any of the candidate node IDs does not exist in the graph.
+    pub fn get_jaccard_similarity_to_node(
+        &self,
+        reference_node_id: NodeT,
+        candidate_node_ids: Option<&[NodeT]>,
+    ) -> Result<Vec<f64>> {
+        self.validate_node_id(reference_node_id)?;
+        let get_jaccard_similarity = |node_id: NodeT| {
+            let (intersection_count, src_count, dst_count) = unsafe {
+                self.get_unchecked_neighbours_intersection_and_degrees_from_node_ids(
+                    reference_node_id,
+                    node_id,
+                )
+            };
+            if intersection_count == 0 {
+                0.0
+            } else {
+                intersection_count as f64 / (src_count + dst_count - intersection_count) as f64
+            }
+        };
+        Ok(if let Some(candidate_node_ids) = candidate_node_ids {
+            candidate_node_ids
+                .iter()
+                .try_for_each(|&node_id| self.validate_node_id(node_id).map(|_| ()))?;
+            candidate_node_ids
+                .par_iter()
+                .map(|&node_id| get_jaccard_similarity(node_id))
+                .collect()
+        } else {
+            self.par_iter_node_ids()
+                .map(get_jaccard_similarity)
+                .collect()
+        })
+    }
+
+    /// Returns the Sørensen-Dice index for the two given nodes from the given node IDs.
+    ///
+    /// # Arguments
+    ///
+    /// * `source_node_id`: NodeT - Node ID of the first node.
+    /// * `destination_node_id`: NodeT - Node ID of the second node.
+    ///
+    /// # Implementation details
+    /// The Sørensen-Dice index is twice the number of shared neighbours
+    /// divided by the sum of the degrees of the two nodes, and it is zero
+    /// when the two nodes do not share any neighbour.
+    ///
+    /// # References
+    /// T. Sørensen. A method of establishing groups of equal amplitude in plant
+    /// sociology based on similarity of species content (1948).
+    ///
+    /// # Safety
+    /// If either of the provided one and two node IDs are higher than the
+    /// number of nodes in the graph.
+    pub unsafe fn get_unchecked_sorensen_index_from_node_ids(
+        &self,
+        source_node_id: NodeT,
+        destination_node_id: NodeT,
+    ) -> f64 {
+        let (intersection_count, src_count, dst_count) = self
+            .get_unchecked_neighbours_intersection_and_degrees_from_node_ids(
+                source_node_id,
+                destination_node_id,
+            );
+
+        if intersection_count == 0 {
+            0.0
+        } else {
+            2.0 * intersection_count as f64 / (src_count + dst_count) as f64
+        }
+    }
+
+    /// Returns the Sørensen-Dice index for the two given nodes from the given node IDs.
+    ///
+    /// # Arguments
+    ///
+    /// * `source_node_id`: NodeT - Node ID of the first node.
+    /// * `destination_node_id`: NodeT - Node ID of the second node.
+    ///
+    /// # Implementation details
+    /// The Sørensen-Dice index is twice the number of shared neighbours
+    /// divided by the sum of the degrees of the two nodes, and it is zero
+    /// when the two nodes do not share any neighbour.
+    ///
+    /// # References
+    /// T. Sørensen. A method of establishing groups of equal amplitude in plant
+    /// sociology based on similarity of species content (1948).
+    ///
+    /// # Example
+    ///```rust
+    /// # let graph = graph::test_utilities::load_ppi(true, true, true, true, false, false);
+    /// println!("The Sørensen-Dice index between node 1 and node 2 is {}", graph.get_sorensen_index_from_node_ids(1, 2).unwrap());
+    /// ```
+    ///
+    /// # Raises
+    /// * If either of the node IDs are higher than the number of nodes in the graph.
+    pub fn get_sorensen_index_from_node_ids(
+        &self,
+        source_node_id: NodeT,
+        destination_node_id: NodeT,
+    ) -> Result<f64> {
+        Ok(unsafe {
+            self.get_unchecked_sorensen_index_from_node_ids(
+                self.validate_node_id(source_node_id)?,
+                self.validate_node_id(destination_node_id)?,
+            )
+        })
+    }
+
+    /// Returns the Hub Promoted index for the two given nodes from the given node IDs.
+    ///
+    /// # Arguments
+    ///
+    /// * `source_node_id`: NodeT - Node ID of the first node.
+    /// * `destination_node_id`: NodeT - Node ID of the second node.
+    ///
+    /// # Implementation details
+    /// The Hub Promoted index is the number of shared neighbours divided by
+    /// the smaller of the degrees of the two nodes, hence the links adjacent
+    /// to hubs are likely to get high scores. It is zero when the two nodes
+    /// do not share any neighbour.
+    ///
+    /// # References
+    /// [E. Ravasz, A. L. Somera, D. A. Mongru, Z. N. Oltvai, A.-L. Barabási.
+    /// Hierarchical Organization of Modularity in Metabolic Networks (2002).](https://doi.org/10.1126/science.1073374)
+    ///
+    /// # Safety
+    /// If either of the provided one and two node IDs are higher than the
+    /// number of nodes in the graph.
+    pub unsafe fn get_unchecked_hub_promoted_index_from_node_ids(
+        &self,
+        source_node_id: NodeT,
+        destination_node_id: NodeT,
+    ) -> f64 {
+        let (intersection_count, src_count, dst_count) = self
+            .get_unchecked_neighbours_intersection_and_degrees_from_node_ids(
+                source_node_id,
+                destination_node_id,
+            );
+
+        if intersection_count == 0 {
+            0.0
+        } else {
+            intersection_count as f64 / src_count.min(dst_count) as f64
+        }
+    }
+
+    /// Returns the Hub Promoted index for the two given nodes from the given node IDs.
+    ///
+    /// # Arguments
+    ///
+    /// * `source_node_id`: NodeT - Node ID of the first node.
+    /// * `destination_node_id`: NodeT - Node ID of the second node.
+    ///
+    /// # Implementation details
+    /// The Hub Promoted index is the number of shared neighbours divided by
+    /// the smaller of the degrees of the two nodes, hence the links adjacent
+    /// to hubs are likely to get high scores. It is zero when the two nodes
+    /// do not share any neighbour.
+    ///
+    /// # References
+    /// [E. Ravasz, A. L. Somera, D. A. Mongru, Z. N. Oltvai, A.-L. Barabási.
+    /// Hierarchical Organization of Modularity in Metabolic Networks (2002).](https://doi.org/10.1126/science.1073374)
+    ///
+    /// # Example
+    ///```rust
+    /// # let graph = graph::test_utilities::load_ppi(true, true, true, true, false, false);
+    /// println!("The Hub Promoted index between node 1 and node 2 is {}", graph.get_hub_promoted_index_from_node_ids(1, 2).unwrap());
+    /// ```
+    ///
+    /// # Raises
+    /// * If either of the node IDs are higher than the number of nodes in the graph.
+    pub fn get_hub_promoted_index_from_node_ids(
+        &self,
+        source_node_id: NodeT,
+        destination_node_id: NodeT,
+    ) -> Result<f64> {
+        Ok(unsafe {
+            self.get_unchecked_hub_promoted_index_from_node_ids(
+                self.validate_node_id(source_node_id)?,
+                self.validate_node_id(destination_node_id)?,
+            )
+        })
+    }
+
+    /// Returns the Hub Depressed index for the two given nodes from the given node IDs.
+    ///
+    /// # Arguments
+    ///
+    /// * `source_node_id`: NodeT - Node ID of the first node.
+    /// * `destination_node_id`: NodeT - Node ID of the second node.
+    ///
+    /// # Implementation details
+    /// The Hub Depressed index is the number of shared neighbours divided by
+    /// the larger of the degrees of the two nodes, hence the links adjacent
+    /// to hubs are likely to get low scores. It is zero when the two nodes
+    /// do not share any neighbour.
+    ///
+    /// # References
+    /// [T. Zhou, L. Lu, Y.-C. Zhang.
+    /// Predicting missing links via local information.
+    /// Eur. Phys. J. B 71 (2009) 623.](http://arxiv.org/pdf/0901.0553.pdf)
+    ///
+    /// # Safety
+    /// If either of the provided one and two node IDs are higher than the
+    /// number of nodes in the graph.
+    pub unsafe fn get_unchecked_hub_depressed_index_from_node_ids(
+        &self,
+        source_node_id: NodeT,
+        destination_node_id: NodeT,
+    ) -> f64 {
+        let (intersection_count, src_count, dst_count) = self
+            .get_unchecked_neighbours_intersection_and_degrees_from_node_ids(
+                source_node_id,
+                destination_node_id,
+            );
+
+        if intersection_count == 0 {
+            0.0
+        } else {
+            intersection_count as f64 / src_count.max(dst_count) as f64
+        }
+    }
+
+    /// Returns the Hub Depressed index for the two given nodes from the given node IDs.
+    ///
+    /// # Arguments
+    ///
+    /// * `source_node_id`: NodeT - Node ID of the first node.
+    /// * `destination_node_id`: NodeT - Node ID of the second node.
+    ///
+    /// # Implementation details
+    /// The Hub Depressed index is the number of shared neighbours divided by
+    /// the larger of the degrees of the two nodes, hence the links adjacent
+    /// to hubs are likely to get low scores. It is zero when the two nodes
+    /// do not share any neighbour.
+    ///
+    /// # References
+    /// [T. Zhou, L. Lu, Y.-C. Zhang.
+    /// Predicting missing links via local information.
+    /// Eur. Phys. J. B 71 (2009) 623.](http://arxiv.org/pdf/0901.0553.pdf)
+    ///
+    /// # Example
+    ///```rust
+    /// # let graph = graph::test_utilities::load_ppi(true, true, true, true, false, false);
+    /// println!("The Hub Depressed index between node 1 and node 2 is {}", graph.get_hub_depressed_index_from_node_ids(1, 2).unwrap());
+    /// ```
+    ///
+    /// # Raises
+    /// * If either of the node IDs are higher than the number of nodes in the graph.
+    pub fn get_hub_depressed_index_from_node_ids(
+        &self,
+        source_node_id: NodeT,
+        destination_node_id: NodeT,
+    ) -> Result<f64> {
+        Ok(unsafe {
+            self.get_unchecked_hub_depressed_index_from_node_ids(
+                self.validate_node_id(source_node_id)?,
+                self.validate_node_id(destination_node_id)?,
+            )
+        })
+    }
+
     /// Returns the Adamic/Adar Index for the given pair of nodes from the given node IDs.
     ///
     /// # Arguments
@@ -1032,6 +1339,96 @@ impl Graph {
             })
     }
 
+    /// Returns the neighbourhood overlap of the edge between the two given node IDs.
+    ///
+    /// The neighbourhood overlap, also known as embeddedness, is the Jaccard
+    /// index of the neighbourhoods of the two nodes, excluding from each
+    /// neighbourhood the other endpoint of the edge. Edges with a low
+    /// overlap are the so called local bridges.
+    ///
+    /// # Arguments
+    /// * `source_node_id`: NodeT - Node ID of the first node.
+    /// * `destination_node_id`: NodeT - Node ID of the second node.
+    ///
+    /// # Implementative details
+    /// The neighbourhoods are considered as sets: parallel edges are counted
+    /// once and selfloops are ignored. The overlap of a selfloop edge is zero,
+    /// as it does not connect two distinct nodes. When both neighbourhoods
+    /// are empty, the overlap is zero.
+    ///
+    /// # References
+    /// [D. Easley, J. Kleinberg.
+    /// Networks, Crowds, and Markets (2010), Chapter 3.](https://www.cs.cornell.edu/home/kleinber/networks-book/)
+    ///
+    /// # Safety
+    /// If either of the provided one and two node IDs are higher than the
+    /// number of nodes in the graph.
+    pub unsafe fn get_unchecked_neighbourhood_overlap_from_node_ids(
+        &self,
+        source_node_id: NodeT,
+        destination_node_id: NodeT,
+    ) -> f64 {
+        if source_node_id == destination_node_id {
+            return 0.0;
+        }
+        let src_neighbours = self
+            .iter_unchecked_unique_neighbour_node_ids_from_source_node_id(source_node_id)
+            .filter(|&node_id| node_id != source_node_id && node_id != destination_node_id)
+            .collect::<Vec<NodeT>>();
+        let dst_neighbours = self
+            .iter_unchecked_unique_neighbour_node_ids_from_source_node_id(destination_node_id)
+            .filter(|&node_id| node_id != source_node_id && node_id != destination_node_id)
+            .collect::<Vec<NodeT>>();
+        let intersection_count = iter_set::intersection(
+            src_neighbours.iter().copied(),
+            dst_neighbours.iter().copied(),
+        )
+        .count();
+        let union_count = src_neighbours.len() + dst_neighbours.len() - intersection_count;
+        if intersection_count == 0 {
+            0.0
+        } else {
+            intersection_count as f64 / union_count as f64
+        }
+    }
+
+    /// Returns parallel iterator on the neighbourhood overlap for all edges.
+    ///
+    /// The scores are indexed by the directed edge IDs of the graph.
+    /// See `get_unchecked_neighbourhood_overlap_from_node_ids` for the
+    /// definition of the neighbourhood overlap.
+    pub fn par_iter_edge_neighbourhood_overlap(
+        &self,
+    ) -> impl IndexedParallelIterator<Item = f64> + '_ {
+        self.par_iter_directed_edge_node_ids().map(
+            move |(_, source_node_id, destination_node_id)| unsafe {
+                self.get_unchecked_neighbourhood_overlap_from_node_ids(
+                    source_node_id,
+                    destination_node_id,
+                )
+            },
+        )
+    }
+
+    /// Returns the neighbourhood overlap for all edges.
+    ///
+    /// The scores are indexed by the directed edge IDs of the graph.
+    /// See `get_unchecked_neighbourhood_overlap_from_node_ids` for the
+    /// definition of the neighbourhood overlap.
+    ///
+    /// # Example
+    ///```rust
+    /// # let graph = graph::test_utilities::load_ppi(true, true, true, true, false, false);
+    /// let overlaps = graph.get_edge_neighbourhood_overlap();
+    /// assert_eq!(overlaps.len(), graph.get_number_of_directed_edges() as usize);
+    /// ```
+    pub fn get_edge_neighbourhood_overlap(&self) -> Vec<f64> {
+        let mut result = Vec::with_capacity(self.get_number_of_directed_edges() as usize);
+        self.par_iter_edge_neighbourhood_overlap()
+            .collect_into_vec(&mut result);
+        result
+    }
+
     /// Returns parallel iterator on Adamic-Adar for all edges.
     ///
     /// # Arguments
@@ -1243,4 +1640,149 @@ impl Graph {
             result
         })
     }
+
+    /// Returns the number of directed edges and the sums of the second and fourth powers of the node degrees.
+    fn get_configuration_model_degree_moments(&self) -> (f64, f64, f64) {
+        self.par_iter_node_degrees()
+            .map(|degree| {
+                let degree = degree as f64;
+                (degree, degree.powi(2), degree.powi(4))
+            })
+            .reduce(
+                || (0.0, 0.0, 0.0),
+                |(first_a, second_a, fourth_a), (first_b, second_b, fourth_b)| {
+                    (first_a + first_b, second_a + second_b, fourth_a + fourth_b)
+                },
+            )
+    }
+
+    /// Returns the z-score of the number of common neighbours of the two given nodes under the configuration model.
+    ///
+    /// # Arguments
+    /// * `source_node_id`: NodeT - Node ID of the first node.
+    /// * `destination_node_id`: NodeT - Node ID of the second node.
+    /// * `degree_moments`: (f64, f64, f64) - The sum of the node degrees, of their squares and of their fourth powers.
+    ///
+    /// # Safety
+    /// If either of the provided node IDs are higher than the number of nodes in the graph.
+    unsafe fn get_unchecked_common_neighbours_zscore_from_node_ids_and_degree_moments(
+        &self,
+        source_node_id: NodeT,
+        destination_node_id: NodeT,
+        degree_moments: (f64, f64, f64),
+    ) -> f64 {
+        let (degrees_sum, squared_degrees_sum, fourth_power_degrees_sum) = degree_moments;
+        if degrees_sum == 0.0 {
+            return 0.0;
+        }
+        let source_degree = self.get_unchecked_node_degree_from_node_id(source_node_id) as f64;
+        let destination_degree =
+            self.get_unchecked_node_degree_from_node_id(destination_node_id) as f64;
+        let scale = source_degree * destination_degree / degrees_sum.powi(2);
+        let expected_common_neighbours =
+            scale * (squared_degrees_sum - source_degree.powi(2) - destination_degree.powi(2));
+        let variance = expected_common_neighbours
+            - scale.powi(2)
+                * (fourth_power_degrees_sum - source_degree.powi(4) - destination_degree.powi(4));
+        if variance <= 0.0 {
+            return 0.0;
+        }
+        let observed_common_neighbours = self
+            .iter_unchecked_neighbour_node_ids_intersection_from_source_node_ids(
+                source_node_id,
+                destination_node_id,
+            )
+            .count() as f64;
+        (observed_common_neighbours - expected_common_neighbours) / variance.sqrt()
+    }
+
+    /// Returns the z-score of the number of common neighbours of the two given nodes under the configuration model.
+    ///
+    /// # Arguments
+    /// * `source_node_id`: NodeT - Node ID of the first node.
+    /// * `destination_node_id`: NodeT - Node ID of the second node.
+    ///
+    /// # Implementative details
+    /// In the configuration model with `2m` edge endpoints, a node `k` of degree
+    /// `d_k` is a neighbour of the node `a` of degree `d_a` with probability
+    /// approximately `d_a d_k / 2m`, and the node `k` is a common neighbour of the
+    /// nodes `a` and `b` with probability `q_k = d_a d_b d_k^2 / (2m)^2`. Treating
+    /// these events as independent, the expected number of common neighbours is
+    /// the sum of `q_k` over all the other nodes, and its variance is the sum of
+    /// `q_k (1 - q_k)`, both computed in closed form from the sums of the squared
+    /// and of the fourth powers of the node degrees.
+    ///
+    /// The z-score is the observed number of common neighbours minus its expected
+    /// value, divided by its standard deviation. When the variance is not positive,
+    /// as for nodes without neighbours or in very dense graphs where the
+    /// approximation breaks down, the z-score is zero.
+    ///
+    /// Note that this method computes the degree moments, which requires a pass
+    /// over all the nodes: use `get_common_neighbours_zscores` to score many pairs.
+    ///
+    /// # Example
+    ///```rust
+    /// # let graph = graph::test_utilities::load_ppi(true, true, true, true, false, false);
+    /// println!("The common neighbours z-score between node 1 and node 2 is {}", graph.get_common_neighbours_zscore_from_node_ids(1, 2).unwrap());
+    /// ```
+    ///
+    /// # Raises
+    /// * If the graph is directed.
+    /// * If either of the node IDs are higher than the number of nodes in the graph.
+    pub fn get_common_neighbours_zscore_from_node_ids(
+        &self,
+        source_node_id: NodeT,
+        destination_node_id: NodeT,
+    ) -> Result<f64> {
+        self.must_be_undirected()?;
+        Ok(unsafe {
+            self.get_unchecked_common_neighbours_zscore_from_node_ids_and_degree_moments(
+                self.validate_node_id(source_node_id)?,
+                self.validate_node_id(destination_node_id)?,
+                self.get_configuration_model_degree_moments(),
+            )
+        })
+    }
+
+    /// Returns the z-scores of the number of common neighbours of the given node pairs under the configuration model.
+    ///
+    /// # Arguments
+    /// * `node_id_pairs`: &[(NodeT, NodeT)] - The pairs of node IDs to score.
+    ///
+    /// # Implementative details
+    /// See `get_common_neighbours_zscore_from_node_ids` for the definition of
+    /// the z-score. The degree moments are computed once for all the pairs,
+    /// which are scored in parallel.
+    ///
+    /// # Example
+    ///```rust
+    /// # let graph = graph::test_utilities::load_ppi(true, true, true, true, false, false);
+    /// let zscores = graph.get_common_neighbours_zscores(&[(1, 2), (3, 4)]).unwrap();
+    /// assert_eq!(zscores[0], graph.get_common_neighbours_zscore_from_node_ids(1, 2).unwrap());
+    /// ```
+    ///
+    /// # Raises
+    /// * If the graph is directed.
+    /// * If any of the node IDs are higher than the number of nodes in the graph.
+    pub fn get_common_neighbours_zscores(
+        &self,
+        node_id_pairs: &[(NodeT, NodeT)],
+    ) -> Result<Vec<f64>> {
+        self.must_be_undirected()?;
+        for &(source_node_id, destination_node_id) in node_id_pairs {
+            self.validate_node_id(source_node_id)?;
+            self.validate_node_id(destination_node_id)?;
+        }
+        let degree_moments = self.get_configuration_model_degree_moments();
+        Ok(node_id_pairs
+            .par_iter()
+            .map(|&(source_node_id, destination_node_id)| unsafe {
+                self.get_unchecked_common_neighbours_zscore_from_node_ids_and_degree_moments(
+                    source_node_id,
+                    destination_node_id,
+                    degree_moments,
+                )
+            })
+            .collect())
+    }
 }
@@ -0,0 +1,282 @@
+use super::*;
+use rayon::prelude::*;
+
+/// Maximum number of nodes of the connected component for which
+/// the effective resistance is computed with a dense direct solver.
+const MAXIMUM_NUMBER_OF_NODES_FOR_DENSE_SOLVER: usize = 512;
+
+/// # Effective resistance
+impl Graph {
+    /// Returns the sorted node IDs of the connected component containing the given node.
+    ///
+    /// # Arguments
+    /// * `node_id`: NodeT - The node whose component is to be returned.
+    ///
+    /// # Safety
+    /// If the given node ID does not exist in the graph the method will panic.
+    unsafe fn get_unchecked_connected_component_node_ids_from_node_id(
+        &self,
+        node_id: NodeT,
+    ) -> Vec<NodeT> {
+        self.get_unchecked_breadth_first_search_from_node_ids(
+            vec![node_id],
+            None,
+            Some(false),
+            None,
+        )
+        .into_par_iter_node_ids_and_finite_distances()
+        .map(|(node_id, _)| node_id)
+        .collect()
+    }
+
+    /// Returns the solution of the Laplacian system restricted to the provided component.
+    ///
+    /// # Arguments
+    /// * `component`: &[NodeT] - The sorted node IDs of the connected component.
+    /// * `local_node_ids`: &[NodeT] - Mapping from the node IDs to the position within the component.
+    /// * `source`: usize - Position of the source node within the component.
+    /// * `destination`: usize - Position of the destination node within the component.
+    ///
+    /// # Implementative details
+    /// The known term is the difference between the indicator vectors of
+    /// the source and destination nodes. Since the Laplacian of a connected
+    /// component has the constant vector as its only null space, we solve
+    /// the system on the Laplacian with an added rank one constant matrix,
+    /// whose inverse is equal to the pseudoinverse of the Laplacian plus
+    /// the constant matrix, which is orthogonal to the known term.
+    ///
+    /// # Safety
+    /// If the component contains node IDs that do not exist in the graph the method will panic.
+    unsafe fn get_unchecked_dense_laplacian_solution(
+        &self,
+        component: &[NodeT],
+        local_node_ids: &[NodeT],
+        source: usize,
+        destination: usize,
+    ) -> Vec<f64> {
+        let size = component.len();
+        let constant = 1.0 / size as f64;
+        let mut matrix = vec![vec![constant; size]; size];
+        component.iter().enumerate().for_each(|(i, &node_id)| {
+            self.iter_unchecked_neighbour_node_ids_from_source_node_id(node_id)
+                .filter(|&neighbour_node_id| neighbour_node_id != node_id)
+                .for_each(|neighbour_node_id| {
+                    let j = local_node_ids[neighbour_node_id as usize] as usize;
+                    matrix[i][i] += 1.0;
+                    matrix[i][j] -= 1.0;
+                });
+        });
+        let mut known_term = vec![0.0; size];
+        known_term[source] = 1.0;
+        known_term[destination] = -1.0;
+
+        // Gaussian elimination with partial pivoting.
+        for pivot in 0..size {
+            let best_row = (pivot..size)
+                .max_by(|&a, &b| {
+                    matrix[a][pivot]
+                        .abs()
+                        .partial_cmp(&matrix[b][pivot].abs())
+                        .unwrap()
+                })
+                .unwrap();
+            matrix.swap(pivot, best_row);
+            known_term.swap(pivot, best_row);
+            for row in (pivot + 1)..size {
+                let factor = matrix[row][pivot] / matrix[pivot][pivot];
+                if factor == 0.0 {
+                    continue;
+                }
+                for column in pivot..size {
+                    matrix[row][column] -= factor * matrix[pivot][column];
+                }
+                known_term[row] -= factor * known_term[pivot];
+            }
+        }
+
+        // Back substitution.
+        let mut solution = vec![0.0; size];
+        for row in (0..size).rev() {
+            let partial_sum: f64 = ((row + 1)..size)
+                .map(|column| matrix[row][column] * solution[column])
+                .sum();
+            solution[row] = (known_term[row] - partial_sum) / matrix[row][row];
+        }
+        solution
+    }
+
+    /// Returns the solution of the Laplacian system restricted to the provided component using conjugate gradient.
+    ///
+    /// # Arguments
+    /// * `component`: &[NodeT] - The sorted node IDs of the connected component.
+    /// * `local_node_ids`: &[NodeT] - Mapping from the node IDs to the position within the component.
+    /// * `source`: usize - Position of the source node within the component.
+    /// * `destination`: usize - Position of the destination node within the component.
+    /// * `tolerance`: f64 - The relative tolerance on the residual norm.
+    /// * `maximum_number_of_iterations`: usize - The maximum number of iterations.
+    ///
+    /// # Implementative details
+    /// The Laplacian is positive semi-definite and the known term is
+    /// orthogonal to its null space, so the conjugate gradient converges
+    /// to a solution that differs from the one using the pseudoinverse only
+    /// by a constant vector, which does not affect the effective resistance.
+    ///
+    /// # Raises
+    /// * If the method does not converge within the maximum number of iterations.
+    ///
+    /// # Safety
+    /// If the component contains node IDs that do not exist in the graph the method will panic.
+    unsafe fn get_unchecked_conjugate_gradient_laplacian_solution(
+        &self,
+        component: &[NodeT],
+        local_node_ids: &[NodeT],
+        source: usize,
+        destination: usize,
+        tolerance: f64,
+        maximum_number_of_iterations: usize,
+    ) -> Result<Vec<f64>> {
+        let size = component.len();
+        let laplacian_product = |vector: &[f64]| -> Vec<f64> {
+            component
+                .par_iter()
+                .map(|&node_id| {
+                    let local_node_id = local_node_ids[node_id as usize] as usize;
+                    self.iter_unchecked_neighbour_node_ids_from_source_node_id(node_id)
+                        .filter(|&neighbour_node_id| neighbour_node_id != node_id)
+                        .map(|neighbour_node_id| {
+                            vector[local_node_id]
+                                - vector[local_node_ids[neighbour_node_id as usize] as usize]
+                        })
+                        .sum()
+                })
+                .collect()
+        };
+        let dot = |a: &[f64], b: &[f64]| -> f64 {
+            a.par_iter().zip(b.par_iter()).map(|(a, b)| a * b).sum()
+        };
+
+        let mut solution = vec![0.0; size];
+        let mut residual = vec![0.0; size];
+        residual[source] = 1.0;
+        residual[destination] = -1.0;
+        let mut direction = residual.clone();
+        let mut residual_squared_norm = dot(&residual, &residual);
+        let threshold = tolerance * tolerance * residual_squared_norm;
+
+        for _ in 0..maximum_number_of_iterations {
+            let product = laplacian_product(&direction);
+            let step = residual_squared_norm / dot(&direction, &product);
+            solution
+                .par_iter_mut()
+                .zip(direction.par_iter())
+                .for_each(|(value, direction)| *value += step * direction);
+            residual
+                .par_iter_mut()
+                .zip(product.par_iter())
+                .for_each(|(value, product)| *value -= step * product);
+            let new_residual_squared_norm = dot(&residual, &residual);
+            if new_residual_squared_norm <= threshold {
+                return Ok(solution);
+            }
+            let beta = new_residual_squared_norm / residual_squared_norm;
+            residual_squared_norm = new_residual_squared_norm;
+            direction
+                .par_iter_mut()
+                .zip(residual.par_iter())
+                .for_each(|(direction, residual)| *direction = residual + beta * *direction);
+        }
+
+        Err(format!(
+            concat!(
+                "The conjugate gradient did not converge to the requested ",
+                "tolerance {} within {} iterations."
+            ),
+            tolerance, maximum_number_of_iterations
+        ))
+    }
+
+    /// Returns the effective resistance between the two given nodes.
+    ///
+    /// # Arguments
+    /// * `source_node_id`: NodeT - The first node ID.
+    /// * `destination_node_id`: NodeT - The second node ID.
+    ///
+    /// # Implementative details
+    /// The graph is seen as an electrical network where each edge is a
+    /// unit resistor, and the effective resistance is the quadratic form of
+    /// the Laplacian pseudoinverse on the difference of the indicator vectors
+    /// of the two nodes. Parallel edges act as resistors in parallel, while
+    /// selfloops are ignored. Only the connected component containing the
+    /// two nodes is considered: when it has at most 512 nodes the Laplacian
+    /// system is solved exactly with a dense direct solver, otherwise we use
+    /// the conjugate gradient method on the sparse Laplacian.
+    ///
+    /// When the two nodes are not connected, the effective resistance is infinite.
+    ///
+    /// The effective resistance is proportional to the commute time of
+    /// random walks between the two nodes, with the proportionality constant
+    /// being the number of directed edges of the graph.
+    ///
+    /// # References
+    /// [D. J. Klein, M. Randić. Resistance distance (1993)](https://doi.org/10.1007/BF01164627)
+    ///
+    /// # Example
+    ///```rust
+    /// # let graph = graph::test_utilities::load_ppi(true, true, true, true, false, false);
+    /// let resistance = graph.get_effective_resistance(0, 1).unwrap();
+    /// println!("The effective resistance is {}.", resistance);
+    /// ```
+    ///
+    /// # Raises
+    /// * If the graph is directed.
+    /// * If any of the given node IDs does not exist in the graph.
+    /// * If the conjugate gradient does not converge.
+    pub fn get_effective_resistance(
+        &self,
+        source_node_id: NodeT,
+        destination_node_id: NodeT,
+    ) -> Result<f64> {
+        self.must_be_undirected()?;
+        self.validate_node_id(source_node_id)?;
+        self.validate_node_id(destination_node_id)?;
+        if source_node_id == destination_node_id {
+            return Ok(0.0);
+        }
+        let component =
+            unsafe { self.get_unchecked_connected_component_node_ids_from_node_id(source_node_id) };
+        if component.binary_search(&destination_node_id).is_err() {
+            return Ok(f64::INFINITY);
+        }
+        let mut local_node_ids = vec![NODE_NOT_PRESENT; self.get_number_of_nodes() as usize];
+        component
+            .iter()
+            .enumerate()
+            .for_each(|(local_node_id, &node_id)| {
+                local_node_ids[node_id as usize] = local_node_id as NodeT;
+            });
+        let source = local_node_ids[source_node_id as usize] as usize;
+        let destination = local_node_ids[destination_node_id as usize] as usize;
+        let solution = if component.len() <= MAXIMUM_NUMBER_OF_NODES_FOR_DENSE_SOLVER {
+            unsafe {
+                self.get_unchecked_dense_laplacian_solution(
+                    &component,
+                    &local_node_ids,
+                    source,
+                    destination,
+                )
+            }
+        } else {
+            unsafe {
+                self.get_unchecked_conjugate_gradient_laplacian_solution(
+                    &component,
+                    &local_node_ids,
+                    source,
+                    destination,
+                    1e-10,
+                    10 * component.len(),
+                )?
+            }
+        };
+        Ok(solution[source] - solution[destination])
+    }
+}
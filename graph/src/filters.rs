@@ -385,8 +385,8 @@ impl Graph {
             number_of_edges -= self.get_number_of_selfloops();
         }
 
-        match (has_node_filters, has_edge_filters) {
-            (false, false) => Ok(self.clone()),
+        let graph = match (has_node_filters, has_edge_filters) {
+            (false, false) => return Ok(self.clone()),
             (false, true) => build_graph_from_integers(
                 Some(
                     self.par_iter_directed_edge_node_names_and_edge_type_name_and_edge_weight()
@@ -577,7 +577,8 @@ impl Graph {
                     self.get_name(),
                 )
             }
-        }
+        }?;
+        Ok(self.with_node_metadata_from_node_names(graph))
     }
 
     /// Returns a **NEW** Graph that does not have the required attributes.
@@ -789,6 +790,192 @@ impl Graph {
         )
     }
 
+    /// Returns the subgraph induced by the nodes with any of the given node types.
+    ///
+    /// # Arguments
+    /// * `node_type_names`: Vec<String> - The node type names of the nodes to keep or remove.
+    /// * `keep`: bool - Whether to keep the nodes with any of the given node types, or to remove them.
+    ///
+    /// # Implementative details
+    /// A node with multiple node types is kept, or removed, when any of its
+    /// node types is among the given ones, while the nodes with unknown node
+    /// type are removed when the nodes are kept and kept when they are removed.
+    /// The resulting graph contains all the edges between the remaining nodes,
+    /// with their edge types and weights, and the remaining nodes are remapped
+    /// to dense node IDs, following their original order.
+    ///
+    /// # Example
+    ///```rust
+    /// # let graph = graph::test_utilities::load_ppi(true, true, true, true, false, false);
+    /// let node_type_name = graph.get_unique_node_type_names().unwrap()[0].clone();
+    /// let subgraph = graph.filter_from_node_type_names(vec![node_type_name.clone()], true).unwrap();
+    /// assert!(subgraph.get_number_of_nodes() <= graph.get_number_of_nodes());
+    /// ```
+    ///
+    /// # Raises
+    /// * If the graph does not have node types.
+    /// * If any of the given node type names does not exist in the graph.
+    pub fn filter_from_node_type_names(
+        &self,
+        node_type_names: Vec<String>,
+        keep: bool,
+    ) -> Result<Graph> {
+        self.must_have_node_types()?;
+        let node_type_ids = node_type_names
+            .iter()
+            .map(|node_type_name| {
+                self.get_node_type_id_from_node_type_name(node_type_name)
+                    .map(Some)
+            })
+            .collect::<Result<Vec<Option<NodeTypeT>>>>()?;
+        let (node_type_ids_to_keep, node_type_ids_to_remove) = if keep {
+            (Some(node_type_ids), None)
+        } else {
+            (None, Some(node_type_ids))
+        };
+        self.filter_from_ids(
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            node_type_ids_to_keep,
+            node_type_ids_to_remove,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+    }
+
+    /// Returns new graph with only the edges whose weight is within the given range.
+    ///
+    /// # Arguments
+    /// * `min_weight`: Option<WeightT> - The lower bound of the edge weights to keep. By default, there is no lower bound.
+    /// * `max_weight`: Option<WeightT> - The upper bound of the edge weights to keep. By default, there is no upper bound.
+    /// * `include_min`: Option<bool> - Whether to keep the edges whose weight is equal to the lower bound. By default, true.
+    /// * `include_max`: Option<bool> - Whether to keep the edges whose weight is equal to the upper bound. By default, true.
+    /// * `filter_singleton_nodes`: Option<bool> - Whether to remove the nodes that are singletons in the resulting graph. By default, false.
+    ///
+    /// # Implementative details
+    /// The nodes, node types and edge types vocabularies of the current graph
+    /// are kept, and the nodes that remain without edges are kept as singleton
+    /// nodes unless they are requested to be removed, in which case the remaining
+    /// nodes are remapped to dense node IDs.
+    ///
+    /// # Example
+    ///```rust
+    /// # let graph = graph::test_utilities::load_ppi(true, true, true, true, false, false);
+    /// let filtered = graph.filter_from_weight_range(Some(500.0), None, Some(false), None, None).unwrap();
+    /// assert!(filtered.get_number_of_directed_edges() <= graph.get_number_of_directed_edges());
+    /// assert!(filtered.get_mininum_edge_weight().unwrap() > 500.0);
+    /// ```
+    ///
+    /// # Raises
+    /// * If the graph does not have edge weights.
+    /// * If any of the given bounds is NaN.
+    /// * If the lower bound is greater than the upper bound.
+    pub fn filter_from_weight_range(
+        &self,
+        min_weight: Option<WeightT>,
+        max_weight: Option<WeightT>,
+        include_min: Option<bool>,
+        include_max: Option<bool>,
+        filter_singleton_nodes: Option<bool>,
+    ) -> Result<Graph> {
+        self.must_have_edge_weights()?;
+        let min_weight = min_weight.unwrap_or(WeightT::NEG_INFINITY);
+        let max_weight = max_weight.unwrap_or(WeightT::INFINITY);
+        if min_weight.is_nan() || max_weight.is_nan() {
+            return Err("The given weight range bounds must not be NaN.".to_string());
+        }
+        if min_weight > max_weight {
+            return Err(format!(
+                concat!(
+                    "The given minimum weight {} is greater ",
+                    "than the given maximum weight {}."
+                ),
+                min_weight, max_weight
+            ));
+        }
+        let include_min = include_min.unwrap_or(true);
+        let include_max = include_max.unwrap_or(true);
+        let filter_singleton_nodes = filter_singleton_nodes.unwrap_or(false);
+
+        let is_in_range = |weight: WeightT| {
+            (weight > min_weight || include_min && weight == min_weight)
+                && (weight < max_weight || include_max && weight == max_weight)
+        };
+
+        let graph = build_graph_from_integers(
+            Some(
+                self.par_iter_directed_edge_node_ids_and_edge_type_id_and_edge_weight()
+                    .filter_map(|(_, src, dst, edge_type, weight)| {
+                        let weight = weight.unwrap();
+                        if is_in_range(weight) {
+                            Some((0, (src, dst, edge_type, weight)))
+                        } else {
+                            None
+                        }
+                    }),
+            ),
+            self.nodes.clone(),
+            self.node_types.clone(),
+            self.edge_types
+                .as_ref()
+                .as_ref()
+                .map(|ets| ets.vocabulary.clone()),
+            true,
+            self.is_directed(),
+            Some(true),
+            Some(false),
+            Some(false),
+            None,
+            true,
+            self.has_selfloops(),
+            self.get_name(),
+        )?;
+
+        Ok(if filter_singleton_nodes {
+            graph.remove_singleton_nodes()
+        } else {
+            graph
+        })
+    }
+
     /// Returns new graph without unknown node types and relative nodes.
     ///
     /// Note that this method will remove ALL nodes labeled with unknown node
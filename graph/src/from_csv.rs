@@ -31,7 +31,7 @@ impl Graph {
             node_type_file_reader.map(|ntfr| ntfr.set_graph_name(name.clone().into()));
         edge_type_file_reader =
             edge_type_file_reader.map(|etfr| etfr.set_graph_name(name.clone().into()));
-        build_graph_from_strings(
+        let mut graph = build_graph_from_strings(
             node_type_file_reader
                 .as_ref()
                 .map_or(Ok::<_, String>(None), |nfr| {
@@ -131,7 +131,16 @@ impl Graph {
             may_have_singletons,
             may_have_singleton_with_selfloops,
             name.into(),
-        )
+        )?;
+        if let Some(edge_file_reader) = edge_file_reader.as_ref() {
+            if edge_file_reader
+                .additional_edge_attribute_column_numbers
+                .is_some()
+            {
+                graph.attach_inplace_edge_attributes_from_edge_file_reader(edge_file_reader)?;
+            }
+        }
+        Ok(graph)
     }
 
     /// Return graph renderized from given CSVs or TSVs-like files.
@@ -224,6 +233,12 @@ impl Graph {
     /// * `directed`: bool - Whether to load the graph as directed or undirected.
     /// * `name`: Option<String> - The name of the graph to be loaded.
     ///
+    /// # Implementative details
+    /// The files with the `.gz` extension are read as gzip compressed, and are
+    /// decompressed as a stream. When they are loaded in parallel, the decompressed
+    /// lines are processed in parallel as they are read, as only plain files can be
+    /// split into chunks read independently by each thread.
+    ///
     pub fn from_csv(
         directed: bool,
 
@@ -130,6 +130,115 @@ impl Graph {
             .collect()
     }
 
+    /// Returns the unweighted node degrees of the requested degree type.
+    ///
+    /// # Arguments
+    /// * `degree_type`: Option<&str> - The degree to use. Can either be `out`, `in` or `total`. By default, `out`.
+    ///
+    /// # Implementative details
+    /// On undirected graphs the outbound and inbound degrees coincide, and
+    /// therefore the `total` degree is the same as the `out` degree, that is
+    /// the usual node degree. On directed graphs the `total` degree is the sum
+    /// of the inbound and outbound degrees.
+    ///
+    /// # Raises
+    /// * If the provided degree type is not supported.
+    pub(crate) fn get_node_degrees_from_degree_type(
+        &self,
+        degree_type: Option<&str>,
+    ) -> Result<Vec<NodeT>> {
+        let degree_type = degree_type.unwrap_or("out");
+        match degree_type {
+            "out" => Ok(self.get_node_degrees()),
+            "in" => Ok(self.get_node_indegrees()),
+            "total" => {
+                if !self.is_directed() {
+                    return Ok(self.get_node_degrees());
+                }
+                let mut node_degrees = self.get_node_indegrees();
+                node_degrees
+                    .par_iter_mut()
+                    .zip(self.par_iter_node_degrees())
+                    .for_each(|(indegree, outdegree)| {
+                        *indegree += outdegree;
+                    });
+                Ok(node_degrees)
+            }
+            degree_type => Err(format!(
+                concat!(
+                    "The provided degree type `{}` is not supported. ",
+                    "The supported degree types are `out`, `in` and `total`."
+                ),
+                degree_type
+            )),
+        }
+    }
+
+    /// Returns vector of pendant node IDs of the graph, that is nodes with degree exactly one.
+    ///
+    /// # Arguments
+    /// * `degree_type`: Option<&str> - The degree to use on directed graphs. Can either be `out`, `in` or `total`. By default, `out`.
+    ///
+    /// # Implementative details
+    /// Selfloops are counted as any other edge, so a node whose only
+    /// edge is a selfloop is considered a pendant node.
+    /// On undirected graphs the degree type has no effect.
+    ///
+    /// # Example
+    ///```rust
+    /// # let graph = graph::test_utilities::load_ppi(true, true, true, true, false, false);
+    /// println!("The graph pendant node IDs are {:?}.", graph.get_pendant_node_ids(None).unwrap());
+    /// ```
+    ///
+    /// # Raises
+    /// * If the provided degree type is not supported.
+    pub fn get_pendant_node_ids(&self, degree_type: Option<&str>) -> Result<Vec<NodeT>> {
+        Ok(self
+            .get_node_degrees_from_degree_type(degree_type)?
+            .into_par_iter()
+            .enumerate()
+            .filter_map(|(node_id, degree)| {
+                if degree == 1 {
+                    Some(node_id as NodeT)
+                } else {
+                    None
+                }
+            })
+            .collect())
+    }
+
+    /// Returns vector of isolated node IDs of the graph, that is nodes with no inbound nor outbound edges.
+    ///
+    /// # Implementative details
+    /// Isolated nodes are the singleton nodes of the graph: singleton
+    /// nodes with selfloops are not considered isolated, as they
+    /// have a degree of at least one.
+    ///
+    /// # Example
+    ///```rust
+    /// # let graph = graph::test_utilities::load_ppi(true, true, true, true, false, false);
+    /// println!("The graph isolated node IDs are {:?}.", graph.get_isolated_node_ids());
+    /// ```
+    pub fn get_isolated_node_ids(&self) -> Vec<NodeT> {
+        // If there are no disconnected nodes, we do not need
+        // to iterate over the degrees at all.
+        if !self.has_disconnected_nodes() {
+            return Vec::new();
+        }
+        self.get_node_degrees_from_degree_type(Some("total"))
+            .unwrap()
+            .into_par_iter()
+            .enumerate()
+            .filter_map(|(node_id, degree)| {
+                if degree == 0 {
+                    Some(node_id as NodeT)
+                } else {
+                    None
+                }
+            })
+            .collect()
+    }
+
     /// Returns density of the graph.
     ///
     /// # Example
@@ -284,6 +393,102 @@ impl Graph {
         Ok(degrees[(self.get_number_of_nodes() / 2) as usize])
     }
 
+    /// Returns the node degrees sorted by decreasing value and their 1-indexed ranks.
+    ///
+    /// This is the data for the degree rank (Zipf) plot, which on
+    /// a log-log scale is approximately linear for power-law graphs.
+    ///
+    /// # Example
+    ///```rust
+    /// # let graph = graph::test_utilities::load_ppi(true, true, true, true, false, false);
+    /// let (degrees, ranks) = graph.get_degree_rank_plot_data();
+    /// assert_eq!(degrees.len(), ranks.len());
+    /// ```
+    pub fn get_degree_rank_plot_data(&self) -> (Vec<NodeT>, Vec<NodeT>) {
+        let mut degrees = self.get_node_degrees();
+        degrees.par_sort_unstable_by(|a, b| b.cmp(a));
+        let ranks = (1..=degrees.len() as NodeT).collect();
+        (degrees, ranks)
+    }
+
+    /// Returns the estimated exponent of the power-law fitting the node degrees and the used minimum degree.
+    ///
+    /// # Implementative details
+    /// The exponent is estimated using the discrete maximum likelihood
+    /// approximation `alpha = 1 + n / sum(ln(d / (x_min - 0.5)))` over
+    /// the `n` node degrees `d` greater or equal than `x_min`.
+    ///
+    /// The minimum degree `x_min` is selected among the unique non-zero node
+    /// degrees as the one minimizing the Kolmogorov-Smirnov distance
+    /// between the empirical cumulative distribution of the degrees greater
+    /// or equal than `x_min` and the one of the fitted power-law.
+    /// Candidates leaving less than two degrees in the tail are not considered.
+    ///
+    /// # References
+    /// The estimator is described in [Power-law distributions in empirical data](https://arxiv.org/abs/0706.1062),
+    /// by Clauset, Shalizi and Newman.
+    ///
+    /// # Example
+    ///```rust
+    /// # let graph = graph::test_utilities::load_ppi(true, true, true, true, false, false);
+    /// let (exponent, minimum_degree) = graph.estimate_power_law_exponent().unwrap();
+    /// println!("The power-law exponent is {} from degree {}.", exponent, minimum_degree);
+    /// ```
+    ///
+    /// # Raises
+    /// * If the graph does not have edges.
+    /// * If there are not enough distinct node degrees to fit the power-law.
+    pub fn estimate_power_law_exponent(&self) -> Result<(f64, NodeT)> {
+        self.must_have_edges()?;
+        let mut degrees = self
+            .par_iter_node_degrees()
+            .filter(|&degree| degree > 0)
+            .collect::<Vec<NodeT>>();
+        degrees.par_sort_unstable();
+        let mut candidates = degrees.clone();
+        candidates.dedup();
+        candidates
+            .into_par_iter()
+            .filter_map(|minimum_degree| {
+                let tail = &degrees[degrees.partition_point(|&degree| degree < minimum_degree)..];
+                if tail.len() < 2 {
+                    return None;
+                }
+                let tail_size = tail.len() as f64;
+                let exponent = 1.0
+                    + tail_size
+                        / tail
+                            .iter()
+                            .map(|&degree| (degree as f64 / (minimum_degree as f64 - 0.5)).ln())
+                            .sum::<f64>();
+                // We evaluate the cumulative distributions only on the last
+                // occurrence of each degree, so that ties are handled correctly.
+                let distance = tail
+                    .iter()
+                    .enumerate()
+                    .filter(|&(i, degree)| i + 1 == tail.len() || tail[i + 1] != *degree)
+                    .map(|(i, &degree)| {
+                        let empirical = (i + 1) as f64 / tail_size;
+                        let fitted =
+                            1.0 - (degree as f64 / minimum_degree as f64).powf(1.0 - exponent);
+                        (empirical - fitted).abs()
+                    })
+                    .fold(0.0, f64::max);
+                Some((exponent, minimum_degree, distance))
+            })
+            .min_by(|(_, a, a_distance), (_, b, b_distance)| {
+                a_distance.partial_cmp(b_distance).unwrap().then(a.cmp(b))
+            })
+            .map(|(exponent, minimum_degree, _)| (exponent, minimum_degree))
+            .ok_or_else(|| {
+                concat!(
+                    "The power-law exponent cannot be estimated as the graph ",
+                    "does not have enough non-zero node degrees."
+                )
+                .to_string()
+            })
+    }
+
     /// Returns weighted median node degree of the graph
     ///
     /// # Example
@@ -363,15 +568,94 @@ impl Graph {
     /// println!("The mode node degree of the graph is  {}", graph.get_node_degrees_mode().unwrap());
     /// ```
     pub fn get_node_degrees_mode(&self) -> Result<NodeT> {
+        Ok(self
+            .get_node_degree_counts()?
+            .into_par_iter()
+            .argmax()
+            .unwrap()
+            .0 as NodeT)
+    }
+
+    /// Returns the number of nodes with each node degree, indexed by the node degree.
+    ///
+    /// # Raises
+    /// * If the graph does not contain any node (is an empty graph).
+    fn get_node_degree_counts(&self) -> Result<Vec<NodeT>> {
         let degree_counts = (0..(self.get_maximum_node_degree()? + 1))
             .map(|_| AtomicU32::new(0))
             .collect::<Vec<AtomicU32>>();
         self.par_iter_node_degrees().for_each(|node_degree| {
             degree_counts[node_degree as usize].fetch_add(1, Ordering::Relaxed);
         });
-        let degree_counts =
-            unsafe { std::mem::transmute::<Vec<AtomicU32>, Vec<NodeT>>(degree_counts) };
-        Ok(degree_counts.into_par_iter().argmax().unwrap().0 as NodeT)
+        Ok(unsafe { std::mem::transmute::<Vec<AtomicU32>, Vec<NodeT>>(degree_counts) })
+    }
+
+    #[cache_property(degree_entropy)]
+    /// Returns the Shannon entropy of the node degree distribution, in nats.
+    ///
+    /// # Implementative details
+    /// The entropy is computed on the fraction of nodes having each node degree,
+    /// using the natural logarithm. To convert it to bits, divide it by `ln(2)`.
+    /// The entropy is zero when all nodes have the same degree.
+    ///
+    /// # Example
+    ///```rust
+    /// # let graph = graph::test_utilities::load_ppi(true, true, true, true, false, false);
+    /// println!("The degree entropy of the graph is {}", graph.get_degree_entropy().unwrap());
+    /// ```
+    ///
+    /// # Raises
+    /// * If the graph does not contain any node (is an empty graph).
+    pub fn get_degree_entropy(&self) -> Result<f64> {
+        let number_of_nodes = self.get_number_of_nodes() as f64;
+        Ok(-self
+            .get_node_degree_counts()?
+            .into_par_iter()
+            .filter(|&count| count > 0)
+            .map(|count| {
+                let frequency = count as f64 / number_of_nodes;
+                frequency * frequency.ln()
+            })
+            .sum::<f64>())
+    }
+
+    #[cache_property(degree_gini_coefficient)]
+    /// Returns the Gini coefficient of the node degrees.
+    ///
+    /// # Implementative details
+    /// The Gini coefficient is zero when all nodes have the same degree and
+    /// approaches one as the edges concentrate on few hub nodes.
+    /// It is computed from the counts of the node degrees, without sorting the nodes.
+    ///
+    /// # Example
+    ///```rust
+    /// # let graph = graph::test_utilities::load_ppi(true, true, true, true, false, false);
+    /// println!("The degree Gini coefficient of the graph is {}", graph.get_degree_gini_coefficient().unwrap());
+    /// ```
+    ///
+    /// # Raises
+    /// * If the graph does not contain any edge.
+    pub fn get_degree_gini_coefficient(&self) -> Result<f64> {
+        self.must_have_edges()?;
+        let number_of_nodes = self.get_number_of_nodes() as f64;
+        let mut number_of_nodes_with_lower_degree = 0.0;
+        // The nodes with degree `k` occupy a contiguous range of the
+        // ranks of the sorted degrees, so their contribution to the
+        // rank-weighted sum can be computed in closed form.
+        let rank_weighted_sum = self
+            .get_node_degree_counts()?
+            .into_iter()
+            .enumerate()
+            .map(|(degree, count)| {
+                let count = count as f64;
+                let contribution = degree as f64
+                    * count
+                    * (2.0 * number_of_nodes_with_lower_degree + count - number_of_nodes);
+                number_of_nodes_with_lower_degree += count;
+                contribution
+            })
+            .sum::<f64>();
+        Ok(rank_weighted_sum / (number_of_nodes * self.get_number_of_directed_edges() as f64))
     }
 
     /// Returns rate of self-loops.
@@ -1722,6 +2006,82 @@ impl Graph {
         }
     }
 
+    /// Return a vector with the connected component ID of each node.
+    ///
+    /// # Arguments
+    /// * `verbose`: Option<bool> - Whether to show the loading bar.
+    /// * `strongly_connected`: Option<bool> - Whether to use the strongly connected components in directed graphs. By default, false.
+    ///
+    /// # Implementative details
+    /// The component IDs range from zero to the number of components minus one,
+    /// and position `i` of the vector holds the component ID of the node `i`.
+    /// In directed graphs the weakly connected components are used by default,
+    /// that is the direction of the edges is ignored, while the strongly
+    /// connected components are computed with the Tarjan algorithm when
+    /// requested. In undirected graphs the two coincide.
+    ///
+    /// # Example
+    ///```rust
+    /// # let graph = graph::test_utilities::load_ppi(true, true, true, false, false, false);
+    /// let component_ids = graph.get_connected_component_ids(None, None);
+    /// assert_eq!(component_ids.len(), graph.get_number_of_nodes() as usize);
+    /// ```
+    pub fn get_connected_component_ids(
+        &self,
+        verbose: Option<bool>,
+        strongly_connected: Option<bool>,
+    ) -> Vec<NodeT> {
+        if !(self.is_directed() && strongly_connected.unwrap_or(false)) {
+            return self.get_node_connected_component_ids(verbose);
+        }
+        let mut component_ids = vec![NODE_NOT_PRESENT; self.get_number_of_nodes() as usize];
+        self.strongly_connected_components()
+            .into_iter()
+            .enumerate()
+            .for_each(|(component_id, component)| {
+                component.into_iter().for_each(|node_id| {
+                    component_ids[node_id as usize] = component_id as NodeT;
+                });
+            });
+        component_ids
+    }
+
+    /// Return a vector with the sizes of the connected components, sorted by decreasing size.
+    ///
+    /// # Arguments
+    /// * `verbose`: Option<bool> - Whether to show the loading bar.
+    /// * `strongly_connected`: Option<bool> - Whether to use the strongly connected components in directed graphs. By default, false.
+    ///
+    /// # Implementative details
+    /// The components are the same of the method `get_connected_component_ids`,
+    /// hence in directed graphs the weakly connected components are used by default.
+    ///
+    /// # Example
+    ///```rust
+    /// # let graph = graph::test_utilities::load_ppi(true, true, true, false, false, false);
+    /// let component_sizes = graph.get_connected_component_sizes(None, None);
+    /// let (number_of_components, _, maximum_size) = graph.get_number_of_connected_components(None);
+    /// assert_eq!(component_sizes.len(), number_of_components as usize);
+    /// assert_eq!(component_sizes[0], maximum_size);
+    /// ```
+    pub fn get_connected_component_sizes(
+        &self,
+        verbose: Option<bool>,
+        strongly_connected: Option<bool>,
+    ) -> Vec<NodeT> {
+        let component_ids = self.get_connected_component_ids(verbose, strongly_connected);
+        let number_of_components = component_ids
+            .iter()
+            .max()
+            .map_or(0, |&component_id| component_id as usize + 1);
+        let mut component_sizes = vec![0; number_of_components];
+        component_ids.into_iter().for_each(|component_id| {
+            component_sizes[component_id as usize] += 1;
+        });
+        component_sizes.sort_unstable_by(|a, b| b.cmp(a));
+        component_sizes
+    }
+
     #[inline(always)]
     /// Returns number of directed edges in the graph.
     pub fn get_number_of_directed_edges(&self) -> EdgeT {
@@ -1808,6 +2168,74 @@ impl Graph {
         self.get_number_of_directed_edges() - self.get_number_of_unique_directed_edges()
     }
 
+    /// Return parallel iterator over the representative edge ID and multiplicity of each unique directed edge.
+    ///
+    /// The representative edge ID is the smallest edge ID among the parallel edges.
+    fn par_iter_edge_ids_and_multiplicities(
+        &self,
+    ) -> impl ParallelIterator<Item = (EdgeT, EdgeT)> + '_ {
+        self.par_iter_node_ids().flat_map_iter(move |src| unsafe {
+            let (min_edge_id, _) = self.get_unchecked_minmax_edge_ids_from_source_node_id(src);
+            let mut edge_ids_and_multiplicities: Vec<(EdgeT, EdgeT)> = Vec::new();
+            let mut previous_dst = None;
+            self.iter_unchecked_neighbour_node_ids_from_source_node_id(src)
+                .zip(min_edge_id..)
+                .for_each(|(dst, edge_id)| {
+                    if previous_dst == Some(dst) {
+                        edge_ids_and_multiplicities.last_mut().unwrap().1 += 1;
+                    } else {
+                        edge_ids_and_multiplicities.push((edge_id, 1));
+                        previous_dst = Some(dst);
+                    }
+                });
+            edge_ids_and_multiplicities.into_iter()
+        })
+    }
+
+    /// Return the representative edge IDs and the multiplicity of each unique directed edge.
+    ///
+    /// The multiplicity of an edge is the number of parallel edges connecting
+    /// its source and destination nodes, that is the number of edges between
+    /// the two nodes across all edge types. The representative edge ID is the
+    /// smallest edge ID among the parallel edges, and the returned edges are
+    /// sorted by it. In undirected graphs each edge is reported in both directions,
+    /// consistently with `get_number_of_unique_directed_edges`.
+    /// In graphs that are not multigraphs all multiplicities are equal to one.
+    ///
+    /// # Example
+    ///```rust
+    /// # let graph = graph::test_utilities::load_ppi(true, true, true, true, false, false);
+    /// let (edge_ids, multiplicities) = graph.get_edge_multiplicities();
+    /// assert_eq!(edge_ids.len() as u64, graph.get_number_of_unique_directed_edges());
+    /// assert_eq!(multiplicities.iter().sum::<u64>(), graph.get_number_of_directed_edges());
+    /// ```
+    pub fn get_edge_multiplicities(&self) -> (Vec<EdgeT>, Vec<EdgeT>) {
+        self.par_iter_edge_ids_and_multiplicities().unzip()
+    }
+
+    /// Return the maximum multiplicity of the edges of the graph.
+    ///
+    /// The multiplicity is one in graphs that are not multigraphs,
+    /// and zero in graphs without edges.
+    ///
+    /// # Example
+    ///```rust
+    /// # let graph = graph::test_utilities::load_ppi(true, true, true, true, false, false);
+    /// println!("The maximum edge multiplicity is {}.", graph.get_maximum_edge_multiplicity());
+    /// ```
+    pub fn get_maximum_edge_multiplicity(&self) -> EdgeT {
+        if !self.has_edges() {
+            return 0;
+        }
+        if !self.is_multigraph() {
+            return 1;
+        }
+        self.par_iter_edge_ids_and_multiplicities()
+            .map(|(_, multiplicity)| multiplicity)
+            .max()
+            .unwrap_or(0)
+    }
+
     #[inline(always)]
     /// Return vector with node cumulative_node_degrees, that is the comulative node degree.
     pub fn get_cumulative_node_degrees(&self) -> &[EdgeT] {
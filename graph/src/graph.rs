@@ -40,6 +40,16 @@ pub struct Graph {
     /// Vocabulary that save the mappings from string to index of every node
     pub(crate) nodes: Arc<Vocabulary<NodeT>>,
 
+    /// Optional vector of the weights of every node.
+    /// `node_weights[10]` return the weight of the node with node_id 10
+    pub(crate) node_weights: Arc<Option<Vec<f64>>>,
+    /// Optional matrix of the features of every node.
+    /// `node_features[10]` return the features of the node with node_id 10
+    pub(crate) node_features: Arc<Option<Vec<Vec<f64>>>>,
+    /// Optional matrix of the additional attributes of every edge.
+    /// `edge_attributes[10]` return the attributes of the edge with edge_id 10
+    pub(crate) edge_attributes: Arc<Option<Vec<Vec<f64>>>>,
+
     /// if the graph is directed or undirected
     pub(crate) directed: bool,
     /// Graph name
@@ -108,6 +118,9 @@ impl Graph {
             node_types: node_types,
             edge_types: edge_types,
             nodes: nodes,
+            node_weights: Arc::new(None),
+            node_features: Arc::new(None),
+            edge_attributes: Arc::new(None),
             name: Arc::new(name.into()),
             cache: Arc::new(ClonableUnsafeCell::default()),
             unique_sources: Arc::new(None),
@@ -0,0 +1,341 @@
+use super::*;
+use rayon::prelude::*;
+
+/// Version of the layout of the vectors returned by `get_structural_fingerprint`.
+///
+/// It is increased whenever the features composing the fingerprint, their
+/// order or the way they are computed change, so that only fingerprints with
+/// the same version are compared.
+pub const STRUCTURAL_FINGERPRINT_VERSION: u32 = 1;
+
+/// # Graph-level features
+impl Graph {
+    /// Returns the names of the features returned by `get_graph_level_feature_vector`.
+    ///
+    /// # Arguments
+    /// * `include_diameter`: Option<bool> - Whether to include the diameter. By default, true.
+    /// * `include_triangle_features`: Option<bool> - Whether to include the transitivity and the average clustering coefficient. By default, true.
+    pub fn get_graph_level_feature_names(
+        &self,
+        include_diameter: Option<bool>,
+        include_triangle_features: Option<bool>,
+    ) -> Vec<String> {
+        let include_diameter = include_diameter.unwrap_or(true);
+        let include_triangle_features = include_triangle_features.unwrap_or(true);
+        let mut feature_names = vec![
+            "number_of_nodes",
+            "number_of_edges",
+            "density",
+            "node_degrees_mean",
+            "node_degrees_variance",
+        ];
+        if include_triangle_features {
+            feature_names.push("transitivity");
+        }
+        feature_names.extend(["degree_assortativity", "number_of_connected_components"]);
+        if include_diameter {
+            feature_names.push("diameter");
+        }
+        if include_triangle_features {
+            feature_names.push("average_clustering_coefficient");
+        }
+        feature_names
+            .into_iter()
+            .map(|feature_name| feature_name.to_string())
+            .collect()
+    }
+
+    /// Returns vector of scalar features describing the whole graph, meant for graph classification.
+    ///
+    /// # Arguments
+    /// * `include_diameter`: Option<bool> - Whether to include the diameter. By default, true.
+    /// * `include_triangle_features`: Option<bool> - Whether to include the transitivity and the average clustering coefficient. By default, true.
+    /// * `verbose`: Option<bool> - Whether to show the loading bars. By default, false.
+    ///
+    /// # Implementative details
+    /// The features are returned in the following order, which is the same
+    /// of the names returned by `get_graph_level_feature_names`, with the
+    /// excluded features omitted:
+    /// * The number of nodes.
+    /// * The number of undirected edges.
+    /// * The density.
+    /// * The mean of the node degrees.
+    /// * The variance of the node degrees.
+    /// * The transitivity, if the triangle features are included.
+    /// * The degree assortativity.
+    /// * The number of connected components.
+    /// * The diameter, ignoring the infinite distances between components, if the diameter is included.
+    /// * The average clustering coefficient, if the triangle features are included.
+    ///
+    /// The features that are undefined, such as the transitivity of a graph
+    /// without wedges or the assortativity of a graph without edges or where
+    /// all nodes have the same degree, are set to zero, so that the vectors
+    /// of different graphs can always be compared.
+    ///
+    /// # Example
+    ///```rust
+    /// # let graph = graph::test_utilities::load_ppi(true, true, true, true, false, false);
+    /// let features = graph.get_graph_level_feature_vector(Some(false), None, None).unwrap();
+    /// assert_eq!(features.len(), graph.get_graph_level_feature_names(Some(false), None).len());
+    /// assert_eq!(features[0], graph.get_number_of_nodes() as f64);
+    /// ```
+    ///
+    /// # Raises
+    /// * If the graph does not have nodes.
+    /// * If the graph is directed.
+    pub fn get_graph_level_feature_vector(
+        &self,
+        include_diameter: Option<bool>,
+        include_triangle_features: Option<bool>,
+        verbose: Option<bool>,
+    ) -> Result<Vec<f64>> {
+        self.must_have_nodes()?;
+        self.must_be_undirected()?;
+        let include_diameter = include_diameter.unwrap_or(true);
+        let include_triangle_features = include_triangle_features.unwrap_or(true);
+        let verbose = verbose.unwrap_or(false);
+        let finite_or_zero = |value: f64| if value.is_finite() { value } else { 0.0 };
+
+        let node_degrees_mean = self.get_node_degrees_mean()?;
+        let node_degrees_variance = self
+            .par_iter_node_degrees()
+            .map(|degree| (degree as f64 - node_degrees_mean).powi(2))
+            .sum::<f64>()
+            / self.get_number_of_nodes() as f64;
+
+        let mut features = vec![
+            self.get_number_of_nodes() as f64,
+            self.get_number_of_edges() as f64,
+            self.get_density()?,
+            node_degrees_mean,
+            node_degrees_variance,
+        ];
+        if include_triangle_features {
+            features.push(if self.get_number_of_triads() == 0 {
+                0.0
+            } else {
+                finite_or_zero(self.get_transitivity(Some(verbose)))
+            });
+        }
+        features.push(if self.has_edges() {
+            finite_or_zero(self.get_degree_assortativity()?)
+        } else {
+            0.0
+        });
+        features.push(self.get_number_of_connected_components(Some(verbose)).0 as f64);
+        if include_diameter {
+            features.push(if self.has_edges() {
+                self.get_diameter(Some(true), Some(verbose))? as f64
+            } else {
+                0.0
+            });
+        }
+        if include_triangle_features {
+            features.push(finite_or_zero(self.get_average_clustering_coefficient(
+                None,
+                None,
+                Some(verbose),
+            )));
+        }
+        Ok(features)
+    }
+
+    /// Returns an estimate of the spectral radius of the adjacency matrix of the graph.
+    ///
+    /// # Arguments
+    /// * `maximum_iterations`: Option<usize> - The maximum number of power iterations. By default, 100.
+    /// * `tolerance`: Option<f64> - The relative change of the estimate under which the iterations are stopped. By default, 1e-6.
+    ///
+    /// # Implementative details
+    /// The estimate is computed with the power iteration on the matrix `A + I`,
+    /// starting from the uniform vector. The shift by the identity matrix avoids
+    /// the oscillations of the power iteration on bipartite graphs, whose adjacency
+    /// matrices have both the spectral radius and its opposite as eigenvalues.
+    /// The edge weights are ignored and multi-edges are counted with their multiplicity.
+    ///
+    /// # Example
+    ///```rust
+    /// # let graph = graph::test_utilities::load_ppi(true, true, true, true, false, false);
+    /// let spectral_radius = graph.get_spectral_radius(None, None).unwrap();
+    /// // The spectral radius is bounded by the maximum node degree.
+    /// assert!(spectral_radius <= graph.get_maximum_node_degree().unwrap() as f64 + 1e-3);
+    /// ```
+    ///
+    /// # Raises
+    /// * If the graph does not have nodes.
+    /// * If the graph is directed.
+    pub fn get_spectral_radius(
+        &self,
+        maximum_iterations: Option<usize>,
+        tolerance: Option<f64>,
+    ) -> Result<f64> {
+        self.must_have_nodes()?;
+        self.must_be_undirected()?;
+        let maximum_iterations = maximum_iterations.unwrap_or(100);
+        let tolerance = tolerance.unwrap_or(1e-6);
+        let number_of_nodes = self.get_number_of_nodes() as usize;
+        let mut vector = vec![1.0 / (number_of_nodes as f64).sqrt(); number_of_nodes];
+        let mut next_vector = vec![0.0; number_of_nodes];
+        let mut spectral_radius = 0.0;
+        for _ in 0..maximum_iterations {
+            next_vector
+                .par_iter_mut()
+                .enumerate()
+                .for_each(|(node_id, value)| {
+                    *value = vector[node_id]
+                        + unsafe {
+                            self.iter_unchecked_neighbour_node_ids_from_source_node_id(
+                                node_id as NodeT,
+                            )
+                        }
+                        .map(|dst| vector[dst as usize])
+                        .sum::<f64>();
+                });
+            // The norm is computed sequentially so that the estimate is reproducible.
+            let norm = next_vector
+                .iter()
+                .map(|value| value * value)
+                .sum::<f64>()
+                .sqrt();
+            next_vector.iter_mut().for_each(|value| *value /= norm);
+            std::mem::swap(&mut vector, &mut next_vector);
+            let estimate = norm - 1.0;
+            let converged =
+                (estimate - spectral_radius).abs() <= tolerance * f64::max(estimate, 1.0);
+            spectral_radius = estimate;
+            if converged {
+                break;
+            }
+        }
+        Ok(spectral_radius)
+    }
+
+    /// Returns the names of the features returned by `get_structural_fingerprint`.
+    pub fn get_structural_fingerprint_feature_names(&self) -> Vec<String> {
+        [
+            "number_of_nodes",
+            "number_of_edges",
+            "density",
+            "node_degrees_mean",
+            "node_degrees_standard_deviation",
+            "node_degrees_skewness",
+            "node_degrees_excess_kurtosis",
+            "maximum_node_degree",
+            "average_clustering_coefficient",
+            "degree_assortativity",
+            "spectral_radius",
+            "number_of_connected_components",
+            "largest_connected_component_nodes_fraction",
+            "connected_component_sizes_mean",
+            "connected_component_sizes_standard_deviation",
+            "singleton_connected_components_fraction",
+        ]
+        .iter()
+        .map(|feature_name| feature_name.to_string())
+        .collect()
+    }
+
+    /// Returns fixed-length vector describing the structure of the graph, meant to compare snapshots of a graph.
+    ///
+    /// # Implementative details
+    /// The layout of the fingerprint is identified by `STRUCTURAL_FINGERPRINT_VERSION`
+    /// and, for the current version, the features are the following, in the same
+    /// order of the names returned by `get_structural_fingerprint_feature_names`:
+    /// * The number of nodes.
+    /// * The number of undirected edges.
+    /// * The density.
+    /// * The mean of the node degrees.
+    /// * The standard deviation of the node degrees.
+    /// * The skewness of the node degrees.
+    /// * The excess kurtosis of the node degrees.
+    /// * The maximum node degree.
+    /// * The average clustering coefficient.
+    /// * The degree assortativity.
+    /// * The spectral radius estimate, as computed by `get_spectral_radius` with its default parameters.
+    /// * The number of connected components.
+    /// * The fraction of nodes in the largest connected component.
+    /// * The mean of the connected component sizes.
+    /// * The standard deviation of the connected component sizes.
+    /// * The fraction of connected components composed of a single node.
+    ///
+    /// The features that are undefined, such as the skewness of a graph where all
+    /// nodes have the same degree, are set to zero. Fingerprints of the same version
+    /// can be compared, for instance, with their euclidean distance. Note that the
+    /// features have different scales, hence they may need to be standardized
+    /// across the snapshots beforehand.
+    ///
+    /// # Example
+    ///```rust
+    /// # let graph = graph::test_utilities::load_ppi(true, true, true, true, false, false);
+    /// let fingerprint = graph.get_structural_fingerprint().unwrap();
+    /// assert_eq!(fingerprint.len(), graph.get_structural_fingerprint_feature_names().len());
+    /// ```
+    ///
+    /// # Raises
+    /// * If the graph does not have nodes.
+    /// * If the graph is directed.
+    pub fn get_structural_fingerprint(&self) -> Result<Vec<f64>> {
+        self.must_have_nodes()?;
+        self.must_be_undirected()?;
+        let finite_or_zero = |value: f64| if value.is_finite() { value } else { 0.0 };
+        let number_of_nodes = self.get_number_of_nodes() as f64;
+
+        // The moments are computed sequentially so that they are reproducible.
+        let node_degrees_mean = self.get_node_degrees_mean()?;
+        let (mut second_moment, mut third_moment, mut fourth_moment) = (0.0, 0.0, 0.0);
+        self.iter_node_degrees().for_each(|degree| {
+            let deviation = degree as f64 - node_degrees_mean;
+            second_moment += deviation.powi(2) / number_of_nodes;
+            third_moment += deviation.powi(3) / number_of_nodes;
+            fourth_moment += deviation.powi(4) / number_of_nodes;
+        });
+        let (node_degrees_skewness, node_degrees_excess_kurtosis) = if second_moment > 0.0 {
+            (
+                third_moment / second_moment.powf(1.5),
+                fourth_moment / second_moment.powi(2) - 3.0,
+            )
+        } else {
+            (0.0, 0.0)
+        };
+
+        let mut component_sizes = vec![0 as NodeT; self.get_number_of_nodes() as usize];
+        self.get_node_connected_component_ids(Some(false))
+            .into_iter()
+            .for_each(|component_id| component_sizes[component_id as usize] += 1);
+        component_sizes.retain(|&component_size| component_size > 0);
+        let number_of_components = component_sizes.len() as f64;
+        let component_sizes_mean = number_of_nodes / number_of_components;
+        let component_sizes_variance = component_sizes
+            .iter()
+            .map(|&component_size| (component_size as f64 - component_sizes_mean).powi(2))
+            .sum::<f64>()
+            / number_of_components;
+
+        Ok(vec![
+            number_of_nodes,
+            self.get_number_of_edges() as f64,
+            self.get_density()?,
+            node_degrees_mean,
+            second_moment.sqrt(),
+            finite_or_zero(node_degrees_skewness),
+            finite_or_zero(node_degrees_excess_kurtosis),
+            self.get_maximum_node_degree()? as f64,
+            finite_or_zero(self.get_average_clustering_coefficient(None, None, Some(false))),
+            if self.has_edges() {
+                finite_or_zero(self.get_degree_assortativity()?)
+            } else {
+                0.0
+            },
+            self.get_spectral_radius(None, None)?,
+            number_of_components,
+            *component_sizes.iter().max().unwrap() as f64 / number_of_nodes,
+            component_sizes_mean,
+            component_sizes_variance.sqrt(),
+            component_sizes
+                .iter()
+                .filter(|&&component_size| component_size == 1)
+                .count() as f64
+                / number_of_components,
+        ])
+    }
+}
@@ -0,0 +1,156 @@
+use super::*;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+
+/// Returns the given text with the XML special characters escaped.
+///
+/// # Arguments
+/// * `text`: &str - The text to escape.
+fn escape_xml(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for character in text.chars() {
+        match character {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            _ => escaped.push(character),
+        }
+    }
+    escaped
+}
+
+/// # GraphML
+impl Graph {
+    /// Write the graph to the given path in the GraphML format.
+    ///
+    /// # Arguments
+    /// * `path`: &str - The path where to write the GraphML file.
+    ///
+    /// # Implementative details
+    /// Each node is written with the ID `n` followed by its node ID, and with
+    /// its name and, when the graph has node types, its node types joined by
+    /// `|` as `<data>` attributes. Each edge is written with its edge type and
+    /// weight as `<data>` attributes, when the graph has edge types and edge
+    /// weights, and undirected graphs have the `edgedefault` attribute set to
+    /// `undirected` and each of their edges is written once.
+    ///
+    /// The attributes are declared as `<key>` elements, whose description lists
+    /// the node types or edge types vocabulary, and the keys of the node types,
+    /// edge types and weights are omitted when the graph does not have them.
+    /// Nodes with unknown node types and edges with unknown edge types do not
+    /// have the respective `<data>` attribute. The XML special characters in
+    /// the graph name, node names and type names are escaped.
+    ///
+    /// # Example
+    ///```rust
+    /// # let graph = graph::test_utilities::load_ppi(true, true, true, true, false, false);
+    /// let path = std::env::temp_dir().join("ppi_example.graphml");
+    /// let path = path.to_str().unwrap();
+    /// graph.to_graphml(path).unwrap();
+    /// # std::fs::remove_file(path).unwrap();
+    /// ```
+    ///
+    /// # Raises
+    /// * If some I/O error is encountered.
+    pub fn to_graphml(&self, path: &str) -> Result<()> {
+        let file =
+            File::create(path).map_err(|_| format!("Cannot open in writing the file {}", path))?;
+        let mut stream = BufWriter::with_capacity(8 * 1024 * 1024, file);
+        let mut write = |text: String| -> Result<()> {
+            stream.write_all(text.as_bytes()).map_err(|_| {
+                concat!(
+                    "It was not possible to write a line to file. ",
+                    "This was likely caused by some form of I/O error."
+                )
+                .to_string()
+            })
+        };
+
+        write(
+            concat!(
+                "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n",
+                "<graphml xmlns=\"http://graphml.graphdrawing.org/xmlns\" ",
+                "xmlns:xsi=\"http://www.w3.org/2001/XMLSchema-instance\" ",
+                "xsi:schemaLocation=\"http://graphml.graphdrawing.org/xmlns ",
+                "http://graphml.graphdrawing.org/xmlns/1.0/graphml.xsd\">\n",
+                "  <key id=\"name\" for=\"node\" attr.name=\"name\" attr.type=\"string\"/>\n"
+            )
+            .to_string(),
+        )?;
+        for (key, domain, vocabulary) in [
+            ("node_type", "node", self.get_unique_node_type_names().ok()),
+            ("edge_type", "edge", self.get_unique_edge_type_names().ok()),
+        ] {
+            if let Some(vocabulary) = vocabulary {
+                write(format!(
+                    concat!(
+                        "  <key id=\"{key}\" for=\"{domain}\" attr.name=\"{key}\" attr.type=\"string\">\n",
+                        "    <desc>{vocabulary}</desc>\n",
+                        "  </key>\n"
+                    ),
+                    key = key,
+                    domain = domain,
+                    vocabulary = escape_xml(&vocabulary.join("|"))
+                ))?;
+            }
+        }
+        if self.has_edge_weights() {
+            write(
+                "  <key id=\"weight\" for=\"edge\" attr.name=\"weight\" attr.type=\"double\"/>\n"
+                    .to_string(),
+            )?;
+        }
+        write(format!(
+            "  <graph id=\"{}\" edgedefault=\"{}\">\n",
+            escape_xml(&self.get_name()),
+            if self.is_directed() {
+                "directed"
+            } else {
+                "undirected"
+            }
+        ))?;
+
+        for node_id in self.iter_node_ids() {
+            write(format!(
+                "    <node id=\"n{}\">\n      <data key=\"name\">{}</data>\n",
+                node_id,
+                escape_xml(&unsafe { self.get_unchecked_node_name_from_node_id(node_id) })
+            ))?;
+            if let Some(node_type_names) =
+                unsafe { self.get_unchecked_node_type_names_from_node_id(node_id) }
+            {
+                write(format!(
+                    "      <data key=\"node_type\">{}</data>\n",
+                    escape_xml(&node_type_names.join("|"))
+                ))?;
+            }
+            write("    </node>\n".to_string())?;
+        }
+
+        for (edge_id, src, _, dst, _, _, edge_type_name, weight) in
+            self.iter_edge_node_names_and_edge_type_name_and_edge_weight(self.is_directed())
+        {
+            write(format!(
+                "    <edge id=\"e{}\" source=\"n{}\" target=\"n{}\">\n",
+                edge_id, src, dst
+            ))?;
+            if let Some(edge_type_name) = edge_type_name {
+                write(format!(
+                    "      <data key=\"edge_type\">{}</data>\n",
+                    escape_xml(&edge_type_name)
+                ))?;
+            }
+            if let Some(weight) = weight {
+                write(format!("      <data key=\"weight\">{}</data>\n", weight))?;
+            }
+            write("    </edge>\n".to_string())?;
+        }
+
+        write("  </graph>\n</graphml>\n".to_string())?;
+        stream
+            .flush()
+            .map_err(|_| "Unable to close file. There might have been an I/O error.".to_string())
+    }
+}
@@ -145,11 +145,21 @@ impl Hash for EdgeQuadruple {
 
 impl Hash for GraphBuilder {
     fn hash<H: Hasher>(&self, state: &mut H) {
-        self.edges.hash(state);
+        self.edges.iter().for_each(|(src, dst, edge_type, weight)| {
+            src.hash(state);
+            dst.hash(state);
+            edge_type.hash(state);
+            if let Some(weight) = weight {
+                hash_f32(*weight, state);
+            }
+        });
         self.nodes.hash(state);
+        self.nodes_vocabulary.hash(state);
+        self.edge_types_vocabulary.hash(state);
         self.has_node_types.hash(state);
         self.has_edge_types.hash(state);
         self.has_edge_weights.hash(state);
+        self.ignore_duplicated_edges.hash(state);
         self.directed.hash(state);
         self.name.hash(state);
         hash_f32(self.default_weight, state);
@@ -376,11 +376,35 @@ impl Graph {
     /// * `graph_to_avoid`: Option<&Graph> - Compatible graph whose edges are not to be sampled.
     /// * `support`: Option<&Graph> - Parent graph of this subgraph, defining the `true` topology of the graph. Node degrees and connected components are sampled from this support graph when provided. Useful when sampling negative edges for a test graph. In this latter case, the support graph should be the training graph.
     /// * `use_scale_free_distribution`: Option<bool> - Whether to sample the nodes using scale_free distribution. By default True. Not using this may cause significant biases.
+    /// * `negative_sampling_strategy`: Option<&str> - The distribution of the nodes of the negative edges, either `uniform`, `scale_free` or `degree_proportional`. By default, `scale_free` unless `use_scale_free_distribution` is false.
+    /// * `degree_exponent`: Option<f64> - The exponent to raise the node degrees to with the `degree_proportional` strategy. By default, 0.75.
     /// * `sample_edge_types`: Option<bool> - Whether to sample edge types, following the edge type counts distribution. By default it is true only when the current graph instance has edge types.
     /// * `sample_edge_weights`: Option<bool> - Whether to sample edge weights, following the edge weight distribution. By default it is true only when the current graph instance has edge weights.
     /// * `enforce_node_type_connection_consistency`: Option<bool> - Whether to enforce that the sampled negative edges have the same node types as the positive edges. By default it is true only when the current graph instance has node types.
     /// * `number_of_sampling_attempts`: Option<usize> - Number of times to attempt to sample edges before giving up.
     ///
+    /// # Implementative details
+    /// With the `uniform` strategy, the nodes of the negative edges are sampled
+    /// uniformly at random, which tends to produce trivially easy negatives on
+    /// graphs with a skewed degree distribution. With the `scale_free` strategy,
+    /// the nodes are sampled proportionally to their degree. With the
+    /// `degree_proportional` strategy, the nodes are sampled proportionally to
+    /// their degree raised to the provided exponent, as done in word2vec with
+    /// the exponent 0.75: smaller exponents flatten the distribution towards the
+    /// uniform one, while the nodes without edges are never sampled. In directed
+    /// graphs, the outbound node degree is used. When any of the filters is active,
+    /// both the degree-based strategies use the degrees of the filtered graph,
+    /// outbound for the source nodes and inbound for the destination nodes.
+    ///
+    /// With all the strategies, the edges of the graph, and of the graph to avoid
+    /// when provided, are never sampled, and selfloops are only sampled when
+    /// the graph contains selfloops.
+    ///
+    /// # Raises
+    /// * If the number of negative samples is zero.
+    /// * If both the negative sampling strategy and whether to use the scale free distribution are provided.
+    /// * If the negative sampling strategy is not supported.
+    /// * If the degree exponent is negative or not finite.
     pub fn sample_negative_graph(
         &self,
         number_of_negative_samples: EdgeT,
@@ -397,6 +421,8 @@ impl Graph {
         graph_to_avoid: Option<&Graph>,
         support: Option<&Graph>,
         use_scale_free_distribution: Option<bool>,
+        negative_sampling_strategy: Option<&str>,
+        degree_exponent: Option<f64>,
         sample_edge_types: Option<bool>,
         sample_edge_weights: Option<bool>,
         enforce_node_type_connection_consistency: Option<bool>,
@@ -419,7 +445,43 @@ impl Graph {
 
         let sample_edge_types = sample_edge_types.unwrap_or(self.has_edge_types());
         let sample_edge_weights = sample_edge_weights.unwrap_or(self.has_edge_weights());
-        let use_scale_free_distribution = use_scale_free_distribution.unwrap_or(true);
+        let negative_sampling_strategy = match (
+            negative_sampling_strategy,
+            use_scale_free_distribution,
+        ) {
+            (Some(_), Some(_)) => {
+                return Err(concat!(
+                    "Both the parameters `negative_sampling_strategy` and `use_scale_free_distribution` ",
+                    "were provided, but only one of them can be used to choose the ",
+                    "distribution of the nodes of the negative edges."
+                )
+                .to_string());
+            }
+            (Some(negative_sampling_strategy), None) => negative_sampling_strategy,
+            (None, use_scale_free_distribution) => {
+                if use_scale_free_distribution.unwrap_or(true) {
+                    "scale_free"
+                } else {
+                    "uniform"
+                }
+            }
+        };
+        if !["uniform", "scale_free", "degree_proportional"].contains(&negative_sampling_strategy) {
+            return Err(format!(
+                concat!(
+                    "The provided negative sampling strategy `{}` is not supported. ",
+                    "The supported strategies are `uniform`, `scale_free` and `degree_proportional`."
+                ),
+                negative_sampling_strategy
+            ));
+        }
+        let degree_exponent = degree_exponent.unwrap_or(0.75);
+        if !degree_exponent.is_finite() || degree_exponent < 0.0 {
+            return Err(format!(
+                "The provided degree exponent {} must be a non-negative real number.",
+                degree_exponent
+            ));
+        }
         let only_from_same_component = only_from_same_component.unwrap_or(false);
         let enforce_node_type_connection_consistency = enforce_node_type_connection_consistency
             .unwrap_or(self.has_node_types() && !self.has_homogeneous_node_types().unwrap());
@@ -567,11 +629,11 @@ impl Graph {
             || destination_node_filter_is_active
             || edge_wise_filter_is_active;
 
-        // If we have any active filter and a scale free distribution is requested,
+        // If we have any active filter and a degree-based distribution is requested,
         // we need to compute the node degree distribution of the filtered graph so
         // to avoid biases relative to the divergence of the node degree distribution.
-        let source_and_destination_degrees_cumsum: Option<(Vec<u32>, Vec<u32>)> =
-            if filters_are_active && use_scale_free_distribution {
+        let mut filtered_node_degrees: Option<(Vec<u32>, Vec<u32>)> =
+            if filters_are_active && negative_sampling_strategy != "uniform" {
                 let outbound_node_degrees = unsafe {
                     std::mem::transmute::<Vec<u32>, Vec<AtomicU32>>(vec![
                         0;
@@ -601,18 +663,98 @@ impl Graph {
 
                 // We transmute back the two vectors into a vector of u32, as we do not need
                 // atomic operations any longer.
-                let mut outbound_node_degrees = unsafe {
-                    std::mem::transmute::<Vec<AtomicU32>, Vec<u32>>(outbound_node_degrees)
-                };
-                let mut inbound_node_degrees = unsafe {
-                    std::mem::transmute::<Vec<AtomicU32>, Vec<u32>>(inbound_node_degrees)
-                };
+                Some(unsafe {
+                    (
+                        std::mem::transmute::<Vec<AtomicU32>, Vec<u32>>(outbound_node_degrees),
+                        std::mem::transmute::<Vec<AtomicU32>, Vec<u32>>(inbound_node_degrees),
+                    )
+                })
+            } else {
+                None
+            };
+
+        // In order to avoid deadlocks down the line, we check whether by using
+        // the provided filters we are not creating a situation where the graph
+        // is empty. If this is the case, we return an error with the adequate
+        // informations to the user. We do this by checking whether all of the nodes
+        // in the graph, either the sources or the destination, now seem to be singletons.
+        if let Some((outbound_node_degrees, inbound_node_degrees)) = filtered_node_degrees.as_ref()
+        {
+            for (node_degrees, degree_distribution_name) in [
+                (outbound_node_degrees, "source"),
+                (inbound_node_degrees, "destination"),
+            ] {
+                if node_degrees.par_iter().all(|&node_degree| node_degree == 0) {
+                    return Err(format!(
+                        concat!(
+                            "With the provided filters, you have ended up filtering out all ",
+                            "of the {degree_distribution_name} nodes of the graph. This is most likely due to the ",
+                            "fact that you have provided a combination of filters that is too ",
+                            "restrictive. Please, consider relaxing the filters or removing ",
+                            "them altogether if compatible with you task.",
+                        ),
+                        degree_distribution_name=degree_distribution_name
+                    ));
+                }
+            }
+        }
 
-                // We compute the comulative sums of the node degrees.
-                cumsum(&mut outbound_node_degrees);
-                cumsum(&mut inbound_node_degrees);
+        // We compute the comulative sums of the filtered node degrees.
+        let source_and_destination_degrees_cumsum: Option<(Vec<u32>, Vec<u32>)> =
+            if negative_sampling_strategy == "scale_free" {
+                filtered_node_degrees.take().map(
+                    |(mut outbound_node_degrees, mut inbound_node_degrees)| {
+                        cumsum(&mut outbound_node_degrees);
+                        cumsum(&mut inbound_node_degrees);
+                        (outbound_node_degrees, inbound_node_degrees)
+                    },
+                )
+            } else {
+                None
+            };
 
-                Some((outbound_node_degrees, inbound_node_degrees))
+        // When the degree proportional strategy is requested, we compute the
+        // comulative sums of the node degrees raised to the provided exponent.
+        // The nodes without edges, possibly after the filtering, get a zero
+        // weight and therefore are never sampled.
+        let source_and_destination_cumulative_weights: Option<(Vec<f64>, Vec<f64>)> =
+            if negative_sampling_strategy == "degree_proportional" {
+                let (outbound_node_degrees, inbound_node_degrees) = filtered_node_degrees
+                    .unwrap_or_else(|| {
+                        let node_degrees = support.par_iter_node_degrees().collect::<Vec<NodeT>>();
+                        (node_degrees.clone(), node_degrees)
+                    });
+                let get_cumulative_weights = |node_degrees: Vec<NodeT>| {
+                    let mut cumulative_weights = node_degrees
+                        .into_par_iter()
+                        .map(|node_degree| {
+                            if node_degree == 0 {
+                                0.0
+                            } else {
+                                (node_degree as f64).powf(degree_exponent)
+                            }
+                        })
+                        .collect::<Vec<f64>>();
+                    for i in 1..cumulative_weights.len() {
+                        cumulative_weights[i] += cumulative_weights[i - 1];
+                    }
+                    cumulative_weights
+                };
+                let outbound_cumulative_weights = get_cumulative_weights(outbound_node_degrees);
+                if outbound_cumulative_weights
+                    .last()
+                    .map_or(true, |&total| total == 0.0)
+                {
+                    return Err(concat!(
+                        "The degree proportional negative sampling distribution is not defined ",
+                        "as all the nodes in the graph have zero degree."
+                    )
+                    .to_string());
+                }
+                Some((
+                    outbound_cumulative_weights,
+                    get_cumulative_weights(inbound_node_degrees),
+                ))
             } else {
                 None
             };
@@ -637,34 +779,16 @@ impl Graph {
             comulative_node_degree == 0
         };
 
-        // In order to avoid deadlocks down the line, we check whether by using
-        // the provided filters we are not creating a situation where the graph
-        // is empty. If this is the case, we return an error with the adequate
-        // informations to the user. We do this by checking whether all of the nodes
-        // in the graph, either the sources or the destination, now seem to be singletons.
-        if let Some((outbound_cumsum, inbound_cumsum)) =
-            source_and_destination_degrees_cumsum.as_ref()
-        {
-            for (degree_distribution, degree_distribution_name) in
-                [(outbound_cumsum, "source"), (inbound_cumsum, "destination")]
-            {
-                if self
-                    .par_iter_node_ids()
-                    .all(|node_id| is_filtered_singleton(node_id, degree_distribution))
-                {
-                    return Err(format!(
-                        concat!(
-                            "With the provided filters, you have ended up filtering out all ",
-                            "of the {degree_distribution_name} nodes of the graph. This is most likely due to the ",
-                            "fact that you have provided a combination of filters that is too ",
-                            "restrictive. Please, consider relaxing the filters or removing ",
-                            "them altogether if compatible with you task.",
-                        ),
-                        degree_distribution_name=degree_distribution_name
-                    ));
-                }
-            }
-        }
+        // We define a method to sample a node from the comulative sums of the node weights,
+        // searching the first node whose comulative weight exceeds a random threshold,
+        // so that the nodes with zero weight are never sampled.
+        let sample_from_cumulative_weights = |cumulative_weights: &[f64], random_state: u64| {
+            let threshold = splitmix64(random_state) as f64 / u64::MAX as f64
+                * cumulative_weights.last().unwrap();
+            cumulative_weights
+                .partition_point(|&cumulative_weight| cumulative_weight <= threshold)
+                .min(cumulative_weights.len() - 1) as NodeT
+        };
 
         // We define a method to sample a random edge from the graph.
         let sample_edge =
@@ -691,7 +815,14 @@ impl Graph {
                     }
 
                     (src, dst)
-                } else if use_scale_free_distribution {
+                } else if let Some((outbound_weights, inbound_weights)) =
+                    source_and_destination_cumulative_weights.as_ref()
+                {
+                    (
+                        sample_from_cumulative_weights(outbound_weights, src_random_state),
+                        sample_from_cumulative_weights(inbound_weights, dst_random_state),
+                    )
+                } else if negative_sampling_strategy == "scale_free" {
                     (
                         self.get_random_outbounds_scale_free_node(src_random_state),
                         self.get_random_outbounds_scale_free_node(dst_random_state),
@@ -1293,6 +1424,17 @@ impl Graph {
                 actual_validation_number_of_edges=actual_validation_number_of_edges,
             ));
         }
+        self.get_edge_holdout_from_validation_edge_ids(&valid_edges_bitmap)
+    }
+
+    /// Returns training and validation graph from the given validation edge IDs.
+    ///
+    /// # Arguments
+    /// * `valid_edges_bitmap`: &RoaringTreemap - The directed edge IDs to put in the validation graph.
+    fn get_edge_holdout_from_validation_edge_ids(
+        &self,
+        valid_edges_bitmap: &RoaringTreemap,
+    ) -> Result<(Graph, Graph)> {
         let validation_edge_ids = (0..self.get_number_of_directed_edges())
             .into_par_iter()
             .filter(|edge_id| valid_edges_bitmap.contains(*edge_id))
@@ -1381,6 +1523,13 @@ impl Graph {
     /// In the option where a list of edge types has been provided, these
     /// edge types will be those put into the validation set.
     ///
+    /// When the edge type stratification is requested, the train size is
+    /// applied independently within each edge type, so that the validation set
+    /// contains at least one edge of each edge type with edges, and the edges
+    /// with unknown edge type are treated as an additional edge type. The edge
+    /// types with too few edges outside of the spanning tree to reach their
+    /// validation size put all of these edges in the validation set.
+    ///
     /// # Arguments
     ///
     /// * `train_size`: f64 - Rate target to reserve for training.
@@ -1389,10 +1538,14 @@ impl Graph {
     /// * `include_all_edge_types`: Option<bool> - Whether to include all the edges between two nodes.
     /// * `minimum_node_degree`: Option<NodeT> - The minimum node degree of either the source or destination node to be sampled. By default 0.
     /// * `maximum_node_degree`: Option<NodeT> - The maximum node degree of either the source or destination node to be sampled. By default, the number of nodes.
+    /// * `edge_type_stratification`: Option<bool> - Whether to apply the train size within each edge type. By default, false.
     /// * `verbose`: Option<bool> - Whether to show the loading bar.
     ///
     /// # Raises
     /// * If the edge types have been specified but the graph does not have edge types.
+    /// * If the edge type stratification has been requested but the graph does not have edge types.
+    /// * If the edge type stratification has been requested together with the inclusion of all the edges between two nodes.
+    /// * If the edge type stratification has been requested and all the edges of an edge type are required to keep the training graph connected.
     /// * If the required training size is not a real value between 0 and 1.
     /// * If the current graph does not allow for the creation of a spanning tree for the requested training size.
     pub fn connected_holdout(
@@ -1403,14 +1556,23 @@ impl Graph {
         include_all_edge_types: Option<bool>,
         minimum_node_degree: Option<NodeT>,
         maximum_node_degree: Option<NodeT>,
+        edge_type_stratification: Option<bool>,
         verbose: Option<bool>,
     ) -> Result<(Graph, Graph)> {
         let include_all_edge_types = include_all_edge_types.unwrap_or(false);
+        let edge_type_stratification = edge_type_stratification.unwrap_or(false);
         // If the user has requested to restrict the connected holdout to a
         // limited set of edge types, the graph must have edge types.
-        if edge_types.is_some() {
+        if edge_types.is_some() || edge_type_stratification {
             self.must_have_edge_types()?;
         }
+        if edge_type_stratification && include_all_edge_types {
+            return Err(concat!(
+                "The edge type stratification samples each edge independently, ",
+                "and cannot be combined with the inclusion of all the edges between two nodes."
+            )
+            .to_string());
+        }
         if train_size <= 0.0 || train_size >= 1.0 {
             return Err(String::from("Train rate must be strictly between 0 and 1."));
         }
@@ -1427,6 +1589,29 @@ impl Graph {
             .random_spanning_arborescence_kruskal(random_state, edge_type_ids.clone(), verbose)
             .0;
 
+        let is_within_node_degree_bounds = |src: NodeT, dst: NodeT| unsafe {
+            let src_degree = self.get_unchecked_node_degree_from_node_id(src);
+            let dst_degree = self.get_unchecked_node_degree_from_node_id(dst);
+            minimum_node_degree.map_or(true, |minimum_node_degree| {
+                src_degree >= minimum_node_degree && dst_degree >= minimum_node_degree
+            }) && maximum_node_degree.map_or(true, |maximum_node_degree| {
+                src_degree <= maximum_node_degree && dst_degree <= maximum_node_degree
+            })
+        };
+
+        if edge_type_stratification {
+            let valid_edges_bitmap = self.get_edge_type_stratified_validation_edge_ids(
+                train_size,
+                random_state,
+                &tree,
+                edge_type_ids.as_ref(),
+                is_within_node_degree_bounds,
+                true,
+                verbose,
+            )?;
+            return self.get_edge_holdout_from_validation_edge_ids(&valid_edges_bitmap);
+        }
+
         let edge_factor = if self.is_directed() { 1 } else { 2 };
 
         // We need to check if the connected holdout can actually be built with
@@ -1484,24 +1669,8 @@ impl Graph {
             include_all_edge_types,
             |_, src, dst, edge_type| {
                 let is_in_tree = tree.contains(&(src, dst));
-                unsafe {
-                    if let Some(minimum_node_degree) = &minimum_node_degree {
-                        if self.get_unchecked_node_degree_from_node_id(src) < *minimum_node_degree
-                            || self.get_unchecked_node_degree_from_node_id(dst)
-                                < *minimum_node_degree
-                        {
-                            return false;
-                        }
-                    }
-
-                    if let Some(maximum_node_degree) = &maximum_node_degree {
-                        if self.get_unchecked_node_degree_from_node_id(src) > *maximum_node_degree
-                            || self.get_unchecked_node_degree_from_node_id(dst)
-                                > *maximum_node_degree
-                        {
-                            return false;
-                        }
-                    }
+                if !is_within_node_degree_bounds(src, dst) {
+                    return false;
                 }
                 let singleton_selfloop =
                     unsafe { self.is_unchecked_singleton_with_selfloops_from_node_id(src) };
@@ -1517,6 +1686,208 @@ impl Graph {
         )
     }
 
+    /// Returns the validation edge IDs sampled independently within each edge type.
+    ///
+    /// # Arguments
+    /// * `train_size`: f64 - Rate target to reserve for training within each edge type.
+    /// * `random_state`: Option<EdgeT> - The random_state to use for the holdout.
+    /// * `tree`: &HashSet<(NodeT, NodeT)> - The spanning tree edges that must remain in the training set.
+    /// * `edge_type_ids`: Option<&HashSet<Option<EdgeTypeT>>> - The edge types to sample the validation edges from. By default, all of them.
+    /// * `user_condition_for_validation_edges`: impl Fn(NodeT, NodeT) -> bool - The function to use to put edges in validation set.
+    /// * `at_least_one_validation_edge`: bool - Whether each edge type with edges must get at least one validation edge.
+    /// * `verbose`: Option<bool> - Whether to show the loading bar.
+    ///
+    /// # Implementative details
+    /// In undirected graphs the validation sizes are computed on the undirected
+    /// edges, as the two directions of an edge are always sampled together,
+    /// so that no edge type goes past its validation size.
+    /// When at least one validation edge is requested, each edge type with edges
+    /// gets a validation size of at least one, even when its number of edges times
+    /// the validation rate is less than one, and the edge types whose edges outside
+    /// of the spanning tree cannot reach their whole validation size get all of them.
+    ///
+    /// # Raises
+    /// * If the graph does not have edge types.
+    /// * If at least one validation edge is requested and all the edges of an edge type are required to keep the training graph connected.
+    /// * If at least one validation edge is not requested and any edge type does not have enough edges outside of the spanning tree to reach its validation size.
+    fn get_edge_type_stratified_validation_edge_ids(
+        &self,
+        train_size: f64,
+        random_state: Option<EdgeT>,
+        tree: &HashSet<(NodeT, NodeT)>,
+        edge_type_ids: Option<&HashSet<Option<EdgeTypeT>>>,
+        user_condition_for_validation_edges: impl Fn(NodeT, NodeT) -> bool,
+        at_least_one_validation_edge: bool,
+        verbose: Option<bool>,
+    ) -> Result<RoaringTreemap> {
+        let number_of_edge_types = self.get_number_of_edge_types()? as usize;
+        let verbose = verbose.unwrap_or(false);
+        let random_state = random_state.unwrap_or(0xbadf00d);
+        // The last bucket is reserved for the edges with unknown edge type.
+        let get_bucket = |edge_type: Option<EdgeTypeT>| {
+            edge_type.map_or(number_of_edge_types, |edge_type| edge_type as usize)
+        };
+        let mut number_of_edges_per_edge_type = vec![0; number_of_edge_types + 1];
+        self.iter_directed_edge_node_ids_and_edge_type_id()
+            .filter(|&(_, src, dst, _)| self.is_directed() || src <= dst)
+            .for_each(|(_, _, _, edge_type)| {
+                number_of_edges_per_edge_type[get_bucket(edge_type)] += 1;
+            });
+        let validation_number_of_edges_per_edge_type = number_of_edges_per_edge_type
+            .iter()
+            .enumerate()
+            .map(|(bucket, &number_of_edges)| {
+                let edge_type_id = if bucket == number_of_edge_types {
+                    None
+                } else {
+                    Some(bucket as EdgeTypeT)
+                };
+                if number_of_edges == 0
+                    || edge_type_ids.map_or(false, |etis| !etis.contains(&edge_type_id))
+                {
+                    return 0;
+                }
+                let validation_number_of_edges =
+                    (number_of_edges as f64 * (1.0 - train_size)) as EdgeT;
+                if at_least_one_validation_edge {
+                    validation_number_of_edges.max(1)
+                } else {
+                    validation_number_of_edges
+                }
+            })
+            .collect::<Vec<EdgeT>>();
+        let validation_number_of_edges = validation_number_of_edges_per_edge_type
+            .iter()
+            .sum::<EdgeT>();
+        let validation_edges_pb = get_loading_bar(
+            verbose,
+            "Picking stratified validation edges",
+            validation_number_of_edges as usize,
+        );
+
+        let mut rng = SmallRng::seed_from_u64(splitmix64(random_state as u64) as EdgeT);
+        let mut edge_indices: Vec<EdgeT> = (0..self.get_number_of_directed_edges()).collect();
+        edge_indices.shuffle(&mut rng);
+
+        let mut current_validation_number_of_edges_per_edge_type =
+            vec![0; number_of_edge_types + 1];
+        let mut valid_edges_bitmap = RoaringTreemap::new();
+
+        for edge_id in edge_indices {
+            let (src, dst, edge_type) =
+                unsafe { self.get_unchecked_node_ids_and_edge_type_id_from_edge_id(edge_id) };
+            // If the graph is undirected and we have extracted an edge that is a
+            // simmetric one, we can skip this iteration.
+            if !self.directed && src > dst {
+                continue;
+            }
+            let bucket = get_bucket(edge_type);
+            if current_validation_number_of_edges_per_edge_type[bucket]
+                >= validation_number_of_edges_per_edge_type[bucket]
+                || tree.contains(&(src, dst))
+                || unsafe { self.is_unchecked_singleton_with_selfloops_from_node_id(src) }
+                || !user_condition_for_validation_edges(src, dst)
+            {
+                continue;
+            }
+            valid_edges_bitmap.insert(edge_id);
+            if !self.directed && src != dst {
+                valid_edges_bitmap.insert(unsafe {
+                    self.get_unchecked_edge_id_from_node_ids_and_edge_type_id(dst, src, edge_type)
+                });
+            }
+            current_validation_number_of_edges_per_edge_type[bucket] += 1;
+            validation_edges_pb.inc(1);
+        }
+
+        let get_edge_type_name = |bucket: usize| {
+            if bucket == number_of_edge_types {
+                Ok("unknown".to_string())
+            } else {
+                self.get_edge_type_name_from_edge_type_id(bucket as EdgeTypeT)
+            }
+        };
+        let mut buckets = current_validation_number_of_edges_per_edge_type
+            .iter()
+            .zip(validation_number_of_edges_per_edge_type.iter())
+            .enumerate();
+        if at_least_one_validation_edge {
+            if let Some((bucket, _)) =
+                buckets.find(|(_, (current, expected))| **current == 0 && **expected > 0)
+            {
+                return Err(format!(
+                    concat!(
+                        "It is not possible to reserve any edge of the edge type {} for the validation set, ",
+                        "as all of its edges are either required to keep the training graph connected ",
+                        "or excluded by the node degree bounds."
+                    ),
+                    get_edge_type_name(bucket)?,
+                ));
+            }
+        } else if let Some((bucket, (&current, &expected))) =
+            buckets.find(|(_, (current, expected))| current < expected)
+        {
+            return Err(format!(
+                concat!(
+                    "It is not possible to reserve {} edges of the edge type {} for the validation set, ",
+                    "as only {} of its edges are not required to keep the training graph connected.\n",
+                    "If possible, you should increase the train_size parameter ",
+                    "which is currently equal to {}."
+                ),
+                expected,
+                get_edge_type_name(bucket)?,
+                current,
+                train_size
+            ));
+        }
+
+        Ok(valid_edges_bitmap)
+    }
+
+    /// Returns connected holdout where the train size is applied within each edge type.
+    ///
+    /// The holdouts returned are a tuple of graphs. As for `connected_holdout`,
+    /// the training graph is garanteed to have the same number of graph
+    /// components as the initial graph. Differently from it, the validation
+    /// edges are sampled independently within each edge type, so that every
+    /// edge type is represented in both graphs proportionally to its frequency,
+    /// with at least one validation edge for each edge type that has edges.
+    /// The edges with unknown edge type are treated as an additional edge type.
+    ///
+    /// # Arguments
+    /// * `train_size`: f64 - Rate target to reserve for training within each edge type.
+    /// * `random_state`: Option<EdgeT> - The random_state to use for the holdout,
+    /// * `verbose`: Option<bool> - Whether to show the loading bar.
+    ///
+    /// # Raises
+    /// * If the graph does not have edge types.
+    /// * If the required training size is not a real value between 0 and 1.
+    /// * If any edge type does not have enough edges outside of the spanning tree to reach its validation size.
+    pub fn get_holdout_per_edge_type(
+        &self,
+        train_size: f64,
+        random_state: Option<EdgeT>,
+        verbose: Option<bool>,
+    ) -> Result<(Graph, Graph)> {
+        self.must_have_edge_types()?;
+        if train_size <= 0.0 || train_size >= 1.0 {
+            return Err(String::from("Train rate must be strictly between 0 and 1."));
+        }
+        let tree = self
+            .random_spanning_arborescence_kruskal(random_state, None, verbose)
+            .0;
+        let valid_edges_bitmap = self.get_edge_type_stratified_validation_edge_ids(
+            train_size,
+            random_state,
+            &tree,
+            None,
+            |_, _| true,
+            false,
+            verbose,
+        )?;
+        self.get_edge_holdout_from_validation_edge_ids(&valid_edges_bitmap)
+    }
+
     /// Returns random holdout for training ML algorithms on the graph edges.
     ///
     /// The holdouts returned are a tuple of graphs. In neither holdouts the
@@ -1597,6 +1968,104 @@ impl Graph {
         )
     }
 
+    /// Returns chronological holdout for training ML algorithms on the graph edges.
+    ///
+    /// The edges are sorted by the provided timestamps, and the earliest
+    /// `train_size` fraction of them is put into the training graph, while the
+    /// remaining ones are put into the validation graph. Edges with equal
+    /// timestamps are sorted by their edge ID. In neither holdouts the graph
+    /// connectivity is necessarily preserved.
+    ///
+    /// In directed graphs, including multigraphs, each directed edge is treated
+    /// independently. In undirected graphs, each edge is split according to the
+    /// timestamp of its direction with the smaller source node ID, and both its
+    /// directions are put into the same graph.
+    ///
+    /// # Arguments
+    /// * `train_size`: f64 - Rate target to reserve for training.
+    /// * `timestamps`: Vec<f64> - The timestamp of each directed edge, in edge ID order.
+    ///
+    /// # Example
+    ///```rust
+    /// # let graph = graph::test_utilities::load_ppi(true, true, true, true, false, false);
+    /// let timestamps = (0..graph.get_number_of_directed_edges()).map(|edge_id| edge_id as f64).collect();
+    /// let (train, test) = graph.get_temporal_holdout(0.8, timestamps).unwrap();
+    /// assert_eq!(
+    ///     train.get_number_of_directed_edges() + test.get_number_of_directed_edges(),
+    ///     graph.get_number_of_directed_edges()
+    /// );
+    /// ```
+    ///
+    /// # Raises
+    /// * If the required training size is not a real value between 0 and 1.
+    /// * If the timestamps do not have a value for each directed edge.
+    /// * If any of the timestamps is NaN.
+    /// * If either the training or the validation graph would have no edges.
+    pub fn get_temporal_holdout(
+        &self,
+        train_size: f64,
+        timestamps: Vec<f64>,
+    ) -> Result<(Graph, Graph)> {
+        if timestamps.len() != self.get_number_of_directed_edges() as usize {
+            return Err(format!(
+                concat!(
+                    "The provided timestamps have length {}, ",
+                    "while the number of directed edges in the current graph is {}."
+                ),
+                timestamps.len(),
+                self.get_number_of_directed_edges()
+            ));
+        }
+        if let Some(edge_id) = timestamps
+            .par_iter()
+            .position_any(|timestamp| timestamp.is_nan())
+        {
+            return Err(format!(
+                "The provided timestamp of the edge {} is NaN.",
+                edge_id
+            ));
+        }
+        // In undirected graphs, each edge is represented by its direction
+        // with the smaller source node ID.
+        let mut edge_ids = self
+            .par_iter_directed_edge_node_ids()
+            .filter_map(|(edge_id, src, dst)| {
+                if self.is_directed() || src <= dst {
+                    Some(edge_id)
+                } else {
+                    None
+                }
+            })
+            .collect::<Vec<EdgeT>>();
+        let (train_number_of_edges, _) =
+            self.get_holdouts_elements_number(train_size, edge_ids.len())?;
+        edge_ids.par_sort_unstable_by(|&a, &b| {
+            timestamps[a as usize]
+                .total_cmp(&timestamps[b as usize])
+                .then(a.cmp(&b))
+        });
+
+        let mut valid_edges_bitmap = RoaringTreemap::new();
+        edge_ids[train_number_of_edges..]
+            .iter()
+            .for_each(|&edge_id| {
+                valid_edges_bitmap.insert(edge_id);
+                if !self.is_directed() {
+                    let (src, dst, edge_type) = unsafe {
+                        self.get_unchecked_node_ids_and_edge_type_id_from_edge_id(edge_id)
+                    };
+                    if src != dst {
+                        valid_edges_bitmap.insert(unsafe {
+                            self.get_unchecked_edge_id_from_node_ids_and_edge_type_id(
+                                dst, src, edge_type,
+                            )
+                        });
+                    }
+                }
+            });
+        self.get_edge_holdout_from_validation_edge_ids(&valid_edges_bitmap)
+    }
+
     /// Returns node-label holdout indices for training ML algorithms on the graph node labels.
     ///
     /// # Arguments
@@ -545,4 +545,36 @@ impl Graph {
 
         Ok(approximated_diameter)
     }
+
+    /// Returns an approximation of the number of descendants of each node of the directed acyclic graph.
+    ///
+    /// # Arguments
+    /// * `precision`: Option<u8> - The number of bits to use to represent the HyperLogLog registers. By default 6.
+    /// * `bits`: Option<u8> - The number of bits to use for the HyperLogLog counters. It must be either 4, 5 or 6, and by default 6.
+    ///
+    /// # Implementation details
+    /// The HyperBall algorithm is executed until convergence, at which point the counter
+    /// of each node approximates the set of nodes reachable from it, including the node itself,
+    /// which is removed from the estimate. Differently from `get_number_of_descendants_per_node`,
+    /// which stores the descendants of each node in a bitmap, the memory required is constant
+    /// for each node. The relative standard error of each estimate is roughly `1.04 / sqrt(2^precision)`,
+    /// that is about 13% with the default precision and 1.6% with precision 12.
+    ///
+    /// # Raises
+    /// * If the graph is not a directed acyclic graph.
+    /// * If the combination of precision and bits is not supported.
+    pub fn get_approximated_number_of_descendants_per_node(
+        &self,
+        precision: Option<u8>,
+        bits: Option<u8>,
+    ) -> Result<Vec<f32>> {
+        self.must_be_directed_acyclic()?;
+        self.dispatch_hyperball(
+            precision,
+            bits,
+            |number_of_descendants: &mut f32, current_count, _previous_count, _iteration| {
+                *number_of_descendants = (current_count - 1.0).max(0.0);
+            },
+        )
+    }
 }
@@ -1,5 +1,6 @@
 use super::*;
-//use crate::hashes::*;
+use crate::hashes::*;
+use crate::isomorphism_iter::EqualBucketsParIter;
 use log::info;
 use rayon::prelude::*;
 
@@ -442,6 +443,212 @@ impl Graph {
         })
     }
 
+    /// Returns the edges of the first node, split between the ones towards the second node and the others.
+    ///
+    /// # Arguments
+    /// * `node_id`: NodeT - The node whose edges are to be returned.
+    /// * `other_node_id`: NodeT - The node whose edges from the first node are returned separately.
+    ///
+    /// # Implementative details
+    /// Each edge is returned as the destination node ID, the edge type and the weight.
+    /// The selfloops are not returned.
+    ///
+    /// # Safety
+    /// If the given node IDs do not exist in the graph the method will panic.
+    unsafe fn get_unchecked_isomorphism_edges(
+        &self,
+        node_id: NodeT,
+        other_node_id: NodeT,
+    ) -> (
+        Vec<(NodeT, Option<EdgeTypeT>, Option<WeightT>)>,
+        Vec<(NodeT, Option<EdgeTypeT>, Option<WeightT>)>,
+    ) {
+        self.iter_unchecked_edge_ids_from_source_node_id(node_id)
+            .map(|edge_id| {
+                let edge_id = edge_id as EdgeT;
+                (
+                    self.get_unchecked_destination_node_id_from_edge_id(edge_id),
+                    self.get_unchecked_edge_type_id_from_edge_id(edge_id),
+                    self.get_unchecked_edge_weight_from_edge_id(edge_id),
+                )
+            })
+            .filter(|&(dst, _, _)| dst != node_id)
+            .partition(|&(dst, _, _)| dst == other_node_id)
+    }
+
+    /// Returns whether the two given nodes are isomorphic to one another.
+    ///
+    /// # Arguments
+    /// * `first_node_id`: NodeT - The first node to check for.
+    /// * `second_node_id`: NodeT - The second node to check for.
+    ///
+    /// # Implementative details
+    /// The two nodes are isomorphic when they have the same node types and the same
+    /// edges, ignoring the selfloops, while the edges between the two nodes
+    /// must be symmetrical.
+    ///
+    /// # Safety
+    /// If the given node IDs do not exist in the graph the method will panic.
+    unsafe fn are_unchecked_isomorphic_node_neighbourhoods(
+        &self,
+        first_node_id: NodeT,
+        second_node_id: NodeT,
+    ) -> bool {
+        if self.get_unchecked_node_type_ids_from_node_id(first_node_id)
+            != self.get_unchecked_node_type_ids_from_node_id(second_node_id)
+        {
+            return false;
+        }
+        let are_equal_edges = |first_edges: &[(NodeT, Option<EdgeTypeT>, Option<WeightT>)],
+                               second_edges: &[(NodeT, Option<EdgeTypeT>, Option<WeightT>)],
+                               compare_destinations: bool| {
+            first_edges.len() == second_edges.len()
+                && first_edges.iter().zip(second_edges.iter()).all(
+                    |(
+                        &(first_dst, first_edge_type, first_weight),
+                        &(second_dst, second_edge_type, second_weight),
+                    )| {
+                        (!compare_destinations || first_dst == second_dst)
+                            && first_edge_type == second_edge_type
+                            && match (first_weight, second_weight) {
+                                (Some(first_weight), Some(second_weight)) => {
+                                    (first_weight - second_weight).abs() <= WeightT::EPSILON
+                                }
+                                _ => true,
+                            }
+                    },
+                )
+        };
+        let (first_to_second_edges, first_edges) =
+            self.get_unchecked_isomorphism_edges(first_node_id, second_node_id);
+        let (second_to_first_edges, second_edges) =
+            self.get_unchecked_isomorphism_edges(second_node_id, first_node_id);
+        are_equal_edges(&first_edges, &second_edges, true)
+            && are_equal_edges(&first_to_second_edges, &second_to_first_edges, false)
+    }
+
+    /// Returns the groups of isomorphic nodes found within the nodes with the same hash.
+    ///
+    /// # Arguments
+    /// * `hashes_and_node_ids`: Vec<(u64, NodeT)> - The hashes of the nodes, sorted by hash and node ID.
+    /// * `minimum_group_size`: usize - Minimum number of nodes in the returned groups.
+    fn get_isomorphic_node_groups_from_hashes(
+        &self,
+        hashes_and_node_ids: Vec<(u64, NodeT)>,
+        minimum_group_size: usize,
+    ) -> Vec<Vec<NodeT>> {
+        unsafe { EqualBucketsParIter::new(hashes_and_node_ids) }
+            .filter(|bucket| bucket.len() >= minimum_group_size)
+            .flat_map(|bucket| {
+                // Within the same hash there may be colliding nodes which are not
+                // isomorphic, so we split the bucket into the actual groups.
+                let mut candidate_isomorphic_groups: Vec<Vec<NodeT>> = Vec::new();
+                for &(_, node_id) in bucket.iter() {
+                    if let Some(isomorphic_group) =
+                        candidate_isomorphic_groups.iter_mut().find(|group| unsafe {
+                            self.are_unchecked_isomorphic_node_neighbourhoods(group[0], node_id)
+                        })
+                    {
+                        isomorphic_group.push(node_id);
+                    } else {
+                        candidate_isomorphic_groups.push(vec![node_id]);
+                    }
+                }
+                candidate_isomorphic_groups.retain(|group| group.len() >= minimum_group_size);
+                candidate_isomorphic_groups
+            })
+            .collect()
+    }
+
+    /// Returns the groups of nodes that are isomorphic to one another.
+    ///
+    /// # Arguments
+    /// * `minimum_group_size`: Option<NodeT> - Minimum number of nodes in the returned groups. By default, 2.
+    ///
+    /// # Implementative details
+    /// Two nodes are isomorphic when they have the same node types and the same
+    /// neighbours, with the same multiplicity, edge types and weights, ignoring
+    /// their selfloops and the edges connecting them to one another.
+    /// Therefore, the isomorphic nodes either share the same neighbours and are
+    /// not connected, or share the same neighbours, themselves included, and are
+    /// all connected to one another: the two cases are detected separately by hashing,
+    /// in parallel, the neighbours of each node either without or with the node itself.
+    /// The nodes with the same hash are then checked to be isomorphic, so that
+    /// the hash collisions do not affect the result.
+    /// In directed graphs, only the outbound neighbours are considered.
+    /// The nodes within each group are sorted, and the groups are sorted by their first node.
+    ///
+    /// # Example
+    ///```rust
+    /// # let graph = graph::test_utilities::load_ppi(true, true, true, false, false, false);
+    /// let groups = graph.get_isomorphic_node_groups(Some(3)).unwrap();
+    /// assert!(groups.iter().all(|group| group.len() >= 3));
+    /// ```
+    ///
+    /// # Raises
+    /// * If the given minimum group size is smaller than two.
+    pub fn get_isomorphic_node_groups(
+        &self,
+        minimum_group_size: Option<NodeT>,
+    ) -> Result<Vec<Vec<NodeT>>> {
+        let minimum_group_size = minimum_group_size.unwrap_or(2);
+        if minimum_group_size < 2 {
+            return Err(format!(
+                concat!(
+                    "The given minimum group size {} is smaller than two, ",
+                    "but a group of isomorphic nodes contains at least two nodes."
+                ),
+                minimum_group_size
+            ));
+        }
+        info!("Computing node neighbourhoods hashes.");
+        let (mut open_hashes_and_node_ids, mut closed_hashes_and_node_ids): (
+            Vec<(u64, NodeT)>,
+            Vec<(u64, NodeT)>,
+        ) = self
+            .par_iter_node_ids()
+            .map(|node_id| unsafe {
+                let mut open_hasher = Hasher::simple();
+                open_hasher.update(&self.get_unchecked_node_type_ids_from_node_id(node_id));
+                let mut closed_hasher = open_hasher.clone();
+                let mut node_id_was_hashed = false;
+                for neighbour_node_id in
+                    self.iter_unchecked_neighbour_node_ids_from_source_node_id(node_id)
+                {
+                    if neighbour_node_id == node_id {
+                        continue;
+                    }
+                    if !node_id_was_hashed && neighbour_node_id > node_id {
+                        <Hasher as UpdateHash<u32>>::update(&mut closed_hasher, &node_id);
+                        node_id_was_hashed = true;
+                    }
+                    <Hasher as UpdateHash<u32>>::update(&mut open_hasher, &neighbour_node_id);
+                    <Hasher as UpdateHash<u32>>::update(&mut closed_hasher, &neighbour_node_id);
+                }
+                if !node_id_was_hashed {
+                    <Hasher as UpdateHash<u32>>::update(&mut closed_hasher, &node_id);
+                }
+                (
+                    (open_hasher.digest(), node_id),
+                    (closed_hasher.digest(), node_id),
+                )
+            })
+            .unzip();
+        open_hashes_and_node_ids.par_sort_unstable();
+        closed_hashes_and_node_ids.par_sort_unstable();
+
+        info!("Computing isomorphic node groups.");
+        let minimum_group_size = minimum_group_size as usize;
+        let mut isomorphic_node_groups = self
+            .get_isomorphic_node_groups_from_hashes(open_hashes_and_node_ids, minimum_group_size);
+        isomorphic_node_groups.extend(self.get_isomorphic_node_groups_from_hashes(
+            closed_hashes_and_node_ids,
+            minimum_group_size,
+        ));
+        isomorphic_node_groups.par_sort_unstable_by_key(|group| group[0]);
+        Ok(isomorphic_node_groups)
+    }
+
     /// Returns whether the set of provided node IDs have isomorphic node types.
     ///
     /// # Arguments
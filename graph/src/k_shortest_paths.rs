@@ -0,0 +1,237 @@
+use super::*;
+use std::collections::HashSet;
+
+/// # K shortest paths
+impl Graph {
+    /// Returns the shortest path between the given nodes avoiding the given nodes and edges, with its cumulative distances.
+    ///
+    /// # Arguments
+    /// * `src_node_id`: NodeT - The source node ID.
+    /// * `dst_node_id`: NodeT - The destination node ID.
+    /// * `use_edge_weights`: bool - Whether to use the edge weights as distances.
+    /// * `removed_node_ids`: &[bool] - Whether each node is to be avoided.
+    /// * `removed_edges`: &HashSet<(NodeT, NodeT)> - The directed edges to be avoided.
+    ///
+    /// # Safety
+    /// If any of the given node IDs does not exist in the graph the method will panic,
+    /// and if the edge weights are requested the graph must have edge weights.
+    unsafe fn get_unchecked_shortest_path_avoiding_node_ids_and_edges(
+        &self,
+        src_node_id: NodeT,
+        dst_node_id: NodeT,
+        use_edge_weights: bool,
+        removed_node_ids: &[bool],
+        removed_edges: &HashSet<(NodeT, NodeT)>,
+    ) -> Option<(Vec<NodeT>, Vec<f64>)> {
+        let number_of_nodes = self.get_number_of_nodes() as usize;
+        let mut predecessors: Vec<Option<NodeT>> = vec![None; number_of_nodes];
+        let mut distances = vec![f64::INFINITY; number_of_nodes];
+        let mut nodes_to_explore: DijkstraQueue<f64> = DijkstraQueue::with_capacity_from_roots(
+            number_of_nodes,
+            vec![src_node_id],
+            &mut distances,
+        );
+        while let Some(closest_node_id) = nodes_to_explore.pop() {
+            if closest_node_id as NodeT == dst_node_id {
+                break;
+            }
+            let closest_node_distance = nodes_to_explore[closest_node_id];
+            self.iter_unchecked_edge_ids_from_source_node_id(closest_node_id as NodeT)
+                .for_each(|edge_id| {
+                    let neighbour_node_id =
+                        self.get_unchecked_destination_node_id_from_edge_id(edge_id as EdgeT);
+                    if removed_node_ids[neighbour_node_id as usize]
+                        || removed_edges.contains(&(closest_node_id as NodeT, neighbour_node_id))
+                    {
+                        return;
+                    }
+                    let edge_length = if use_edge_weights {
+                        self.get_unchecked_edge_weight_from_edge_id(edge_id as EdgeT)
+                            .unwrap() as f64
+                    } else {
+                        1.0
+                    };
+                    let neighbour_distance = closest_node_distance + edge_length;
+                    if neighbour_distance < nodes_to_explore[neighbour_node_id as usize] {
+                        predecessors[neighbour_node_id as usize] = Some(closest_node_id as NodeT);
+                        nodes_to_explore.push(neighbour_node_id as usize, neighbour_distance);
+                    }
+                });
+        }
+        if distances[dst_node_id as usize].is_infinite() {
+            return None;
+        }
+        // We rebuild the path backwards from the destination node.
+        let mut path = vec![dst_node_id];
+        while let Some(predecessor) = predecessors[*path.last().unwrap() as usize] {
+            path.push(predecessor);
+        }
+        path.reverse();
+        let cumulative_distances = path
+            .iter()
+            .map(|&node_id| distances[node_id as usize])
+            .collect();
+        Some((path, cumulative_distances))
+    }
+
+    /// Returns up to `k` loopless paths between the given nodes, by increasing length.
+    ///
+    /// # Arguments
+    /// * `src_node_id`: NodeT - The source node ID.
+    /// * `dst_node_id`: NodeT - The destination node ID.
+    /// * `k`: usize - The maximum number of paths to return.
+    /// * `use_edge_weights`: Option<bool> - Whether to use the edge weights as distances. By default, false.
+    ///
+    /// # Implementative details
+    /// The paths are computed with Yen's algorithm: starting from the shortest
+    /// path, each following path is the shortest one among the candidates that
+    /// deviate from a previously found path at one of its nodes, the spur node,
+    /// avoiding the nodes that precede it on the path and the edges leaving it
+    /// that are used by the previously found paths sharing the same prefix. Each
+    /// candidate requires a Dijkstra search, hence the complexity is `k` times
+    /// the number of nodes in a path times the cost of a single shortest path
+    /// computation. When the edge weights are not used, each edge has unit length.
+    ///
+    /// Paths of equal length are sorted lexicographically by their node IDs.
+    /// When fewer than `k` loopless paths exist, all of them are returned, and
+    /// when the destination node cannot be reached no path is returned.
+    ///
+    /// # References
+    /// [Finding the K Shortest Loopless Paths in a Network](https://doi.org/10.1287/mnsc.17.11.712),
+    /// by Jin Y. Yen.
+    ///
+    /// # Example
+    ///```rust
+    /// # let graph = graph::test_utilities::load_ppi(true, true, true, true, false, false);
+    /// let paths = graph.get_k_shortest_paths_from_node_ids(0, 1, 3, None).unwrap();
+    /// assert!(paths.len() <= 3);
+    /// assert!(paths.iter().all(|path| path[0] == 0 && *path.last().unwrap() == 1));
+    /// ```
+    ///
+    /// # Raises
+    /// * If any of the given node IDs does not exist in the current graph.
+    /// * If the number of paths is zero.
+    /// * If the edge weights are requested but the graph does not have edge weights.
+    /// * If the edge weights are requested but the graph contains negative edge weights.
+    pub fn get_k_shortest_paths_from_node_ids(
+        &self,
+        src_node_id: NodeT,
+        dst_node_id: NodeT,
+        k: usize,
+        use_edge_weights: Option<bool>,
+    ) -> Result<Vec<Vec<NodeT>>> {
+        let src_node_id = self.validate_node_id(src_node_id)?;
+        let dst_node_id = self.validate_node_id(dst_node_id)?;
+        if k == 0 {
+            return Err("The number of paths must be strictly positive.".to_string());
+        }
+        let use_edge_weights = use_edge_weights.unwrap_or(false);
+        if use_edge_weights {
+            self.must_have_positive_edge_weights()?;
+        }
+        let mut removed_node_ids = vec![false; self.get_number_of_nodes() as usize];
+        let mut paths: Vec<(Vec<NodeT>, Vec<f64>)> = match unsafe {
+            self.get_unchecked_shortest_path_avoiding_node_ids_and_edges(
+                src_node_id,
+                dst_node_id,
+                use_edge_weights,
+                &removed_node_ids,
+                &HashSet::new(),
+            )
+        } {
+            Some(path) => vec![path],
+            None => return Ok(Vec::new()),
+        };
+        let mut candidates: Vec<(f64, Vec<NodeT>, Vec<f64>)> = Vec::new();
+
+        while paths.len() < k {
+            let (last_path, last_cumulative_distances) = paths.last().unwrap();
+            for spur_index in 0..last_path.len() - 1 {
+                let root_path = &last_path[..=spur_index];
+                let spur_node_id = root_path[spur_index];
+                // We avoid the edges leaving the spur node along the found paths with the same root.
+                let mut removed_edges = HashSet::new();
+                paths
+                    .iter()
+                    .filter(|(path, _)| {
+                        path.len() > spur_index + 1 && &path[..=spur_index] == root_path
+                    })
+                    .for_each(|(path, _)| {
+                        removed_edges.insert((spur_node_id, path[spur_index + 1]));
+                        if !self.is_directed() {
+                            removed_edges.insert((path[spur_index + 1], spur_node_id));
+                        }
+                    });
+                // We avoid the nodes of the root path, so that the path is loopless.
+                root_path[..spur_index]
+                    .iter()
+                    .for_each(|&node_id| removed_node_ids[node_id as usize] = true);
+                let spur_path = unsafe {
+                    self.get_unchecked_shortest_path_avoiding_node_ids_and_edges(
+                        spur_node_id,
+                        dst_node_id,
+                        use_edge_weights,
+                        &removed_node_ids,
+                        &removed_edges,
+                    )
+                };
+                root_path[..spur_index]
+                    .iter()
+                    .for_each(|&node_id| removed_node_ids[node_id as usize] = false);
+
+                if let Some((spur_path, spur_cumulative_distances)) = spur_path {
+                    let root_distance = last_cumulative_distances[spur_index];
+                    let path = root_path[..spur_index]
+                        .iter()
+                        .chain(spur_path.iter())
+                        .copied()
+                        .collect::<Vec<NodeT>>();
+                    if paths.iter().any(|(found_path, _)| found_path == &path)
+                        || candidates
+                            .iter()
+                            .any(|(_, candidate_path, _)| candidate_path == &path)
+                    {
+                        continue;
+                    }
+                    let cumulative_distances = last_cumulative_distances[..spur_index]
+                        .iter()
+                        .copied()
+                        .chain(
+                            spur_cumulative_distances
+                                .into_iter()
+                                .map(|distance| root_distance + distance),
+                        )
+                        .collect::<Vec<f64>>();
+                    candidates.push((
+                        *cumulative_distances.last().unwrap(),
+                        path,
+                        cumulative_distances,
+                    ));
+                }
+            }
+
+            // We move the shortest candidate to the found paths.
+            match candidates
+                .iter()
+                .enumerate()
+                .min_by(
+                    |(_, (a_distance, a_path, _)), (_, (b_distance, b_path, _))| {
+                        a_distance
+                            .partial_cmp(b_distance)
+                            .unwrap()
+                            .then_with(|| a_path.cmp(b_path))
+                    },
+                )
+                .map(|(candidate_index, _)| candidate_index)
+            {
+                Some(candidate_index) => {
+                    let (_, path, cumulative_distances) = candidates.swap_remove(candidate_index);
+                    paths.push((path, cumulative_distances));
+                }
+                None => break,
+            }
+        }
+
+        Ok(paths.into_iter().map(|(path, _)| path).collect())
+    }
+}
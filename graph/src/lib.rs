@@ -48,7 +48,7 @@ pub use self::node_file_writer::NodeFileWriter;
 mod edge_file_reader;
 pub use self::edge_file_reader::EdgeFileReader;
 mod edge_file_writer;
-pub use self::edge_file_writer::EdgeFileWriter;
+pub use self::edge_file_writer::{write_edge_iterator, EdgeFileWriter};
 mod type_file_reader;
 pub use self::type_file_reader::TypeFileReader;
 mod type_file_writer;
@@ -63,6 +63,8 @@ pub use self::dijkstra::*;
 
 mod coo;
 
+mod cores;
+
 mod edge_prediction_analysis;
 
 mod heterogeneous_graphlets;
@@ -70,17 +72,25 @@ mod heterogeneous_graphlets;
 mod constructors;
 pub use constructors::*;
 
+mod assortativity;
+mod astar;
+mod bidirectional_breadth_first_search;
+mod bipartite_projection;
 mod bitmaps;
 mod centrality;
 mod dense;
 mod distributions;
+mod edge_attributes;
 mod edge_isomorphism;
 mod edge_list_utils;
 mod edge_lists;
 mod edge_metrics;
+mod effective_resistance;
 mod filters;
 mod getters;
 mod graph;
+mod graph_level_features;
+mod graphml;
 mod hash;
 mod hashes;
 mod holdouts;
@@ -89,14 +99,18 @@ mod isomorphism;
 pub mod isomorphism_iter;
 mod iter_queries;
 mod iters;
+mod k_shortest_paths;
 mod modifiers;
 mod operators;
+pub use operators::WeightMergeStrategy;
 mod polygons;
 mod preprocessing;
 mod random_graphs;
+mod recursive_structural_features;
 mod remap;
 mod remove;
 mod selfloops;
+mod semantic_similarity;
 mod setters;
 mod sort;
 mod tarjan;
@@ -107,6 +121,7 @@ mod transitivity;
 mod trees;
 mod triad_census;
 mod url_utilities;
+mod vertex_connectivity;
 mod vertex_cover;
 mod walks;
 pub mod walks_parameters;
@@ -124,6 +139,7 @@ pub mod test_utilities;
 pub use self::graph::Graph;
 pub use self::walks_parameters::*;
 pub use edge_isomorphism::*;
+pub use graph_level_features::*;
 pub use preprocessing::*;
 pub use tfidf::*;
 
@@ -142,6 +158,14 @@ mod memory;
 pub use memory::*;
 
 mod louvain;
+mod local_communities;
+mod matching;
+pub use matching::*;
+mod minhash;
+mod node_features;
+pub use node_features::*;
+mod node_weights;
+pub use node_weights::*;
 mod nodes_sampling;
 
 mod subgraphs;
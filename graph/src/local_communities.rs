@@ -0,0 +1,563 @@
+use super::*;
+use rayon::prelude::*;
+use vec_rand::splitmix64;
+
+/// # Local community detection
+/// Methods to score and grow node sets without partitioning the whole graph.
+impl Graph {
+    /// Returns the validated membership mask of the provided node set.
+    ///
+    /// # Arguments
+    /// * `node_ids`: &[NodeT] - The node IDs of the node set.
+    ///
+    /// # Raises
+    /// * If the provided node set is empty.
+    /// * If any of the provided node IDs does not exist in the graph.
+    pub(crate) fn get_node_set_membership(&self, node_ids: &[NodeT]) -> Result<Vec<bool>> {
+        if node_ids.is_empty() {
+            return Err("The provided node set is empty.".to_string());
+        }
+        let mut membership = vec![false; self.get_number_of_nodes() as usize];
+        for &node_id in node_ids {
+            self.validate_node_id(node_id)?;
+            membership[node_id as usize] = true;
+        }
+        Ok(membership)
+    }
+
+    /// Returns the cut and the volume of the provided node set.
+    ///
+    /// The cut is the total (weighted) number of edges starting
+    /// from a node within the set and ending in a node outside of it,
+    /// while the volume is the total (weighted) outbound degree of the
+    /// nodes within the set.
+    ///
+    /// # Arguments
+    /// * `node_ids`: &[NodeT] - The node IDs of the node set. It must not contain duplicates.
+    /// * `membership`: &[bool] - The membership mask of the node set.
+    /// * `use_edge_weights`: bool - Whether to use the edge weights.
+    ///
+    /// # Safety
+    /// If any of the node IDs does not exist in the graph, or the graph has no
+    /// edge weights while they are requested, the method will panic.
+    pub(crate) unsafe fn get_unchecked_node_set_cut_and_volume(
+        &self,
+        node_ids: &[NodeT],
+        membership: &[bool],
+        use_edge_weights: bool,
+    ) -> (f64, f64) {
+        node_ids
+            .iter()
+            .map(|&node_id| {
+                if use_edge_weights {
+                    self.iter_unchecked_neighbour_node_ids_from_source_node_id(node_id)
+                        .zip(self.iter_unchecked_edge_weights_from_source_node_id(node_id))
+                        .fold((0.0, 0.0), |(cut, volume), (dst, weight)| {
+                            (
+                                cut + if membership[dst as usize] {
+                                    0.0
+                                } else {
+                                    weight as f64
+                                },
+                                volume + weight as f64,
+                            )
+                        })
+                } else {
+                    let cut = self
+                        .iter_unchecked_neighbour_node_ids_from_source_node_id(node_id)
+                        .filter(|&dst| !membership[dst as usize])
+                        .count();
+                    (
+                        cut as f64,
+                        self.get_unchecked_node_degree_from_node_id(node_id) as f64,
+                    )
+                }
+            })
+            .fold((0.0, 0.0), |(cut, volume), (node_cut, node_volume)| {
+                (cut + node_cut, volume + node_volume)
+            })
+    }
+
+    /// Returns the conductance of the node set from its cut and volume.
+    ///
+    /// # Arguments
+    /// * `cut`: f64 - The cut of the node set.
+    /// * `volume`: f64 - The volume of the node set.
+    /// * `total_volume`: f64 - The volume of the whole graph.
+    fn get_conductance_from_cut_and_volume(cut: f64, volume: f64, total_volume: f64) -> f64 {
+        let denominator = volume.min(total_volume - volume);
+        if denominator <= 0.0 {
+            0.0
+        } else {
+            cut / denominator
+        }
+    }
+
+    /// Returns the conductance of the provided node set.
+    ///
+    /// # Arguments
+    /// * `node_ids`: &[NodeT] - The node IDs of the node set.
+    /// * `use_edge_weights`: Option<bool> - Whether to use the edge weights. By default, false.
+    ///
+    /// # Implementative details
+    /// The conductance is the (weighted) number of edges leaving the node set,
+    /// divided by the minimum between the volume of the node set and the
+    /// volume of its complement, where the volume is the total (weighted)
+    /// node degree. When either of the two volumes is zero, as it happens
+    /// when the node set contains the whole graph, the conductance is zero.
+    ///
+    /// # Example
+    ///```rust
+    /// # let graph = graph::test_utilities::load_ppi(true, true, true, true, false, false);
+    /// println!("The conductance is {}.", graph.get_conductance(&[0, 1, 2], None).unwrap());
+    /// ```
+    ///
+    /// # Raises
+    /// * If the provided node set is empty.
+    /// * If any of the provided node IDs does not exist in the graph.
+    /// * If edge weights are requested but the graph does not have them.
+    pub fn get_conductance(
+        &self,
+        node_ids: &[NodeT],
+        use_edge_weights: Option<bool>,
+    ) -> Result<f64> {
+        let use_edge_weights = use_edge_weights.unwrap_or(false);
+        let total_volume = if use_edge_weights {
+            self.get_total_edge_weights()?
+        } else {
+            self.get_number_of_directed_edges() as f64
+        };
+        let membership = self.get_node_set_membership(node_ids)?;
+        let mut node_ids = node_ids.to_vec();
+        node_ids.sort_unstable();
+        node_ids.dedup();
+        let (cut, volume) = unsafe {
+            self.get_unchecked_node_set_cut_and_volume(&node_ids, &membership, use_edge_weights)
+        };
+        Ok(Self::get_conductance_from_cut_and_volume(
+            cut,
+            volume,
+            total_volume,
+        ))
+    }
+
+    /// Returns the number of edges within the first set, within the second set and between the two sets.
+    ///
+    /// # Arguments
+    /// * `first_node_ids`: &[NodeT] - The node IDs of the first node set.
+    /// * `second_node_ids`: &[NodeT] - The node IDs of the second node set.
+    ///
+    /// # Implementative details
+    /// Edges with at least one endpoint outside of both sets are ignored.
+    /// In undirected graphs each edge is counted once, while in directed
+    /// graphs the crossing edges include the edges going from the first set
+    /// to the second one and vice versa. Selfloops and parallel edges are
+    /// counted like any other edge.
+    ///
+    /// # Example
+    ///```rust
+    /// # let graph = graph::test_utilities::load_ppi(true, true, true, true, false, false);
+    /// let (first_internal, second_internal, crossing) = graph.get_cut_summary(&[0, 1, 2], &[3, 4, 5]).unwrap();
+    /// ```
+    ///
+    /// # Raises
+    /// * If either of the provided node sets is empty.
+    /// * If any of the provided node IDs does not exist in the graph.
+    /// * If the two provided node sets are not disjoint.
+    pub fn get_cut_summary(
+        &self,
+        first_node_ids: &[NodeT],
+        second_node_ids: &[NodeT],
+    ) -> Result<(EdgeT, EdgeT, EdgeT)> {
+        let first_membership = self.get_node_set_membership(first_node_ids)?;
+        let second_membership = self.get_node_set_membership(second_node_ids)?;
+        if let Some(node_id) = second_node_ids
+            .iter()
+            .find(|&&node_id| first_membership[node_id as usize])
+        {
+            return Err(format!(
+                "The provided node sets are not disjoint: the node ID {} appears in both.",
+                node_id
+            ));
+        }
+        let mut node_ids = first_node_ids
+            .iter()
+            .chain(second_node_ids.iter())
+            .copied()
+            .collect::<Vec<NodeT>>();
+        node_ids.par_sort_unstable();
+        node_ids.dedup();
+        let is_directed = self.is_directed();
+        Ok(node_ids
+            .par_iter()
+            .map(|&src| {
+                let src_is_first = first_membership[src as usize];
+                unsafe { self.iter_unchecked_neighbour_node_ids_from_source_node_id(src) }
+                    .filter(|&dst| is_directed || src <= dst)
+                    .fold(
+                        (0, 0, 0),
+                        |(first_internal, second_internal, crossing), dst| match (
+                            src_is_first,
+                            first_membership[dst as usize],
+                            second_membership[dst as usize],
+                        ) {
+                            (true, true, _) => (first_internal + 1, second_internal, crossing),
+                            (false, _, true) => (first_internal, second_internal + 1, crossing),
+                            (true, _, true) | (false, true, _) => {
+                                (first_internal, second_internal, crossing + 1)
+                            }
+                            _ => (first_internal, second_internal, crossing),
+                        },
+                    )
+            })
+            .reduce(
+                || (0, 0, 0),
+                |(a_first, a_second, a_crossing), (b_first, b_second, b_crossing)| {
+                    (
+                        a_first + b_first,
+                        a_second + b_second,
+                        a_crossing + b_crossing,
+                    )
+                },
+            ))
+    }
+
+    /// Returns the Clauset local modularity of the provided node set.
+    ///
+    /// The local modularity is the fraction of the edges touching the
+    /// boundary of the node set that do not lead outside of the set,
+    /// where the boundary is the subset of the nodes of the set having
+    /// at least one neighbour outside of it.
+    ///
+    /// # Arguments
+    /// * `node_ids`: &[NodeT] - The node IDs of the node set. It must not contain duplicates.
+    /// * `membership`: &[bool] - The membership mask of the node set.
+    ///
+    /// # Safety
+    /// If any of the node IDs does not exist in the graph the method will panic.
+    unsafe fn get_unchecked_node_set_local_modularity(
+        &self,
+        node_ids: &[NodeT],
+        membership: &[bool],
+    ) -> f64 {
+        let is_boundary = |node_id: NodeT| {
+            self.iter_unchecked_neighbour_node_ids_from_source_node_id(node_id)
+                .any(|dst| !membership[dst as usize])
+        };
+        let boundary = node_ids
+            .iter()
+            .copied()
+            .filter(|&node_id| is_boundary(node_id))
+            .collect::<Vec<NodeT>>();
+        // If the set has no boundary, it is a whole connected component
+        // and no edge leaves the set.
+        if boundary.is_empty() {
+            return 1.0;
+        }
+        let (internal, total) = boundary.iter().fold((0.0, 0.0), |(internal, total), &src| {
+            self.iter_unchecked_neighbour_node_ids_from_source_node_id(src)
+                .fold((internal, total), |(internal, total), dst| {
+                    if !membership[dst as usize] {
+                        (internal, total + 1.0)
+                    } else if boundary.binary_search(&dst).is_ok() {
+                        // Edges between two boundary nodes are
+                        // encountered twice, once for each endpoint.
+                        (internal + 0.5, total + 0.5)
+                    } else {
+                        (internal + 1.0, total + 1.0)
+                    }
+                })
+        });
+        internal / total
+    }
+
+    /// Returns the community grown greedily from the provided seed nodes and its achieved score.
+    ///
+    /// # Arguments
+    /// * `seed_node_ids`: &[NodeT] - The node IDs from which to start growing the community.
+    /// * `max_size`: NodeT - The maximum number of nodes in the community.
+    /// * `score`: Option<&str> - The score to optimize. Can either be `local_modularity` or `conductance`. By default, `local_modularity`.
+    /// * `random_state`: Option<u64> - The random state to use to break ties between candidates. By default, 42.
+    ///
+    /// # Implementative details
+    /// At each step, among the neighbours of the current community, we add
+    /// the node that most improves the score: the `local_modularity` is
+    /// maximized while the `conductance` is minimized. The process stops
+    /// when no neighbour improves the score or the community reaches the
+    /// maximum size. Candidates with the same score are ranked by a hash of
+    /// their node ID and the random state, so that the procedure is deterministic.
+    ///
+    /// The returned node IDs are sorted.
+    ///
+    /// # References
+    /// The local modularity is the one described in [Finding local community structure in networks](https://arxiv.org/abs/physics/0503036),
+    /// by Aaron Clauset.
+    ///
+    /// # Raises
+    /// * If the graph is directed.
+    /// * If the provided seed node set is empty.
+    /// * If any of the provided seed node IDs does not exist in the graph.
+    /// * If the maximum size is smaller than the number of seed nodes.
+    /// * If the provided score is not supported.
+    pub fn grow_community_from_seed(
+        &self,
+        seed_node_ids: &[NodeT],
+        max_size: NodeT,
+        score: Option<&str>,
+        random_state: Option<u64>,
+    ) -> Result<(Vec<NodeT>, f64)> {
+        self.must_be_undirected()?;
+        let mut membership = self.get_node_set_membership(seed_node_ids)?;
+        let mut community = seed_node_ids.to_vec();
+        community.sort_unstable();
+        community.dedup();
+        if (max_size as usize) < community.len() {
+            return Err(format!(
+                concat!(
+                    "The provided maximum size {} is smaller than ",
+                    "the number of provided seed nodes {}."
+                ),
+                max_size,
+                community.len()
+            ));
+        }
+        let maximize = match score.unwrap_or("local_modularity") {
+            "local_modularity" => true,
+            "conductance" => false,
+            score => {
+                return Err(format!(
+                    concat!(
+                        "The provided score `{}` is not supported. ",
+                        "The supported scores are `local_modularity` and `conductance`."
+                    ),
+                    score
+                ))
+            }
+        };
+        let random_state = splitmix64(random_state.unwrap_or(42));
+        let total_volume = self.get_number_of_directed_edges() as f64;
+
+        let compute_score = |community: &[NodeT], membership: &[bool]| unsafe {
+            if maximize {
+                self.get_unchecked_node_set_local_modularity(community, membership)
+            } else {
+                let (cut, volume) =
+                    self.get_unchecked_node_set_cut_and_volume(community, membership, false);
+                Self::get_conductance_from_cut_and_volume(cut, volume, total_volume)
+            }
+        };
+
+        let mut current_score = compute_score(&community, &membership);
+
+        while community.len() < max_size as usize {
+            let mut candidates = community
+                .iter()
+                .flat_map(|&node_id| unsafe {
+                    self.iter_unchecked_neighbour_node_ids_from_source_node_id(node_id)
+                })
+                .filter(|&node_id| !membership[node_id as usize])
+                .collect::<Vec<NodeT>>();
+            candidates.sort_unstable();
+            candidates.dedup();
+
+            let best_candidate = candidates
+                .into_iter()
+                .map(|candidate| {
+                    membership[candidate as usize] = true;
+                    let position = community.binary_search(&candidate).unwrap_err();
+                    community.insert(position, candidate);
+                    let candidate_score = compute_score(&community, &membership);
+                    community.remove(position);
+                    membership[candidate as usize] = false;
+                    (candidate, candidate_score)
+                })
+                .max_by(|(a, a_score), (b, b_score)| {
+                    let ordering = if maximize {
+                        a_score.partial_cmp(b_score).unwrap()
+                    } else {
+                        b_score.partial_cmp(a_score).unwrap()
+                    };
+                    ordering.then_with(|| {
+                        splitmix64(random_state ^ *a as u64)
+                            .cmp(&splitmix64(random_state ^ *b as u64))
+                    })
+                });
+
+            match best_candidate {
+                Some((candidate, candidate_score))
+                    if maximize && candidate_score > current_score
+                        || !maximize && candidate_score < current_score =>
+                {
+                    membership[candidate as usize] = true;
+                    let position = community.binary_search(&candidate).unwrap_err();
+                    community.insert(position, candidate);
+                    current_score = candidate_score;
+                }
+                _ => break,
+            }
+        }
+
+        Ok((community, current_score))
+    }
+
+    /// Returns the nodes ranked by decreasing density of their ego network.
+    ///
+    /// # Arguments
+    /// * `radius`: NodeT - The maximum number of hops from the central node of the ego networks.
+    /// * `maximum_ego_size`: Option<NodeT> - The maximum number of nodes of each ego network. By default, 1000.
+    /// * `use_edge_weights`: Option<bool> - Whether to use the edge weights. By default, false.
+    ///
+    /// # Implementative details
+    /// The ego network of a node is the subgraph induced by the nodes within
+    /// `radius` hops from it. The density of an ego network with `n > 1` nodes
+    /// is the number of directed edges between its nodes, excluding self-loops,
+    /// divided by `n(n - 1)`: in undirected graphs each edge is counted in both
+    /// directions, so that this is equal to the usual `2m / (n(n - 1))`, while in
+    /// directed graphs it is `m / (n(n - 1))`, where edges are followed in their
+    /// direction also to build the ego network. Multi-edges are counted once,
+    /// unless the edge weights are used, in which case the weights of all the
+    /// edges are summed. Ego networks with a single node have zero density.
+    ///
+    /// To bound the cost of hubs, the ego networks are built breadth-first
+    /// and the search stops as soon as they reach the maximum size: the ego network
+    /// of a hub is therefore composed of the nodes closest to it, with the ties at
+    /// the last explored depth broken by the order in which they are reached, where
+    /// the neighbours of each node are visited by increasing node ID. Similarly, the
+    /// edges of the nodes with more neighbours than the size of their ego network
+    /// are looked up for each of the other nodes of the ego network, instead of
+    /// scanning all of their neighbours.
+    ///
+    /// The nodes with the same density are sorted by increasing node ID.
+    /// Dense ego networks are good seeds for `grow_community_from_seed`.
+    ///
+    /// # Example
+    ///```rust
+    /// # let graph = graph::test_utilities::load_ppi(true, true, true, true, false, false);
+    /// let ranking = graph.get_nodes_ranked_by_ego_density(1, None, None).unwrap();
+    /// assert_eq!(ranking.len(), graph.get_number_of_nodes() as usize);
+    /// assert!(ranking.windows(2).all(|window| window[0].1 >= window[1].1));
+    /// ```
+    ///
+    /// # Raises
+    /// * If the maximum ego size is zero.
+    /// * If edge weights are requested but the graph does not have them.
+    pub fn get_nodes_ranked_by_ego_density(
+        &self,
+        radius: NodeT,
+        maximum_ego_size: Option<NodeT>,
+        use_edge_weights: Option<bool>,
+    ) -> Result<Vec<(NodeT, f64)>> {
+        let maximum_ego_size = maximum_ego_size.unwrap_or(1000) as usize;
+        if maximum_ego_size == 0 {
+            return Err("The maximum ego size must be strictly positive.".to_string());
+        }
+        let use_edge_weights = use_edge_weights.unwrap_or(false);
+        if use_edge_weights {
+            self.must_have_edge_weights()?;
+        }
+        let number_of_nodes = self.get_number_of_nodes() as usize;
+
+        let mut ranking = self
+            .par_iter_node_ids()
+            .map_init(
+                || (vec![false; number_of_nodes], Vec::new(), Vec::new()),
+                |(membership, ego, depths), root| {
+                    // The nodes of the ego network are stored in the order they
+                    // are reached, so the same vector is used as the search queue.
+                    membership[root as usize] = true;
+                    ego.push(root);
+                    depths.push(0);
+                    let mut index = 0;
+                    'search: while index < ego.len() && ego.len() < maximum_ego_size {
+                        let (node_id, depth) = (ego[index], depths[index]);
+                        index += 1;
+                        if depth == radius {
+                            continue;
+                        }
+                        for neighbour_node_id in unsafe {
+                            self.iter_unchecked_unique_neighbour_node_ids_from_source_node_id(
+                                node_id,
+                            )
+                        } {
+                            if !membership[neighbour_node_id as usize] {
+                                membership[neighbour_node_id as usize] = true;
+                                ego.push(neighbour_node_id);
+                                depths.push(depth + 1);
+                                if ego.len() == maximum_ego_size {
+                                    break 'search;
+                                }
+                            }
+                        }
+                    }
+
+                    let internal_edges = ego
+                        .iter()
+                        .map(|&src| unsafe {
+                            // The neighbours of the nodes with a degree higher than
+                            // the size of the ego network, such as the hubs, are not
+                            // scanned: the edges towards each of the other nodes of
+                            // the ego network are searched instead.
+                            if self.get_unchecked_node_degree_from_node_id(src) as usize > ego.len()
+                            {
+                                ego.iter()
+                                    .filter(|&&dst| src != dst)
+                                    .map(|&dst| {
+                                        let (min_edge_id, max_edge_id) = self
+                                            .get_unchecked_minmax_edge_ids_from_node_ids(src, dst);
+                                        if use_edge_weights {
+                                            (min_edge_id..max_edge_id)
+                                                .map(|edge_id| {
+                                                    self.get_unchecked_edge_weight_from_edge_id(
+                                                        edge_id,
+                                                    )
+                                                    .unwrap()
+                                                        as f64
+                                                })
+                                                .sum::<f64>()
+                                        } else if max_edge_id > min_edge_id {
+                                            1.0
+                                        } else {
+                                            0.0
+                                        }
+                                    })
+                                    .sum::<f64>()
+                            } else if use_edge_weights {
+                                self.iter_unchecked_neighbour_node_ids_from_source_node_id(src)
+                                    .zip(self.iter_unchecked_edge_weights_from_source_node_id(src))
+                                    .filter(|&(dst, _)| src != dst && membership[dst as usize])
+                                    .map(|(_, weight)| weight as f64)
+                                    .sum::<f64>()
+                            } else {
+                                self.iter_unchecked_unique_neighbour_node_ids_from_source_node_id(
+                                    src,
+                                )
+                                .filter(|&dst| src != dst && membership[dst as usize])
+                                .count() as f64
+                            }
+                        })
+                        .sum::<f64>();
+                    let ego_size = ego.len() as f64;
+                    let density = if ego.len() > 1 {
+                        internal_edges / (ego_size * (ego_size - 1.0))
+                    } else {
+                        0.0
+                    };
+
+                    ego.drain(..).for_each(|node_id| {
+                        membership[node_id as usize] = false;
+                    });
+                    depths.clear();
+                    (root, density)
+                },
+            )
+            .collect::<Vec<(NodeT, f64)>>();
+
+        ranking.par_sort_unstable_by(|(a, a_density), (b, b_density)| {
+            b_density
+                .partial_cmp(a_density)
+                .unwrap()
+                .then_with(|| a.cmp(b))
+        });
+        Ok(ranking)
+    }
+}
@@ -532,4 +532,86 @@ impl Graph {
             &weighted_node_degrees,
         )
     }
+
+    /// Returns the internal density of each community from the given memberships.
+    ///
+    /// # Arguments
+    /// * `node_community_memberships`: &[NodeT], The memberships assigned to each node of the graph.
+    ///
+    /// # Implementative details
+    /// The density of a community is the number of edges between its nodes
+    /// divided by the maximum number of edges possible among them, which is
+    /// `n(n-1)/2` in undirected graphs and `n(n-1)` in directed graphs.
+    /// Selfloops are ignored and parallel edges are counted once.
+    /// Communities composed of a single node have zero density.
+    ///
+    /// # Raises
+    /// * If the number of provided memberships does not match the number of nodes of the graph.
+    /// * If the provided community IDs are not contiguous, that is some community between zero and the maximum community ID has no nodes.
+    pub fn get_community_densities(
+        &self,
+        node_community_memberships: &[NodeT],
+    ) -> Result<Vec<f64>> {
+        self.validate_modularity_parameters(node_community_memberships)?;
+        let number_of_communities = node_community_memberships
+            .iter()
+            .copied()
+            .max()
+            .map_or(0, |community_id| community_id as usize + 1);
+        let mut community_sizes = vec![0 as NodeT; number_of_communities];
+        node_community_memberships
+            .iter()
+            .for_each(|&community_id| community_sizes[community_id as usize] += 1);
+        if let Some(community_id) = community_sizes.iter().position(|&size| size == 0) {
+            return Err(format!(
+                concat!(
+                    "The provided community IDs are not contiguous, as the community {} ",
+                    "has no nodes while the maximum community ID is {}."
+                ),
+                community_id,
+                number_of_communities - 1
+            ));
+        }
+
+        let internal_edges = self
+            .par_iter_node_ids()
+            .fold(
+                || vec![0 as EdgeT; number_of_communities],
+                |mut internal_edges, src| {
+                    let community_id = node_community_memberships[src as usize];
+                    internal_edges[community_id as usize] += unsafe {
+                        self.iter_unchecked_unique_neighbour_node_ids_from_source_node_id(src)
+                    }
+                    .filter(|&dst| {
+                        dst != src && node_community_memberships[dst as usize] == community_id
+                    })
+                    .count() as EdgeT;
+                    internal_edges
+                },
+            )
+            .reduce(
+                || vec![0 as EdgeT; number_of_communities],
+                |mut first, second| {
+                    first
+                        .iter_mut()
+                        .zip(second.into_iter())
+                        .for_each(|(first, second)| *first += second);
+                    first
+                },
+            );
+
+        Ok(internal_edges
+            .into_par_iter()
+            .zip(community_sizes.into_par_iter())
+            .map(|(internal_edges, community_size)| {
+                if community_size < 2 {
+                    return 0.0;
+                }
+                // In undirected graphs each edge was counted in both directions,
+                // hence the directed maximum number of edges is used in both cases.
+                let community_size = community_size as f64;
+                internal_edges as f64 / (community_size * (community_size - 1.0))
+            })
+            .collect())
+    }
 }
@@ -0,0 +1,292 @@
+use super::*;
+use rayon::prelude::*;
+
+/// Minimum ratio between the total weight of the matching returned by
+/// `get_maximum_weight_matching` and the one of the maximum weight matching.
+pub const MAXIMUM_WEIGHT_MATCHING_APPROXIMATION_RATIO: f64 = 0.5;
+
+/// Returns the column assigned to each row minimizing the total cost.
+///
+/// # Arguments
+/// * `costs`: &[Vec<f64>] - The cost matrix, with no more rows than columns.
+///
+/// # Implementative details
+/// This is the Hungarian algorithm with node potentials and shortest
+/// augmenting paths, running in `O(n^2 m)` time for `n` rows and `m` columns.
+/// Every row is assigned to a distinct column.
+fn get_minimum_cost_assignment(costs: &[Vec<f64>]) -> Vec<usize> {
+    let number_of_rows = costs.len();
+    let number_of_columns = costs.first().map_or(0, |row| row.len());
+    // All vectors are 1-indexed, with the zero position used as sentinel.
+    let mut row_potentials = vec![0.0; number_of_rows + 1];
+    let mut column_potentials = vec![0.0; number_of_columns + 1];
+    let mut column_assignments = vec![0; number_of_columns + 1];
+    let mut predecessors = vec![0; number_of_columns + 1];
+
+    for row in 1..=number_of_rows {
+        column_assignments[0] = row;
+        let mut current_column = 0;
+        let mut minimum_slacks = vec![f64::INFINITY; number_of_columns + 1];
+        let mut visited = vec![false; number_of_columns + 1];
+        loop {
+            visited[current_column] = true;
+            let current_row = column_assignments[current_column];
+            let mut delta = f64::INFINITY;
+            let mut next_column = 0;
+            for column in 1..=number_of_columns {
+                if visited[column] {
+                    continue;
+                }
+                let slack = costs[current_row - 1][column - 1]
+                    - row_potentials[current_row]
+                    - column_potentials[column];
+                if slack < minimum_slacks[column] {
+                    minimum_slacks[column] = slack;
+                    predecessors[column] = current_column;
+                }
+                if minimum_slacks[column] < delta {
+                    delta = minimum_slacks[column];
+                    next_column = column;
+                }
+            }
+            for column in 0..=number_of_columns {
+                if visited[column] {
+                    row_potentials[column_assignments[column]] += delta;
+                    column_potentials[column] -= delta;
+                } else {
+                    minimum_slacks[column] -= delta;
+                }
+            }
+            current_column = next_column;
+            if column_assignments[current_column] == 0 {
+                break;
+            }
+        }
+        // We flip the assignments along the augmenting path.
+        loop {
+            let previous_column = predecessors[current_column];
+            column_assignments[current_column] = column_assignments[previous_column];
+            current_column = previous_column;
+            if current_column == 0 {
+                break;
+            }
+        }
+    }
+
+    let mut row_assignments = vec![0; number_of_rows];
+    (1..=number_of_columns).for_each(|column| {
+        if column_assignments[column] != 0 {
+            row_assignments[column_assignments[column] - 1] = column - 1;
+        }
+    });
+    row_assignments
+}
+
+/// # Matchings
+impl Graph {
+    /// Returns the minimum weight matching between the two given sets of nodes.
+    ///
+    /// # Arguments
+    /// * `left_node_ids`: &[NodeT] - The node IDs of the first side of the bipartite graph.
+    /// * `right_node_ids`: &[NodeT] - The node IDs of the second side of the bipartite graph.
+    ///
+    /// # Implementative details
+    /// Only the edges going from a node of the left set to a node of the
+    /// right set are considered, and when there are parallel edges the one
+    /// with minimum weight is used. The problem is solved with the Hungarian
+    /// (Kuhn-Munkres) algorithm on a dense cost matrix, hence it requires
+    /// memory proportional to the product of the sizes of the two sets.
+    ///
+    /// When the two sets have the same size and a perfect matching exists,
+    /// the returned matching is the perfect matching with minimum total weight.
+    /// Otherwise, the returned matching is the one with minimum total weight
+    /// among those matching the maximum possible number of nodes: the nodes
+    /// in excess in the larger set, and those that cannot be matched with
+    /// any existing edge, are left unmatched and do not appear in the result.
+    ///
+    /// The returned pairs are composed of the left and the right node IDs,
+    /// sorted by the left node ID.
+    ///
+    /// # References
+    /// [The Hungarian method for the assignment problem](https://doi.org/10.1002/nav.3800020109),
+    /// by H. W. Kuhn.
+    ///
+    /// # Raises
+    /// * If the graph does not have edge weights.
+    /// * If any of the given node IDs does not exist in the graph.
+    /// * If either of the given sets of nodes is empty or contains duplicates.
+    /// * If the two given sets of nodes are not disjoint.
+    pub fn get_minimum_weight_bipartite_matching(
+        &self,
+        left_node_ids: &[NodeT],
+        right_node_ids: &[NodeT],
+    ) -> Result<Vec<(NodeT, NodeT)>> {
+        self.must_have_edge_weights()?;
+        let number_of_nodes = self.get_number_of_nodes() as usize;
+        // We store the position of each node within its set,
+        // shifted by one to keep zero for the nodes in neither set.
+        let mut left_positions = vec![0; number_of_nodes];
+        let mut right_positions = vec![0; number_of_nodes];
+        for (positions, node_ids, side) in [
+            (&mut left_positions, left_node_ids, "left"),
+            (&mut right_positions, right_node_ids, "right"),
+        ] {
+            if node_ids.is_empty() {
+                return Err(format!("The provided {} set of nodes is empty.", side));
+            }
+            for (position, &node_id) in node_ids.iter().enumerate() {
+                self.validate_node_id(node_id)?;
+                if positions[node_id as usize] != 0 {
+                    return Err(format!(
+                        "The provided {} set of nodes contains the node ID {} more than once.",
+                        side, node_id
+                    ));
+                }
+                positions[node_id as usize] = position + 1;
+            }
+        }
+        if let Some(&node_id) = left_node_ids
+            .iter()
+            .find(|&&node_id| right_positions[node_id as usize] != 0)
+        {
+            return Err(format!(
+                "The provided sets of nodes are not disjoint, as they both contain the node ID {}.",
+                node_id
+            ));
+        }
+
+        let mut costs = left_node_ids
+            .par_iter()
+            .map(|&src| {
+                let mut row = vec![f64::INFINITY; right_node_ids.len()];
+                unsafe {
+                    self.iter_unchecked_neighbour_node_ids_from_source_node_id(src)
+                        .zip(self.iter_unchecked_edge_weights_from_source_node_id(src))
+                        .for_each(|(dst, weight)| {
+                            let position = right_positions[dst as usize];
+                            if position != 0 {
+                                row[position - 1] = row[position - 1].min(weight as f64);
+                            }
+                        });
+                }
+                row
+            })
+            .collect::<Vec<Vec<f64>>>();
+
+        // The missing edges are replaced by a cost larger than any matching made
+        // of existing edges, so that the assignment first maximizes the number
+        // of existing edges used and then minimizes their total weight.
+        let missing_edge_cost = 1.0
+            + costs
+                .par_iter()
+                .flat_map(|row| row.par_iter())
+                .filter(|cost| cost.is_finite())
+                .map(|cost| cost.abs())
+                .sum::<f64>()
+                * 2.0;
+        costs.par_iter_mut().for_each(|row| {
+            row.iter_mut().for_each(|cost| {
+                if !cost.is_finite() {
+                    *cost = missing_edge_cost;
+                }
+            })
+        });
+
+        // The assignment requires no more rows than columns.
+        let transposed = left_node_ids.len() > right_node_ids.len();
+        if transposed {
+            costs = (0..right_node_ids.len())
+                .into_par_iter()
+                .map(|column| costs.iter().map(|row| row[column]).collect())
+                .collect();
+        }
+        let assignments = get_minimum_cost_assignment(&costs);
+
+        let mut matching = assignments
+            .into_iter()
+            .enumerate()
+            .filter(|&(row, column)| costs[row][column] < missing_edge_cost)
+            .map(|(row, column)| {
+                if transposed {
+                    (left_node_ids[column], right_node_ids[row])
+                } else {
+                    (left_node_ids[row], right_node_ids[column])
+                }
+            })
+            .collect::<Vec<(NodeT, NodeT)>>();
+        matching.par_sort_unstable();
+        Ok(matching)
+    }
+
+    /// Returns the sorted edge IDs of a matching with approximately maximum total weight.
+    ///
+    /// # Implementative details
+    /// The matching is computed with the greedy algorithm, which considers the
+    /// edges by decreasing weight, breaking ties by increasing edge ID, and adds
+    /// each edge whose nodes are both still unmatched. It runs in `O(m log m)` time
+    /// and the total weight of the returned matching is at least
+    /// `MAXIMUM_WEIGHT_MATCHING_APPROXIMATION_RATIO`, that is half, of the one of
+    /// the maximum weight matching. When the graph does not have edge weights,
+    /// all the edges have unit weight and the returned matching is a maximal
+    /// matching, with at least half of the edges of a maximum matching.
+    ///
+    /// Selfloops and edges with non-positive weight are never part of the matching,
+    /// as they cannot increase its total weight. Each edge is returned as the edge
+    /// ID going from the smaller to the larger of its node IDs.
+    ///
+    /// # Example
+    ///```rust
+    /// # let graph = graph::test_utilities::load_ppi(true, true, true, true, false, false);
+    /// let matching = graph.get_maximum_weight_matching().unwrap();
+    /// let mut matched_node_ids = matching
+    ///     .iter()
+    ///     .flat_map(|&edge_id| {
+    ///         let (src, dst) = graph.get_node_ids_from_edge_id(edge_id).unwrap();
+    ///         vec![src, dst]
+    ///     })
+    ///     .collect::<Vec<_>>();
+    /// matched_node_ids.sort_unstable();
+    /// matched_node_ids.dedup();
+    /// // No two edges of the matching share a node.
+    /// assert_eq!(matched_node_ids.len(), 2 * matching.len());
+    /// ```
+    ///
+    /// # Raises
+    /// * If the graph is directed.
+    pub fn get_maximum_weight_matching(&self) -> Result<Vec<EdgeT>> {
+        self.must_be_undirected()?;
+        let mut candidate_edges = self
+            .par_iter_directed_edge_node_ids()
+            .filter_map(|(edge_id, src, dst)| {
+                if src >= dst {
+                    return None;
+                }
+                let weight =
+                    unsafe { self.get_unchecked_edge_weight_from_edge_id(edge_id) }.unwrap_or(1.0);
+                if weight > 0.0 {
+                    Some((weight, edge_id, src, dst))
+                } else {
+                    None
+                }
+            })
+            .collect::<Vec<(WeightT, EdgeT, NodeT, NodeT)>>();
+        candidate_edges.par_sort_unstable_by(|first, second| {
+            second.0.total_cmp(&first.0).then(first.1.cmp(&second.1))
+        });
+
+        let mut matched = vec![false; self.get_number_of_nodes() as usize];
+        let mut matching = candidate_edges
+            .into_iter()
+            .filter_map(|(_, edge_id, src, dst)| {
+                if matched[src as usize] || matched[dst as usize] {
+                    return None;
+                }
+                matched[src as usize] = true;
+                matched[dst as usize] = true;
+                Some(edge_id)
+            })
+            .collect::<Vec<EdgeT>>();
+        matching.par_sort_unstable();
+        Ok(matching)
+    }
+}
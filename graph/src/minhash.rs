@@ -0,0 +1,145 @@
+use super::*;
+use rayon::prelude::*;
+use vec_rand::splitmix64;
+
+/// # MinHash of the node neighbourhoods
+impl Graph {
+    /// Returns the MinHash signature of the neighbourhood of each node.
+    ///
+    /// # Arguments
+    /// * `number_of_hashes`: usize - Number of hash functions, that is the length of each signature.
+    /// * `random_state`: Option<u64> - The random state to use to generate the hash functions. By default, 42.
+    ///
+    /// # Implementative details
+    /// The i-th value of the signature of a node is the minimum of the i-th
+    /// hash function over its unique neighbours, so that the probability of
+    /// two signatures matching on any given position is equal to the Jaccard
+    /// index of the two neighbourhoods. The nodes without neighbours have a
+    /// signature composed only of `u64::MAX`.
+    ///
+    /// # References
+    /// [On the resemblance and containment of documents](https://doi.org/10.1109/SEQUEN.1997.666900),
+    /// by Andrei Z. Broder.
+    ///
+    /// # Example
+    ///```rust
+    /// # let graph = graph::test_utilities::load_ppi(true, true, true, true, false, false);
+    /// let signatures = graph.get_node_minhash_signatures(64, None).unwrap();
+    /// assert_eq!(signatures.len(), graph.get_number_of_nodes() as usize);
+    /// assert!(signatures.iter().all(|signature| signature.len() == 64));
+    /// ```
+    ///
+    /// # Raises
+    /// * If the number of hashes is zero.
+    pub fn get_node_minhash_signatures(
+        &self,
+        number_of_hashes: usize,
+        random_state: Option<u64>,
+    ) -> Result<Vec<Vec<u64>>> {
+        if number_of_hashes == 0 {
+            return Err("The number of hashes must be strictly positive.".to_string());
+        }
+        let random_state = splitmix64(random_state.unwrap_or(42));
+        let hash_seeds = (0..number_of_hashes as u64)
+            .map(|i| splitmix64(random_state.wrapping_add(i)))
+            .collect::<Vec<u64>>();
+        Ok(self
+            .par_iter_node_ids()
+            .map(|node_id| {
+                let mut signature = vec![u64::MAX; number_of_hashes];
+                unsafe {
+                    self.iter_unchecked_unique_neighbour_node_ids_from_source_node_id(node_id)
+                }
+                .for_each(|neighbour_node_id| {
+                    signature.iter_mut().zip(hash_seeds.iter()).for_each(
+                        |(minimum, &hash_seed)| {
+                            *minimum =
+                                (*minimum).min(splitmix64(hash_seed ^ neighbour_node_id as u64));
+                        },
+                    );
+                });
+                signature
+            })
+            .collect())
+    }
+
+    /// Returns the pairs of nodes whose neighbourhoods are candidate near-duplicates.
+    ///
+    /// # Arguments
+    /// * `number_of_hashes`: usize - Number of hash functions, that is the length of each signature.
+    /// * `number_of_bands`: usize - Number of bands the signatures are split into. It must divide the number of hashes.
+    /// * `random_state`: Option<u64> - The random state to use to generate the hash functions. By default, 42.
+    ///
+    /// # Implementative details
+    /// The MinHash signatures are split into bands of `r = number_of_hashes / number_of_bands`
+    /// rows, and two nodes are candidates when all the rows of at least one of the bands
+    /// match. A pair of nodes with Jaccard index `s` is therefore returned with probability
+    /// `1 - (1 - s^r)^b`, which sharply rises around the threshold `(1 / b)^(1 / r)`:
+    /// more bands return more candidates with lower similarity, while more rows per band
+    /// return fewer candidates with higher similarity. The candidates may be verified with
+    /// the exact Jaccard index. The nodes without neighbours are never returned.
+    /// The pairs are returned with the smaller node ID first, sorted and without duplicates.
+    ///
+    /// # References
+    /// [Mining of Massive Datasets, Chapter 3](http://www.mmds.org/),
+    /// by Jure Leskovec, Anand Rajaraman and Jeffrey D. Ullman.
+    ///
+    /// # Raises
+    /// * If the number of hashes or the number of bands is zero.
+    /// * If the number of bands does not divide the number of hashes.
+    pub fn get_candidate_duplicate_node_pairs(
+        &self,
+        number_of_hashes: usize,
+        number_of_bands: usize,
+        random_state: Option<u64>,
+    ) -> Result<Vec<(NodeT, NodeT)>> {
+        if number_of_bands == 0 || number_of_hashes % number_of_bands != 0 {
+            return Err(format!(
+                concat!(
+                    "The number of bands must be a strictly positive divisor of ",
+                    "the number of hashes, but {} bands and {} hashes were provided."
+                ),
+                number_of_bands, number_of_hashes
+            ));
+        }
+        let signatures = self.get_node_minhash_signatures(number_of_hashes, random_state)?;
+        let rows_per_band = number_of_hashes / number_of_bands;
+        let mut candidate_pairs = (0..number_of_bands)
+            .into_par_iter()
+            .flat_map(|band| {
+                let band_range = band * rows_per_band..(band + 1) * rows_per_band;
+                let mut buckets = signatures
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, signature)| signature[0] != u64::MAX)
+                    .map(|(node_id, signature)| {
+                        (
+                            signature[band_range.clone()]
+                                .iter()
+                                .fold(band as u64, |hash, &value| splitmix64(hash ^ value)),
+                            node_id as NodeT,
+                        )
+                    })
+                    .collect::<Vec<(u64, NodeT)>>();
+                buckets.sort_unstable();
+                let mut band_candidate_pairs = Vec::new();
+                let mut bucket_start = 0;
+                while bucket_start < buckets.len() {
+                    let bucket_hash = buckets[bucket_start].0;
+                    let bucket_end = bucket_start
+                        + buckets[bucket_start..].partition_point(|&(hash, _)| hash == bucket_hash);
+                    (bucket_start..bucket_end).for_each(|i| {
+                        (i + 1..bucket_end).for_each(|j| {
+                            band_candidate_pairs.push((buckets[i].1, buckets[j].1));
+                        });
+                    });
+                    bucket_start = bucket_end;
+                }
+                band_candidate_pairs
+            })
+            .collect::<Vec<(NodeT, NodeT)>>();
+        candidate_pairs.par_sort_unstable();
+        candidate_pairs.dedup();
+        Ok(candidate_pairs)
+    }
+}
@@ -0,0 +1,86 @@
+use super::*;
+use rayon::prelude::*;
+
+/// # Node features
+/// The node features are an optional matrix associated to the nodes, which
+/// is carried along when the graph is filtered or remapped.
+impl Graph {
+    /// Returns whether the graph has node features.
+    pub fn has_node_features(&self) -> bool {
+        self.node_features.is_some()
+    }
+
+    /// Returns the node features of the graph, if any.
+    pub fn get_node_features(&self) -> Option<Vec<Vec<f64>>> {
+        self.node_features.as_ref().clone()
+    }
+
+    /// Set the node features of the graph.
+    ///
+    /// This happens INPLACE, that is edits the current graph instance.
+    ///
+    /// # Arguments
+    /// * `features`: Vec<Vec<f64>> - The features of each node.
+    ///
+    /// # Raises
+    /// * If the graph does not have nodes.
+    /// * If the provided features do not have a row for each node.
+    /// * If the provided features do not have the same dimensionality for all the nodes.
+    pub fn attach_inplace_node_features(&mut self, features: Vec<Vec<f64>>) -> Result<&Graph> {
+        self.must_have_nodes()?;
+        validate_features(&features, self.get_number_of_nodes() as usize)?;
+        self.node_features = Arc::new(Some(features));
+        Ok(self)
+    }
+
+    /// Returns graph with the given node features.
+    ///
+    /// # Arguments
+    /// * `features`: Vec<Vec<f64>> - The features of each node.
+    ///
+    /// # Implementative details
+    /// When the graph is filtered, the features of the remaining nodes are kept,
+    /// while when the graph is remapped the features follow the node names.
+    /// When multiple nodes are remapped to the same node, their features are averaged.
+    ///
+    /// # Raises
+    /// * If the graph does not have nodes.
+    /// * If the provided features do not have a row for each node.
+    /// * If the provided features do not have the same dimensionality for all the nodes.
+    pub fn attach_node_features(&self, features: Vec<Vec<f64>>) -> Result<Graph> {
+        let mut graph = self.clone();
+        graph.attach_inplace_node_features(features)?;
+        Ok(graph)
+    }
+
+    /// Returns the given graph with the node weights and features of the current graph, matched by node name.
+    ///
+    /// # Arguments
+    /// * `graph`: Graph - The graph whose nodes are a subset of the nodes of the current graph.
+    pub(crate) fn with_node_metadata_from_node_names(&self, mut graph: Graph) -> Graph {
+        if !self.has_node_weights() && !self.has_node_features() {
+            return graph;
+        }
+        let node_ids = graph
+            .par_iter_node_names()
+            .map(|node_name| unsafe { self.get_unchecked_node_id_from_node_name(&node_name) })
+            .collect::<Vec<NodeT>>();
+        if let Some(node_weights) = self.node_weights.as_ref() {
+            graph.node_weights = Arc::new(Some(
+                node_ids
+                    .par_iter()
+                    .map(|&node_id| node_weights[node_id as usize])
+                    .collect(),
+            ));
+        }
+        if let Some(node_features) = self.node_features.as_ref() {
+            graph.node_features = Arc::new(Some(
+                node_ids
+                    .par_iter()
+                    .map(|&node_id| node_features[node_id as usize].clone())
+                    .collect(),
+            ));
+        }
+        graph
+    }
+}
@@ -318,6 +318,24 @@ impl NodeFileReader {
         Ok(self)
     }
 
+    /// Set the compression of the node list.
+    ///
+    /// # Arguments
+    ///
+    /// * compression: Option<String> - The compression of the node list, either `gzip` or `none`. By default, node lists with the `.gz` extension are read as gzip compressed.
+    ///
+    /// Gzip compressed node lists are decompressed as a stream, whose lines are processed in parallel when the parallel reader is requested.
+    ///
+    pub fn set_compression(mut self, compression: Option<String>) -> Result<NodeFileReader> {
+        if compression.is_some() {
+            self.must_have_reader()?;
+        }
+        self.reader = self.reader.map_or(Ok::<_, String>(None), |reader| {
+            Ok(Some(reader.set_compression(compression)?))
+        })?;
+        Ok(self)
+    }
+
     /// Set whether the CSV is expected to be well written.
     ///
     /// # Arguments
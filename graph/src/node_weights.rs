@@ -0,0 +1,157 @@
+use super::*;
+use rayon::prelude::*;
+use std::convert::TryFrom;
+
+/// The centralities that can be stored as node weights.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CentralityType {
+    Degree,
+    WeightedDegree,
+    Closeness,
+    Harmonic,
+    Betweenness,
+    Eigenvector,
+    WeightedEigenvector,
+    WeightedPageRank,
+}
+
+impl TryFrom<&str> for CentralityType {
+    type Error = String;
+
+    fn try_from(value: &str) -> std::result::Result<Self, Self::Error> {
+        match value {
+            "degree" => Ok(CentralityType::Degree),
+            "weighted_degree" => Ok(CentralityType::WeightedDegree),
+            "closeness" => Ok(CentralityType::Closeness),
+            "harmonic" => Ok(CentralityType::Harmonic),
+            "betweenness" => Ok(CentralityType::Betweenness),
+            "eigenvector" => Ok(CentralityType::Eigenvector),
+            "weighted_eigenvector" => Ok(CentralityType::WeightedEigenvector),
+            "weighted_pagerank" => Ok(CentralityType::WeightedPageRank),
+            _ => Err(format!(
+                concat!(
+                    "Unknown centrality {}. The supported centralities are degree, ",
+                    "weighted_degree, closeness, harmonic, betweenness, eigenvector, ",
+                    "weighted_eigenvector and weighted_pagerank."
+                ),
+                value
+            )),
+        }
+    }
+}
+
+/// # Node weights
+/// The node weights are an optional scalar value associated to each node,
+/// which is carried along when the graph is filtered or remapped.
+impl Graph {
+    /// Returns whether the graph has node weights.
+    pub fn has_node_weights(&self) -> bool {
+        self.node_weights.is_some()
+    }
+
+    /// Returns the node weights of the graph, if any.
+    pub fn get_node_weights(&self) -> Option<Vec<f64>> {
+        self.node_weights.as_ref().clone()
+    }
+
+    /// Set the node weights of the graph.
+    ///
+    /// This happens INPLACE, that is edits the current graph instance.
+    ///
+    /// # Arguments
+    /// * `node_weights`: Vec<f64> - The weight of each node.
+    ///
+    /// # Raises
+    /// * If the provided node weights do not have a value for each node.
+    /// * If any of the provided node weights is not finite.
+    pub fn set_inplace_node_weights(&mut self, node_weights: Vec<f64>) -> Result<&Graph> {
+        if node_weights.len() != self.get_number_of_nodes() as usize {
+            return Err(format!(
+                concat!(
+                    "The provided node weights have length {}, ",
+                    "while the number of nodes in the current graph is {}."
+                ),
+                node_weights.len(),
+                self.get_number_of_nodes()
+            ));
+        }
+        if let Some(node_id) = node_weights
+            .par_iter()
+            .position_any(|node_weight| !node_weight.is_finite())
+        {
+            return Err(format!(
+                "The provided node weight of the node {} is not finite: {}.",
+                node_id, node_weights[node_id]
+            ));
+        }
+        self.node_weights = Arc::new(Some(node_weights));
+        Ok(self)
+    }
+
+    /// Returns graph with the given node weights.
+    ///
+    /// # Arguments
+    /// * `node_weights`: Vec<f64> - The weight of each node.
+    ///
+    /// # Raises
+    /// * If the provided node weights do not have a value for each node.
+    /// * If any of the provided node weights is not finite.
+    pub fn set_node_weights(&self, node_weights: Vec<f64>) -> Result<Graph> {
+        let mut graph = self.clone();
+        graph.set_inplace_node_weights(node_weights)?;
+        Ok(graph)
+    }
+
+    /// Set the node weights of the graph to the given centrality.
+    ///
+    /// This happens INPLACE, that is edits the current graph instance.
+    ///
+    /// # Arguments
+    /// * `centrality`: CentralityType - The centrality to compute and store.
+    ///
+    /// # Implementative details
+    /// The centralities are computed with their default parameters and
+    /// without showing loading bars.
+    ///
+    /// # Raises
+    /// * If the requested centrality cannot be computed on the current graph.
+    pub fn set_inplace_node_weights_from_centrality(
+        &mut self,
+        centrality: CentralityType,
+    ) -> Result<&Graph> {
+        let to_f64 = |centralities: Vec<f32>| {
+            centralities
+                .into_par_iter()
+                .map(|centrality| centrality as f64)
+                .collect::<Vec<f64>>()
+        };
+        let node_weights = match centrality {
+            CentralityType::Degree => to_f64(self.get_degree_centrality()?),
+            CentralityType::WeightedDegree => to_f64(self.get_weighted_degree_centrality()?),
+            CentralityType::Closeness => self.get_closeness_centrality(None, Some(false))?,
+            CentralityType::Harmonic => to_f64(self.get_harmonic_centrality()),
+            CentralityType::Betweenness => {
+                to_f64(self.get_betweenness_centrality(None, None, Some(false))?)
+            }
+            CentralityType::Eigenvector => to_f64(self.get_eigenvector_centrality(None, None)?),
+            CentralityType::WeightedEigenvector => {
+                to_f64(self.get_weighted_eigenvector_centrality(None, None)?)
+            }
+            CentralityType::WeightedPageRank => self.get_weighted_pagerank(None, None, None)?,
+        };
+        self.set_inplace_node_weights(node_weights)
+    }
+
+    /// Returns graph with the node weights set to the given centrality.
+    ///
+    /// # Arguments
+    /// * `centrality`: CentralityType - The centrality to compute and store.
+    ///
+    /// # Raises
+    /// * If the requested centrality cannot be computed on the current graph.
+    pub fn set_node_weights_from_centrality(&self, centrality: CentralityType) -> Result<Graph> {
+        let mut graph = self.clone();
+        graph.set_inplace_node_weights_from_centrality(centrality)?;
+        Ok(graph)
+    }
+}
@@ -1,5 +1,6 @@
 use super::*;
 use itertools::Itertools;
+use rayon::prelude::*;
 use std::collections::HashSet;
 use vec_rand::sorted_unique_sub_sampling;
 use vec_rand::{sample_uniform, splitmix64};
@@ -262,4 +263,126 @@ impl Graph {
             ))
         }
     }
+
+    /// Return the probability of sampling each node as a negative in DeepWalk-like negative sampling.
+    ///
+    /// # Arguments
+    /// * `exponent`: Option<f64> - The exponent to raise the node degrees to. By default, 0.75.
+    ///
+    /// # Implementative details
+    /// The probability of each node is its degree raised to the given exponent,
+    /// normalized so that the probabilities sum to one. In directed graphs the
+    /// outbound node degree is used. With an exponent equal to one, the
+    /// distribution is the same followed by `get_random_outbounds_scale_free_node`,
+    /// which is the one used by the embedding models when sampling with the scale
+    /// free distribution. Smaller exponents flatten the distribution towards the
+    /// uniform one, which is obtained with a zero exponent.
+    ///
+    /// The returned vector can be used to sample nodes with `sample_nodes_with_weights`.
+    ///
+    /// # References
+    /// [Distributed Representations of Words and Phrases and their Compositionality](https://arxiv.org/abs/1310.4546),
+    /// by Mikolov et al.
+    ///
+    /// # Example
+    ///```rust
+    /// # let graph = graph::test_utilities::load_ppi(true, true, true, true, false, false);
+    /// let distribution = graph.get_negative_sampling_distribution(None).unwrap();
+    /// assert!((distribution.iter().sum::<f64>() - 1.0).abs() < 1e-6);
+    /// ```
+    ///
+    /// # Raises
+    /// * If the given exponent is negative or not finite.
+    /// * If the graph does not have edges and the exponent is not zero.
+    pub fn get_negative_sampling_distribution(&self, exponent: Option<f64>) -> Result<Vec<f64>> {
+        let exponent = exponent.unwrap_or(0.75);
+        if !exponent.is_finite() || exponent < 0.0 {
+            return Err(format!(
+                "The provided exponent {} must be a non-negative real number.",
+                exponent
+            ));
+        }
+        self.must_have_nodes()?;
+        let weights = self
+            .par_iter_node_degrees()
+            .map(|degree| (degree as f64).powf(exponent))
+            .collect::<Vec<f64>>();
+        let total_weight = weights.par_iter().sum::<f64>();
+        if total_weight == 0.0 {
+            return Err(concat!(
+                "The negative sampling distribution is not defined ",
+                "as all the nodes in the graph have zero degree."
+            )
+            .to_string());
+        }
+        Ok(weights
+            .into_par_iter()
+            .map(|weight| weight / total_weight)
+            .collect())
+    }
+
+    /// Return nodes sampled with replacement proportionally to the given weights.
+    ///
+    /// # Arguments
+    /// * `weights`: &[f64] - The non-negative weight of each node. It does not need to be normalized.
+    /// * `number_of_samples`: usize - The number of nodes to sample.
+    /// * `random_state`: Option<u64> - The random state to reproduce the sampling. By default, 42.
+    ///
+    /// # Example
+    ///```rust
+    /// # let graph = graph::test_utilities::load_ppi(true, true, true, true, false, false);
+    /// let distribution = graph.get_negative_sampling_distribution(None).unwrap();
+    /// let negatives = graph.sample_nodes_with_weights(&distribution, 100, None).unwrap();
+    /// assert_eq!(negatives.len(), 100);
+    /// ```
+    ///
+    /// # Raises
+    /// * If the given weights do not have a value for each node.
+    /// * If any of the given weights is negative or not finite.
+    /// * If all the given weights are zero.
+    pub fn sample_nodes_with_weights(
+        &self,
+        weights: &[f64],
+        number_of_samples: usize,
+        random_state: Option<u64>,
+    ) -> Result<Vec<NodeT>> {
+        if weights.len() != self.get_number_of_nodes() as usize {
+            return Err(format!(
+                "The provided weights have size {}, but the graph has {} nodes.",
+                weights.len(),
+                self.get_number_of_nodes()
+            ));
+        }
+        if let Some(weight) = weights
+            .iter()
+            .find(|weight| !weight.is_finite() || **weight < 0.0)
+        {
+            return Err(format!(
+                "The provided weights contain the value {}, which is not a non-negative real number.",
+                weight
+            ));
+        }
+        let mut cumulative_weights = weights.to_vec();
+        for i in 1..cumulative_weights.len() {
+            cumulative_weights[i] += cumulative_weights[i - 1];
+        }
+        let total_weight = cumulative_weights.last().copied().unwrap_or(0.0);
+        if total_weight == 0.0 {
+            return Err("The provided weights are all zero.".to_string());
+        }
+        let random_state = splitmix64(random_state.unwrap_or(42));
+        Ok((0..number_of_samples)
+            .into_par_iter()
+            .map(|i| {
+                let threshold = splitmix64(random_state.wrapping_add(i as u64)) as f64
+                    / u64::MAX as f64
+                    * total_weight;
+                // We search the first node whose cumulative weight exceeds the
+                // threshold, so that nodes with zero weight are skipped.
+                cumulative_weights
+                    .partition_point(|&cumulative_weight| cumulative_weight <= threshold)
+                    .min(cumulative_weights.len() - 1) as NodeT
+            })
+            .collect())
+    }
 }
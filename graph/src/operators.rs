@@ -3,8 +3,72 @@ use rayon::prelude::*;
 
 use super::*;
 use itertools::Itertools;
+use std::convert::TryFrom;
 use std::ops;
 
+/// Strategy used to merge the weights of the edges present in both graphs of an union.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WeightMergeStrategy {
+    /// The sum of the two weights.
+    Sum,
+    /// The maximum of the two weights.
+    Max,
+    /// The minimum of the two weights.
+    Min,
+    /// The mean of the two weights.
+    Mean,
+    /// The weight of the left hand side graph, which is the behaviour of the `|` operator.
+    KeepLeft,
+    /// The weight of the right hand side graph.
+    KeepRight,
+}
+
+impl Default for WeightMergeStrategy {
+    fn default() -> Self {
+        WeightMergeStrategy::KeepLeft
+    }
+}
+
+impl TryFrom<&str> for WeightMergeStrategy {
+    type Error = String;
+
+    fn try_from(value: &str) -> std::result::Result<Self, Self::Error> {
+        match value {
+            "sum" => Ok(WeightMergeStrategy::Sum),
+            "max" => Ok(WeightMergeStrategy::Max),
+            "min" => Ok(WeightMergeStrategy::Min),
+            "mean" => Ok(WeightMergeStrategy::Mean),
+            "keep_left" => Ok(WeightMergeStrategy::KeepLeft),
+            "keep_right" => Ok(WeightMergeStrategy::KeepRight),
+            _ => Err(format!(
+                concat!(
+                    "Unknown weight merge strategy {}. The supported strategies are ",
+                    "`sum`, `max`, `min`, `mean`, `keep_left` and `keep_right`."
+                ),
+                value
+            )),
+        }
+    }
+}
+
+impl WeightMergeStrategy {
+    /// Returns the weight obtained by merging the two given weights.
+    ///
+    /// # Arguments
+    /// * `left`: WeightT - The weight of the edge in the left hand side graph.
+    /// * `right`: WeightT - The weight of the edge in the right hand side graph.
+    pub fn merge(&self, left: WeightT, right: WeightT) -> WeightT {
+        match self {
+            WeightMergeStrategy::Sum => left + right,
+            WeightMergeStrategy::Max => left.max(right),
+            WeightMergeStrategy::Min => left.min(right),
+            WeightMergeStrategy::Mean => (left + right) / 2.0,
+            WeightMergeStrategy::KeepLeft => left,
+            WeightMergeStrategy::KeepRight => right,
+        }
+    }
+}
+
 fn build_operator_graph_name(main: &Graph, other: &Graph, operator: String) -> String {
     format!("({} {} {})", main.name, operator, other.name)
 }
@@ -21,12 +85,14 @@ fn build_operator_graph_name(main: &Graph, other: &Graph, operator: String) -> S
 /// * `operator`: String - The operator used.
 /// * `graphs`: Vec<(&Graph, Option<&Graph>, Option<&Graph>)> - Graph list for the operation.
 /// * `may_have_singletons`: bool - Whether the resulting graph may have singletons.
+/// * `weight_merge_strategy`: Option<WeightMergeStrategy> - The strategy to merge the weights of the edges of the main graph that are also present in the other graph. By default, the weights of the main graph are kept.
 fn generic_string_operator(
     main: &Graph,
     other: &Graph,
     operator: String,
     graphs: Vec<(&Graph, Option<&Graph>, Option<&Graph>)>,
     may_have_singletons: bool,
+    weight_merge_strategy: Option<WeightMergeStrategy>,
 ) -> Result<Graph> {
     // Chaining node types of the two graphs so to define the set of shared node types.
     let mut combined_node_type_names: Vec<String> = main
@@ -106,17 +172,29 @@ fn generic_string_operator(
                         }
                         true
                     })
-                    .map(|(_, _, src_name, _, dst_name, _, edge_type_name, weight)| {
-                        Ok((
-                            0,
-                            (
-                                src_name,
-                                dst_name,
-                                edge_type_name,
-                                weight.unwrap_or(WeightT::NAN),
-                            ),
-                        ))
-                    })
+                    .map(
+                        move |(_, _, src_name, _, dst_name, _, edge_type_name, weight)| {
+                            let weight = match (weight, weight_merge_strategy) {
+                                // The weights of the edges of the main graph are merged
+                                // with the ones of the same edges in the other graph.
+                                (Some(weight), Some(weight_merge_strategy))
+                                    if std::ptr::eq(one, main) =>
+                                {
+                                    other
+                                        .get_edge_weight_from_node_names_and_edge_type_name(
+                                            &src_name,
+                                            &dst_name,
+                                            edge_type_name.as_deref(),
+                                        )
+                                        .map_or(weight, |other_weight| {
+                                            weight_merge_strategy.merge(weight, other_weight)
+                                        })
+                                }
+                                _ => weight.unwrap_or(WeightT::NAN),
+                            };
+                            Ok((0, (src_name, dst_name, edge_type_name, weight)))
+                        },
+                    )
             },
         ));
 
@@ -225,12 +303,14 @@ fn generic_string_operator(
 /// * `operator`: String - The operator used.
 /// * `graphs`: Vec<(&Graph, Option<&Graph>, Option<&Graph>)> - Graph list for the operation.
 /// * `may_have_singletons`: bool - Whether the resulting graph may have singletons.
+/// * `weight_merge_strategy`: Option<WeightMergeStrategy> - The strategy to merge the weights of the edges of the main graph that are also present in the other graph. By default, the weights of the main graph are kept.
 fn generic_integer_operator(
     main: &Graph,
     other: &Graph,
     operator: String,
     graphs: Vec<(&Graph, Option<&Graph>, Option<&Graph>)>,
     may_have_singletons: bool,
+    weight_merge_strategy: Option<WeightMergeStrategy>,
 ) -> Graph {
     // one: left hand side of the operator
     // deny_graph: right hand edges "deny list"
@@ -257,8 +337,24 @@ fn generic_integer_operator(
                         }
                         true
                     })
-                    .map(|(_, src, dst, edge_type, weight)| {
-                        (0, (src, dst, edge_type, weight.unwrap_or(WeightT::NAN)))
+                    .map(move |(_, src, dst, edge_type, weight)| {
+                        let weight = match (weight, weight_merge_strategy) {
+                            // The weights of the edges of the main graph are merged
+                            // with the ones of the same edges in the other graph.
+                            (Some(weight), Some(weight_merge_strategy))
+                                if std::ptr::eq(one, main) =>
+                            {
+                                other
+                                    .get_edge_weight_from_node_ids_and_edge_type_id(
+                                        src, dst, edge_type,
+                                    )
+                                    .map_or(weight, |other_weight| {
+                                        weight_merge_strategy.merge(weight, other_weight)
+                                    })
+                            }
+                            _ => weight.unwrap_or(WeightT::NAN),
+                        };
+                        (0, (src, dst, edge_type, weight))
                     })
             });
 
@@ -422,12 +518,14 @@ impl Graph {
     /// * `operator`: String - The operator used.
     /// * `graphs`: Vec<(&Graph, Option<&Graph>, Option<&Graph>)> - Graph list for the operation.
     /// * `may_have_singletons`: bool - Whether the resulting graph may have singletons.
+    /// * `weight_merge_strategy`: Option<WeightMergeStrategy> - The strategy to merge the weights of the edges of the current graph that are also present in the other graph. By default, the weights of the current graph are kept.
     pub(crate) fn generic_operator(
         &self,
         other: &Graph,
         operator: String,
         graphs: Vec<(&Graph, Option<&Graph>, Option<&Graph>)>,
         may_have_singletons: bool,
+        weight_merge_strategy: Option<WeightMergeStrategy>,
     ) -> Result<Graph> {
         match self.is_compatible(other)? {
             true => Ok(generic_integer_operator(
@@ -436,10 +534,66 @@ impl Graph {
                 operator,
                 graphs,
                 may_have_singletons,
+                weight_merge_strategy,
             )),
-            false => generic_string_operator(self, other, operator, graphs, may_have_singletons),
+            false => generic_string_operator(
+                self,
+                other,
+                operator,
+                graphs,
+                may_have_singletons,
+                weight_merge_strategy,
+            ),
         }
     }
+
+    /// Return graph composed of the two graphs, merging the weights of the shared edges with the given strategy.
+    ///
+    /// # Arguments
+    /// * `other`: &Graph - The other graph.
+    /// * `weight_merge_strategy`: Option<WeightMergeStrategy> - The strategy to merge the weights of the edges present in both graphs. By default, `KeepLeft`, as the `|` operator.
+    ///
+    /// # Implementative details
+    /// The resulting graph has the same nodes, node types, edge types and edges
+    /// of the graph obtained with the `|` operator, which keeps the weights of the
+    /// current graph for the edges present in both graphs. Two edges are shared
+    /// when they have the same source and destination node names and edge type
+    /// name, and the weights of the edges present in only one of the two graphs
+    /// are kept as they are. In multigraphs, the weight of the first of the
+    /// parallel edges of the other graph is used.
+    ///
+    /// The weights are merged while the edges of the two graphs are merged, so
+    /// the resulting graph is built only once, and when the two graphs are
+    /// compatible the shared edges are found by their node and edge type IDs.
+    ///
+    /// # Example
+    ///```rust
+    /// # use graph::WeightMergeStrategy;
+    /// # let graph = graph::test_utilities::load_ppi(true, true, true, true, false, false);
+    /// let union = graph.union(&graph, Some(WeightMergeStrategy::Sum)).unwrap();
+    /// assert_eq!(union.get_number_of_directed_edges(), graph.get_number_of_directed_edges());
+    /// ```
+    ///
+    /// # Raises
+    /// * If a graph is directed and the other is undirected.
+    /// * If one of the two graphs has edge weights and the other does not.
+    /// * If one of the two graphs has edge types and the other does not.
+    pub fn union(
+        &self,
+        other: &Graph,
+        weight_merge_strategy: Option<WeightMergeStrategy>,
+    ) -> Result<Graph> {
+        self.generic_operator(
+            other,
+            "|".to_owned(),
+            vec![(self, None, None), (other, Some(self), None)],
+            self.has_singleton_nodes() || other.has_singleton_nodes(),
+            match weight_merge_strategy.unwrap_or_default() {
+                WeightMergeStrategy::KeepLeft => None,
+                weight_merge_strategy => Some(weight_merge_strategy),
+            },
+        )
+    }
 }
 
 impl<'a, 'b> ops::BitOr<&'b Graph> for &'a Graph {
@@ -447,6 +601,8 @@ impl<'a, 'b> ops::BitOr<&'b Graph> for &'a Graph {
     /// Return graph composed of the two graphs.
     ///
     /// The two graphs must have the same nodes, node types and edge types.
+    /// The edges present in both graphs keep the weight of the left hand side
+    /// graph, see `union` to merge their weights with another strategy.
     ///
     /// # Arguments
     ///
@@ -463,6 +619,7 @@ impl<'a, 'b> ops::BitOr<&'b Graph> for &'a Graph {
             // have no singleton nodes, the resulting graph cannot have singleton
             // nodes.
             self.has_singleton_nodes() || other.has_singleton_nodes(),
+            None,
         )
     }
 }
@@ -486,6 +643,7 @@ impl<'a, 'b> ops::BitXor<&'b Graph> for &'a Graph {
             // more singleton nodes than either of the two original graph as edges are
             // being removed as part of this operation.
             true,
+            None,
         )
     }
 }
@@ -501,7 +659,13 @@ impl<'a, 'b> ops::Sub<&'b Graph> for &'a Graph {
     /// * `other`: &Graph - Graph to be subtracted.
     ///
     fn sub(self, other: &'b Graph) -> Result<Graph> {
-        self.generic_operator(other, "-".to_owned(), vec![(self, Some(other), None)], true)
+        self.generic_operator(
+            other,
+            "-".to_owned(),
+            vec![(self, Some(other), None)],
+            true,
+            None,
+        )
     }
 }
 
@@ -516,6 +680,12 @@ impl<'a, 'b> ops::BitAnd<&'b Graph> for &'a Graph {
     /// * `other`: &Graph - Graph to be subtracted.
     ///
     fn bitand(self, other: &'b Graph) -> Result<Graph> {
-        self.generic_operator(other, "&".to_owned(), vec![(self, None, Some(other))], true)
+        self.generic_operator(
+            other,
+            "&".to_owned(),
+            vec![(self, None, Some(other))],
+            true,
+            None,
+        )
     }
 }
@@ -2,7 +2,7 @@ use super::*;
 use bitvec::prelude::*;
 use indicatif::ParallelProgressIterator;
 use rayon::prelude::*;
-use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::atomic::{AtomicU32, AtomicU64, Ordering};
 
 impl Graph {
     /// Returns number of triangles in the graph.
@@ -157,6 +157,99 @@ impl Graph {
             .sum::<EdgeT>() / 3)
     }
 
+    /// Returns the girth of the graph, that is the length of its shortest cycle.
+    ///
+    /// # Implementative details
+    /// A breadth-first search is executed in parallel from each node, stopping
+    /// as soon as no cycle shorter than the shortest one found so far can be
+    /// discovered from the current depth. In undirected graphs, a non-tree edge
+    /// between two nodes at distances `i` and `j` from the root closes a cycle
+    /// of length at most `i + j + 1`, and the minimum over all roots is exactly
+    /// the girth. In directed graphs the girth is the length of the shortest
+    /// directed cycle, that is the minimum over all roots of the distance of
+    /// the root from itself.
+    ///
+    /// Self-loops are considered cycles of length one, so graphs with self-loops
+    /// have girth one. In directed graphs, a pair of opposite edges is a cycle
+    /// of length two, while in undirected graphs the parallel edges of multigraphs
+    /// are collapsed and are not considered cycles.
+    ///
+    /// # Example
+    ///```rust
+    /// # let graph = graph::test_utilities::load_ppi(true, true, true, true, false, false);
+    /// println!("The girth of the graph is {:?}.", graph.get_girth());
+    /// ```
+    ///
+    /// # Returns
+    /// The length of the shortest cycle, or None if the graph is acyclic.
+    pub fn get_girth(&self) -> Option<NodeT> {
+        if self.has_selfloops() {
+            return Some(1);
+        }
+        let number_of_nodes = self.get_number_of_nodes() as usize;
+        let girth = AtomicU32::new(NODE_NOT_PRESENT);
+        let is_directed = self.is_directed();
+
+        self.par_iter_node_ids().for_each_init(
+            || {
+                (
+                    vec![NODE_NOT_PRESENT; number_of_nodes],
+                    vec![NODE_NOT_PRESENT; number_of_nodes],
+                    Vec::new(),
+                )
+            },
+            |(distances, parents, visited), root| {
+                // The visited nodes are stored in the order they are reached,
+                // so the same vector is also used as the queue of the search.
+                distances[root as usize] = 0;
+                visited.push(root);
+                let mut index = 0;
+                while index < visited.len() {
+                    let node_id = visited[index];
+                    index += 1;
+                    let distance = distances[node_id as usize];
+                    let current_girth = girth.load(Ordering::Relaxed);
+                    // No shorter cycle may be found from this depth onwards.
+                    if is_directed && distance + 1 >= current_girth
+                        || !is_directed && 2 * distance >= current_girth
+                    {
+                        break;
+                    }
+                    unsafe {
+                        self.iter_unchecked_unique_neighbour_node_ids_from_source_node_id(node_id)
+                    }
+                    .for_each(|neighbour_node_id| {
+                        if is_directed {
+                            if neighbour_node_id == root {
+                                girth.fetch_min(distance + 1, Ordering::Relaxed);
+                                return;
+                            }
+                        } else if neighbour_node_id == parents[node_id as usize] {
+                            return;
+                        }
+                        let neighbour_distance = distances[neighbour_node_id as usize];
+                        if neighbour_distance == NODE_NOT_PRESENT {
+                            distances[neighbour_node_id as usize] = distance + 1;
+                            parents[neighbour_node_id as usize] = node_id;
+                            visited.push(neighbour_node_id);
+                        } else if !is_directed {
+                            girth.fetch_min(distance + neighbour_distance + 1, Ordering::Relaxed);
+                        }
+                    });
+                }
+                visited.drain(..).for_each(|node_id| {
+                    distances[node_id as usize] = NODE_NOT_PRESENT;
+                    parents[node_id as usize] = NODE_NOT_PRESENT;
+                });
+            },
+        );
+
+        match girth.into_inner() {
+            NODE_NOT_PRESENT => None,
+            girth => Some(girth),
+        }
+    }
+
     /// Returns number of squares in the graph.
     ///
     /// # Arguments
@@ -314,6 +407,112 @@ impl Graph {
             }).sum::<EdgeT>() / 2)
     }
 
+    /// Returns the side of each node in the bipartition of the graph.
+    ///
+    /// # Implementative details
+    /// Each connected component is two-coloured with a breadth first search
+    /// started from its node with the smallest node ID, which is placed on the
+    /// first side, that is the side marked as `false`.
+    ///
+    /// # Raises
+    /// * If the graph is directed.
+    /// * If the graph is not bipartite, that is it contains selfloops or odd cycles.
+    pub fn get_bipartite_node_sides(&self) -> Result<Vec<bool>> {
+        self.must_be_undirected()?;
+        let number_of_nodes = self.get_number_of_nodes() as usize;
+        let mut sides = vec![false; number_of_nodes];
+        let mut visited = vec![false; number_of_nodes];
+        let mut frontier = Vec::new();
+        for root_node_id in 0..number_of_nodes {
+            if visited[root_node_id] {
+                continue;
+            }
+            visited[root_node_id] = true;
+            frontier.push(root_node_id as NodeT);
+            while let Some(src) = frontier.pop() {
+                let src_side = sides[src as usize];
+                for dst in unsafe {
+                    self.iter_unchecked_unique_neighbour_node_ids_from_source_node_id(src)
+                } {
+                    if !visited[dst as usize] {
+                        visited[dst as usize] = true;
+                        sides[dst as usize] = !src_side;
+                        frontier.push(dst);
+                    } else if sides[dst as usize] == src_side {
+                        return Err(format!(
+                            concat!(
+                                "The current graph is not bipartite, as the nodes {} and {} ",
+                                "are connected and would need to be on the same side."
+                            ),
+                            src, dst
+                        ));
+                    }
+                }
+            }
+        }
+        Ok(sides)
+    }
+
+    #[cache_property(number_of_butterflies)]
+    /// Returns number of butterflies in the bipartite graph.
+    ///
+    /// A butterfly is a complete bipartite subgraph with two nodes on each
+    /// side, that is a square in a bipartite graph.
+    ///
+    /// # Implementative details
+    /// For each pair of nodes on the same side, the number of butterflies they
+    /// belong to is the number of pairs of their common neighbours. The pairs
+    /// are enumerated, in parallel, from the nodes on the smaller side, by
+    /// counting the wedges towards the nodes with a larger node ID on the same side.
+    /// Multi-edges are counted once. The result is cached.
+    ///
+    /// # Example
+    ///```rust
+    /// # let graph = graph::test_utilities::load_ppi(true, true, true, true, false, false);
+    /// // The PPI graph is not bipartite.
+    /// assert!(graph.get_number_of_butterflies().is_err());
+    /// ```
+    ///
+    /// # Raises
+    /// * If the graph is directed.
+    /// * If the graph is not bipartite.
+    pub fn get_number_of_butterflies(&self) -> Result<EdgeT> {
+        let sides = self.get_bipartite_node_sides()?;
+        let number_of_nodes_on_second_side = sides.par_iter().filter(|&&side| side).count();
+        let smaller_side = 2 * number_of_nodes_on_second_side < sides.len();
+        let sides = sides.as_slice();
+        Ok(self
+            .par_iter_node_ids()
+            .filter(|&node_id| sides[node_id as usize] == smaller_side)
+            .map_init(
+                || (vec![0 as EdgeT; sides.len()], Vec::new()),
+                |(common_neighbours, touched), first| {
+                    unsafe {
+                        self.iter_unchecked_unique_neighbour_node_ids_from_source_node_id(first)
+                    }
+                    .for_each(|middle| unsafe {
+                        self.iter_unchecked_unique_neighbour_node_ids_from_source_node_id(middle)
+                            .filter(|&second| second > first)
+                            .for_each(|second| {
+                                if common_neighbours[second as usize] == 0 {
+                                    touched.push(second);
+                                }
+                                common_neighbours[second as usize] += 1;
+                            });
+                    });
+                    touched
+                        .drain(..)
+                        .map(|second| {
+                            let count = common_neighbours[second as usize];
+                            common_neighbours[second as usize] = 0;
+                            count * (count - 1) / 2
+                        })
+                        .sum::<EdgeT>()
+                },
+            )
+            .sum())
+    }
+
     /// Returns number of squares in the graph.
     ///
     /// # Arguments
@@ -547,6 +746,26 @@ impl Graph {
             .sum()
     }
 
+    /// Returns total number of wedges, i.e. paths of length two, in the graph.
+    ///
+    /// # Implementative details
+    /// The number of wedges is the sum over all nodes of the binomial
+    /// coefficient of the node degree over two, that is half the number of triads.
+    /// In directed graphs the outbound node degree is used, hence the
+    /// counted wedges are the pairs of edges leaving the same central node.
+    /// Parallel edges and selfloops are counted as distinct edges.
+    ///
+    /// # Example
+    ///```rust
+    /// # let graph = graph::test_utilities::load_ppi(true, true, true, true, false, false);
+    /// assert_eq!(2 * graph.get_number_of_wedges(), graph.get_number_of_triads());
+    /// ```
+    pub fn get_number_of_wedges(&self) -> EdgeT {
+        self.par_iter_node_degrees()
+            .map(|degree| (degree as EdgeT) * (degree.saturating_sub(1) as EdgeT) / 2)
+            .sum()
+    }
+
     /// Returns total number of triads in the weighted graph.
     pub fn get_number_of_weighted_triads(&self) -> Result<f64> {
         Ok(self
@@ -734,6 +953,104 @@ impl Graph {
         Ok(unsafe { std::mem::transmute::<Vec<AtomicU64>, Vec<EdgeT>>(node_triangles_number) })
     }
 
+    /// Returns number of triangles each node participates in.
+    ///
+    /// The triangle participation of a node is the number of distinct triangles
+    /// incident to it, that is the number of triangles that would be removed
+    /// from the graph when deleting the node.
+    ///
+    /// # Implementative details
+    /// Each triangle `(first, second, third)` with `first < second < third` is
+    /// enumerated exactly once, by intersecting the sorted neighbours of `first`
+    /// and `second` above `second`, and is accounted to all of its three nodes.
+    /// Self-loops are ignored and multi-edges are counted once, so differently
+    /// from `get_number_of_triangles_per_node`, triangles in multigraphs are
+    /// not multiplied by the number of parallel edges.
+    ///
+    /// # Example
+    ///```rust
+    /// # let graph = graph::test_utilities::load_ppi(true, true, true, true, false, false);
+    /// let triangle_participation = graph.get_node_triangle_participation().unwrap();
+    /// assert_eq!(triangle_participation.len(), graph.get_number_of_nodes() as usize);
+    /// ```
+    ///
+    /// # Raises
+    /// * If the graph is directed.
+    pub fn get_node_triangle_participation(&self) -> Result<Vec<EdgeT>> {
+        self.must_be_undirected()?;
+        let node_triangles_number = unsafe {
+            std::mem::transmute::<Vec<EdgeT>, Vec<AtomicU64>>(vec![
+                0;
+                self.get_number_of_nodes()
+                    as usize
+            ])
+        };
+
+        self.par_iter_node_ids()
+            .flat_map(|first| {
+                let first_order_neighbours = unsafe {
+                    self.edges
+                        .get_unchecked_neighbours_node_ids_from_src_node_id(first)
+                };
+                let index = first_order_neighbours.partition_point(|&second| second <= first);
+                first_order_neighbours[index..]
+                    .par_iter()
+                    .enumerate()
+                    .filter(move |&(i, &second)| {
+                        i == 0 || first_order_neighbours[index + i - 1] != second
+                    })
+                    .map(move |(_, &second)| (first, second, first_order_neighbours))
+            })
+            .for_each(|(first, second, first_order_neighbours)| {
+                let second_order_neighbours = unsafe {
+                    self.edges
+                        .get_unchecked_neighbours_node_ids_from_src_node_id(second)
+                };
+                // We only consider the third nodes greater than the second one,
+                // so that each triangle is enumerated exactly once.
+                let first_order_neighbours = &first_order_neighbours
+                    [first_order_neighbours.partition_point(|&third| third <= second)..];
+                let second_order_neighbours = &second_order_neighbours
+                    [second_order_neighbours.partition_point(|&third| third <= second)..];
+
+                let mut first_neighbour_index = 0;
+                let mut second_neighbour_index = 0;
+                let mut triangles: EdgeT = 0;
+                let mut last_third: Option<NodeT> = None;
+
+                while first_neighbour_index < first_order_neighbours.len()
+                    && second_neighbour_index < second_order_neighbours.len()
+                {
+                    let first_order_neighbour = first_order_neighbours[first_neighbour_index];
+                    let second_order_neighbour = second_order_neighbours[second_neighbour_index];
+                    if first_order_neighbour < second_order_neighbour {
+                        first_neighbour_index += 1;
+                        continue;
+                    }
+                    if first_order_neighbour > second_order_neighbour {
+                        second_neighbour_index += 1;
+                        continue;
+                    }
+                    first_neighbour_index += 1;
+                    second_neighbour_index += 1;
+                    // We skip the repeated intersections caused by multi-edges.
+                    if last_third == Some(first_order_neighbour) {
+                        continue;
+                    }
+                    last_third = Some(first_order_neighbour);
+                    triangles += 1;
+                    node_triangles_number[first_order_neighbour as usize]
+                        .fetch_add(1, Ordering::Relaxed);
+                }
+                if triangles > 0 {
+                    node_triangles_number[first as usize].fetch_add(triangles, Ordering::Relaxed);
+                    node_triangles_number[second as usize].fetch_add(triangles, Ordering::Relaxed);
+                }
+            });
+
+        Ok(unsafe { std::mem::transmute::<Vec<AtomicU64>, Vec<EdgeT>>(node_triangles_number) })
+    }
+
     /// Returns iterator over the clustering coefficients for all nodes in the graph.
     ///
     /// # Arguments
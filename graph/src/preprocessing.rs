@@ -796,4 +796,100 @@ impl Graph {
             verbose,
         )
     }
+
+    /// Returns node features smoothed by propagating them over the graph.
+    ///
+    /// # Arguments
+    /// * `features`: Vec<Vec<f64>> - The features to propagate.
+    /// * `iterations`: usize - The number of propagation iterations to execute.
+    /// * `alpha`: f64 - The weight of the propagated features, between zero and one.
+    /// * `normalize`: Option<bool> - Whether to normalize each row of features to sum to one after each iteration. By default, false.
+    ///
+    /// # Implementative details
+    /// At each iteration, the features are updated as `X <- alpha * A_norm X + (1 - alpha) X_0`,
+    /// where `X_0` are the given features and `A_norm` is the symmetric normalized
+    /// adjacency matrix `D^(-1/2) A D^(-1/2)`, that is the identity minus the
+    /// symmetric normalized Laplacian. The degrees are the node degrees of the graph,
+    /// hence multi-edges are counted with their multiplicity, and the edge weights are ignored.
+    /// The features of the nodes without neighbours are only scaled by `1 - alpha`.
+    /// When the rows are normalized, they are divided by the sum of their absolute
+    /// values, and the rows whose values are all zeros are left unchanged.
+    /// The rows are propagated in parallel.
+    ///
+    /// # References
+    /// [Learning with Local and Global Consistency](https://proceedings.neurips.cc/paper/2003/hash/87682805257e619d49b8e0dfdc14affa-Abstract.html),
+    /// by Zhou et al.
+    ///
+    /// # Example
+    ///```rust
+    /// # let graph = graph::test_utilities::load_ppi(false, false, false, false, false, false);
+    /// let features = vec![vec![1.0, 0.0]; graph.get_number_of_nodes() as usize];
+    /// let smoothed = graph.propagate_node_features(features, 3, 0.5, Some(true)).unwrap();
+    /// assert_eq!(smoothed.len(), graph.get_number_of_nodes() as usize);
+    /// ```
+    ///
+    /// # Raises
+    /// * If the graph does not have nodes.
+    /// * If the provided features do not have a row for each node.
+    /// * If the provided features do not have the same dimensionality for all the nodes.
+    /// * If the given alpha is not between zero and one.
+    pub fn propagate_node_features(
+        &self,
+        features: Vec<Vec<f64>>,
+        iterations: usize,
+        alpha: f64,
+        normalize: Option<bool>,
+    ) -> Result<Vec<Vec<f64>>> {
+        self.must_have_nodes()?;
+        validate_features(&features, self.get_number_of_nodes() as usize)?;
+        if !(0.0..=1.0).contains(&alpha) {
+            return Err(format!(
+                "The given alpha {} must be a value between zero and one.",
+                alpha
+            ));
+        }
+        let normalize = normalize.unwrap_or(false);
+        let inverse_sqrt_node_degrees = self
+            .par_iter_node_degrees()
+            .map(|degree| {
+                if degree == 0 {
+                    0.0
+                } else {
+                    1.0 / (degree as f64).sqrt()
+                }
+            })
+            .collect::<Vec<f64>>();
+        let mut propagated_features = features.clone();
+        for _ in 0..iterations {
+            propagated_features = self
+                .par_iter_node_ids()
+                .zip(features.par_iter())
+                .map(|(src, src_features)| {
+                    let mut row = src_features
+                        .iter()
+                        .map(|&feature| (1.0 - alpha) * feature)
+                        .collect::<Vec<f64>>();
+                    unsafe { self.iter_unchecked_neighbour_node_ids_from_source_node_id(src) }
+                        .for_each(|dst| {
+                            let weight = alpha
+                                * inverse_sqrt_node_degrees[src as usize]
+                                * inverse_sqrt_node_degrees[dst as usize];
+                            row.iter_mut()
+                                .zip(propagated_features[dst as usize].iter())
+                                .for_each(|(feature, &dst_feature)| {
+                                    *feature += weight * dst_feature;
+                                });
+                        });
+                    if normalize {
+                        let total = row.iter().map(|feature| feature.abs()).sum::<f64>();
+                        if total > 0.0 {
+                            row.iter_mut().for_each(|feature| *feature /= total);
+                        }
+                    }
+                    row
+                })
+                .collect();
+        }
+        Ok(propagated_features)
+    }
 }
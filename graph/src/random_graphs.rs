@@ -1,6 +1,7 @@
 use num_traits::Zero;
 use rand::prelude::SliceRandom;
 use rand::prelude::SmallRng;
+use rand::Rng;
 use rand::SeedableRng;
 use rayon::iter::IndexedParallelIterator;
 use rayon::iter::IntoParallelIterator;
@@ -1479,4 +1480,232 @@ impl Graph {
             name.to_string(),
         )
     }
+
+    /// Creates new random graph with community structure sampled from a stochastic block model.
+    ///
+    /// # Arguments
+    /// * `block_sizes`: Vec<NodeT> - The number of nodes in each block.
+    /// * `within_probability`: f64 - The probability of an edge between two nodes of the same block.
+    /// * `between_probability`: f64 - The probability of an edge between two nodes of different blocks.
+    /// * `directed`: bool - Whether the graph is to built as directed.
+    /// * `random_state`: u64 - The random state to use to reproduce the sampling.
+    ///
+    /// # Implementative details
+    /// The nodes are assigned to the blocks in order, so that each block is a
+    /// contiguous range of node IDs, and the node type of each node is the index
+    /// of its block, so to be usable as ground truth for community detection.
+    /// Each pair of distinct nodes is connected independently with the probability
+    /// associated to their blocks, hence the graph has no selfloops and may have
+    /// singletons. In directed graphs the two directions of each pair of nodes are
+    /// sampled independently. The sampling is executed in parallel, and it requires
+    /// time quadratic in the number of nodes.
+    ///
+    /// # References
+    /// [Stochastic blockmodels: First steps](https://doi.org/10.1016/0378-8733(83)90021-7),
+    /// by Holland, Laskey and Leinhardt.
+    ///
+    /// # Raises
+    /// * If the given probabilities are not between zero and one.
+    /// * If no block sizes are given, or any of the blocks is empty.
+    /// * If the total number of nodes does not fit in a node ID.
+    pub fn generate_stochastic_block_model(
+        block_sizes: Vec<NodeT>,
+        within_probability: f64,
+        between_probability: f64,
+        directed: bool,
+        random_state: u64,
+    ) -> Result<Graph> {
+        for (probability_name, probability) in [
+            ("within", within_probability),
+            ("between", between_probability),
+        ] {
+            if !(0.0..=1.0).contains(&probability) {
+                return Err(format!(
+                    "The given {} probability {} must be between zero and one.",
+                    probability_name, probability
+                ));
+            }
+        }
+        if block_sizes.is_empty() || block_sizes.iter().any(|block_size| block_size.is_zero()) {
+            return Err(format!(
+                concat!(
+                    "The block sizes must be strictly positive and at least one ",
+                    "block must be provided, but the given block sizes are {:?}."
+                ),
+                block_sizes
+            ));
+        }
+        let number_of_nodes = block_sizes
+            .iter()
+            .map(|&block_size| block_size as u64)
+            .sum::<u64>();
+        if number_of_nodes > NodeT::MAX as u64 {
+            return Err(format!(
+                "The total number of nodes {} does not fit in a node ID.",
+                number_of_nodes
+            ));
+        }
+        let number_of_nodes = number_of_nodes as NodeT;
+
+        let node_block_ids = block_sizes
+            .iter()
+            .enumerate()
+            .flat_map(|(block_id, &block_size)| {
+                std::iter::repeat(block_id as NodeTypeT).take(block_size as usize)
+            })
+            .collect::<Vec<NodeTypeT>>();
+        let node_types = NodeTypeVocabulary::from_structs(
+            node_block_ids
+                .iter()
+                .map(|&block_id| Some(vec![block_id]))
+                .collect(),
+            Vocabulary::from_reverse_map(
+                (0..block_sizes.len())
+                    .map(|block_id| block_id.to_string())
+                    .collect(),
+                "Node types".to_string(),
+            )?,
+        );
+        let nodes = Vocabulary::from_range(0..number_of_nodes, "Nodes".to_string());
+
+        // In undirected graphs only the pairs with the smaller source node are
+        // sampled, and the graph builder adds the opposite direction.
+        let node_block_ids_reference = &node_block_ids;
+        let edges_iterator = (0..number_of_nodes).into_par_iter().flat_map(move |src| {
+            let mut rng =
+                SmallRng::seed_from_u64(splitmix64(random_state.wrapping_add(src as u64)));
+            let first_dst = if directed { 0 } else { src + 1 };
+            (first_dst..number_of_nodes)
+                .filter(|&dst| {
+                    if src == dst {
+                        return false;
+                    }
+                    let probability = if node_block_ids_reference[src as usize]
+                        == node_block_ids_reference[dst as usize]
+                    {
+                        within_probability
+                    } else {
+                        between_probability
+                    };
+                    rng.gen::<f64>() < probability
+                })
+                .map(|dst| (0, (src, dst, None, WeightT::NAN)))
+                .collect::<Vec<_>>()
+        });
+
+        build_graph_from_integers(
+            Some(edges_iterator),
+            Arc::new(nodes),
+            Arc::new(Some(node_types)),
+            None,
+            false,
+            directed,
+            Some(directed),
+            Some(false),
+            Some(false),
+            None,
+            true,
+            false,
+            "StochasticBlockModel".to_string(),
+        )
+    }
+
+    /// Creates new random scale-free graph following the Barabási-Albert preferential attachment model.
+    ///
+    /// # Arguments
+    /// * `nodes_number`: NodeT - Number of nodes of the graph.
+    /// * `edges_per_node`: NodeT - Number of edges each new node attaches to the existing nodes.
+    /// * `random_state`: u64 - The random state to use to reproduce the sampling.
+    /// * `directed`: Option<bool> - Whether the graph is to built as directed. By default false.
+    ///
+    /// # Implementative details
+    /// The graph starts from a clique of `edges_per_node + 1` nodes, so that the
+    /// first node added afterwards can always find enough distinct neighbours.
+    /// Each following node is then connected to `edges_per_node` distinct existing
+    /// nodes, sampled with probability proportional to their current degree.
+    /// In directed graphs the edges go from the newly added node to the sampled
+    /// nodes, while the degree used for the attachment accounts for both directions.
+    ///
+    /// # References
+    /// [Emergence of scaling in random networks](https://doi.org/10.1126/science.286.5439.509),
+    /// by Barabási and Albert.
+    ///
+    /// # Raises
+    /// * If the number of edges per node is zero.
+    /// * If the number of nodes is not greater than the number of edges per node.
+    pub fn generate_barabasi_albert(
+        nodes_number: NodeT,
+        edges_per_node: NodeT,
+        random_state: u64,
+        directed: Option<bool>,
+    ) -> Result<Graph> {
+        let directed = directed.unwrap_or(false);
+        if edges_per_node.is_zero() {
+            return Err("The number of edges per node must be strictly positive.".to_string());
+        }
+        if nodes_number <= edges_per_node {
+            return Err(format!(
+                concat!(
+                    "The number of nodes {} must be greater than the number of ",
+                    "edges per node {}, as the initial clique has {} nodes."
+                ),
+                nodes_number,
+                edges_per_node,
+                edges_per_node as u64 + 1
+            ));
+        }
+        let initial_clique_size = edges_per_node + 1;
+        let number_of_edges = edges_per_node as usize * initial_clique_size as usize / 2
+            + (nodes_number - initial_clique_size) as usize * edges_per_node as usize;
+        let mut rng = SmallRng::seed_from_u64(splitmix64(random_state));
+        let mut edges: Vec<(NodeT, NodeT)> = Vec::with_capacity(number_of_edges);
+        // Every node appears in this vector once for each of its edges, so that
+        // sampling uniformly from it is equivalent to sampling proportionally
+        // to the node degrees.
+        let mut endpoints: Vec<NodeT> = Vec::with_capacity(2 * number_of_edges);
+        for src in 0..initial_clique_size {
+            for dst in (src + 1)..initial_clique_size {
+                edges.push((src, dst));
+                endpoints.push(src);
+                endpoints.push(dst);
+            }
+        }
+        let mut targets: Vec<NodeT> = Vec::with_capacity(edges_per_node as usize);
+        for src in initial_clique_size..nodes_number {
+            targets.clear();
+            while targets.len() < edges_per_node as usize {
+                let dst = endpoints[rng.gen_range(0, endpoints.len())];
+                if !targets.contains(&dst) {
+                    targets.push(dst);
+                }
+            }
+            for &dst in targets.iter() {
+                edges.push((src, dst));
+                endpoints.push(src);
+                endpoints.push(dst);
+            }
+        }
+
+        let nodes = Vocabulary::from_range(0..nodes_number, "Nodes".to_string());
+
+        build_graph_from_integers(
+            Some(
+                edges
+                    .into_par_iter()
+                    .map(|(src, dst)| (0, (src, dst, None, WeightT::NAN))),
+            ),
+            Arc::new(nodes),
+            Arc::new(None),
+            None,
+            false,
+            directed,
+            Some(directed),
+            Some(false),
+            Some(false),
+            None,
+            false,
+            false,
+            "BarabasiAlbert".to_string(),
+        )
+    }
 }
@@ -0,0 +1,231 @@
+use super::*;
+use rayon::prelude::*;
+
+/// Returns the vertical logarithmic bin of each of the provided values.
+///
+/// # Arguments
+/// * `values`: &[f64] - The values to be binned.
+/// * `binning_fraction`: f64 - The fraction of the remaining values assigned to each bin.
+///
+/// # Implementative details
+/// The values are sorted and the smallest `binning_fraction` of them are
+/// assigned to the bin zero, the smallest `binning_fraction` of the remaining
+/// ones to the bin one and so on. Equal values are always assigned to the
+/// same bin, namely the one of the first of them in the sorted order.
+fn get_vertical_logarithmic_bins(values: &[f64], binning_fraction: f64) -> Vec<usize> {
+    let mut sorted_indices = (0..values.len()).collect::<Vec<usize>>();
+    sorted_indices.par_sort_unstable_by(|&a, &b| values[a].total_cmp(&values[b]));
+    let mut bins = vec![0; values.len()];
+    let mut bin = 0;
+    let mut bin_end = 0;
+    for (rank, &index) in sorted_indices.iter().enumerate() {
+        if rank > 0 && values[index] == values[sorted_indices[rank - 1]] {
+            bins[index] = bins[sorted_indices[rank - 1]];
+            continue;
+        }
+        if rank >= bin_end {
+            if rank > 0 {
+                bin += 1;
+            }
+            bin_end =
+                rank + (((values.len() - rank) as f64 * binning_fraction).ceil() as usize).max(1);
+        }
+        bins[index] = bin;
+    }
+    bins
+}
+
+/// # Recursive structural features
+impl Graph {
+    /// Returns recursive structural features of the nodes and their names, meant for structural role discovery.
+    ///
+    /// # Arguments
+    /// * `number_of_iterations`: Option<usize> - The number of recursive aggregations of the neighbour features. By default, 3.
+    /// * `binning_fraction`: Option<f64> - The fraction of the remaining nodes assigned to each logarithmic bin when pruning the features. By default, 0.5.
+    /// * `maximum_number_of_features`: Option<usize> - The maximum number of features to return. By default, there is no limit.
+    ///
+    /// # Implementative details
+    /// The features are returned as a matrix with a row for each node and a
+    /// column for each retained feature, together with the name of each column.
+    /// The first features are the local ones, in the following order:
+    /// * `degree`: the number of unique neighbours, ignoring selfloops.
+    /// * `clustering_coefficient`: the local clustering coefficient.
+    /// * `egonet_internal_edges`: the number of edges within the egonet, that is the subgraph induced by the node and its neighbours.
+    /// * `egonet_boundary_edges`: the number of edges leaving the egonet.
+    ///
+    /// At each iteration, the sum and the mean of each feature added in the
+    /// previous iteration are computed over the neighbours of each node, and
+    /// the new features are named `sum_` and `mean_` followed by the name of the
+    /// aggregated feature, in the same order.
+    ///
+    /// To avoid the exponential growth of the number of features, each feature
+    /// is binned with the vertical logarithmic binning, and it is discarded when
+    /// its bins are equal to the ones of a previously retained feature. The
+    /// iterations stop early when no new feature is retained, or when the maximum
+    /// number of features is reached. The returned features are not binned.
+    ///
+    /// # References
+    /// [It's who you know: graph mining using recursive structural features](https://doi.org/10.1145/2020408.2020512),
+    /// by Keith Henderson et al.
+    ///
+    /// # Example
+    ///```rust
+    /// # let graph = graph::test_utilities::load_ppi(true, true, true, true, false, false);
+    /// let (features, feature_names) = graph.get_recursive_structural_features(Some(2), None, None).unwrap();
+    /// assert_eq!(features.len(), graph.get_number_of_nodes() as usize);
+    /// assert!(features.iter().all(|node_features| node_features.len() == feature_names.len()));
+    /// assert_eq!(feature_names[0], "degree");
+    /// ```
+    ///
+    /// # Raises
+    /// * If the graph does not have nodes.
+    /// * If the graph is directed.
+    /// * If the binning fraction is not strictly between zero and one.
+    /// * If the maximum number of features is zero.
+    pub fn get_recursive_structural_features(
+        &self,
+        number_of_iterations: Option<usize>,
+        binning_fraction: Option<f64>,
+        maximum_number_of_features: Option<usize>,
+    ) -> Result<(Vec<Vec<f64>>, Vec<String>)> {
+        self.must_have_nodes()?;
+        self.must_be_undirected()?;
+        let number_of_iterations = number_of_iterations.unwrap_or(3);
+        let binning_fraction = binning_fraction.unwrap_or(0.5);
+        if !(binning_fraction > 0.0 && binning_fraction < 1.0) {
+            return Err(format!(
+                concat!(
+                    "The binning fraction must be strictly between zero and one, ",
+                    "but the provided one is {}."
+                ),
+                binning_fraction
+            ));
+        }
+        let maximum_number_of_features = maximum_number_of_features.unwrap_or(usize::MAX);
+        if maximum_number_of_features == 0 {
+            return Err("The maximum number of features must be strictly positive.".to_string());
+        }
+
+        let neighbours = |node_id: NodeT| unsafe {
+            self.iter_unchecked_unique_neighbour_node_ids_from_source_node_id(node_id)
+                .filter(move |&neighbour_node_id| neighbour_node_id != node_id)
+        };
+
+        // We compute the local features of each node.
+        let local_features = self
+            .par_iter_node_ids()
+            .map(|node_id| {
+                let degree = neighbours(node_id).count();
+                let (mut internal_neighbour_edges, mut boundary_edges) = (0, 0);
+                let node_neighbours = unsafe {
+                    self.edges
+                        .get_unchecked_neighbours_node_ids_from_src_node_id(node_id)
+                };
+                neighbours(node_id).for_each(|neighbour_node_id| {
+                    neighbours(neighbour_node_id)
+                        .filter(|&other_node_id| other_node_id != node_id)
+                        .for_each(|other_node_id| {
+                            if node_neighbours.binary_search(&other_node_id).is_ok() {
+                                internal_neighbour_edges += 1;
+                            } else {
+                                boundary_edges += 1;
+                            }
+                        });
+                });
+                let clustering_coefficient = if degree > 1 {
+                    internal_neighbour_edges as f64 / (degree * (degree - 1)) as f64
+                } else {
+                    0.0
+                };
+                [
+                    degree as f64,
+                    clustering_coefficient,
+                    (degree + internal_neighbour_edges / 2) as f64,
+                    boundary_edges as f64,
+                ]
+            })
+            .collect::<Vec<[f64; 4]>>();
+
+        let mut candidate_features = [
+            "degree",
+            "clustering_coefficient",
+            "egonet_internal_edges",
+            "egonet_boundary_edges",
+        ]
+        .iter()
+        .enumerate()
+        .map(|(feature_number, feature_name)| {
+            (
+                local_features
+                    .par_iter()
+                    .map(|node_features| node_features[feature_number])
+                    .collect::<Vec<f64>>(),
+                feature_name.to_string(),
+            )
+        })
+        .collect::<Vec<(Vec<f64>, String)>>();
+
+        let mut features: Vec<Vec<f64>> = Vec::new();
+        let mut feature_names: Vec<String> = Vec::new();
+        let mut binned_features: Vec<Vec<usize>> = Vec::new();
+        for iteration in 0..=number_of_iterations {
+            // We retain the candidate features that are not redundant.
+            let mut added_feature_ids = Vec::new();
+            for (feature, feature_name) in candidate_features {
+                if features.len() >= maximum_number_of_features {
+                    break;
+                }
+                let bins = get_vertical_logarithmic_bins(&feature, binning_fraction);
+                if binned_features.contains(&bins) {
+                    continue;
+                }
+                added_feature_ids.push(features.len());
+                features.push(feature);
+                feature_names.push(feature_name);
+                binned_features.push(bins);
+            }
+            if added_feature_ids.is_empty()
+                || iteration == number_of_iterations
+                || features.len() >= maximum_number_of_features
+            {
+                break;
+            }
+            // We aggregate the features added in this iteration over the neighbours.
+            candidate_features = added_feature_ids
+                .into_iter()
+                .flat_map(|feature_id| {
+                    let feature = &features[feature_id];
+                    let (sums, means): (Vec<f64>, Vec<f64>) = self
+                        .par_iter_node_ids()
+                        .map(|node_id| {
+                            let (count, sum) = neighbours(node_id).fold(
+                                (0, 0.0),
+                                |(count, sum), neighbour_node_id| {
+                                    (count + 1, sum + feature[neighbour_node_id as usize])
+                                },
+                            );
+                            (sum, if count > 0 { sum / count as f64 } else { 0.0 })
+                        })
+                        .unzip();
+                    vec![
+                        (sums, format!("sum_{}", feature_names[feature_id])),
+                        (means, format!("mean_{}", feature_names[feature_id])),
+                    ]
+                })
+                .collect();
+        }
+
+        // We transpose the features so to have a row for each node.
+        Ok((
+            self.par_iter_node_ids()
+                .map(|node_id| {
+                    features
+                        .iter()
+                        .map(|feature| feature[node_id as usize])
+                        .collect()
+                })
+                .collect(),
+            feature_names,
+        ))
+    }
+}
@@ -130,7 +130,20 @@ impl Graph {
             None
         };
 
-        build_graph_from_integers(
+        // The node weights of the nodes remapped to the same node are summed.
+        let vocabulary_len = vocabulary.len();
+        let new_node_weights = self.node_weights.as_ref().as_ref().map(|node_weights| {
+            let mut remapped_node_weights = vec![0.0; vocabulary_len];
+            node_weights
+                .iter()
+                .zip(positions.iter())
+                .for_each(|(&node_weight, &position)| {
+                    remapped_node_weights[position as usize] += node_weight;
+                });
+            remapped_node_weights
+        });
+
+        let mut graph = build_graph_from_integers(
             Some(
                 self.par_iter_directed_edge_node_ids_and_edge_type_id_and_edge_weight()
                     .map(|(_, src_name_id, dst_name_id, edge_type_id, weight)| {
@@ -161,7 +174,36 @@ impl Graph {
             true,
             self.get_name(),
         )
-        .unwrap()
+        .unwrap();
+        // The node features of the nodes remapped to the same node are averaged.
+        let new_node_features = self.node_features.as_ref().as_ref().map(|node_features| {
+            // The graph may have been filtered down to no nodes, hence
+            // the dimensionality is retrieved without indexing the first row.
+            let dimensionality = node_features.first().map_or(0, |features| features.len());
+            let mut remapped_node_features = vec![vec![0.0; dimensionality]; vocabulary_len];
+            let mut counts = vec![0.0; vocabulary_len];
+            node_features
+                .iter()
+                .zip(positions.iter())
+                .for_each(|(features, &position)| {
+                    counts[position as usize] += 1.0;
+                    remapped_node_features[position as usize]
+                        .iter_mut()
+                        .zip(features.iter())
+                        .for_each(|(remapped_feature, feature)| *remapped_feature += feature);
+                });
+            remapped_node_features
+                .iter_mut()
+                .zip(counts.into_iter())
+                .filter(|(_, count)| *count > 1.0)
+                .for_each(|(features, count)| {
+                    features.iter_mut().for_each(|feature| *feature /= count);
+                });
+            remapped_node_features
+        });
+        graph.node_weights = Arc::new(new_node_weights);
+        graph.node_features = Arc::new(new_node_features);
+        graph
     }
 
     /// Returns graph remapped using given node IDs ordering.
@@ -258,6 +300,42 @@ impl Graph {
         )
     }
 
+    /// Returns graph remapped to follow the given node names ordering, adding the missing nodes.
+    ///
+    /// # Arguments
+    /// * `node_names`: Vec<String> - The node names, in the order the node IDs of the new graph must follow.
+    ///
+    /// # Implementative details
+    /// The node names in the given list that do not appear in the current graph
+    /// are added as singleton nodes, without node types, so that the node IDs of
+    /// the resulting graph line up with the given list. This is useful, for instance,
+    /// to align the rows of the node embedding computed on another graph.
+    ///
+    /// # Raises
+    /// * If the given node names are not unique.
+    /// * If a node name of the graph is not present in the given node names.
+    pub fn remap_to_node_names_order(&self, node_names: Vec<String>) -> Result<Graph> {
+        let new_nodes_vocabulary: Vocabulary<NodeT> =
+            Vocabulary::from_reverse_map(node_names, "Nodes".to_string())?;
+        let positions = self
+            .par_iter_node_names()
+            .map(|node_name| {
+                new_nodes_vocabulary.get(&node_name).ok_or_else(|| {
+                    format!(
+                        concat!(
+                            "The node name {} of the current graph is not ",
+                            "present in the given node names."
+                        ),
+                        node_name
+                    )
+                })
+            })
+            .collect::<Result<Vec<NodeT>>>()?;
+        Ok(unsafe {
+            self.remap_unchecked_from_positions_and_vocabulary(&positions, new_nodes_vocabulary)
+        })
+    }
+
     /// Returns graph remapped using given node names mapping hashmap.
     ///
     /// # Arguments
@@ -385,5 +463,6 @@ impl Graph {
             self.has_singleton_nodes_with_selfloops(),
             self.get_name(),
         )
+        .map(|graph| self.with_node_metadata_from_node_names(graph))
     }
 }
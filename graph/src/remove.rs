@@ -140,4 +140,93 @@ impl Graph {
             None,
         )
     }
+
+    /// Return a new graph with solely the edges with weight at or above the given percentile.
+    ///
+    /// # Arguments
+    /// * `percentile`: f64 - The percentile, between 0 and 100, of the edge weights to use as threshold.
+    /// * `remove_singleton_nodes`: Option<bool> - Whether to remove the nodes that become singletons. By default, false.
+    ///
+    /// # Implementative details
+    /// The threshold is computed with the nearest-rank method, hence it is always
+    /// one of the weights of the graph, and all the edges with weight equal to
+    /// the threshold are kept. In undirected graphs both directions of each edge
+    /// are considered, which does not change the resulting percentiles.
+    ///
+    /// # Raises
+    /// * If the graph does not have edge weights.
+    /// * If the given percentile is not between 0 and 100.
+    pub fn remove_edges_below_weight_percentile(
+        &self,
+        percentile: f64,
+        remove_singleton_nodes: Option<bool>,
+    ) -> Result<Graph> {
+        let mut weights = self.get_directed_edge_weights()?;
+        if !(0.0..=100.0).contains(&percentile) {
+            return Err(format!(
+                "The given percentile {} must be between 0 and 100.",
+                percentile
+            ));
+        }
+        let remove_singleton_nodes = remove_singleton_nodes.unwrap_or(false);
+        if weights.is_empty() {
+            return Ok(self.clone());
+        }
+        weights.par_sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+        let rank = ((percentile / 100.0 * weights.len() as f64).ceil() as usize).max(1);
+        let threshold = weights[rank.min(weights.len()) - 1];
+
+        let graph = self.filter_from_ids(
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(threshold),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )?;
+
+        Ok(if remove_singleton_nodes {
+            graph.remove_singleton_nodes()
+        } else {
+            graph
+        })
+    }
 }
@@ -4,6 +4,52 @@ use itertools::Itertools;
 use rayon::prelude::*;
 use std::collections::HashMap;
 
+/// The version of the schema of the JSON report, to be increased whenever its keys change.
+const JSON_REPORT_SCHEMA_VERSION: usize = 1;
+
+/// Returns the given text as a JSON string.
+///
+/// # Arguments
+/// * `text`: &str - The text to escape.
+fn to_json_string(text: &str) -> String {
+    let mut json = String::with_capacity(text.len() + 2);
+    json.push('"');
+    for character in text.chars() {
+        match character {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            character if character.is_control() => {
+                json.push_str(&format!("\\u{:04x}", character as u32))
+            }
+            character => json.push(character),
+        }
+    }
+    json.push('"');
+    json
+}
+
+/// Returns the given value as a JSON number, or null when it is not available.
+///
+/// # Arguments
+/// * `value`: Option<T> - The value to convert.
+fn to_json_integer<T: ToString>(value: Option<T>) -> String {
+    value.map_or_else(|| "null".to_string(), |value| value.to_string())
+}
+
+/// Returns the given value as a JSON number, or null when it is not available or not finite.
+///
+/// # Arguments
+/// * `value`: Option<f64> - The value to convert.
+fn to_json_float(value: Option<f64>) -> String {
+    match value {
+        Some(value) if value.is_finite() => format!("{:?}", value),
+        _ => "null".to_string(),
+    }
+}
+
 /// # Human readable report of the properties of the graph
 impl Graph {
     /// Returns report relative to the graph metrics
@@ -2486,6 +2532,167 @@ impl Graph {
         paragraphs.join("")
     }
 
+    /// Return the JSON report of the graph, with the quantities of the textual report.
+    ///
+    /// # Implementative details
+    /// The report is a JSON object with a key for each quantity, which is
+    /// always present and is null when the quantity is not defined for the
+    /// graph, such as the degree statistics of a graph without edges or the
+    /// edge weights statistics of an unweighted graph, so that the reports of
+    /// different graphs can be compared key by key. The quantities are computed
+    /// with the same getters used by the textual report, and the connected
+    /// components are only reported for undirected graphs, as in the textual
+    /// report. The `schema_version` key is increased whenever the keys change.
+    ///
+    /// # Example
+    /// ```rust
+    /// # let graph = graph::test_utilities::load_ppi(true, true, true, true, false, false);
+    /// let report = graph.get_report_json();
+    /// assert!(report.contains("\"schema_version\": 1"));
+    /// assert!(report.contains(&format!("\"number_of_nodes\": {}", graph.get_number_of_nodes())));
+    /// ```
+    pub fn get_report_json(&self) -> String {
+        let has_edges = self.has_edges();
+        let connected_components = if self.is_directed() {
+            None
+        } else {
+            Some(self.get_number_of_connected_components(None))
+        };
+        let weight_property = |value: Result<WeightT>| value.ok().map(|value| value as f64);
+        let report: Vec<(&str, String)> = vec![
+            ("schema_version", JSON_REPORT_SCHEMA_VERSION.to_string()),
+            ("name", to_json_string(&self.get_name())),
+            ("directed", self.is_directed().to_string()),
+            ("multigraph", self.is_multigraph().to_string()),
+            ("number_of_nodes", self.get_number_of_nodes().to_string()),
+            (
+                "number_of_singleton_nodes",
+                self.get_number_of_singleton_nodes().to_string(),
+            ),
+            (
+                "number_of_singleton_nodes_with_selfloops",
+                self.get_number_of_singleton_nodes_with_selfloops()
+                    .to_string(),
+            ),
+            (
+                "number_of_disconnected_nodes",
+                self.get_number_of_disconnected_nodes().to_string(),
+            ),
+            ("number_of_edges", self.get_number_of_edges().to_string()),
+            (
+                "number_of_directed_edges",
+                self.get_number_of_directed_edges().to_string(),
+            ),
+            (
+                "number_of_selfloops",
+                self.get_number_of_selfloops().to_string(),
+            ),
+            (
+                "number_of_parallel_edges",
+                self.get_number_of_parallel_edges().to_string(),
+            ),
+            ("density", to_json_float(self.get_density().ok())),
+            (
+                "number_of_connected_components",
+                to_json_integer(connected_components.map(|(number, _, _)| number)),
+            ),
+            (
+                "minimum_connected_component_size",
+                to_json_integer(connected_components.map(|(_, minimum, _)| minimum)),
+            ),
+            (
+                "maximum_connected_component_size",
+                to_json_integer(connected_components.map(|(_, _, maximum)| maximum)),
+            ),
+            (
+                "minimum_node_degree",
+                to_json_integer(self.get_minimum_node_degree().ok().filter(|_| has_edges)),
+            ),
+            (
+                "maximum_node_degree",
+                to_json_integer(self.get_maximum_node_degree().ok().filter(|_| has_edges)),
+            ),
+            (
+                "node_degrees_mode",
+                to_json_integer(self.get_node_degrees_mode().ok().filter(|_| has_edges)),
+            ),
+            (
+                "node_degrees_median",
+                to_json_integer(self.get_node_degrees_median().ok().filter(|_| has_edges)),
+            ),
+            (
+                "node_degrees_mean",
+                to_json_float(self.get_node_degrees_mean().ok().filter(|_| has_edges)),
+            ),
+            ("has_edge_weights", self.has_edge_weights().to_string()),
+            (
+                "minimum_edge_weight",
+                to_json_float(weight_property(self.get_mininum_edge_weight())),
+            ),
+            (
+                "maximum_edge_weight",
+                to_json_float(weight_property(self.get_maximum_edge_weight())),
+            ),
+            (
+                "total_edge_weights",
+                to_json_float(self.get_total_edge_weights().ok()),
+            ),
+            (
+                "minimum_weighted_node_degree",
+                to_json_float(self.get_weighted_minimum_node_degree().ok()),
+            ),
+            (
+                "maximum_weighted_node_degree",
+                to_json_float(self.get_weighted_maximum_node_degree().ok()),
+            ),
+            (
+                "weighted_node_degrees_mean",
+                to_json_float(self.get_weighted_node_degrees_mean().ok()),
+            ),
+            ("has_node_types", self.has_node_types().to_string()),
+            (
+                "number_of_node_types",
+                to_json_integer(self.get_number_of_node_types().ok()),
+            ),
+            (
+                "number_of_singleton_node_types",
+                to_json_integer(self.get_number_of_singleton_node_types().ok()),
+            ),
+            (
+                "number_of_nodes_with_unknown_node_types",
+                to_json_integer(self.get_number_of_unknown_node_types().ok()),
+            ),
+            (
+                "number_of_nodes_with_known_node_types",
+                to_json_integer(self.get_number_of_known_node_types().ok()),
+            ),
+            ("has_edge_types", self.has_edge_types().to_string()),
+            (
+                "number_of_edge_types",
+                to_json_integer(self.get_number_of_edge_types().ok()),
+            ),
+            (
+                "number_of_singleton_edge_types",
+                to_json_integer(self.get_number_of_singleton_edge_types().ok()),
+            ),
+            (
+                "number_of_edges_with_unknown_edge_types",
+                to_json_integer(self.get_number_of_unknown_edge_types().ok()),
+            ),
+            (
+                "number_of_edges_with_known_edge_types",
+                to_json_integer(self.get_number_of_known_edge_types().ok()),
+            ),
+        ];
+        format!(
+            "{{\n{}\n}}",
+            report
+                .into_iter()
+                .map(|(key, value)| format!("  {}: {}", to_json_string(key), value))
+                .join(",\n")
+        )
+    }
+
     /// Return html short textual report of the graph.
     ///
     /// TODO! Add reports on various node metrics
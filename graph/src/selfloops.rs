@@ -5,21 +5,26 @@ use rayon::iter::ParallelIterator;
 
 /// # Selfloops.
 impl Graph {
-    /// Returns new graph with added in missing self-loops with given edge type and weight.
+    /// Returns new graph with a self-loop added to each of the nodes satisfying the given predicate.
     ///
     /// # Arguments
     /// `edge_type_name`: Option<&str> - The edge type to use for the selfloops.
     /// `weight`: Option<WeightT> - The weight to use for the new selfloops edges.
+    /// `node_predicate`: F - The predicate returning whether a self-loop should be added to the given node.
     ///
     /// # Raises
     /// * If the edge type for the new selfloops is provided but the graph does not have edge types.
     /// * If the edge weight for the new selfloops is provided but the graph does not have edge weights.
     /// * If the edge weight for the new selfloops is NOT provided but the graph does have edge weights.
-    pub fn add_selfloops(
+    fn add_selfloops_from_node_predicate<F>(
         &self,
         edge_type_name: Option<&str>,
         weight: Option<WeightT>,
-    ) -> Result<Graph> {
+        node_predicate: F,
+    ) -> Result<Graph>
+    where
+        F: Fn(NodeT) -> bool + Send + Sync,
+    {
         let edge_type_id = if edge_type_name.is_some() {
             self.get_edge_type_id_from_edge_type_name(edge_type_name)?
         } else {
@@ -34,8 +39,10 @@ impl Graph {
         }
         let weight = weight.unwrap_or(WeightT::NAN);
         let total_number_of_edges = self.get_number_of_directed_edges()
-            - self.get_number_of_selfloops()
-            + self.get_number_of_nodes() as EdgeT;
+            + self
+                .par_iter_node_ids()
+                .filter(|&node_id| node_predicate(node_id))
+                .count() as EdgeT;
 
         build_graph_from_integers(
             Some(
@@ -45,7 +52,7 @@ impl Graph {
                     })
                     .chain(
                         self.par_iter_node_ids()
-                            .filter(|&node_id| !self.has_selfloop_from_node_id(node_id))
+                            .filter(|&node_id| node_predicate(node_id))
                             .map(|node_id| (0, (node_id, node_id, edge_type_id, weight))),
                     ),
             ),
@@ -66,4 +73,50 @@ impl Graph {
             self.get_name(),
         )
     }
+
+    /// Returns new graph with added in missing self-loops with given edge type and weight.
+    ///
+    /// # Arguments
+    /// `edge_type_name`: Option<&str> - The edge type to use for the selfloops.
+    /// `weight`: Option<WeightT> - The weight to use for the new selfloops edges.
+    ///
+    /// # Raises
+    /// * If the edge type for the new selfloops is provided but the graph does not have edge types.
+    /// * If the edge weight for the new selfloops is provided but the graph does not have edge weights.
+    /// * If the edge weight for the new selfloops is NOT provided but the graph does have edge weights.
+    pub fn add_selfloops(
+        &self,
+        edge_type_name: Option<&str>,
+        weight: Option<WeightT>,
+    ) -> Result<Graph> {
+        self.add_selfloops_from_node_predicate(edge_type_name, weight, |node_id| {
+            !self.has_selfloop_from_node_id(node_id)
+        })
+    }
+
+    /// Returns new graph with added self-loops only on the singleton nodes, with given edge type and weight.
+    ///
+    /// # Arguments
+    /// `edge_type_name`: Option<&str> - The edge type to use for the selfloops.
+    /// `weight`: Option<WeightT> - The weight to use for the new selfloops edges.
+    ///
+    /// # Implementative details
+    /// A node is considered a singleton when it has neither incoming nor outgoing
+    /// edges, hence in directed graphs trap nodes are left untouched. All the other
+    /// nodes and edges are kept as they are, so that after this operation the
+    /// only nodes without outgoing edges are the trap nodes.
+    ///
+    /// # Raises
+    /// * If the edge type for the new selfloops is provided but the graph does not have edge types.
+    /// * If the edge weight for the new selfloops is provided but the graph does not have edge weights.
+    /// * If the edge weight for the new selfloops is NOT provided but the graph does have edge weights.
+    pub fn add_selfloops_to_singletons(
+        &self,
+        edge_type_name: Option<&str>,
+        weight: Option<WeightT>,
+    ) -> Result<Graph> {
+        self.add_selfloops_from_node_predicate(edge_type_name, weight, |node_id| unsafe {
+            self.is_unchecked_singleton_from_node_id(node_id)
+        })
+    }
 }
@@ -0,0 +1,258 @@
+use super::*;
+use rayon::prelude::*;
+use roaring::RoaringBitmap;
+
+/// # Semantic similarity over directed acyclic graphs
+/// The edges of the directed acyclic graph are expected to go from the
+/// more general terms to the more specific ones, as in ontologies.
+impl Graph {
+    /// Validates the provided per-node vector against the graph.
+    ///
+    /// # Arguments
+    /// * `values_len`: usize - The length of the provided vector.
+    /// * `values_name`: &str - The name of the provided vector, used in the error message.
+    fn validate_node_vector(&self, values_len: usize, values_name: &str) -> Result<()> {
+        if values_len != self.get_number_of_nodes() as usize {
+            return Err(format!(
+                "The provided {} have size {}, but the graph has {} nodes.",
+                values_name,
+                values_len,
+                self.get_number_of_nodes()
+            ));
+        }
+        Ok(())
+    }
+
+    /// Returns the information content of each node computed from the provided annotation counts.
+    ///
+    /// # Arguments
+    /// * `node_counts`: &[NodeT] - The number of annotations of each node.
+    ///
+    /// # Implementative details
+    /// The annotations of a node are propagated to all of its ancestors,
+    /// so that the count of a node is the sum of its own count and of the counts of
+    /// its descendants, each counted once. The information content of a node
+    /// is the negative logarithm of its count divided by the maximum count,
+    /// which is the count of the most general root. Nodes with no propagated
+    /// annotation have infinite information content.
+    ///
+    /// # Raises
+    /// * If the graph is not a directed acyclic graph.
+    /// * If the provided node counts do not have a value for each node.
+    /// * If all the provided node counts are zero.
+    pub fn get_information_contents_from_node_counts(
+        &self,
+        node_counts: &[NodeT],
+    ) -> Result<Vec<f64>> {
+        self.must_be_directed_acyclic()?;
+        self.validate_node_vector(node_counts.len(), "node counts")?;
+        let descendants = unsafe { self.get_unchecked_directed_acyclic_descendants() };
+        let propagated_counts = descendants
+            .par_iter()
+            .enumerate()
+            .map(|(node_id, node_descendants)| {
+                node_counts[node_id] as f64
+                    + node_descendants
+                        .iter()
+                        .map(|descendant| node_counts[descendant as usize] as f64)
+                        .sum::<f64>()
+            })
+            .collect::<Vec<f64>>();
+        let maximum_count = propagated_counts.iter().copied().fold(0.0, f64::max);
+        if maximum_count == 0.0 {
+            return Err("The provided node counts are all zero.".to_string());
+        }
+        Ok(propagated_counts
+            .into_par_iter()
+            .map(|count| -(count / maximum_count).ln())
+            .collect())
+    }
+
+    #[cache_property(directed_acyclic_ancestors)]
+    /// Returns the ancestors of each node of the directed acyclic graph as bitmaps.
+    ///
+    /// # Implementative details
+    /// The ancestors are the descendants in the transposed graph, and a node
+    /// is not an ancestor of itself. The result is cached, so that the transposed
+    /// graph and the ancestors are computed once for all the similarity queries.
+    ///
+    /// # Raises
+    /// * If the graph is not a directed acyclic graph.
+    pub(crate) fn get_directed_acyclic_ancestors(&self) -> Result<Arc<Vec<RoaringBitmap>>> {
+        self.must_be_directed_acyclic()?;
+        Ok(Arc::new(unsafe {
+            self.to_transposed()
+                .get_unchecked_directed_acyclic_descendants()
+        }))
+    }
+
+    /// Returns the information content of the most informative common ancestor of each of the provided pairs.
+    ///
+    /// # Arguments
+    /// * `node_id_pairs`: &[(NodeT, NodeT)] - The pairs of node IDs.
+    /// * `information_contents`: &[f64] - The information content of each node.
+    ///
+    /// # Raises
+    /// * If the graph is not a directed acyclic graph.
+    /// * If the provided information contents do not have a value for each node.
+    /// * If any of the provided node IDs does not exist in the graph.
+    fn get_most_informative_common_ancestor_information_contents(
+        &self,
+        node_id_pairs: &[(NodeT, NodeT)],
+        information_contents: &[f64],
+    ) -> Result<Vec<f64>> {
+        // The ancestors are cached together with the check that the graph is acyclic.
+        let ancestors = self.get_directed_acyclic_ancestors()?;
+        self.validate_node_vector(information_contents.len(), "information contents")?;
+        node_id_pairs.iter().try_for_each(|&(first, second)| {
+            self.validate_node_id(first)?;
+            self.validate_node_id(second)?;
+            Ok::<(), String>(())
+        })?;
+        let get_ancestors = |node_id: NodeT| {
+            let mut node_ancestors: RoaringBitmap = ancestors[node_id as usize].clone();
+            node_ancestors.insert(node_id);
+            node_ancestors
+        };
+        Ok(node_id_pairs
+            .par_iter()
+            .map(|&(first, second)| {
+                (get_ancestors(first) & get_ancestors(second))
+                    .iter()
+                    .map(|ancestor| information_contents[ancestor as usize])
+                    .fold(0.0, f64::max)
+            })
+            .collect())
+    }
+
+    /// Returns the Resnik similarity of each of the provided pairs of nodes.
+    ///
+    /// # Arguments
+    /// * `node_id_pairs`: &[(NodeT, NodeT)] - The pairs of node IDs.
+    /// * `information_contents`: &[f64] - The information content of each node.
+    ///
+    /// # Implementative details
+    /// The Resnik similarity of two nodes is the information content of their
+    /// most informative common ancestor, where each node is considered an
+    /// ancestor of itself. Pairs without common ancestors have zero similarity.
+    /// The information contents can be computed from annotation counts with
+    /// `get_information_contents_from_node_counts`.
+    ///
+    /// # References
+    /// [Using Information Content to Evaluate Semantic Similarity in a Taxonomy](https://arxiv.org/abs/cmp-lg/9511007),
+    /// by Philip Resnik.
+    ///
+    /// # Raises
+    /// * If the graph is not a directed acyclic graph.
+    /// * If the provided information contents do not have a value for each node.
+    /// * If any of the provided node IDs does not exist in the graph.
+    pub fn get_resnik_similarities_from_node_ids(
+        &self,
+        node_id_pairs: &[(NodeT, NodeT)],
+        information_contents: &[f64],
+    ) -> Result<Vec<f64>> {
+        self.get_most_informative_common_ancestor_information_contents(
+            node_id_pairs,
+            information_contents,
+        )
+    }
+
+    /// Returns the Resnik similarity of the provided pair of nodes.
+    ///
+    /// # Arguments
+    /// * `first_node_id`: NodeT - The first node ID.
+    /// * `second_node_id`: NodeT - The second node ID.
+    /// * `information_contents`: &[f64] - The information content of each node.
+    ///
+    /// # Implementative details
+    /// See `get_resnik_similarities_from_node_ids`. The ancestors of the nodes
+    /// are computed on the first call and cached, so that the following calls
+    /// only intersect the ancestors of the given nodes.
+    ///
+    /// # Raises
+    /// * If the graph is not a directed acyclic graph.
+    /// * If the provided information contents do not have a value for each node.
+    /// * If any of the provided node IDs does not exist in the graph.
+    pub fn get_resnik_similarity_from_node_ids(
+        &self,
+        first_node_id: NodeT,
+        second_node_id: NodeT,
+        information_contents: &[f64],
+    ) -> Result<f64> {
+        Ok(self.get_resnik_similarities_from_node_ids(
+            &[(first_node_id, second_node_id)],
+            information_contents,
+        )?[0])
+    }
+
+    /// Returns the Lin similarity of each of the provided pairs of nodes.
+    ///
+    /// # Arguments
+    /// * `node_id_pairs`: &[(NodeT, NodeT)] - The pairs of node IDs.
+    /// * `information_contents`: &[f64] - The information content of each node.
+    ///
+    /// # Implementative details
+    /// The Lin similarity of two nodes is twice the information content of their
+    /// most informative common ancestor, divided by the sum of their information
+    /// contents. When the sum of their information contents is zero, the similarity is zero.
+    ///
+    /// # References
+    /// [An Information-Theoretic Definition of Similarity](https://dl.acm.org/doi/10.5555/645527.657297),
+    /// by Dekang Lin.
+    ///
+    /// # Raises
+    /// * If the graph is not a directed acyclic graph.
+    /// * If the provided information contents do not have a value for each node.
+    /// * If any of the provided node IDs does not exist in the graph.
+    pub fn get_lin_similarities_from_node_ids(
+        &self,
+        node_id_pairs: &[(NodeT, NodeT)],
+        information_contents: &[f64],
+    ) -> Result<Vec<f64>> {
+        Ok(self
+            .get_most_informative_common_ancestor_information_contents(
+                node_id_pairs,
+                information_contents,
+            )?
+            .into_par_iter()
+            .zip(node_id_pairs.par_iter())
+            .map(|(common_information_content, &(first, second))| {
+                let denominator =
+                    information_contents[first as usize] + information_contents[second as usize];
+                if denominator > 0.0 {
+                    2.0 * common_information_content / denominator
+                } else {
+                    0.0
+                }
+            })
+            .collect())
+    }
+
+    /// Returns the Lin similarity of the provided pair of nodes.
+    ///
+    /// # Arguments
+    /// * `first_node_id`: NodeT - The first node ID.
+    /// * `second_node_id`: NodeT - The second node ID.
+    /// * `information_contents`: &[f64] - The information content of each node.
+    ///
+    /// # Implementative details
+    /// See `get_lin_similarities_from_node_ids`. The ancestors of the nodes
+    /// are computed on the first call and cached, so that the following calls
+    /// only intersect the ancestors of the given nodes.
+    ///
+    /// # Raises
+    /// * If the graph is not a directed acyclic graph.
+    /// * If the provided information contents do not have a value for each node.
+    /// * If any of the provided node IDs does not exist in the graph.
+    pub fn get_lin_similarity_from_node_ids(
+        &self,
+        first_node_id: NodeT,
+        second_node_id: NodeT,
+        information_contents: &[f64],
+    ) -> Result<f64> {
+        Ok(self.get_lin_similarities_from_node_ids(
+            &[(first_node_id, second_node_id)],
+            information_contents,
+        )?[0])
+    }
+}
@@ -1,4 +1,5 @@
 use super::*;
+use atomic_float::AtomicF64;
 use itertools::Itertools;
 use rayon::iter::IndexedParallelIterator;
 use rayon::iter::IntoParallelIterator;
@@ -1060,6 +1061,67 @@ impl Graph {
         Ok(graph)
     }
 
+    /// Returns directed graph whose edge weights are normalized to sum to one for each destination node.
+    ///
+    /// # Implementative details
+    /// Each edge weight is divided by the total weight of the edges ending
+    /// in its destination node, so that the weighted adjacency matrix is
+    /// column-stochastic: this is the transition matrix of the random walks
+    /// on the transposed graph. When the graph does not have edge weights,
+    /// all the edges are considered to have unit weight, so that each edge
+    /// weight becomes the inverse of the inbound degree of its destination.
+    /// Nodes without inbound edges are left without inbound weights.
+    ///
+    /// Since the weight of an edge generally differs from the weight of its
+    /// reverse edge, the returned graph is always directed: in undirected
+    /// graphs, each undirected edge becomes a pair of directed edges.
+    ///
+    /// # Example
+    ///```rust
+    /// # let graph = graph::test_utilities::load_ppi(true, true, true, true, false, false);
+    /// let normalized_graph = graph.get_column_normalized_graph().unwrap();
+    /// assert!(normalized_graph.is_directed());
+    /// ```
+    ///
+    /// # Raises
+    /// * If the graph has edge weights that are not strictly positive.
+    pub fn get_column_normalized_graph(&self) -> Result<Graph> {
+        if self.has_edge_weights() {
+            self.must_have_positive_edge_weights()?;
+        }
+        let get_edge_weight = |edge_id: EdgeT| {
+            self.weights
+                .as_ref()
+                .as_ref()
+                .map_or(1.0, |weights| weights[edge_id as usize] as f64)
+        };
+        let inbound_weights = self
+            .iter_node_ids()
+            .map(|_| AtomicF64::new(0.0))
+            .collect::<Vec<AtomicF64>>();
+        self.par_iter_directed_edge_node_ids()
+            .for_each(|(edge_id, _, dst)| {
+                inbound_weights[dst as usize]
+                    .fetch_add(get_edge_weight(edge_id), Ordering::Relaxed);
+            });
+        let inbound_weights = inbound_weights
+            .into_iter()
+            .map(|inbound_weight| inbound_weight.into_inner())
+            .collect::<Vec<f64>>();
+
+        let mut graph = self.to_directed();
+        // The cache is shared between clones, so the new graph needs its own.
+        graph.cache = Arc::new(ClonableUnsafeCell::default());
+        graph.weights = Arc::new(Some(
+            self.par_iter_directed_edge_node_ids()
+                .map(|(edge_id, _, dst)| {
+                    (get_edge_weight(edge_id) / inbound_weights[dst as usize]) as WeightT
+                })
+                .collect(),
+        ));
+        Ok(graph)
+    }
+
     /// Multiply edge weights in place.
     ///
     /// Note that the modification happens inplace.
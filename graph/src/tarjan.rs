@@ -86,4 +86,183 @@ impl Graph {
         }
         components
     }
+
+    /// Returns the neighbours of each node in the undirected interpretation of the graph.
+    ///
+    /// # Implementative details
+    /// The neighbours are sorted and each one is repeated as many times as the
+    /// edges connecting it to the node, while selfloops are removed. In directed
+    /// graphs the edges are considered regardless of their direction, and two
+    /// reciprocal edges are considered as the same undirected edge.
+    fn get_undirected_neighbours_with_multiplicity(&self) -> Vec<Vec<NodeT>> {
+        let get_outbound_neighbours = |src: NodeT| -> Vec<NodeT> {
+            unsafe { self.iter_unchecked_neighbour_node_ids_from_source_node_id(src) }
+                .filter(|&dst| dst != src)
+                .collect()
+        };
+        if !self.is_directed() {
+            return self.iter_node_ids().map(get_outbound_neighbours).collect();
+        }
+        let mut inbound_neighbours: Vec<Vec<NodeT>> =
+            vec![Vec::new(); self.get_number_of_nodes() as usize];
+        // The sources are iterated in increasing order, so the inbound neighbours are sorted.
+        self.iter_directed_edge_node_ids()
+            .filter(|&(_, src, dst)| src != dst)
+            .for_each(|(_, src, dst)| inbound_neighbours[dst as usize].push(src));
+        self.iter_node_ids()
+            .zip(inbound_neighbours.into_iter())
+            .map(|(node_id, inbound_neighbours)| {
+                let outbound_neighbours = get_outbound_neighbours(node_id);
+                let (mut i, mut j) = (0, 0);
+                let mut neighbours = Vec::with_capacity(outbound_neighbours.len());
+                while i < outbound_neighbours.len() || j < inbound_neighbours.len() {
+                    let neighbour = min(
+                        outbound_neighbours.get(i).copied().unwrap_or(NodeT::MAX),
+                        inbound_neighbours.get(j).copied().unwrap_or(NodeT::MAX),
+                    );
+                    let outbound_count = outbound_neighbours[i..]
+                        .iter()
+                        .take_while(|&&dst| dst == neighbour)
+                        .count();
+                    let inbound_count = inbound_neighbours[j..]
+                        .iter()
+                        .take_while(|&&src| src == neighbour)
+                        .count();
+                    i += outbound_count;
+                    j += inbound_count;
+                    neighbours.extend(
+                        std::iter::repeat(neighbour).take(outbound_count.max(inbound_count)),
+                    );
+                }
+                neighbours
+            })
+            .collect()
+    }
+
+    /// Returns the articulation points and the bridges of the undirected interpretation of the graph.
+    ///
+    /// # Implementative details
+    /// The articulation points and the bridges are computed with a single iterative
+    /// depth first search, tracking the discovery time of each node and its low-link,
+    /// that is the smallest discovery time reachable from its subtree through a
+    /// single back edge. Only the first edge towards the parent of a node is skipped,
+    /// so that the other edges between them count as back edges and the edges with
+    /// multiplicity higher than one are never bridges.
+    fn get_articulation_points_and_bridges(&self) -> (Vec<NodeT>, Vec<(NodeT, NodeT)>) {
+        let neighbours = self.get_undirected_neighbours_with_multiplicity();
+        let number_of_nodes = neighbours.len();
+        let mut discovery_times = vec![NODE_NOT_PRESENT; number_of_nodes];
+        let mut low_links = vec![NODE_NOT_PRESENT; number_of_nodes];
+        let mut is_articulation_point = vec![false; number_of_nodes];
+        let mut bridges = Vec::new();
+        let mut time: NodeT = 0;
+
+        for root in 0..number_of_nodes {
+            if discovery_times[root] != NODE_NOT_PRESENT {
+                continue;
+            }
+            discovery_times[root] = time;
+            low_links[root] = time;
+            time += 1;
+            let mut number_of_root_children = 0;
+            // The stack contains the node, its parent, the index of the next
+            // neighbour to visit and whether the edge to the parent was skipped.
+            let mut stack = vec![(root, NODE_NOT_PRESENT as usize, 0, false)];
+            while let Some(frame) = stack.last_mut() {
+                let (node, parent) = (frame.0, frame.1);
+                if let Some(&neighbour) = neighbours[node].get(frame.2) {
+                    frame.2 += 1;
+                    let neighbour = neighbour as usize;
+                    if neighbour == parent && !frame.3 {
+                        frame.3 = true;
+                    } else if discovery_times[neighbour] == NODE_NOT_PRESENT {
+                        discovery_times[neighbour] = time;
+                        low_links[neighbour] = time;
+                        time += 1;
+                        stack.push((neighbour, node, 0, false));
+                    } else {
+                        low_links[node] = min(low_links[node], discovery_times[neighbour]);
+                    }
+                    continue;
+                }
+                stack.pop();
+                if parent == NODE_NOT_PRESENT as usize {
+                    continue;
+                }
+                low_links[parent] = min(low_links[parent], low_links[node]);
+                if low_links[node] > discovery_times[parent] {
+                    bridges.push((min(parent, node) as NodeT, parent.max(node) as NodeT));
+                }
+                if parent == root {
+                    number_of_root_children += 1;
+                } else if low_links[node] >= discovery_times[parent] {
+                    is_articulation_point[parent] = true;
+                }
+            }
+            if number_of_root_children > 1 {
+                is_articulation_point[root] = true;
+            }
+        }
+
+        bridges.sort_unstable();
+        (
+            is_articulation_point
+                .into_iter()
+                .enumerate()
+                .filter(|&(_, is_articulation_point)| is_articulation_point)
+                .map(|(node_id, _)| node_id as NodeT)
+                .collect(),
+            bridges,
+        )
+    }
+
+    /// Returns the articulation points of the undirected interpretation of the graph.
+    ///
+    /// # Implementative details
+    /// An articulation point, or cut vertex, is a node whose removal increases
+    /// the number of connected components of the graph. The articulation points
+    /// are computed with the low-link algorithm, using an iterative depth first
+    /// search. In directed graphs the direction of the edges is ignored, while
+    /// selfloops and parallel edges do not affect the articulation points.
+    /// The returned node IDs are sorted.
+    ///
+    /// # References
+    /// [Algorithm 447: efficient algorithms for graph manipulation](https://doi.org/10.1145/362248.362272),
+    /// by Hopcroft and Tarjan.
+    ///
+    /// # Example
+    ///```rust
+    /// # let graph = graph::test_utilities::load_ppi(true, true, true, false, false, false);
+    /// let articulation_points = graph.get_articulation_points();
+    /// assert!(articulation_points.len() < graph.get_number_of_nodes() as usize);
+    /// ```
+    pub fn get_articulation_points(&self) -> Vec<NodeT> {
+        self.get_articulation_points_and_bridges().0
+    }
+
+    /// Returns the bridges of the undirected interpretation of the graph.
+    ///
+    /// # Implementative details
+    /// A bridge, or cut edge, is an edge whose removal increases the number of
+    /// connected components of the graph. The bridges are computed with the
+    /// low-link algorithm, using an iterative depth first search. In directed
+    /// graphs the direction of the edges is ignored, and two reciprocal edges
+    /// are considered as the same undirected edge, while two nodes connected by
+    /// parallel edges are never connected by a bridge and selfloops are never
+    /// bridges. Each bridge is returned once, with the smaller node ID first,
+    /// and the bridges are sorted.
+    ///
+    /// # References
+    /// [Algorithm 447: efficient algorithms for graph manipulation](https://doi.org/10.1145/362248.362272),
+    /// by Hopcroft and Tarjan.
+    ///
+    /// # Example
+    ///```rust
+    /// # let graph = graph::test_utilities::load_ppi(true, true, true, false, false, false);
+    /// let bridges = graph.get_bridges();
+    /// assert!(bridges.iter().all(|&(src, dst)| src < dst));
+    /// ```
+    pub fn get_bridges(&self) -> Vec<(NodeT, NodeT)> {
+        self.get_articulation_points_and_bridges().1
+    }
 }
@@ -191,6 +191,69 @@ pub fn load_cora() -> Graph {
     .unwrap()
 }
 
+/// Return the graph with the given weighted edges and singleton nodes, built from the node names.
+///
+/// # Arguments
+/// * `edges`: &[(&str, &str, Option<WeightT>)] - The source name, destination name and optional weight of the edges.
+/// * `singletons`: &[&str] - The names of the singleton nodes to add to the graph.
+/// * `directed`: bool - Whether the graph is directed.
+pub fn build_graph_from_weighted_edges_and_singletons(
+    edges: &[(&str, &str, Option<WeightT>)],
+    singletons: &[&str],
+    directed: bool,
+) -> Result<Graph> {
+    let mut builder = GraphBuilder::new(None, Some(directed));
+    for &(src, dst, weight) in edges {
+        builder.add_edge(src.to_string(), dst.to_string(), None, weight)?;
+    }
+    for singleton in singletons {
+        builder.add_node(singleton.to_string(), None)?;
+    }
+    builder.build()
+}
+
+/// Return the graph with the given weighted edges, built from the node names.
+///
+/// # Arguments
+/// * `edges`: &[(&str, &str, Option<WeightT>)] - The source name, destination name and optional weight of the edges.
+/// * `directed`: bool - Whether the graph is directed.
+pub fn build_graph_from_weighted_edges(
+    edges: &[(&str, &str, Option<WeightT>)],
+    directed: bool,
+) -> Result<Graph> {
+    build_graph_from_weighted_edges_and_singletons(edges, &[], directed)
+}
+
+/// Return the graph with the given edges and singleton nodes, built from the node names.
+///
+/// # Arguments
+/// * `edges`: &[(&str, &str)] - The source and destination names of the edges.
+/// * `singletons`: &[&str] - The names of the singleton nodes to add to the graph.
+/// * `directed`: bool - Whether the graph is directed.
+pub fn build_graph_from_edges_and_singletons(
+    edges: &[(&str, &str)],
+    singletons: &[&str],
+    directed: bool,
+) -> Result<Graph> {
+    build_graph_from_weighted_edges_and_singletons(
+        &edges
+            .iter()
+            .map(|&(src, dst)| (src, dst, None))
+            .collect::<Vec<_>>(),
+        singletons,
+        directed,
+    )
+}
+
+/// Return the graph with the given edges, built from the node names.
+///
+/// # Arguments
+/// * `edges`: &[(&str, &str)] - The source and destination names of the edges.
+/// * `directed`: bool - Whether the graph is directed.
+pub fn build_graph_from_edges(edges: &[(&str, &str)], directed: bool) -> Result<Graph> {
+    build_graph_from_edges_and_singletons(edges, &[], directed)
+}
+
 /// Return WalksParameters to execute a first order walk.
 pub fn first_order_walker() -> Result<WalksParameters> {
     Ok(WalksParameters::new(8)?
@@ -1488,7 +1551,16 @@ pub fn test_random_walks(graph: &mut Graph, _verbose: Option<bool>) -> Result<()
 pub fn test_edge_holdouts(graph: &Graph, verbose: Option<bool>) -> Result<()> {
     if !graph.has_edge_types() {
         assert!(graph
-            .connected_holdout(0.8, None, Some(&[None]), Some(false), None, None, None)
+            .connected_holdout(
+                0.8,
+                None,
+                Some(&[None]),
+                Some(false),
+                None,
+                None,
+                None,
+                None
+            )
             .is_err());
     }
     for include_all_edge_types in &[false, true] {
@@ -1508,6 +1580,7 @@ pub fn test_edge_holdouts(graph: &Graph, verbose: Option<bool>) -> Result<()> {
             Some(*include_all_edge_types),
             None,
             None,
+            None,
             verbose,
         )?;
         assert_eq!(graph.get_number_of_nodes(), train.get_number_of_nodes());
@@ -1811,6 +1884,8 @@ pub fn test_negative_edges_generation(graph: &mut Graph, verbose: Option<bool>)
             None,
             None,
             None,
+            None,
+            None,
         )?;
         assert_eq!(
             graph.get_number_of_edges(),
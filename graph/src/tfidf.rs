@@ -227,3 +227,69 @@ pub fn get_okapi_bm25_tfidf_from_documents<T: Eq + Hash + Send + Sync + Clone +
             .collect::<Vec<HashMap<T, f32>>>(),
     )
 }
+
+/// # TF-IDF edge weighting
+impl Graph {
+    /// Returns graph with the same edges reweighted by their Okapi BM25 TF-IDF score.
+    ///
+    /// # Arguments
+    /// * `k1`: Option<f32> - The default parameter for k1, tipically between 1.2 and 2.0. By default, 1.5.
+    /// * `b`: Option<f32> - The default parameter for b, tipically equal to 0.75. By default, 0.75.
+    /// * `verbose`: Option<bool> - Whether to show a loading bar. By default, true.
+    ///
+    /// # Implementative details
+    /// Each source node is treated as a document, whose words are the
+    /// multiset of its destination nodes. Given a source node `s` with
+    /// degree `|s|`, a destination node `d` appearing `c(s, d)` times among
+    /// its neighbours, the average degree `avgdl` and the number of nodes `N`
+    /// of which `n(d)` have `d` as a neighbour, the weight of the edge is:
+    ///
+    /// * TF: `tf = c(s, d) / |s|`, adjusted as `tf * (k1 + 1) / (tf + k1 * (1 - b + b * |s| / avgdl))`.
+    /// * IDF: `idf = ln(1 + (N - n(d) + 0.5) / (n(d) + 0.5))`.
+    ///
+    /// and the edge weight is the product of the adjusted TF and the IDF.
+    /// Edges towards ubiquitous hub nodes are therefore downweighted.
+    ///
+    /// Since the score is not symmetric, on undirected graphs the weight of
+    /// each edge is the mean of the scores of its two directions, so that
+    /// the resulting weights remain symmetric.
+    ///
+    /// The node and edge types, if any, are preserved, while the original
+    /// edge weights, if any, are replaced.
+    ///
+    /// # Raises
+    /// * If the graph does not have edges.
+    pub fn get_tfidf_reweighted_graph(
+        &self,
+        k1: Option<f32>,
+        b: Option<f32>,
+        verbose: Option<bool>,
+    ) -> Result<Graph> {
+        self.must_have_edges()?;
+        let documents = self
+            .par_iter_node_ids()
+            .map(|node_id| unsafe {
+                self.iter_unchecked_neighbour_node_ids_from_source_node_id(node_id)
+                    .collect::<Vec<NodeT>>()
+            })
+            .collect::<Vec<Vec<NodeT>>>();
+        let scores = get_okapi_bm25_tfidf_from_documents(&documents, k1, b, verbose)?;
+        let directed = self.is_directed();
+        let weights = self
+            .par_iter_directed_edge_node_ids()
+            .map(|(_, src, dst)| {
+                let score = scores[src as usize][&dst];
+                if directed {
+                    score
+                } else {
+                    (score + scores[dst as usize][&src]) / 2.0
+                }
+            })
+            .collect::<Vec<WeightT>>();
+        let mut graph = self.clone();
+        // The cache is shared between clones, so the new graph needs its own.
+        graph.cache = Arc::new(ClonableUnsafeCell::default());
+        graph.weights = Arc::new(Some(weights));
+        Ok(graph)
+    }
+}
@@ -3,6 +3,9 @@ use crate::constructors::build_graph_from_integers;
 use super::*;
 use indicatif::ParallelProgressIterator;
 use num_traits::Pow;
+use rand::rngs::SmallRng;
+use rand::Rng;
+use rand::SeedableRng;
 use rayon::prelude::*;
 use std::convert::TryFrom;
 
@@ -211,4 +214,85 @@ impl Graph {
             self.get_name(),
         )
     }
+
+    /// Returns graph with gaussian noise added to the edge weights.
+    ///
+    /// # Arguments
+    /// * `standard_deviation`: f64 - The standard deviation of the gaussian noise to add to the weights.
+    /// * `random_state`: u64 - The random state to use to reproduce the noise.
+    /// * `clip_negative`: Option<bool> - Whether to clamp to zero the weights that become negative. By default, true.
+    ///
+    /// # Implementative details
+    /// The noise of each edge is sampled with the Box-Muller transform from a
+    /// random generator seeded from the random state and the edge itself,
+    /// so the result does not depend on the number of threads. In undirected
+    /// graphs the two directions of an edge receive the same noise, so that the
+    /// resulting weights remain symmetric.
+    ///
+    /// # Raises
+    /// * If the graph does not have edge weights.
+    /// * If the standard deviation is not a finite non-negative value.
+    pub fn add_weight_noise(
+        &self,
+        standard_deviation: f64,
+        random_state: u64,
+        clip_negative: Option<bool>,
+    ) -> Result<Graph> {
+        self.must_have_edge_weights()?;
+        if !standard_deviation.is_finite() || standard_deviation < 0.0 {
+            return Err(format!(
+                concat!(
+                    "The given standard deviation {} must be ",
+                    "a finite non-negative value."
+                ),
+                standard_deviation
+            ));
+        }
+        let clip_negative = clip_negative.unwrap_or(true);
+        let random_state = splitmix64(random_state);
+
+        build_graph_from_integers(
+            Some(
+                self.par_iter_directed_edge_node_ids_and_edge_type_id_and_edge_weight()
+                    .map(|(_, src, dst, edge_type_id, weight)| {
+                        let (first, second) = if self.is_directed() {
+                            (src, dst)
+                        } else {
+                            (src.min(dst), src.max(dst))
+                        };
+                        let mut rng = SmallRng::seed_from_u64(splitmix64(
+                            random_state
+                                ^ splitmix64(((first as u64) << 32) | second as u64)
+                                ^ edge_type_id.map_or(0, |edge_type_id| edge_type_id as u64 + 1),
+                        ));
+                        // We sample from the open interval (0, 1] to avoid the logarithm of zero.
+                        let first_uniform = 1.0 - rng.gen::<f64>();
+                        let second_uniform = rng.gen::<f64>();
+                        let noise = (-2.0 * first_uniform.ln()).sqrt()
+                            * (2.0 * std::f64::consts::PI * second_uniform).cos()
+                            * standard_deviation;
+                        let mut weight = (weight.unwrap() as f64 + noise) as WeightT;
+                        if clip_negative && weight < 0.0 {
+                            weight = 0.0;
+                        }
+                        (0, (src, dst, edge_type_id, weight))
+                    }),
+            ),
+            self.nodes.clone(),
+            self.node_types.clone(),
+            self.edge_types
+                .as_ref()
+                .as_ref()
+                .map(|ets| ets.vocabulary.clone()),
+            true,
+            self.is_directed(),
+            Some(true),
+            Some(false),
+            Some(false),
+            Some(self.get_number_of_directed_edges()),
+            self.has_singleton_nodes(),
+            self.has_singleton_nodes_with_selfloops(),
+            self.get_name(),
+        )
+    }
 }
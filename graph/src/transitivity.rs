@@ -2,7 +2,8 @@ use crate::constructors::build_graph_from_integers;
 
 use super::*;
 use indicatif::ParallelProgressIterator;
-use rayon::iter::ParallelIterator;
+use rayon::prelude::*;
+use roaring::RoaringBitmap;
 
 /// # Transitivity.
 impl Graph {
@@ -83,6 +84,149 @@ impl Graph {
         .unwrap()
     }
 
+    /// Returns the node IDs of the directed acyclic graph in topological order.
+    ///
+    /// # Implementative details
+    /// The topological order is computed with Kahn's algorithm: the nodes
+    /// without incoming edges come first, and each other node follows once
+    /// all of its predecessors have been added.
+    ///
+    /// # Safety
+    /// If the graph is not a directed acyclic graph, the nodes within
+    /// or downstream of cycles will not appear in the returned order.
+    pub(crate) unsafe fn get_unchecked_directed_acyclic_topological_order(&self) -> Vec<NodeT> {
+        let mut indegrees = self.get_node_indegrees();
+        let mut topological_order = self
+            .iter_node_ids()
+            .filter(|&node_id| indegrees[node_id as usize] == 0)
+            .collect::<Vec<NodeT>>();
+        let mut position = 0;
+        while position < topological_order.len() {
+            let src = topological_order[position];
+            position += 1;
+            self.iter_unchecked_neighbour_node_ids_from_source_node_id(src)
+                .for_each(|dst| {
+                    indegrees[dst as usize] -= 1;
+                    if indegrees[dst as usize] == 0 {
+                        topological_order.push(dst);
+                    }
+                });
+        }
+        topological_order
+    }
+
+    /// Returns the descendants of each node of the directed acyclic graph as bitmaps.
+    ///
+    /// # Implementative details
+    /// The nodes are visited in reverse topological order, and the set of
+    /// descendants of each node is the union of its successors and of
+    /// their own sets of descendants. A node is not a descendant of itself.
+    ///
+    /// # Safety
+    /// If the graph is not a directed acyclic graph, the nodes within
+    /// or downstream of cycles will have empty descendants.
+    pub(crate) unsafe fn get_unchecked_directed_acyclic_descendants(&self) -> Vec<RoaringBitmap> {
+        let topological_order = self.get_unchecked_directed_acyclic_topological_order();
+
+        let mut descendants = vec![RoaringBitmap::new(); self.get_number_of_nodes() as usize];
+        topological_order.into_iter().rev().for_each(|src| {
+            let mut src_descendants = RoaringBitmap::new();
+            self.iter_unchecked_neighbour_node_ids_from_source_node_id(src)
+                .for_each(|dst| {
+                    src_descendants.insert(dst);
+                    src_descendants |= &descendants[dst as usize];
+                });
+            descendants[src as usize] = src_descendants;
+        });
+
+        descendants
+    }
+
+    /// Returns the transitive closure of the directed acyclic graph.
+    ///
+    /// The returned graph has an edge from a node to each of its descendants,
+    /// so that ancestor and descendant queries can be answered with `has_edge_from_node_ids`.
+    ///
+    /// # Arguments
+    /// * `maximum_number_of_nodes`: Option<NodeT> - The maximum number of nodes of the graph, as the closure may be quadratic in size. By default, 100000.
+    ///
+    /// # Implementative details
+    /// The nodes are visited in reverse topological order, and the set of
+    /// descendants of each node is the union of its successors and of
+    /// their own sets of descendants, which are stored as bitmaps.
+    /// The returned graph has no edge types nor edge weights.
+    ///
+    /// # Raises
+    /// * If the graph is not a directed acyclic graph.
+    /// * If the graph has more nodes than the provided maximum number of nodes.
+    pub fn get_directed_acyclic_transitive_closure(
+        &self,
+        maximum_number_of_nodes: Option<NodeT>,
+    ) -> Result<Graph> {
+        self.must_be_directed_acyclic()?;
+        let maximum_number_of_nodes = maximum_number_of_nodes.unwrap_or(100_000);
+        if self.get_number_of_nodes() > maximum_number_of_nodes {
+            return Err(format!(
+                concat!(
+                    "The graph has {} nodes, which is more than the provided ",
+                    "maximum number of nodes {}."
+                ),
+                self.get_number_of_nodes(),
+                maximum_number_of_nodes
+            ));
+        }
+
+        let descendants = unsafe { self.get_unchecked_directed_acyclic_descendants() };
+
+        let number_of_edges = descendants
+            .iter()
+            .map(|bitmap| bitmap.len() as EdgeT)
+            .sum::<EdgeT>();
+
+        build_graph_from_integers(
+            Some(
+                descendants
+                    .par_iter()
+                    .enumerate()
+                    .flat_map_iter(|(src, bitmap)| {
+                        bitmap
+                            .iter()
+                            .map(move |dst| (0, (src as NodeT, dst, None, WeightT::NAN)))
+                    }),
+            ),
+            self.nodes.clone(),
+            self.node_types.clone(),
+            None,
+            false,
+            true,
+            Some(true),
+            Some(false),
+            Some(true),
+            Some(number_of_edges),
+            self.has_singleton_nodes(),
+            false,
+            format!("{} transitive closure", self.get_name()),
+        )
+    }
+
+    /// Returns the number of descendants of each node of the directed acyclic graph.
+    ///
+    /// # Implementative details
+    /// The descendants of each node are computed exactly as bitmaps, visiting the
+    /// nodes in reverse topological order, and a node is not a descendant of itself.
+    /// As the bitmaps may require memory quadratic in the number of nodes, on large
+    /// graphs consider `get_approximated_number_of_descendants_per_node` instead.
+    ///
+    /// # Raises
+    /// * If the graph is not a directed acyclic graph.
+    pub fn get_number_of_descendants_per_node(&self) -> Result<Vec<NodeT>> {
+        self.must_be_directed_acyclic()?;
+        Ok(unsafe { self.get_unchecked_directed_acyclic_descendants() }
+            .into_par_iter()
+            .map(|descendants| descendants.len() as NodeT)
+            .collect())
+    }
+
     #[fuzz_type(iterations: Option<u8>)]
     /// Returns graph with unweighted shortest paths computed up to the given depth.
     ///
@@ -275,4 +419,91 @@ impl Graph {
             self.get_name(),
         )
     }
+
+    /// Returns the local clustering coefficient of each node.
+    ///
+    /// # Arguments
+    /// * `verbose`: Option<bool> - Whether to show a loading bar. By default, True.
+    ///
+    /// # Implementative details
+    /// The local clustering coefficient of a node is the fraction of the pairs of
+    /// its neighbours that are connected, that is `2 * triangles(i) / (deg(i) * (deg(i) - 1))`,
+    /// and it is zero for the nodes with degree smaller than two. The degree is the
+    /// number of unique neighbours, hence selfloops and multi-edges are ignored.
+    /// The triangles of each node are counted in parallel by intersecting its sorted
+    /// neighbours with the sorted neighbours of each of its neighbours.
+    ///
+    /// Differently from `get_clustering_coefficient_per_node`, which relies on the
+    /// vertex cover based triangle counting, this method directly counts the
+    /// triangles of each node.
+    ///
+    /// # Example
+    ///```rust
+    /// # let graph = graph::test_utilities::load_ppi(false, false, false, false, false, false);
+    /// let coefficients = graph.get_local_clustering_coefficients(Some(false)).unwrap();
+    /// assert!(coefficients.iter().all(|&coefficient| (0.0..=1.0).contains(&coefficient)));
+    /// ```
+    ///
+    /// # Raises
+    /// * If the graph is directed.
+    pub fn get_local_clustering_coefficients(&self, verbose: Option<bool>) -> Result<Vec<f64>> {
+        self.must_be_undirected()?;
+        let verbose = verbose.unwrap_or(true);
+        let pb = get_loading_bar(
+            verbose,
+            "Computing local clustering coefficients",
+            self.get_number_of_nodes() as usize,
+        );
+        Ok(self
+            .par_iter_node_ids()
+            .progress_with(pb)
+            .map(|node_id| unsafe {
+                let neighbours = self
+                    .iter_unchecked_unique_neighbour_node_ids_from_source_node_id(node_id)
+                    .filter(|&neighbour_node_id| neighbour_node_id != node_id)
+                    .collect::<Vec<NodeT>>();
+                let degree = neighbours.len() as f64;
+                if neighbours.len() < 2 {
+                    return 0.0;
+                }
+                // Each triangle is counted once from each of its two other nodes.
+                let twice_triangles = neighbours
+                    .iter()
+                    .map(|&neighbour_node_id| {
+                        iter_set::intersection(
+                            neighbours.iter().copied(),
+                            self.iter_unchecked_unique_neighbour_node_ids_from_source_node_id(
+                                neighbour_node_id,
+                            ),
+                        )
+                        .filter(|&third_node_id| third_node_id != neighbour_node_id)
+                        .count()
+                    })
+                    .sum::<usize>() as f64;
+                twice_triangles / (degree * (degree - 1.0))
+            })
+            .collect())
+    }
+
+    /// Returns the average of the local clustering coefficients of the nodes.
+    ///
+    /// # Arguments
+    /// * `verbose`: Option<bool> - Whether to show a loading bar. By default, True.
+    ///
+    /// # Implementative details
+    /// The average is computed over all the nodes, including the ones with degree
+    /// smaller than two, whose local clustering coefficient is zero. Note that this
+    /// differs from the transitivity of the graph, which is the ratio between the
+    /// closed and all the triads of the whole graph.
+    /// The name `get_average_clustering_coefficient` is already used by the method
+    /// relying on the vertex cover based triangle counting.
+    ///
+    /// # Raises
+    /// * If the graph is directed.
+    /// * If the graph has no nodes.
+    pub fn get_average_local_clustering_coefficient(&self, verbose: Option<bool>) -> Result<f64> {
+        self.must_have_nodes()?;
+        let coefficients = self.get_local_clustering_coefficients(verbose)?;
+        Ok(coefficients.par_iter().sum::<f64>() / coefficients.len() as f64)
+    }
 }
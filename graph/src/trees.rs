@@ -1,9 +1,12 @@
 use super::*;
 
 use indicatif::ProgressIterator;
+use rayon::iter::IndexedParallelIterator;
 use rayon::iter::IntoParallelIterator;
 use rayon::iter::IntoParallelRefMutIterator;
 use rayon::iter::ParallelIterator;
+use rayon::slice::ParallelSliceMut;
+use std::collections::VecDeque;
 use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
 use std::sync::{Arc, Mutex};
 use std::{collections::HashSet, sync::atomic::AtomicU32};
@@ -521,6 +524,102 @@ impl Graph {
         Ok(parents)
     }
 
+    /// Extends the provided edge IDs with the ones of a BFS spanning tree rooted in the given node.
+    ///
+    /// # Arguments
+    /// * `root_node_id`: NodeT - The root of the BFS spanning tree.
+    /// * `visited`: &mut [bool] - The nodes already visited, which are not explored again.
+    /// * `edge_ids`: &mut Vec<EdgeT> - The vector to extend with the edge IDs of the tree.
+    ///
+    /// # Safety
+    /// If the given root node ID does not exist in the graph the method will panic.
+    unsafe fn extend_unchecked_bfs_spanning_tree_from_node_id(
+        &self,
+        root_node_id: NodeT,
+        visited: &mut [bool],
+        edge_ids: &mut Vec<EdgeT>,
+    ) {
+        let mut frontier = VecDeque::new();
+        visited[root_node_id as usize] = true;
+        frontier.push_back(root_node_id);
+        while let Some(src) = frontier.pop_front() {
+            self.iter_unchecked_edge_ids_from_source_node_id(src)
+                .zip(self.iter_unchecked_neighbour_node_ids_from_source_node_id(src))
+                .for_each(|(edge_id, dst)| {
+                    if !visited[dst as usize] {
+                        visited[dst as usize] = true;
+                        edge_ids.push(edge_id as EdgeT);
+                        frontier.push_back(dst);
+                    }
+                });
+        }
+    }
+
+    /// Returns the edge IDs of a BFS spanning tree rooted in the given node.
+    ///
+    /// The tree only covers the nodes reachable from the root, that is
+    /// the connected component of the root for undirected graphs. Use
+    /// `get_bfs_spanning_forest` to cover all the nodes of the graph.
+    /// Each returned edge ID is the directed edge going from the parent
+    /// node towards the child node.
+    ///
+    /// # Arguments
+    /// * `root_node_id`: NodeT - The root of the BFS spanning tree.
+    ///
+    /// # Example
+    /// To compute the BFS spanning tree rooted in the first node you can use:
+    /// ```rust
+    /// # let graph = graph::test_utilities::load_ppi(true, true, true, true, false, false);
+    /// let tree_edge_ids = graph.get_bfs_spanning_tree_from_node_id(0).unwrap();
+    /// assert!(tree_edge_ids.len() < graph.get_number_of_nodes() as usize);
+    /// ```
+    ///
+    /// # Raises
+    /// * If the given root node ID does not exist in the graph.
+    pub fn get_bfs_spanning_tree_from_node_id(&self, root_node_id: NodeT) -> Result<Vec<EdgeT>> {
+        self.validate_node_id(root_node_id)?;
+        let mut visited = vec![false; self.get_number_of_nodes() as usize];
+        let mut edge_ids = Vec::new();
+        unsafe {
+            self.extend_unchecked_bfs_spanning_tree_from_node_id(
+                root_node_id,
+                &mut visited,
+                &mut edge_ids,
+            )
+        };
+        Ok(edge_ids)
+    }
+
+    /// Returns the edge IDs of a BFS spanning forest covering all the nodes of the graph.
+    ///
+    /// The roots of the trees are chosen as the nodes with the smallest
+    /// node ID not yet covered by any of the previous trees. For directed
+    /// graphs, the trees follow the outbound edges.
+    ///
+    /// # Example
+    /// To compute the BFS spanning forest you can use:
+    /// ```rust
+    /// # let graph = graph::test_utilities::load_ppi(true, true, true, true, false, false);
+    /// let forest_edge_ids = graph.get_bfs_spanning_forest();
+    /// assert!(forest_edge_ids.len() < graph.get_number_of_nodes() as usize);
+    /// ```
+    pub fn get_bfs_spanning_forest(&self) -> Vec<EdgeT> {
+        let mut visited = vec![false; self.get_number_of_nodes() as usize];
+        let mut edge_ids = Vec::new();
+        self.iter_node_ids().for_each(|node_id| {
+            if !visited[node_id as usize] {
+                unsafe {
+                    self.extend_unchecked_bfs_spanning_tree_from_node_id(
+                        node_id,
+                        &mut visited,
+                        &mut edge_ids,
+                    )
+                };
+            }
+        });
+        edge_ids
+    }
+
     /// Compute the connected components building in parallel a spanning tree using [bader's algorithm](https://www.sciencedirect.com/science/article/abs/pii/S0743731505000882).
     ///
     /// **This works only for undirected graphs.**
@@ -724,4 +823,120 @@ impl Graph {
             max_component_size,
         ))
     }
+
+    /// Returns the natural logarithm of the number of spanning trees of the graph.
+    ///
+    /// # Arguments
+    /// * `maximum_number_of_nodes`: Option<NodeT> - The maximum number of nodes of the graph for the count to be computed. By default, 1000.
+    ///
+    /// # Implementative details
+    /// By Kirchhoff's matrix-tree theorem, the number of spanning trees is equal
+    /// to the determinant of the Laplacian matrix without the row and the column
+    /// of any node, which is computed on the dense reduced Laplacian matrix with the
+    /// Cholesky decomposition, as the matrix is symmetric and positive definite
+    /// for connected graphs. The logarithm of the determinant is the sum of the
+    /// logarithms of the squared diagonal of the Cholesky factor, which does not
+    /// overflow even when the count does.
+    ///
+    /// Parallel edges are counted with their multiplicity, that is spanning trees
+    /// using different parallel edges are considered distinct, while selfloops and
+    /// edge weights are ignored. When the graph is not connected, there are no
+    /// spanning trees and negative infinity is returned.
+    ///
+    /// # Raises
+    /// * If the graph does not have nodes.
+    /// * If the graph is directed.
+    /// * If the graph has more nodes than the provided maximum.
+    pub fn get_log_number_of_spanning_trees(
+        &self,
+        maximum_number_of_nodes: Option<NodeT>,
+    ) -> Result<f64> {
+        self.must_have_nodes()?;
+        self.must_be_undirected()?;
+        let maximum_number_of_nodes = maximum_number_of_nodes.unwrap_or(1000);
+        if self.get_number_of_nodes() > maximum_number_of_nodes {
+            return Err(format!(
+                concat!(
+                    "The current graph has {} nodes, more than the maximum number of nodes {} ",
+                    "for which the number of spanning trees is computed on the dense Laplacian matrix."
+                ),
+                self.get_number_of_nodes(),
+                maximum_number_of_nodes
+            ));
+        }
+        if !self.is_connected(Some(false)) {
+            return Ok(f64::NEG_INFINITY);
+        }
+
+        // We build the Laplacian matrix without the row and column of the last node.
+        let size = self.get_number_of_nodes() as usize - 1;
+        let mut matrix = vec![0.0; size * size];
+        matrix
+            .par_chunks_mut(size.max(1))
+            .enumerate()
+            .for_each(|(src, row)| {
+                unsafe { self.iter_unchecked_neighbour_node_ids_from_source_node_id(src as NodeT) }
+                    .filter(|&dst| dst as usize != src)
+                    .for_each(|dst| {
+                        row[src] += 1.0;
+                        if (dst as usize) < size {
+                            row[dst as usize] -= 1.0;
+                        }
+                    });
+            });
+
+        // We compute the lower triangular Cholesky factor in place.
+        let mut log_determinant = 0.0;
+        for column in 0..size {
+            let diagonal = matrix[column * size + column]
+                - (0..column)
+                    .map(|k| matrix[column * size + k].powi(2))
+                    .sum::<f64>();
+            if diagonal <= 0.0 {
+                return Ok(f64::NEG_INFINITY);
+            }
+            let diagonal = diagonal.sqrt();
+            matrix[column * size + column] = diagonal;
+            log_determinant += 2.0 * diagonal.ln();
+            for row in column + 1..size {
+                let value = matrix[row * size + column]
+                    - (0..column)
+                        .map(|k| matrix[row * size + k] * matrix[column * size + k])
+                        .sum::<f64>();
+                matrix[row * size + column] = value / diagonal;
+            }
+        }
+        Ok(log_determinant)
+    }
+
+    /// Returns the number of spanning trees of the graph.
+    ///
+    /// # Arguments
+    /// * `maximum_number_of_nodes`: Option<NodeT> - The maximum number of nodes of the graph for the count to be computed. By default, 1000.
+    ///
+    /// # Implementative details
+    /// The count is the exponential of `get_log_number_of_spanning_trees`, hence
+    /// it is subject to floating point rounding and it is infinite when it does not
+    /// fit in a 64-bit float: use the logarithm of the count for large graphs.
+    /// When the graph is not connected, there are no spanning trees and zero is returned.
+    ///
+    /// # Example
+    ///```rust
+    /// # let graph = graph::test_utilities::load_ppi(true, true, true, true, false, false);
+    /// // The PPI graph is not connected.
+    /// assert_eq!(graph.get_number_of_spanning_trees(Some(graph.get_number_of_nodes())).unwrap(), 0.0);
+    /// ```
+    ///
+    /// # Raises
+    /// * If the graph does not have nodes.
+    /// * If the graph is directed.
+    /// * If the graph has more nodes than the provided maximum.
+    pub fn get_number_of_spanning_trees(
+        &self,
+        maximum_number_of_nodes: Option<NodeT>,
+    ) -> Result<f64> {
+        Ok(self
+            .get_log_number_of_spanning_trees(maximum_number_of_nodes)?
+            .exp())
+    }
 }
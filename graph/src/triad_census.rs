@@ -859,6 +859,158 @@ impl Graph {
         }
     }
 
+    /// Returns the undirected triads of the graph, grouped by their number of edges.
+    ///
+    /// # Implementative details
+    /// The triads are listed by checking all the triples of nodes, hence
+    /// this method should only be used on small graphs.
+    fn get_undirected_triads(&self) -> Vec<Vec<[NodeT; 3]>> {
+        let number_of_nodes = self.get_number_of_nodes();
+        self.par_iter_node_ids()
+            .map(|first| {
+                let mut triads = vec![Vec::new(); 4];
+                for second in first + 1..number_of_nodes {
+                    let first_to_second = self.has_edge_from_node_ids(first, second) as usize;
+                    for third in second + 1..number_of_nodes {
+                        let number_of_edges = first_to_second
+                            + self.has_edge_from_node_ids(first, third) as usize
+                            + self.has_edge_from_node_ids(second, third) as usize;
+                        triads[number_of_edges].push([first, second, third]);
+                    }
+                }
+                triads
+            })
+            .reduce(
+                || vec![Vec::new(); 4],
+                |mut a, b| {
+                    a.iter_mut()
+                        .zip(b.into_iter())
+                        .for_each(|(a, b)| a.extend(b));
+                    a
+                },
+            )
+    }
+
+    /// Returns the undirected triad census and, optionally, the triads of each type.
+    ///
+    /// # Arguments
+    /// * `list_triads`: Option<bool> - Whether to also return the node IDs of the triads of each type. By default, false.
+    /// * `maximum_number_of_nodes_for_listing`: Option<NodeT> - The maximum number of nodes of the graph for listing the triads. By default, 100.
+    ///
+    /// # Implementative details
+    /// The census counts the triples of distinct nodes with zero, one, two and three
+    /// edges among them, in this order. Selfloops and multi-edges are ignored.
+    /// The triangles and the triads with one edge are counted by intersecting the
+    /// neighbours of the two nodes of each edge, while the triads with two edges
+    /// are obtained from the number of pairs of neighbours of each node, and the
+    /// empty triads from the total number of triples.
+    /// When requested, the triads are returned as four vectors of sorted node ID
+    /// triples, one for each type, in the same order of the census. Since the number
+    /// of triads grows cubically with the number of nodes, they can only be listed
+    /// for graphs with at most the given maximum number of nodes.
+    ///
+    /// # Example
+    ///```rust
+    /// # let graph = graph::test_utilities::load_ppi(false, false, false, false, false, false);
+    /// let (census, triads) = graph.get_undirected_triad_census(None, None).unwrap();
+    /// let number_of_nodes = graph.get_number_of_nodes() as u64;
+    /// assert_eq!(
+    ///     census.iter().sum::<u64>(),
+    ///     number_of_nodes * (number_of_nodes - 1) * (number_of_nodes - 2) / 6
+    /// );
+    /// assert!(triads.is_none());
+    /// ```
+    ///
+    /// # Raises
+    /// * If the graph is directed.
+    /// * If the triads are to be listed and the graph has more nodes than the given maximum.
+    pub fn get_undirected_triad_census(
+        &self,
+        list_triads: Option<bool>,
+        maximum_number_of_nodes_for_listing: Option<NodeT>,
+    ) -> Result<([u64; 4], Option<Vec<Vec<[NodeT; 3]>>>)> {
+        self.must_be_undirected()?;
+        let list_triads = list_triads.unwrap_or(false);
+        let maximum_number_of_nodes_for_listing =
+            maximum_number_of_nodes_for_listing.unwrap_or(100);
+        if list_triads && self.get_number_of_nodes() > maximum_number_of_nodes_for_listing {
+            return Err(format!(
+                concat!(
+                    "The triads can only be listed for graphs with at most {} nodes, ",
+                    "but the current graph has {} nodes. You can raise the maximum number ",
+                    "of nodes for listing, but the number of triads grows cubically."
+                ),
+                maximum_number_of_nodes_for_listing,
+                self.get_number_of_nodes()
+            ));
+        }
+
+        let number_of_nodes = self.get_number_of_nodes() as u64;
+        let node_degrees = self
+            .par_iter_node_ids()
+            .map(|node_id| unsafe {
+                self.iter_unchecked_unique_neighbour_node_ids_from_source_node_id(node_id)
+                    .filter(|&neighbour_node_id| neighbour_node_id != node_id)
+                    .count() as u64
+            })
+            .collect::<Vec<u64>>();
+
+        let (number_of_triangles, number_of_one_edge_triads) = self
+            .par_iter_node_ids()
+            .flat_map(|first| unsafe {
+                self.iter_unchecked_unique_neighbour_node_ids_from_source_node_id(first)
+                    .filter(move |&second| first < second)
+                    .map(move |second| (first, second))
+                    .collect::<Vec<(NodeT, NodeT)>>()
+            })
+            .map(|(first, second)| unsafe {
+                let (number_of_shared_neighbours, number_of_triangles) = iter_set::intersection(
+                    self.iter_unchecked_unique_neighbour_node_ids_from_source_node_id(first),
+                    self.iter_unchecked_unique_neighbour_node_ids_from_source_node_id(second),
+                )
+                .filter(|&third| third != first && third != second)
+                .fold((0, 0), |(shared, triangles), third| {
+                    (shared + 1, triangles + (third > second) as u64)
+                });
+                // The third nodes connected to neither of the nodes of the edge.
+                let number_of_one_edge_triads = number_of_nodes + number_of_shared_neighbours
+                    - node_degrees[first as usize]
+                    - node_degrees[second as usize];
+                (number_of_triangles, number_of_one_edge_triads)
+            })
+            .reduce(|| (0, 0), |(a, b), (c, d)| (a + c, b + d));
+
+        let number_of_two_edges_triads = node_degrees
+            .par_iter()
+            .map(|&degree| degree * degree.saturating_sub(1) / 2)
+            .sum::<u64>()
+            - 3 * number_of_triangles;
+
+        let number_of_triads = (number_of_nodes as u128
+            * number_of_nodes.saturating_sub(1) as u128
+            * number_of_nodes.saturating_sub(2) as u128
+            / 6) as u64;
+
+        let census = [
+            number_of_triads
+                - number_of_one_edge_triads
+                - number_of_two_edges_triads
+                - number_of_triangles,
+            number_of_one_edge_triads,
+            number_of_two_edges_triads,
+            number_of_triangles,
+        ];
+
+        Ok((
+            census,
+            if list_triads {
+                Some(self.get_undirected_triads())
+            } else {
+                None
+            },
+        ))
+    }
+
     // unsafe fn get_unchecked_triad_census_from_node_id(
     //     &self,
     //     node_id: NodeT
@@ -0,0 +1,277 @@
+use super::*;
+use rayon::prelude::*;
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicU32, Ordering};
+use vec_rand::splitmix64;
+
+/// Residual network where each node is split into an inbound and an outbound copy.
+///
+/// The node `v` is split into the inbound copy `2v` and the outbound copy
+/// `2v + 1`, connected by an arc with unit capacity, so that the maximum flow
+/// between the outbound copy of a source and the inbound copy of a destination
+/// is the maximum number of internally vertex-disjoint paths between them.
+/// Each arc with ID `2i` has its reverse arc with ID `2i + 1`.
+struct VertexSplitNetwork {
+    /// Offsets of the arcs leaving each split node in `arc_ids`.
+    offsets: Vec<usize>,
+    /// Arc IDs sorted by their tail.
+    arc_ids: Vec<usize>,
+    /// The head of each arc.
+    heads: Vec<usize>,
+}
+
+impl VertexSplitNetwork {
+    /// Returns the split network of the given undirected graph.
+    ///
+    /// # Arguments
+    /// * `graph`: &Graph - The graph whose nodes are to be split.
+    fn new(graph: &Graph) -> VertexSplitNetwork {
+        let number_of_nodes = graph.get_number_of_nodes() as usize;
+        let mut arcs = (0..number_of_nodes)
+            .map(|node_id| (2 * node_id, 2 * node_id + 1))
+            .collect::<Vec<(usize, usize)>>();
+        graph.iter_node_ids().for_each(|src| {
+            arcs.extend(
+                unsafe { graph.iter_unchecked_unique_neighbour_node_ids_from_source_node_id(src) }
+                    .filter(|&dst| dst != src)
+                    .map(|dst| (2 * src as usize + 1, 2 * dst as usize)),
+            );
+        });
+        let mut heads = vec![0; 2 * arcs.len()];
+        let mut offsets = vec![0; 2 * number_of_nodes + 1];
+        arcs.iter().enumerate().for_each(|(arc_id, &(tail, head))| {
+            heads[2 * arc_id] = head;
+            heads[2 * arc_id + 1] = tail;
+            offsets[tail + 1] += 1;
+            offsets[head + 1] += 1;
+        });
+        for split_node_id in 0..2 * number_of_nodes {
+            offsets[split_node_id + 1] += offsets[split_node_id];
+        }
+        let mut next_positions = offsets.clone();
+        let mut arc_ids = vec![0; heads.len()];
+        arcs.iter().enumerate().for_each(|(arc_id, &(tail, head))| {
+            arc_ids[next_positions[tail]] = 2 * arc_id;
+            next_positions[tail] += 1;
+            arc_ids[next_positions[head]] = 2 * arc_id + 1;
+            next_positions[head] += 1;
+        });
+        VertexSplitNetwork {
+            offsets,
+            arc_ids,
+            heads,
+        }
+    }
+
+    /// Returns the number of internally vertex-disjoint paths between the two nodes, up to the given limit.
+    ///
+    /// # Arguments
+    /// * `src`: NodeT - The source node ID.
+    /// * `dst`: NodeT - The destination node ID, which must not be adjacent to the source.
+    /// * `limit`: NodeT - The number of paths after which the search is stopped.
+    /// * `flows`: &mut [i8] - Buffer for the flow on each arc.
+    /// * `parent_arcs`: &mut [usize] - Buffer for the arc used to reach each split node.
+    /// * `frontier`: &mut VecDeque<usize> - Buffer for the breadth first search.
+    ///
+    /// # Implementative details
+    /// This is the Ford-Fulkerson algorithm with breadth first search augmenting
+    /// paths, where each augmenting path increases the flow by one.
+    fn get_local_vertex_connectivity(
+        &self,
+        src: NodeT,
+        dst: NodeT,
+        limit: NodeT,
+        flows: &mut [i8],
+        parent_arcs: &mut [usize],
+        frontier: &mut VecDeque<usize>,
+    ) -> NodeT {
+        let source = 2 * src as usize + 1;
+        let sink = 2 * dst as usize;
+        flows.iter_mut().for_each(|flow| *flow = 0);
+        let mut number_of_paths = 0;
+        while number_of_paths < limit {
+            parent_arcs
+                .iter_mut()
+                .for_each(|parent_arc| *parent_arc = usize::MAX);
+            parent_arcs[source] = usize::MAX - 1;
+            frontier.clear();
+            frontier.push_back(source);
+            'search: while let Some(split_node_id) = frontier.pop_front() {
+                for &arc_id in
+                    &self.arc_ids[self.offsets[split_node_id]..self.offsets[split_node_id + 1]]
+                {
+                    // The forward arcs have unit capacity and the reverse ones have none.
+                    let capacity = 1 - (arc_id % 2) as i8;
+                    let head = self.heads[arc_id];
+                    if flows[arc_id] < capacity && parent_arcs[head] == usize::MAX {
+                        parent_arcs[head] = arc_id;
+                        if head == sink {
+                            break 'search;
+                        }
+                        frontier.push_back(head);
+                    }
+                }
+            }
+            if parent_arcs[sink] == usize::MAX {
+                break;
+            }
+            let mut split_node_id = sink;
+            while split_node_id != source {
+                let arc_id = parent_arcs[split_node_id];
+                flows[arc_id] += 1;
+                flows[arc_id ^ 1] -= 1;
+                split_node_id = self.heads[arc_id ^ 1];
+            }
+            number_of_paths += 1;
+        }
+        number_of_paths
+    }
+}
+
+/// # Vertex connectivity
+impl Graph {
+    /// Returns the vertex connectivity of the graph, that is the minimum number of nodes whose removal disconnects it.
+    ///
+    /// # Arguments
+    /// * `number_of_samples`: Option<usize> - The number of random node pairs to use to compute an upper bound of the vertex connectivity. By default, the exact vertex connectivity is computed.
+    /// * `random_state`: Option<u64> - The random state to use to sample the node pairs. By default, 42.
+    /// * `maximum_number_of_nodes`: Option<NodeT> - The maximum number of nodes of the graph for the exact vertex connectivity to be computed. By default, 1000.
+    ///
+    /// # Implementative details
+    /// The local vertex connectivity of two non-adjacent nodes is the maximum
+    /// number of internally vertex-disjoint paths between them, which is computed
+    /// as a maximum flow on the network where each node is split into two copies
+    /// connected by an arc with unit capacity. The vertex connectivity is the
+    /// minimum of the local vertex connectivity over all the pairs of non-adjacent
+    /// nodes, or the number of nodes minus one for complete graphs, and it is
+    /// never larger than the minimum node degree.
+    ///
+    /// The exact vertex connectivity is computed with Even's algorithm: the node
+    /// pairs `(i, j)` with `i < j` are considered by increasing `i`, and the search
+    /// stops when `i` exceeds the best bound found so far, since at least one of
+    /// the first `k + 1` nodes does not belong to a minimum vertex cut of size `k`.
+    /// This requires up to `(k + 1) n` maximum flows, each requiring up to `k`
+    /// breadth first searches, hence the number of nodes is capped.
+    ///
+    /// When the number of samples is provided, the local vertex connectivity is
+    /// only computed for the sampled pairs of distinct non-adjacent nodes, and
+    /// the returned value is an upper bound of the vertex connectivity, which
+    /// is exact when any of the sampled pairs is separated by a minimum vertex cut.
+    ///
+    /// Directed graphs are handled through their undirected interpretation, that
+    /// is the vertex connectivity of the underlying undirected graph is returned.
+    /// The edge weights are ignored, parallel edges are counted once and selfloops
+    /// are ignored. Graphs that are disconnected or that have at most one node have
+    /// vertex connectivity zero.
+    ///
+    /// # References
+    /// [An algorithm for determining whether the connectivity of a graph is at least k](https://doi.org/10.1137/0204036),
+    /// by Shimon Even.
+    ///
+    /// # Example
+    ///```rust
+    /// # let graph = graph::test_utilities::load_ppi(true, true, true, true, false, false);
+    /// // The PPI graph is not connected.
+    /// assert_eq!(graph.get_vertex_connectivity(Some(100), None, None).unwrap(), 0);
+    /// ```
+    ///
+    /// # Raises
+    /// * If the exact vertex connectivity is requested and the graph has more nodes than the provided maximum.
+    pub fn get_vertex_connectivity(
+        &self,
+        number_of_samples: Option<usize>,
+        random_state: Option<u64>,
+        maximum_number_of_nodes: Option<NodeT>,
+    ) -> Result<NodeT> {
+        let maximum_number_of_nodes = maximum_number_of_nodes.unwrap_or(1000);
+        if number_of_samples.is_none() && self.get_number_of_nodes() > maximum_number_of_nodes {
+            return Err(format!(
+                concat!(
+                    "The current graph has {} nodes, more than the maximum number of nodes {} ",
+                    "for which the exact vertex connectivity is computed. Either increase the ",
+                    "maximum number of nodes or provide a number of samples to compute an upper bound."
+                ),
+                self.get_number_of_nodes(),
+                maximum_number_of_nodes
+            ));
+        }
+        if self.is_directed() {
+            return self.to_undirected().get_vertex_connectivity(
+                number_of_samples,
+                random_state,
+                Some(maximum_number_of_nodes),
+            );
+        }
+        if self.get_number_of_nodes() <= 1 || !self.is_connected(Some(false)) {
+            return Ok(0);
+        }
+
+        let are_adjacent = |src: NodeT, dst: NodeT| unsafe {
+            src == dst
+                || self
+                    .edges
+                    .get_unchecked_neighbours_node_ids_from_src_node_id(src)
+                    .binary_search(&dst)
+                    .is_ok()
+        };
+        let network = VertexSplitNetwork::new(self);
+        let number_of_split_nodes = 2 * self.get_number_of_nodes() as usize;
+        let get_buffers = || {
+            (
+                vec![0; network.heads.len()],
+                vec![usize::MAX; number_of_split_nodes],
+                VecDeque::new(),
+            )
+        };
+        let minimum_node_degree = self
+            .par_iter_node_ids()
+            .map(|node_id| unsafe {
+                self.iter_unchecked_unique_neighbour_node_ids_from_source_node_id(node_id)
+                    .filter(|&dst| dst != node_id)
+                    .count() as NodeT
+            })
+            .min()
+            .unwrap();
+        let vertex_connectivity = AtomicU32::new(minimum_node_degree);
+        let update_vertex_connectivity =
+            |(flows, parent_arcs, frontier): &mut (Vec<i8>, Vec<usize>, VecDeque<usize>),
+             (src, dst): (NodeT, NodeT)| {
+                let limit = vertex_connectivity.load(Ordering::Relaxed);
+                let local_vertex_connectivity = network.get_local_vertex_connectivity(
+                    src,
+                    dst,
+                    limit,
+                    flows,
+                    parent_arcs,
+                    frontier,
+                );
+                vertex_connectivity.fetch_min(local_vertex_connectivity, Ordering::Relaxed);
+            };
+
+        match number_of_samples {
+            Some(number_of_samples) => {
+                let random_state = splitmix64(random_state.unwrap_or(42));
+                self.iter_random_node_ids(2 * number_of_samples, random_state)
+                    .collect::<Vec<NodeT>>()
+                    .par_chunks(2)
+                    .map(|pair| (pair[0], pair[1]))
+                    .filter(|&(src, dst)| !are_adjacent(src, dst))
+                    .for_each_init(&get_buffers, &update_vertex_connectivity);
+            }
+            None => {
+                let mut src = 0;
+                while src <= vertex_connectivity.load(Ordering::Relaxed)
+                    && src < self.get_number_of_nodes()
+                {
+                    (src + 1..self.get_number_of_nodes())
+                        .into_par_iter()
+                        .filter(|&dst| !are_adjacent(src, dst))
+                        .map(|dst| (src, dst))
+                        .for_each_init(&get_buffers, &update_vertex_connectivity);
+                    src += 1;
+                }
+            }
+        }
+        Ok(vertex_connectivity.load(Ordering::Relaxed))
+    }
+}
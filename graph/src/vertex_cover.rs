@@ -134,4 +134,170 @@ impl Graph {
 
         Ok(vertex_cover)
     }
+
+    /// Returns the minimum vertex cover of the graph, computed exactly.
+    ///
+    /// # Arguments
+    /// * `maximum_nodes`: Option<NodeT> - The maximum number of nodes of the graph. By default, 40.
+    ///
+    /// # Implementative details
+    /// The minimum vertex cover is computed with a branch and bound algorithm,
+    /// which branches on the node with the highest degree among the edges still
+    /// to be covered, either adding it to the cover or adding all of its neighbours.
+    /// Each branch is pruned when the size of the current cover plus the size of
+    /// a maximal matching of the edges still to be covered, which is a lower bound
+    /// of the number of nodes still to be added, is not smaller than the best cover found.
+    /// The nodes with a selfloop are always in the cover, and in directed graphs
+    /// the direction of the edges is ignored. The returned node IDs are sorted.
+    ///
+    /// Since the running time grows exponentially with the number of nodes, the method
+    /// refuses to run on graphs with more than `maximum_nodes` nodes: for larger graphs,
+    /// the 2-approximated vertex cover is available through the `get_vertex_cover` method.
+    ///
+    /// # Example
+    ///```rust
+    /// # use graph::*;
+    /// let mut builder = GraphBuilder::new(None, Some(false));
+    /// for (src, dst) in [("A", "B"), ("B", "C"), ("C", "D")] {
+    ///     builder.add_edge(src, dst, None, None).unwrap();
+    /// }
+    /// let graph = builder.build().unwrap();
+    /// assert_eq!(graph.get_minimum_vertex_cover(None).unwrap().len(), 2);
+    /// ```
+    ///
+    /// # Raises
+    /// * If the graph has more nodes than the given maximum number of nodes.
+    pub fn get_minimum_vertex_cover(&self, maximum_nodes: Option<NodeT>) -> Result<Vec<NodeT>> {
+        let maximum_nodes = maximum_nodes.unwrap_or(40);
+        if self.get_number_of_nodes() > maximum_nodes {
+            return Err(format!(
+                concat!(
+                    "The exact minimum vertex cover can only be computed on graphs ",
+                    "with at most {} nodes, but the current graph has {} nodes, ",
+                    "and the running time grows exponentially with the number of nodes. ",
+                    "For larger graphs, you can use the 2-approximated vertex cover ",
+                    "computed by the `get_vertex_cover` method."
+                ),
+                maximum_nodes,
+                self.get_number_of_nodes()
+            ));
+        }
+
+        let number_of_nodes = self.get_number_of_nodes() as usize;
+        let mut neighbours: Vec<Vec<NodeT>> = vec![Vec::new(); number_of_nodes];
+        let mut cover: Vec<NodeT> = Vec::new();
+        let mut is_covered = vec![false; number_of_nodes];
+        for (_, src, dst) in self.iter_directed_edge_node_ids() {
+            if src == dst {
+                if !is_covered[src as usize] {
+                    is_covered[src as usize] = true;
+                    cover.push(src);
+                }
+                continue;
+            }
+            neighbours[src as usize].push(dst);
+            neighbours[dst as usize].push(src);
+        }
+        neighbours.iter_mut().for_each(|neighbours| {
+            neighbours.sort_unstable();
+            neighbours.dedup();
+        });
+
+        // Initially, the best cover contains all the nodes with edges to be covered.
+        let mut best_cover = cover.clone();
+        best_cover.extend((0..number_of_nodes as NodeT).filter(|&node_id| {
+            !is_covered[node_id as usize]
+                && neighbours[node_id as usize]
+                    .iter()
+                    .any(|&neighbour_node_id| !is_covered[neighbour_node_id as usize])
+        }));
+        extend_minimum_vertex_cover(&neighbours, &mut is_covered, &mut cover, &mut best_cover);
+        best_cover.sort_unstable();
+        Ok(best_cover)
+    }
+}
+
+/// Extends the given partial vertex cover with the branch and bound algorithm.
+///
+/// # Arguments
+/// * `neighbours`: &[Vec<NodeT>] - The neighbours of each node, excluding selfloops.
+/// * `is_covered`: &mut [bool] - Whether each node is in the current cover.
+/// * `cover`: &mut Vec<NodeT> - The nodes in the current cover.
+/// * `best_cover`: &mut Vec<NodeT> - The smallest vertex cover found so far.
+fn extend_minimum_vertex_cover(
+    neighbours: &[Vec<NodeT>],
+    is_covered: &mut [bool],
+    cover: &mut Vec<NodeT>,
+    best_cover: &mut Vec<NodeT>,
+) {
+    let get_uncovered_neighbours = |is_covered: &[bool], node_id: usize| {
+        neighbours[node_id]
+            .iter()
+            .copied()
+            .filter(|&neighbour_node_id| !is_covered[neighbour_node_id as usize])
+            .collect::<Vec<NodeT>>()
+    };
+
+    // We compute a maximal matching of the edges still to be covered,
+    // as each of its edges requires a distinct node in the cover.
+    let mut is_matched = is_covered.to_vec();
+    let mut matching_size = 0;
+    let mut branch_node: Option<(usize, usize)> = None;
+    for node_id in 0..neighbours.len() {
+        if is_covered[node_id] {
+            continue;
+        }
+        let uncovered_neighbours = get_uncovered_neighbours(is_covered, node_id);
+        if uncovered_neighbours.is_empty() {
+            continue;
+        }
+        if branch_node.map_or(true, |(_, degree)| uncovered_neighbours.len() > degree) {
+            branch_node = Some((node_id, uncovered_neighbours.len()));
+        }
+        if is_matched[node_id] {
+            continue;
+        }
+        if let Some(&neighbour_node_id) = uncovered_neighbours
+            .iter()
+            .find(|&&neighbour_node_id| !is_matched[neighbour_node_id as usize])
+        {
+            is_matched[node_id] = true;
+            is_matched[neighbour_node_id as usize] = true;
+            matching_size += 1;
+        }
+    }
+
+    // If all the edges are covered, the current cover is better than the best one.
+    let (node_id, _) = match branch_node {
+        Some(branch_node) => branch_node,
+        None => {
+            *best_cover = cover.clone();
+            return;
+        }
+    };
+    if cover.len() + matching_size >= best_cover.len() {
+        return;
+    }
+
+    // First, we add the node with the highest degree to the cover.
+    is_covered[node_id] = true;
+    cover.push(node_id as NodeT);
+    extend_minimum_vertex_cover(neighbours, is_covered, cover, best_cover);
+    cover.pop();
+    is_covered[node_id] = false;
+
+    // Then, we add all of its neighbours to the cover instead.
+    let uncovered_neighbours = get_uncovered_neighbours(is_covered, node_id);
+    if cover.len() + uncovered_neighbours.len() >= best_cover.len() {
+        return;
+    }
+    for &neighbour_node_id in uncovered_neighbours.iter() {
+        is_covered[neighbour_node_id as usize] = true;
+    }
+    cover.extend(uncovered_neighbours.iter().copied());
+    extend_minimum_vertex_cover(neighbours, is_covered, cover, best_cover);
+    cover.truncate(cover.len() - uncovered_neighbours.len());
+    for &neighbour_node_id in uncovered_neighbours.iter() {
+        is_covered[neighbour_node_id as usize] = false;
+    }
 }
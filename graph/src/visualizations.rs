@@ -1,6 +1,559 @@
 use super::*;
+use rayon::prelude::*;
+use vec_rand::random_f32;
+
+/// Returns the provided positions rescaled within the unit hypercube.
+///
+/// # Arguments
+/// * `positions`: Vec<Vec<f64>> - The positions to rescale.
+fn rescale_positions_to_unit_hypercube(mut positions: Vec<Vec<f64>>) -> Vec<Vec<f64>> {
+    let dimensions = positions.first().map_or(0, |position| position.len());
+    for dimension in 0..dimensions {
+        let (minimum, maximum) =
+            positions
+                .iter()
+                .fold((f64::MAX, f64::MIN), |(minimum, maximum), position| {
+                    (
+                        minimum.min(position[dimension]),
+                        maximum.max(position[dimension]),
+                    )
+                });
+        let delta = if maximum > minimum {
+            maximum - minimum
+        } else {
+            1.0
+        };
+        positions.iter_mut().for_each(|position| {
+            position[dimension] = (position[dimension] - minimum) / delta;
+        });
+    }
+    positions
+}
+
+/// Returns the Fruchterman-Reingold force-directed layout of the provided edge list.
+///
+/// # Arguments
+/// * `number_of_nodes`: usize - The number of nodes of the graph to layout.
+/// * `edges`: &[(NodeT, NodeT)] - The edges of the graph, each one appearing once.
+/// * `dimensions`: usize - The number of dimensions of the layout.
+/// * `iterations`: usize - The number of iterations to run.
+/// * `repulsion_scales`: Option<&[f64]> - The factor scaling the repulsion of each node. By default, the repulsion is not scaled.
+/// * `random_state`: u64 - The random state used for the initial positions.
+///
+/// # Implementative details
+/// The nodes start from random positions within the unit hypercube, and at
+/// each iteration every pair of nodes repels with a force inversely proportional
+/// to their distance, scaled by the product of their repulsion scales, while
+/// the endpoints of each edge attract with a force proportional to their squared
+/// distance. The displacement of each node is capped by a temperature that
+/// linearly decreases to zero over the iterations.
+///
+/// The repulsive forces are computed exactly and in parallel over the nodes,
+/// hence each iteration has quadratic complexity in the number of nodes.
+/// The layout is deterministic given the random state, and the returned
+/// positions are rescaled within the unit hypercube.
+///
+/// # References
+/// [Graph drawing by force-directed placement](https://onlinelibrary.wiley.com/doi/10.1002/spe.4380211102),
+/// by Fruchterman and Reingold.
+pub(crate) fn get_fruchterman_reingold_layout(
+    number_of_nodes: usize,
+    edges: &[(NodeT, NodeT)],
+    dimensions: usize,
+    iterations: usize,
+    repulsion_scales: Option<&[f64]>,
+    random_state: u64,
+) -> Vec<Vec<f64>> {
+    if number_of_nodes == 0 {
+        return Vec::new();
+    }
+    let mut random_state = splitmix64(random_state);
+    let mut positions = (0..number_of_nodes)
+        .map(|_| {
+            (0..dimensions)
+                .map(|_| {
+                    random_state = splitmix64(random_state);
+                    random_f32(random_state) as f64
+                })
+                .collect::<Vec<f64>>()
+        })
+        .collect::<Vec<Vec<f64>>>();
+    let distance = |a: &[f64], b: &[f64]| -> f64 {
+        a.iter()
+            .zip(b.iter())
+            .map(|(a, b)| (a - b) * (a - b))
+            .sum::<f64>()
+            .sqrt()
+            .max(1e-9)
+    };
+    // The optimal distance between the nodes in the unit hypercube.
+    let k = (1.0 / number_of_nodes as f64).powf(1.0 / dimensions as f64);
+    let initial_temperature = 0.1;
+    for iteration in 0..iterations {
+        let temperature = initial_temperature * (1.0 - iteration as f64 / iterations as f64);
+        let mut displacements = positions
+            .par_iter()
+            .enumerate()
+            .map(|(i, position)| {
+                let mut displacement = vec![0.0; dimensions];
+                positions
+                    .iter()
+                    .enumerate()
+                    .filter(|&(j, _)| i != j)
+                    .for_each(|(j, other_position)| {
+                        let distance = distance(position, other_position);
+                        let scale = repulsion_scales.map_or(1.0, |scales| scales[i] * scales[j]);
+                        let repulsion = scale * k * k / distance;
+                        displacement
+                            .iter_mut()
+                            .zip(position.iter().zip(other_position.iter()))
+                            .for_each(|(displacement, (a, b))| {
+                                *displacement += (a - b) / distance * repulsion;
+                            });
+                    });
+                displacement
+            })
+            .collect::<Vec<Vec<f64>>>();
+        edges.iter().for_each(|&(src, dst)| {
+            let (src, dst) = (src as usize, dst as usize);
+            let distance = distance(&positions[src], &positions[dst]);
+            let attraction = distance * distance / k;
+            for dimension in 0..dimensions {
+                let delta =
+                    (positions[src][dimension] - positions[dst][dimension]) / distance * attraction;
+                displacements[src][dimension] -= delta;
+                displacements[dst][dimension] += delta;
+            }
+        });
+        positions
+            .par_iter_mut()
+            .zip(displacements.par_iter())
+            .for_each(|(position, displacement)| {
+                let length = displacement
+                    .iter()
+                    .map(|value| value * value)
+                    .sum::<f64>()
+                    .sqrt()
+                    .max(1e-9);
+                let step = length.min(temperature);
+                position
+                    .iter_mut()
+                    .zip(displacement.iter())
+                    .for_each(|(value, displacement)| {
+                        *value += displacement / length * step;
+                    });
+            });
+    }
+    rescale_positions_to_unit_hypercube(positions)
+}
+
+/// Returns the spectral layout of the provided edge list.
+///
+/// # Arguments
+/// * `number_of_nodes`: usize - The number of nodes of the graph to layout.
+/// * `edges`: &[(NodeT, NodeT)] - The edges of the graph, each one appearing once.
+/// * `iterations`: usize - The number of power iterations to run for each coordinate.
+/// * `random_state`: u64 - The random state used for the initial vectors.
+///
+/// # Implementative details
+/// The coordinates are the eigenvectors of the two smallest non-zero
+/// eigenvalues of the Laplacian, which are the two largest eigenvectors of the
+/// shifted matrix `cI - L` once the constant eigenvector is deflated.
+/// They are computed with power iteration.
+pub(crate) fn get_spectral_layout(
+    number_of_nodes: usize,
+    edges: &[(NodeT, NodeT)],
+    iterations: usize,
+    random_state: u64,
+) -> Vec<Vec<f64>> {
+    if number_of_nodes < 3 {
+        return rescale_positions_to_unit_hypercube(
+            (0..number_of_nodes).map(|i| vec![i as f64; 2]).collect(),
+        );
+    }
+    let mut degrees = vec![0.0_f64; number_of_nodes];
+    edges.iter().for_each(|&(src, dst)| {
+        degrees[src as usize] += 1.0;
+        degrees[dst as usize] += 1.0;
+    });
+    // By Gershgorin, the eigenvalues of the Laplacian are at most twice the maximum degree.
+    let shift = 2.0 * degrees.iter().cloned().fold(0.0, f64::max) + 1.0;
+    let shifted_product = |vector: &[f64]| -> Vec<f64> {
+        let mut result = vector
+            .iter()
+            .zip(degrees.iter())
+            .map(|(value, degree)| (shift - degree) * value)
+            .collect::<Vec<f64>>();
+        edges.iter().for_each(|&(src, dst)| {
+            result[src as usize] += vector[dst as usize];
+            result[dst as usize] += vector[src as usize];
+        });
+        result
+    };
+    let orthonormalize = |vector: &mut Vec<f64>, basis: &[Vec<f64>]| {
+        let mean = vector.iter().sum::<f64>() / number_of_nodes as f64;
+        vector.iter_mut().for_each(|value| *value -= mean);
+        basis.iter().for_each(|other| {
+            let projection: f64 = vector.iter().zip(other.iter()).map(|(a, b)| a * b).sum();
+            vector
+                .iter_mut()
+                .zip(other.iter())
+                .for_each(|(value, other)| *value -= projection * other);
+        });
+        let norm = vector.iter().map(|value| value * value).sum::<f64>().sqrt();
+        if norm > 0.0 {
+            vector.iter_mut().for_each(|value| *value /= norm);
+        }
+    };
+    let mut random_state = splitmix64(random_state);
+    let mut basis: Vec<Vec<f64>> = Vec::with_capacity(2);
+    for _ in 0..2 {
+        let mut vector = (0..number_of_nodes)
+            .map(|_| {
+                random_state = splitmix64(random_state);
+                random_f32(random_state) as f64 - 0.5
+            })
+            .collect::<Vec<f64>>();
+        orthonormalize(&mut vector, &basis);
+        for _ in 0..iterations {
+            vector = shifted_product(&vector);
+            orthonormalize(&mut vector, &basis);
+        }
+        basis.push(vector);
+    }
+    rescale_positions_to_unit_hypercube(
+        basis[0]
+            .iter()
+            .zip(basis[1].iter())
+            .map(|(&x, &y)| vec![x, y])
+            .collect(),
+    )
+}
 
 impl Graph {
+    /// Returns the node IDs, the 2D node positions and the edges of the ego network of the given node.
+    ///
+    /// # Arguments
+    /// * `center_node_id`: NodeT - The node at the center of the ego network.
+    /// * `radius`: Option<NodeT> - The maximum distance from the center node of the nodes to include. By default, 1.
+    /// * `layout`: Option<&str> - The layout to compute. Can either be `force_directed` or `spectral`. By default, `force_directed`.
+    /// * `maximum_number_of_nodes`: Option<NodeT> - The maximum number of nodes in the ego network. By default, 1000.
+    /// * `iterations`: Option<usize> - The number of iterations of the layout algorithm. By default, 100.
+    /// * `random_state`: Option<u64> - The random state used to initialize the layout. By default, 42.
+    ///
+    /// # Implementative details
+    /// The ego network is the subgraph induced by the nodes within the given
+    /// radius from the center node. When it has more nodes than the given
+    /// maximum, only the closest nodes are kept, breaking ties by node ID, and
+    /// the breadth-first search stops as soon as the maximum is reached.
+    /// The returned node IDs are sorted by distance from the center node,
+    /// which is therefore the first one, and the positions are aligned with them
+    /// and rescaled within the unit square. The returned edges use the positions
+    /// of the nodes within the returned node IDs, are listed once even in
+    /// undirected graphs and do not include selfloops.
+    ///
+    /// The `force_directed` layout is the Fruchterman-Reingold one, while the
+    /// `spectral` layout uses the eigenvectors of the two smallest non-zero eigenvalues
+    /// of the Laplacian of the ego network, computed with power iteration.
+    ///
+    /// # Example
+    ///```rust
+    /// # let graph = graph::test_utilities::load_ppi(true, true, true, true, false, false);
+    /// let (node_ids, positions, edges) = graph.get_ego_network_layout(0, Some(2), None, Some(100), None, None).unwrap();
+    /// assert_eq!(node_ids[0], 0);
+    /// assert_eq!(node_ids.len(), positions.len());
+    /// ```
+    ///
+    /// # Raises
+    /// * If the given center node ID does not exist in the graph.
+    /// * If the given maximum number of nodes is zero.
+    /// * If the given layout is not supported.
+    pub fn get_ego_network_layout(
+        &self,
+        center_node_id: NodeT,
+        radius: Option<NodeT>,
+        layout: Option<&str>,
+        maximum_number_of_nodes: Option<NodeT>,
+        iterations: Option<usize>,
+        random_state: Option<u64>,
+    ) -> Result<(Vec<NodeT>, Vec<(f64, f64)>, Vec<(NodeT, NodeT)>)> {
+        self.validate_node_id(center_node_id)?;
+        let radius = radius.unwrap_or(1);
+        let maximum_number_of_nodes = maximum_number_of_nodes.unwrap_or(1000);
+        if maximum_number_of_nodes == 0 {
+            return Err("The maximum number of nodes must be strictly positive.".to_string());
+        }
+        let iterations = iterations.unwrap_or(100);
+        let random_state = random_state.unwrap_or(42);
+        let layout = layout.unwrap_or("force_directed");
+        if !["force_directed", "spectral"].contains(&layout) {
+            return Err(format!(
+                concat!(
+                    "The provided layout `{}` is not supported. ",
+                    "The supported layouts are `force_directed` and `spectral`."
+                ),
+                layout
+            ));
+        }
+
+        // The breadth-first search visits the nodes level by level, sorting each
+        // level by node ID, and stops as soon as the maximum number of nodes is reached.
+        let mut local_node_ids = vec![NODE_NOT_PRESENT; self.get_number_of_nodes() as usize];
+        local_node_ids[center_node_id as usize] = 0;
+        let mut node_ids = vec![center_node_id];
+        let mut frontier = vec![center_node_id];
+        let mut distance = 0;
+        while !frontier.is_empty()
+            && distance < radius
+            && node_ids.len() < maximum_number_of_nodes as usize
+        {
+            let mut next_frontier = Vec::new();
+            for &src in frontier.iter() {
+                for dst in
+                    unsafe { self.iter_unchecked_neighbour_node_ids_from_source_node_id(src) }
+                {
+                    if local_node_ids[dst as usize] == NODE_NOT_PRESENT {
+                        // The node is marked as visited, and receives its
+                        // local node ID only once it is known to be kept.
+                        local_node_ids[dst as usize] = 0;
+                        next_frontier.push(dst);
+                    }
+                }
+            }
+            next_frontier.sort_unstable();
+            let number_of_kept_nodes = next_frontier
+                .len()
+                .min(maximum_number_of_nodes as usize - node_ids.len());
+            next_frontier[number_of_kept_nodes..]
+                .iter()
+                .for_each(|&node_id| {
+                    local_node_ids[node_id as usize] = NODE_NOT_PRESENT;
+                });
+            next_frontier.truncate(number_of_kept_nodes);
+            next_frontier.iter().for_each(|&node_id| {
+                local_node_ids[node_id as usize] = node_ids.len() as NodeT;
+                node_ids.push(node_id);
+            });
+            frontier = next_frontier;
+            distance += 1;
+        }
+
+        let mut edges = node_ids
+            .iter()
+            .flat_map(|&src| {
+                let local_src = local_node_ids[src as usize];
+                unsafe { self.iter_unchecked_unique_neighbour_node_ids_from_source_node_id(src) }
+                    .map(|dst| local_node_ids[dst as usize])
+                    .filter(move |&local_dst| {
+                        local_dst != NODE_NOT_PRESENT
+                            && local_dst != local_src
+                            && (self.is_directed() || local_src < local_dst)
+                    })
+                    .map(move |local_dst| (local_src, local_dst))
+            })
+            .collect::<Vec<(NodeT, NodeT)>>();
+        edges.sort_unstable();
+
+        let positions = match layout {
+            "spectral" => get_spectral_layout(node_ids.len(), &edges, iterations, random_state),
+            _ => get_fruchterman_reingold_layout(
+                node_ids.len(),
+                &edges,
+                2,
+                iterations,
+                None,
+                random_state,
+            ),
+        }
+        .into_iter()
+        .map(|position| (position[0], position[1]))
+        .collect();
+
+        Ok((node_ids, positions, edges))
+    }
+
+    /// Returns the coordinates of the nodes in the Fruchterman-Reingold force-directed layout.
+    ///
+    /// # Arguments
+    /// * `dimensions`: usize - The number of dimensions of the layout, either 2 or 3.
+    /// * `iterations`: usize - The number of iterations to run.
+    /// * `random_state`: u64 - The random state used for the initial positions.
+    ///
+    /// # Implementative details
+    /// The layout is the same Fruchterman-Reingold one used for the ego network
+    /// layouts, where the repulsion between two nodes is scaled by the product of
+    /// their degrees plus one, as in ForceAtlas2, so that hubs are kept apart and
+    /// the nodes with few neighbours gather around them.
+    ///
+    /// The repulsive forces are computed exactly and in parallel over the nodes,
+    /// hence each iteration has quadratic complexity in the number of nodes.
+    /// The direction, the types and the weights of the edges are ignored, and so
+    /// are the selfloops. The layout is deterministic given the random state, and
+    /// the returned coordinates are rescaled within the unit hypercube.
+    ///
+    /// # References
+    /// [Graph drawing by force-directed placement](https://onlinelibrary.wiley.com/doi/10.1002/spe.4380211102),
+    /// by Fruchterman and Reingold.
+    ///
+    /// # Example
+    ///```rust
+    /// # let graph = graph::test_utilities::load_ppi(true, true, true, true, false, false);
+    /// let coordinates = graph.compute_force_directed_layout(3, 2, 42).unwrap();
+    /// assert_eq!(coordinates.len(), graph.get_number_of_nodes() as usize);
+    /// assert!(coordinates.iter().all(|node_coordinates| node_coordinates.len() == 3));
+    /// ```
+    ///
+    /// # Raises
+    /// * If the given number of dimensions is neither 2 nor 3.
+    pub fn compute_force_directed_layout(
+        &self,
+        dimensions: usize,
+        iterations: usize,
+        random_state: u64,
+    ) -> Result<Vec<Vec<f64>>> {
+        if dimensions != 2 && dimensions != 3 {
+            return Err(format!(
+                "The number of dimensions must be either 2 or 3, but {} was provided.",
+                dimensions
+            ));
+        }
+        let repulsion_scales = self
+            .get_node_degrees()
+            .into_iter()
+            .map(|degree| degree as f64 + 1.0)
+            .collect::<Vec<f64>>();
+        let edges = self
+            .iter_unique_edge_node_ids(self.is_directed())
+            .filter(|(src, dst)| src != dst)
+            .collect::<Vec<(NodeT, NodeT)>>();
+        Ok(get_fruchterman_reingold_layout(
+            self.get_number_of_nodes() as usize,
+            &edges,
+            dimensions,
+            iterations,
+            Some(&repulsion_scales),
+            random_state,
+        ))
+    }
+
+    /// Returns the x and y coordinates of the layered layout of the directed acyclic graph.
+    ///
+    /// # Arguments
+    /// * `number_of_sweeps`: Option<usize> - The number of crossing minimization sweeps. By default, 10.
+    ///
+    /// # Implementative details
+    /// The layout follows the Sugiyama framework. The y coordinate of each node is
+    /// its layer, that is the length of the longest path reaching it from a root
+    /// node, computed along a topological sorting, so that all edges point towards
+    /// higher layers. Within each layer, the nodes are initially sorted by node ID,
+    /// and each sweep first reorders the layers from the top one downwards by the
+    /// barycenter of the x coordinates of the predecessors of each node, and then
+    /// reorders them from the bottom one upwards by the barycenter of the successors,
+    /// which is the barycenter heuristic to reduce the edge crossings. Nodes without
+    /// predecessors, or successors, keep their current x coordinate as barycenter.
+    /// The sweeps do not stop early, as the number of crossings is not computed.
+    ///
+    /// Differently from the full Sugiyama framework, no dummy nodes are inserted on
+    /// the edges spanning multiple layers, which are accounted in the barycenters
+    /// with the positions of their endpoints. The x coordinate of a node is its
+    /// position within its layer, centered around zero.
+    ///
+    /// # References
+    /// [Methods for Visual Understanding of Hierarchical System Structures](https://doi.org/10.1109/TSMC.1981.4308636),
+    /// by Sugiyama, Tagawa and Toda.
+    ///
+    /// # Raises
+    /// * If the graph is not a directed acyclic graph.
+    pub fn get_layered_dag_layout(
+        &self,
+        number_of_sweeps: Option<usize>,
+    ) -> Result<(Vec<f64>, Vec<f64>)> {
+        self.must_be_directed_acyclic()?;
+        let number_of_sweeps = number_of_sweeps.unwrap_or(10);
+        let number_of_nodes = self.get_number_of_nodes() as usize;
+
+        let successors = self
+            .par_iter_node_ids()
+            .map(|node_id| unsafe {
+                self.iter_unchecked_unique_neighbour_node_ids_from_source_node_id(node_id)
+                    .collect::<Vec<NodeT>>()
+            })
+            .collect::<Vec<Vec<NodeT>>>();
+        let mut predecessors = vec![Vec::new(); number_of_nodes];
+        successors
+            .iter()
+            .enumerate()
+            .for_each(|(src, node_successors)| {
+                node_successors.iter().for_each(|&dst| {
+                    predecessors[dst as usize].push(src as NodeT);
+                });
+            });
+
+        // We compute the layers along the topological sorting.
+        let mut layers = vec![0 as NodeT; number_of_nodes];
+        unsafe { self.get_unchecked_directed_acyclic_topological_order() }
+            .into_iter()
+            .for_each(|src| {
+                successors[src as usize].iter().for_each(|&dst| {
+                    layers[dst as usize] = layers[dst as usize].max(layers[src as usize] + 1);
+                });
+            });
+
+        let number_of_layers = layers.iter().copied().max().unwrap_or(0) as usize + 1;
+        let mut layer_node_ids = vec![Vec::new(); number_of_layers];
+        self.iter_node_ids().for_each(|node_id| {
+            layer_node_ids[layers[node_id as usize] as usize].push(node_id);
+        });
+
+        let mut x = vec![0.0; number_of_nodes];
+        let update_positions = |x: &mut Vec<f64>, node_ids: &[NodeT]| {
+            let offset = (node_ids.len() as f64 - 1.0) / 2.0;
+            node_ids
+                .iter()
+                .enumerate()
+                .for_each(|(position, &node_id)| {
+                    x[node_id as usize] = position as f64 - offset;
+                });
+        };
+        layer_node_ids
+            .iter()
+            .for_each(|node_ids| update_positions(&mut x, node_ids));
+
+        let reorder_layer =
+            |x: &mut Vec<f64>, node_ids: &mut Vec<NodeT>, neighbours: &[Vec<NodeT>]| {
+                let barycenters = node_ids
+                    .iter()
+                    .map(|&node_id| {
+                        let node_neighbours = &neighbours[node_id as usize];
+                        if node_neighbours.is_empty() {
+                            x[node_id as usize]
+                        } else {
+                            node_neighbours
+                                .iter()
+                                .map(|&neighbour_node_id| x[neighbour_node_id as usize])
+                                .sum::<f64>()
+                                / node_neighbours.len() as f64
+                        }
+                    })
+                    .collect::<Vec<f64>>();
+                let mut order = (0..node_ids.len()).collect::<Vec<usize>>();
+                order.sort_by(|&a, &b| barycenters[a].partial_cmp(&barycenters[b]).unwrap());
+                *node_ids = order.into_iter().map(|i| node_ids[i]).collect();
+                update_positions(x, node_ids);
+            };
+
+        for _ in 0..number_of_sweeps {
+            for layer in 1..number_of_layers {
+                reorder_layer(&mut x, &mut layer_node_ids[layer], &predecessors);
+            }
+            for layer in (0..number_of_layers.saturating_sub(1)).rev() {
+                reorder_layer(&mut x, &mut layer_node_ids[layer], &successors);
+            }
+        }
+
+        Ok((x, layers.into_iter().map(|layer| layer as f64).collect()))
+    }
+
     /// Print the current graph in a format compatible with Graphviz dot's format.
     pub fn to_dot(&self) -> String {
         // choose type of graph and if the edges should be directed or not
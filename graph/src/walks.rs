@@ -4,6 +4,17 @@ use vec_rand::sample_f32 as sample;
 use vec_rand::sample_uniform;
 use vec_rand::splitmix64;
 
+/// Returns the random state of a walk from the walks random state, the walk number and the iteration.
+///
+/// # Arguments
+/// * `random_state`: u64 - The random state of the walks.
+/// * `walk_number`: NodeT - The number of the walk within the iteration.
+/// * `iteration`: NodeT - The iteration of the walk.
+#[inline(always)]
+fn get_walk_random_state(random_state: u64, walk_number: NodeT, iteration: NodeT) -> u64 {
+    splitmix64(random_state ^ splitmix64(((iteration as u64) << 32) | walk_number as u64))
+}
+
 #[inline(always)]
 fn update_return_weight_transition(
     transition: &mut Vec<WeightT>,
@@ -330,6 +341,11 @@ impl Graph {
     /// * `min_edge_id`: EdgeT - The minimum edge id.
     /// * `max_edge_id`: EdgeT - The maximum edge id.
     /// * `probabilistic_indices`: &Option<Vec<u64>> - Optional list of the indices used to subsample.
+    /// * `use_edge_weights`: bool - Whether to use the edge weights, if the graph has them.
+    ///
+    /// # Implementative details
+    /// When the edge weights of the considered edges sum to zero, an uniform
+    /// vector is returned, so that the neighbours are sampled uniformly.
     ///
     /// # Safety
     /// Calling this method with either edge ID ranges that do not exist in this
@@ -340,9 +356,15 @@ impl Graph {
         min_edge_id: EdgeT,
         max_edge_id: EdgeT,
         probabilistic_indices: &Option<Vec<u64>>,
+        use_edge_weights: bool,
     ) -> Vec<WeightT> {
-        match &probabilistic_indices {
-            Some(indices) => match &*self.weights {
+        let weights = if use_edge_weights {
+            self.weights.as_ref().as_ref()
+        } else {
+            None
+        };
+        let transition: Vec<WeightT> = match &probabilistic_indices {
+            Some(indices) => match weights {
                 Some(ws) => indices
                     .iter()
                     .map(|edge_id| ws[*edge_id as usize])
@@ -350,12 +372,17 @@ impl Graph {
                 // Otherwise we return an uniform vector.
                 None => vec![1.0; indices.len()],
             },
-            None => match &*self.weights {
+            None => match weights {
                 Some(ws) => ws[(min_edge_id as usize)..(max_edge_id as usize)].to_vec(),
                 // Otherwise we return an uniform vector.
                 None => vec![1.0; (max_edge_id - min_edge_id) as usize],
             },
+        };
+        // If the outgoing weights sum to zero, we fall back to the uniform sampling.
+        if weights.is_some() && transition.iter().all(|&weight| weight == 0.0) {
+            return vec![1.0; transition.len()];
         }
+        transition
     }
 
     /// Updates the the transitions probability score for the change of the node type.
@@ -370,6 +397,7 @@ impl Graph {
     /// * `destinations`: impl Iterator<Item = NodeT> - Iterator of the destinations.
     /// * `change_node_type_weight`: ParamsT - The weight to multiply the transition by if there is a change of node type.
     /// * `normalize_by_degree`: bool - Whether to normalize the random walk by the degree of the destination nodes.
+    /// * `node_mask`: &Option<Vec<bool>> - The optional mask of the nodes that may be visited.
     ///
     /// # Safety
     /// If a non-existing node ID is provided, this method may cause an out of bound.
@@ -380,7 +408,23 @@ impl Graph {
         destinations: &[NodeT],
         change_node_type_weight: ParamsT,
         normalize_by_degree: bool,
+        node_mask: &Option<Vec<bool>>,
     ) {
+        //############################################################
+        //# Handling of the node mask                                #
+        //############################################################
+
+        if let Some(node_mask) = node_mask {
+            transition
+                .iter_mut()
+                .zip(destinations.iter().cloned())
+                .for_each(|(transition_value, dst)| {
+                    if !node_mask[dst as usize] {
+                        *transition_value = 0.0;
+                    }
+                });
+        }
+
         //############################################################
         //# Handling of the change node type parameter               #
         //############################################################
@@ -420,6 +464,8 @@ impl Graph {
     /// * `node`: NodeT, the previous node from which to compute the transitions, if this is bigger that the number of nodes it will panic.
     /// * `walk_weights`: WalkWeights, the weights for the weighted random walks.
     /// * `normalize_by_degree`: bool - Whether to normalize the random walk by the degree of the destination nodes.
+    /// * `node_mask`: &Option<Vec<bool>> - The optional mask of the nodes that may be visited.
+    /// * `use_edge_weights`: bool - Whether to use the edge weights as transition probabilities.
     ///
     /// # Safety
     /// If a non-existing node ID is provided, this method may cause an out of bound.
@@ -432,10 +478,16 @@ impl Graph {
         destinations: &[NodeT],
         probabilistic_indices: &Option<Vec<u64>>,
         normalize_by_degree: bool,
+        node_mask: &Option<Vec<bool>>,
+        use_edge_weights: bool,
     ) -> Vec<WeightT> {
         // Retrieve the data to compute the update transition
-        let mut transition =
-            self.get_edge_weighted_transitions(min_edge_id, max_edge_id, probabilistic_indices);
+        let mut transition = self.get_edge_weighted_transitions(
+            min_edge_id,
+            max_edge_id,
+            probabilistic_indices,
+            use_edge_weights,
+        );
 
         // Compute the transition weights relative to the node weights.
         self.update_node_transition(
@@ -444,6 +496,7 @@ impl Graph {
             destinations,
             walk_weights.change_node_type_weight,
             normalize_by_degree,
+            node_mask,
         );
 
         transition
@@ -456,6 +509,8 @@ impl Graph {
     /// * `edge`: EdgeT - the previous edge from which to compute the transitions.
     /// * `weights`: WalkWeights - Weights to use for the weighted walk.
     /// * `normalize_by_degree`: bool - Whether to normalize the random walk by the degree of the destination nodes.
+    /// * `node_mask`: &Option<Vec<bool>> - The optional mask of the nodes that may be visited.
+    /// * `use_edge_weights`: bool - Whether to use the edge weights as transition probabilities.
     ///
     /// TODO! Update docstring!
     ///
@@ -474,9 +529,15 @@ impl Graph {
         probabilistic_indices: &Option<Vec<u64>>,
         has_selfloop: bool,
         normalize_by_degree: bool,
+        node_mask: &Option<Vec<bool>>,
+        use_edge_weights: bool,
     ) -> (Vec<WeightT>, EdgeT) {
-        let mut transition =
-            self.get_edge_weighted_transitions(min_edge_id, max_edge_id, probabilistic_indices);
+        let mut transition = self.get_edge_weighted_transitions(
+            min_edge_id,
+            max_edge_id,
+            probabilistic_indices,
+            use_edge_weights,
+        );
 
         // Compute the transition weights relative to the node weights.
         self.update_node_transition(
@@ -485,6 +546,7 @@ impl Graph {
             destinations,
             walk_weights.change_node_type_weight,
             normalize_by_degree,
+            node_mask,
         );
 
         //############################################################
@@ -602,6 +664,8 @@ impl Graph {
     /// * `random_state`: usize, the random_state to use for extracting the node.
     /// * `walk_weights`: WalkWeights, the weights for the weighted random walks.
     /// * `normalize_by_degree`: bool - Whether to normalize the random walk by the degree of the destination nodes.
+    /// * `node_mask`: &Option<Vec<bool>> - The optional mask of the nodes that may be visited.
+    /// * `use_edge_weights`: bool - Whether to use the edge weights as transition probabilities.
     ///
     /// !TODO: Update docstring!
     ///
@@ -617,7 +681,9 @@ impl Graph {
         destinations: &[NodeT],
         probabilistic_indices: &Option<Vec<u64>>,
         normalize_by_degree: bool,
-    ) -> (NodeT, EdgeT) {
+        node_mask: &Option<Vec<bool>>,
+        use_edge_weights: bool,
+    ) -> Option<(NodeT, EdgeT)> {
         let mut weights = self.get_node_transition(
             node,
             walk_weights,
@@ -626,17 +692,22 @@ impl Graph {
             destinations,
             probabilistic_indices,
             normalize_by_degree,
+            node_mask,
+            use_edge_weights,
         );
+        if node_mask.is_some() && weights.iter().all(|&weight| weight == 0.0) {
+            return None;
+        }
         let sampled_offset = sample(&mut weights, random_state);
         let edge_id = match probabilistic_indices {
             Some(inds) => inds[sampled_offset],
             None => min_edge_id + sampled_offset as EdgeT,
         };
 
-        (
+        Some((
             self.get_unchecked_destination_node_id_from_edge_id(edge_id),
             edge_id,
-        )
+        ))
     }
 
     /// Return new random edge with given weights.
@@ -654,6 +725,11 @@ impl Graph {
     /// * `previous_destinations`: &[NodeT] - Previous destination slice.
     /// * `probabilistic_indices`: &Option<Vec<u64>> - Probabilistic indices, used when max neighbours is provided.
     /// * `normalize_by_degree`: bool - Whether to normalize the random walk by the degree of the destination nodes.
+    /// * `node_mask`: &Option<Vec<bool>> - The optional mask of the nodes that may be visited.
+    /// * `use_edge_weights`: bool - Whether to use the edge weights as transition probabilities.
+    ///
+    /// # Returns
+    /// The sampled destination and edge, or None if the node mask does not allow any of the destinations.
     ///
     /// # Safety
     /// If a non-existing node ID is provided, this method may cause an out of bound.
@@ -670,7 +746,9 @@ impl Graph {
         previous_destinations: &[NodeT],
         probabilistic_indices: &Option<Vec<u64>>,
         normalize_by_degree: bool,
-    ) -> (NodeT, EdgeT) {
+        node_mask: &Option<Vec<bool>>,
+        use_edge_weights: bool,
+    ) -> Option<(NodeT, EdgeT)> {
         let (mut weights, min_edge_id) = self.get_edge_transition(
             src,
             dst,
@@ -683,18 +761,130 @@ impl Graph {
             probabilistic_indices,
             self.has_selfloops(),
             normalize_by_degree,
+            node_mask,
+            use_edge_weights,
         );
+        if node_mask.is_some() && weights.iter().all(|&weight| weight == 0.0) {
+            return None;
+        }
         let sampled_offset = sample(&mut weights, random_state as u64);
         let edge_id = match probabilistic_indices {
             Some(inds) => inds[sampled_offset],
             None => min_edge_id + sampled_offset as EdgeT,
         };
-        (
+        Some((
             self.get_unchecked_destination_node_id_from_edge_id(edge_id),
             edge_id,
+        ))
+    }
+
+    /// Returns the random state and the source node of the random walk with the given index.
+    ///
+    /// # Arguments
+    /// * `random_state`: u64 - The random state of the walks.
+    /// * `quantity`: NodeT - Number of random walks to compute for each iteration.
+    /// * `index`: NodeT - The index of the random walk, across all the iterations.
+    fn get_random_walk_random_state_and_source_node_id(
+        &self,
+        random_state: u64,
+        quantity: NodeT,
+        index: NodeT,
+    ) -> (u64, NodeT) {
+        let local_index = index % quantity;
+        let random_source_id = splitmix64(
+            random_state
+                .wrapping_add(local_index as u64)
+                .wrapping_add(0x4cc4854c0155130a),
+        ) as NodeT;
+        (
+            get_walk_random_state(random_state, local_index, index / quantity),
+            unsafe {
+                self.get_unchecked_unique_source_node_id(
+                    random_source_id % self.get_number_of_unique_source_nodes(),
+                )
+            },
         )
     }
 
+    #[no_binding]
+    /// Return iterator over walks run on a random subset of the not trap nodes, computed one at a time.
+    ///
+    /// # Arguments
+    /// * `quantity`: NodeT - Number of random walk to compute.
+    /// * `parameters`: &WalksParameters - the weighted walks parameters.
+    ///
+    /// # Implementative details
+    /// The walks are computed lazily and sequentially, so that only the walk
+    /// being yielded is kept in memory, and they are the same walks, in the same
+    /// order, returned by `par_iter_random_walks` with the same parameters.
+    /// The iterator owns a copy of the graph, which shares its data with the
+    /// current graph, and of the parameters, hence it does not borrow either.
+    ///
+    /// # Example
+    /// The streamed walks match the ones computed in parallel:
+    ///
+    /// ```rust
+    /// # use graph::walks_parameters::WalksParameters;
+    /// # use graph::NodeT;
+    /// # use rayon::prelude::*;
+    /// # let ppi = graph::test_utilities::load_ppi(true, true, true, false, false, false);
+    /// let parameters = WalksParameters::new(32).unwrap();
+    /// let walks = ppi.iter_random_walks(100, &parameters).unwrap().collect::<Vec<Vec<NodeT>>>();
+    /// assert_eq!(walks, ppi.par_iter_random_walks(100, &parameters).unwrap().collect::<Vec<Vec<NodeT>>>());
+    /// ```
+    ///
+    /// # Raises
+    /// * If the graph does not contain edges.
+    /// * If the given walks parameters are not compatible with the current graph instance.
+    /// * If the graph contains negative edge weights.
+    pub fn iter_random_walks(
+        &self,
+        quantity: NodeT,
+        parameters: &WalksParameters,
+    ) -> Result<impl Iterator<Item = Vec<NodeT>> + Send + 'static> {
+        self.must_have_edges()?;
+        if parameters.uses_edge_weights(self) {
+            self.must_have_positive_edge_weights()?;
+        }
+
+        // Validate if given parameters are compatible with current graph.
+        parameters.validate(self)?;
+
+        // We use the same criterion of the parallel random walks,
+        // so that the two methods return the same walks.
+        let use_uniform = (!parameters.uses_edge_weights(self)
+            || self.has_constant_edge_weights()?)
+            && parameters.is_first_order_walk()
+            && !parameters.has_node_mask();
+
+        let graph = self.clone();
+        let parameters = parameters.clone();
+        let random_state = splitmix64(parameters.random_state);
+        let total_iterations = quantity * parameters.iterations;
+
+        Ok((0..total_iterations).map(move |index| unsafe {
+            let (random_state, node) = graph.get_random_walk_random_state_and_source_node_id(
+                random_state,
+                quantity,
+                index,
+            );
+            let walk_length = parameters.single_walk_parameters.walk_length;
+            let mut walk_buffer = vec![0; walk_length as usize];
+            match use_uniform {
+                true => {
+                    graph.uniform_walk_from_slice(node, random_state, walk_length, &mut walk_buffer)
+                }
+                false => graph.get_unchecked_single_walk_from_slice(
+                    node,
+                    random_state,
+                    &parameters.single_walk_parameters,
+                    &mut walk_buffer,
+                ),
+            };
+            walk_buffer
+        }))
+    }
+
     /// Return vector of walks run on each non-trap node of the graph.
     ///
     /// # Arguments
@@ -713,19 +903,11 @@ impl Graph {
         random_walks_buffer: &mut [NodeT],
     ) -> Result<()> {
         self.must_have_edges()?;
-        let random_state = splitmix64(parameters.random_state as u64);
+        let random_state = splitmix64(parameters.random_state);
         self.populate_walks_slice(
             quantity,
             move |index| {
-                let local_index = index % quantity;
-                let random_source_id = splitmix64(
-                    (random_state + local_index as u64).wrapping_add(0x4cc4854c0155130a),
-                ) as NodeT;
-                (splitmix64(random_state + index as u64), unsafe {
-                    self.get_unchecked_unique_source_node_id(
-                        random_source_id % self.get_number_of_unique_source_nodes(),
-                    )
-                })
+                self.get_random_walk_random_state_and_source_node_id(random_state, quantity, index)
             },
             parameters,
             random_walks_buffer,
@@ -749,19 +931,11 @@ impl Graph {
         parameters: &'a WalksParameters,
     ) -> Result<impl IndexedParallelIterator<Item = Vec<NodeT>> + 'a> {
         self.must_have_edges()?;
-        let random_state = splitmix64(parameters.random_state as u64);
+        let random_state = splitmix64(parameters.random_state);
         self.par_iter_walks(
             quantity,
             move |index| {
-                let local_index = index % quantity;
-                let random_source_id = splitmix64(
-                    (random_state + local_index as u64).wrapping_add(0x4cc4854c0155130a),
-                ) as NodeT;
-                (splitmix64(random_state + index as u64), unsafe {
-                    self.get_unchecked_unique_source_node_id(
-                        random_source_id % self.get_number_of_unique_source_nodes(),
-                    )
-                })
+                self.get_random_walk_random_state_and_source_node_id(random_state, quantity, index)
             },
             parameters,
         )
@@ -783,15 +957,20 @@ impl Graph {
         parameters: &'a WalksParameters,
     ) -> Result<impl IndexedParallelIterator<Item = Vec<NodeT>> + 'a> {
         self.must_have_edges()?;
-        let random_state = splitmix64(parameters.random_state as u64);
+        let random_state = splitmix64(parameters.random_state);
         self.par_iter_walks(
             self.get_number_of_unique_source_nodes(),
             move |index| {
-                (splitmix64(random_state + index as u64), unsafe {
-                    self.get_unchecked_unique_source_node_id(
-                        index as NodeT % self.get_number_of_unique_source_nodes(),
-                    )
-                })
+                let number_of_unique_source_nodes = self.get_number_of_unique_source_nodes();
+                let local_index = index % number_of_unique_source_nodes;
+                (
+                    get_walk_random_state(
+                        random_state,
+                        local_index,
+                        index / number_of_unique_source_nodes,
+                    ),
+                    unsafe { self.get_unchecked_unique_source_node_id(local_index) },
+                )
             },
             parameters,
         )
@@ -810,15 +989,20 @@ impl Graph {
         parameters: &'a WalksParameters,
     ) -> Result<impl Iterator<Item = Vec<NodeT>> + 'a> {
         self.must_have_edges()?;
-        let random_state = splitmix64(parameters.random_state as u64);
+        let random_state = splitmix64(parameters.random_state);
         self.iter_walks(
             self.get_number_of_unique_source_nodes(),
             move |index| {
-                (splitmix64(random_state + index as u64), unsafe {
-                    self.get_unchecked_unique_source_node_id(
-                        index as NodeT % self.get_number_of_unique_source_nodes(),
-                    )
-                })
+                let number_of_unique_source_nodes = self.get_number_of_unique_source_nodes();
+                let local_index = index % number_of_unique_source_nodes;
+                (
+                    get_walk_random_state(
+                        random_state,
+                        local_index,
+                        index / number_of_unique_source_nodes,
+                    ),
+                    unsafe { self.get_unchecked_unique_source_node_id(local_index) },
+                )
             },
             parameters,
         )
@@ -841,7 +1025,7 @@ impl Graph {
         to_node: impl Fn(NodeT) -> (u64, NodeT) + Sync + Send + 'a,
         parameters: &'a WalksParameters,
     ) -> Result<impl IndexedParallelIterator<Item = Vec<NodeT>> + 'a> {
-        if self.has_edge_weights() {
+        if parameters.uses_edge_weights(self) {
             self.must_have_positive_edge_weights()?;
         }
 
@@ -850,11 +1034,13 @@ impl Graph {
 
         let total_iterations = quantity * parameters.iterations;
 
-        // If the graph does not have any weights and the parameters
+        // If the walks do not use any edge weights and the parameters
         // for the walks are all equal to 1, we can use the first-order
         // random walk algorithm.
-        let use_uniform = (!self.has_edge_weights() || self.has_constant_edge_weights()?)
-            && parameters.is_first_order_walk();
+        let use_uniform = (!parameters.uses_edge_weights(self)
+            || self.has_constant_edge_weights()?)
+            && parameters.is_first_order_walk()
+            && !parameters.has_node_mask();
 
         let walks = (0..total_iterations)
             .into_par_iter()
@@ -899,7 +1085,7 @@ impl Graph {
         to_node: impl Fn(NodeT) -> (u64, NodeT) + Sync + Send + 'a,
         parameters: &'a WalksParameters,
     ) -> Result<impl Iterator<Item = Vec<NodeT>> + 'a> {
-        if self.has_edge_weights() {
+        if parameters.uses_edge_weights(self) {
             self.must_have_positive_edge_weights()?;
         }
 
@@ -908,10 +1094,12 @@ impl Graph {
 
         let total_iterations = quantity * parameters.iterations;
 
-        // If the graph does not have any weights and the parameters
+        // If the walks do not use any edge weights and the parameters
         // for the walks are all equal to 1, we can use the first-order
         // random walk algorithm.
-        let use_uniform = !self.has_edge_weights() && parameters.is_first_order_walk();
+        let use_uniform = !parameters.uses_edge_weights(self)
+            && parameters.is_first_order_walk()
+            && !parameters.has_node_mask();
 
         let walks = (0..total_iterations).map(move |index| unsafe {
             let (random_state, node) = to_node(index);
@@ -955,7 +1143,7 @@ impl Graph {
         parameters: &'a WalksParameters,
         random_walks_buffer: &mut [NodeT],
     ) -> Result<()> {
-        if self.has_edge_weights() {
+        if parameters.uses_edge_weights(self) {
             self.must_have_positive_edge_weights()?;
         }
 
@@ -964,10 +1152,12 @@ impl Graph {
 
         let total_iterations = quantity * parameters.iterations;
 
-        // If the graph does not have any weights and the parameters
+        // If the walks do not use any edge weights and the parameters
         // for the walks are all equal to 1, we can use the first-order
         // random walk algorithm.
-        let use_uniform = !self.has_edge_weights() && parameters.is_first_order_walk();
+        let use_uniform = !parameters.uses_edge_weights(self)
+            && parameters.is_first_order_walk()
+            && !parameters.has_node_mask();
 
         (0..total_iterations)
             .into_par_iter()
@@ -1016,6 +1206,9 @@ impl Graph {
     /// Returns single walk from given node.
     ///
     /// This method assumes that there are no traps in the graph.
+    /// When a node mask is provided and the walk reaches a node with no
+    /// allowed neighbours, the walk terminates and the remaining positions
+    /// of the buffer are filled with the last visited node.
     ///
     /// # Arguments
     /// * `node`: NodeT - Node from where to start the random walks.
@@ -1032,6 +1225,14 @@ impl Graph {
         parameters: &SingleWalkParameters,
         walk_buffer: &mut [NodeT],
     ) {
+        let walk_length = parameters.walk_length as usize;
+        let use_edge_weights = parameters.uses_edge_weights(self);
+        if let Some(node_mask) = &parameters.node_mask {
+            if !node_mask[node as usize] {
+                walk_buffer[..walk_length].fill(node);
+                return;
+            }
+        }
         let (min_edge_id, max_edge_id, destinations, indices) = self
             .get_unchecked_edges_and_destinations_from_source_node_id(
                 parameters.max_neighbours,
@@ -1039,7 +1240,7 @@ impl Graph {
                 node,
             );
         random_state = splitmix64(random_state);
-        let (dst, edge) = self.extract_node(
+        let (dst, edge) = match self.extract_node(
             node,
             random_state,
             &parameters.weights,
@@ -1048,7 +1249,15 @@ impl Graph {
             self.get_destinations_slice(min_edge_id, max_edge_id, &destinations),
             &indices,
             parameters.normalize_by_degree,
-        );
+            &parameters.node_mask,
+            use_edge_weights,
+        ) {
+            Some(step) => step,
+            None => {
+                walk_buffer[..walk_length].fill(node);
+                return;
+            }
+        };
 
         // Here we use the get unchecked mut and the get unchecked
         // because we need to avoid multiple bound checks, which
@@ -1065,7 +1274,7 @@ impl Graph {
         let mut previous_dst = dst;
         let mut previous_edge = edge;
 
-        for iteration in 2..parameters.walk_length {
+        for iteration in 2..walk_length {
             random_state = splitmix64(random_state);
             let (min_edge_id, max_edge_id, destinations, indices) = self
                 .get_unchecked_edges_and_destinations_from_source_node_id(
@@ -1074,7 +1283,7 @@ impl Graph {
                     previous_dst,
                 );
             random_state = splitmix64(random_state);
-            let (dst, edge) = self.extract_edge(
+            let (dst, edge) = match self.extract_edge(
                 previous_src,
                 previous_dst,
                 previous_edge,
@@ -1090,7 +1299,15 @@ impl Graph {
                 ),
                 &indices,
                 parameters.normalize_by_degree,
-            );
+                &parameters.node_mask,
+                use_edge_weights,
+            ) {
+                Some(step) => step,
+                None => {
+                    walk_buffer[iteration..walk_length].fill(previous_dst);
+                    return;
+                }
+            };
 
             previous_min_edge_id = min_edge_id;
             previous_max_edge_id = max_edge_id;
@@ -1098,7 +1315,7 @@ impl Graph {
             previous_src = previous_dst;
             previous_dst = dst;
             previous_edge = edge;
-            *walk_buffer.get_unchecked_mut(iteration as usize) = dst;
+            *walk_buffer.get_unchecked_mut(iteration) = dst;
         }
     }
 
@@ -18,6 +18,8 @@ pub struct SingleWalkParameters {
     pub(crate) weights: WalkWeights,
     pub(crate) max_neighbours: Option<NodeT>,
     pub(crate) normalize_by_degree: bool,
+    pub(crate) node_mask: Option<Vec<bool>>,
+    pub(crate) use_edge_weights_as_probabilities: Option<bool>,
 }
 
 #[derive(Clone, Debug, PartialEq)]
@@ -26,7 +28,7 @@ pub struct SingleWalkParameters {
 pub struct WalksParameters {
     pub(crate) single_walk_parameters: SingleWalkParameters,
     pub(crate) iterations: NodeT,
-    pub(crate) random_state: NodeT,
+    pub(crate) random_state: u64,
 }
 
 impl Default for WalkWeights {
@@ -136,6 +138,8 @@ impl SingleWalkParameters {
             weights: WalkWeights::default(),
             max_neighbours: Some(100),
             normalize_by_degree: false,
+            node_mask: None,
+            use_edge_weights_as_probabilities: None,
         })
     }
 
@@ -166,6 +170,14 @@ impl SingleWalkParameters {
     pub fn is_node2vec_walk(&self) -> bool {
         self.weights.is_node2vec_walk()
     }
+
+    /// Return whether the random walks on the given graph use the edge weights.
+    ///
+    /// # Arguments
+    /// * `graph`: &Graph - The graph on which the random walks are computed.
+    pub fn uses_edge_weights(&self, graph: &Graph) -> bool {
+        graph.has_edge_weights() && self.use_edge_weights_as_probabilities.unwrap_or(true)
+    }
 }
 
 impl Default for WalksParameters {
@@ -191,7 +203,7 @@ impl WalksParameters {
         Ok(WalksParameters {
             single_walk_parameters: SingleWalkParameters::new(walk_length)?,
             iterations: 1,
-            random_state: splitmix64(42) as NodeT,
+            random_state: splitmix64(42),
         })
     }
 
@@ -296,20 +308,123 @@ impl WalksParameters {
         self
     }
 
+    /// Set the mask of the nodes the random walks are allowed to visit.
+    ///
+    /// # Arguments
+    /// * `node_mask`: Option<Vec<bool>> - Whether each node may be visited by the random walks.
+    ///
+    /// # Implementative details
+    /// When the walk would step on a masked-out node, the step is resampled
+    /// among the neighbours that are allowed by the mask, keeping their relative
+    /// transition weights. When `max_neighbours` is set, only the subsampled
+    /// neighbours are considered.
+    ///
+    /// When no neighbour is allowed, the node behaves as a trap within the
+    /// masked subgraph: the walk terminates and the remaining positions of
+    /// the walk are filled with the last visited node. Similarly, walks
+    /// starting from a masked-out node are composed only of the starting node.
+    ///
+    /// Since the masked walks need to check every transition, they never
+    /// use the faster uniform first-order walk.
+    ///
+    /// # Example
+    /// You can restrict the walks to a subset of the nodes as follows:
+    ///
+    /// ```rust
+    /// # use graph::walks_parameters::WalksParameters;
+    /// # let ppi = graph::test_utilities::load_ppi(true, true, true, true, false, false);
+    /// let node_mask = ppi.get_node_ids().into_iter().map(|node_id| node_id % 2 == 0).collect();
+    /// let parameters = WalksParameters::new(32).unwrap().set_node_mask(Some(node_mask));
+    /// assert!(parameters.validate(&ppi).is_ok());
+    /// ```
+    pub fn set_node_mask(mut self, node_mask: Option<Vec<bool>>) -> WalksParameters {
+        if let Some(node_mask) = node_mask {
+            self.single_walk_parameters.node_mask = Some(node_mask);
+        }
+        self
+    }
+
+    /// Return whether the random walks are constrained by a node mask.
+    pub fn has_node_mask(&self) -> bool {
+        self.single_walk_parameters.node_mask.is_some()
+    }
+
+    /// Set whether the transition probabilities should be proportional to the edge weights.
+    ///
+    /// # Arguments
+    /// * `use_edge_weights_as_probabilities`: Option<bool> - Whether to use the edge weights to bias the transitions.
+    ///
+    /// # Implementative details
+    /// When enabled, the transition weight towards each neighbour is the
+    /// edge weight, normalized over the neighbours of the current node and
+    /// multiplied by the node2vec and type-change factors. When a node has
+    /// outgoing edge weights summing to zero, its neighbours are sampled
+    /// uniformly instead. When disabled, the edge weights are ignored and
+    /// only the other factors are used. When not set, the edge weights are
+    /// used if the graph has them.
+    ///
+    /// # Example
+    /// Enabling the edge weights on a graph without them is not allowed:
+    ///
+    /// ```rust
+    /// # use graph::walks_parameters::WalksParameters;
+    /// # let weighted_ppi = graph::test_utilities::load_ppi(true, true, true, false, false, false);
+    /// # let unweighted_ppi = graph::test_utilities::load_ppi(true, true, false, false, false, false);
+    /// let parameters = WalksParameters::new(32).unwrap().set_use_edge_weights_as_probabilities(Some(true));
+    /// assert!(parameters.validate(&weighted_ppi).is_ok());
+    /// assert!(parameters.validate(&unweighted_ppi).is_err());
+    /// ```
+    pub fn set_use_edge_weights_as_probabilities(
+        mut self,
+        use_edge_weights_as_probabilities: Option<bool>,
+    ) -> WalksParameters {
+        if let Some(use_edge_weights_as_probabilities) = use_edge_weights_as_probabilities {
+            self.single_walk_parameters
+                .use_edge_weights_as_probabilities = Some(use_edge_weights_as_probabilities);
+        }
+        self
+    }
+
+    /// Return whether the random walks on the given graph use the edge weights.
+    ///
+    /// # Arguments
+    /// * `graph`: &Graph - The graph on which the random walks are computed.
+    pub fn uses_edge_weights(&self, graph: &Graph) -> bool {
+        self.single_walk_parameters.uses_edge_weights(graph)
+    }
+
     /// Set the random_state.
     ///
     /// # Arguments
-    /// * `random_state`: Option<usize> - random_state for reproducible random walks.
+    /// * `random_state`: Option<u64> - random_state for reproducible random walks.
     ///
-    pub fn set_random_state(mut self, random_state: Option<usize>) -> WalksParameters {
+    /// # Implementative details
+    /// The random state of each walk is derived only from this random state,
+    /// the walk number within the iteration and the iteration number, hence
+    /// the same parameters yield the same walks across calls, independently
+    /// of the number of threads and of their scheduling.
+    ///
+    /// # Example
+    /// The random walks are reproducible given the random state:
+    ///
+    /// ```rust
+    /// # use graph::walks_parameters::WalksParameters;
+    /// # use graph::NodeT;
+    /// # let ppi = graph::test_utilities::load_ppi(true, true, true, false, false, false);
+    /// let parameters = WalksParameters::new(32).unwrap().set_random_state(Some(1234));
+    /// let first_walks = ppi.iter_complete_walks(&parameters).unwrap().collect::<Vec<Vec<NodeT>>>();
+    /// let second_walks = ppi.iter_complete_walks(&parameters).unwrap().collect::<Vec<Vec<NodeT>>>();
+    /// assert_eq!(first_walks, second_walks);
+    /// ```
+    pub fn set_random_state(mut self, random_state: Option<u64>) -> WalksParameters {
         if let Some(s) = random_state {
-            self.random_state = splitmix64(s as u64) as NodeT;
+            self.random_state = splitmix64(s);
         }
         self
     }
 
     /// Return the random_state used in the walks.
-    pub fn get_random_state(&self) -> NodeT {
+    pub fn get_random_state(&self) -> u64 {
         self.random_state
     }
 
@@ -487,6 +602,30 @@ impl WalksParameters {
             )
             .to_string());
         }
+        if self
+            .single_walk_parameters
+            .use_edge_weights_as_probabilities
+            == Some(true)
+            && !graph.has_edge_weights()
+        {
+            return Err(concat!(
+                "The edge weights were requested to be used as transition probabilities, ",
+                "but the current graph does not have edge weights."
+            )
+            .to_string());
+        }
+        if let Some(node_mask) = &self.single_walk_parameters.node_mask {
+            if node_mask.len() != graph.get_number_of_nodes() as usize {
+                return Err(format!(
+                    concat!(
+                        "The provided node mask has length {}, ",
+                        "but the graph has {} nodes."
+                    ),
+                    node_mask.len(),
+                    graph.get_number_of_nodes()
+                ));
+            }
+        }
 
         Ok(())
     }
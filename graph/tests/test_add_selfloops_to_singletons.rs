@@ -0,0 +1,44 @@
+extern crate graph;
+use graph::*;
+
+#[test]
+fn test_add_selfloops_to_singletons() -> Result<()> {
+    let mut builder = GraphBuilder::new(None, Some(true));
+    builder.add_edge("A", "B", Some("link".to_string()), Some(1.0))?;
+    builder.add_edge("B", "B", Some("link".to_string()), Some(2.0))?;
+    builder.add_node("C".to_string(), None)?;
+    builder.add_node("D".to_string(), None)?;
+    let graph = builder.build()?;
+    assert_eq!(graph.get_number_of_singleton_nodes(), 2);
+
+    let with_selfloops = graph.add_selfloops_to_singletons(Some("link"), Some(3.0))?;
+    assert_eq!(with_selfloops.get_number_of_singleton_nodes(), 0);
+    assert_eq!(with_selfloops.get_number_of_directed_edges(), 4);
+    assert_eq!(with_selfloops.get_number_of_selfloops(), 3);
+    // The trap node B and the source node A are left untouched.
+    let node_id = |name: &str| with_selfloops.get_node_id_from_node_name(name).unwrap();
+    assert!(!with_selfloops.has_edge_from_node_ids(node_id("A"), node_id("A")));
+    for name in ["C", "D"] {
+        let edge_id = with_selfloops.get_edge_id_from_node_ids(node_id(name), node_id(name))?;
+        assert_eq!(
+            with_selfloops.get_edge_type_name_from_edge_id(edge_id)?,
+            Some("link".to_string())
+        );
+        assert_eq!(with_selfloops.get_edge_weight_from_edge_id(edge_id)?, 3.0);
+    }
+
+    assert!(graph.add_selfloops_to_singletons(None, None).is_err());
+    assert!(graph
+        .add_selfloops_to_singletons(Some("unknown"), Some(1.0))
+        .is_err());
+
+    let mut builder = GraphBuilder::new(None, Some(false));
+    builder.add_edge("A", "B", None, None)?;
+    builder.add_node("C".to_string(), None)?;
+    let graph = builder.build()?;
+    let with_selfloops = graph.add_selfloops_to_singletons(None, None)?;
+    assert_eq!(with_selfloops.get_number_of_directed_edges(), 3);
+    assert!(graph.add_selfloops_to_singletons(None, Some(1.0)).is_err());
+
+    Ok(())
+}
@@ -0,0 +1,58 @@
+extern crate graph;
+use graph::*;
+
+#[test]
+fn test_add_weight_noise() -> Result<()> {
+    let graph = graph::test_utilities::load_ppi(true, true, true, false, false, false);
+    let noisy = graph.add_weight_noise(0.1, 42, None)?;
+    assert_eq!(
+        noisy.get_number_of_directed_edges(),
+        graph.get_number_of_directed_edges()
+    );
+    let noisy_weights = noisy.get_directed_edge_weights()?;
+    assert!(noisy_weights.iter().all(|&weight| weight >= 0.0));
+    assert_ne!(noisy_weights, graph.get_directed_edge_weights()?);
+    // Undirected edges receive the same noise in both directions.
+    for (edge_id, src, dst) in noisy.iter_directed_edge_node_ids().take(1000) {
+        let reverse_edge_id = noisy.get_edge_id_from_node_ids(dst, src)?;
+        assert_eq!(
+            noisy_weights[edge_id as usize],
+            noisy_weights[reverse_edge_id as usize]
+        );
+    }
+
+    // The noise is reproducible given the random state.
+    assert_eq!(
+        noisy_weights,
+        graph
+            .add_weight_noise(0.1, 42, None)?
+            .get_directed_edge_weights()?
+    );
+    assert_ne!(
+        noisy_weights,
+        graph
+            .add_weight_noise(0.1, 43, None)?
+            .get_directed_edge_weights()?
+    );
+
+    // Without noise the weights are left untouched.
+    assert_eq!(
+        graph
+            .add_weight_noise(0.0, 42, None)?
+            .get_directed_edge_weights()?,
+        graph.get_directed_edge_weights()?
+    );
+
+    let unclipped = graph.add_weight_noise(1000.0, 42, Some(false))?;
+    assert!(unclipped
+        .get_directed_edge_weights()?
+        .iter()
+        .any(|&weight| weight < 0.0));
+
+    assert!(graph.add_weight_noise(-1.0, 42, None).is_err());
+    assert!(graph.add_weight_noise(f64::NAN, 42, None).is_err());
+    let unweighted = graph::test_utilities::load_ppi(true, true, false, false, false, false);
+    assert!(unweighted.add_weight_noise(0.1, 42, None).is_err());
+
+    Ok(())
+}
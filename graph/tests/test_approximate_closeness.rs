@@ -0,0 +1,41 @@
+extern crate graph;
+use graph::*;
+
+#[test]
+fn test_approximate_closeness_centrality() -> Result<()> {
+    let mut builder = GraphBuilder::new(None, Some(false));
+    for (src, dst) in [("A", "B"), ("B", "C"), ("C", "D"), ("D", "E"), ("B", "F")] {
+        builder.add_edge(src.to_string(), dst.to_string(), None, None)?;
+    }
+    let graph = builder.build()?;
+
+    // With as many landmarks as nodes, the estimates are exact.
+    let closeness = graph.get_closeness_centrality(None, Some(false))?;
+    let harmonic = graph.get_harmonic_centrality();
+    let approximate_closeness = graph.get_approximate_closeness_centrality(6, Some(7))?;
+    let approximate_harmonic = graph.get_approximate_harmonic_centrality(100, None)?;
+    for node_id in 0..6 {
+        assert!((closeness[node_id] - approximate_closeness[node_id]).abs() < 1e-6);
+        assert!((harmonic[node_id] as f64 - approximate_harmonic[node_id]).abs() < 1e-5);
+    }
+
+    // The Wasserman-Faust correction is estimated as well on disconnected graphs.
+    let mut builder = GraphBuilder::new(None, Some(false));
+    for (src, dst) in [("A", "B"), ("B", "C"), ("D", "E")] {
+        builder.add_edge(src.to_string(), dst.to_string(), None, None)?;
+    }
+    let disconnected_graph = builder.build()?;
+    let closeness = disconnected_graph.get_closeness_centrality(None, Some(false))?;
+    let approximate_closeness = disconnected_graph.get_approximate_closeness_centrality(5, None)?;
+    for node_id in 0..5 {
+        assert!((closeness[node_id] - approximate_closeness[node_id]).abs() < 1e-6);
+    }
+
+    // With fewer landmarks, the results are reproducible given the random state.
+    assert_eq!(
+        graph.get_approximate_closeness_centrality(3, Some(1))?,
+        graph.get_approximate_closeness_centrality(3, Some(1))?
+    );
+    assert!(graph.get_approximate_closeness_centrality(0, None).is_err());
+    Ok(())
+}
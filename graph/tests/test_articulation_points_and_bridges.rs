@@ -0,0 +1,70 @@
+extern crate graph;
+use graph::*;
+
+/// Builds a triangle A-B-C, attached through C to the path C-D-E,
+/// where E has a selfloop and F is a singleton node.
+fn build_graph(directed: bool, edges_between_d_and_e: &[(&str, &str, &str)]) -> Result<Graph> {
+    let mut builder = GraphBuilder::new(None, Some(directed));
+    for &(src, dst, edge_type) in [
+        ("A", "B", "x"),
+        ("B", "C", "x"),
+        ("C", "A", "x"),
+        ("C", "D", "x"),
+        ("E", "E", "x"),
+    ]
+    .iter()
+    .chain(edges_between_d_and_e.iter())
+    {
+        builder.add_edge(src, dst, Some(edge_type.to_string()), None)?;
+    }
+    builder.add_node("F".to_string(), None)?;
+    builder.build()
+}
+
+fn get_expected_bridge(graph: &Graph, src: &str, dst: &str) -> (NodeT, NodeT) {
+    let src = graph.get_node_id_from_node_name(src).unwrap();
+    let dst = graph.get_node_id_from_node_name(dst).unwrap();
+    (src.min(dst), src.max(dst))
+}
+
+#[test]
+fn test_articulation_points_and_bridges() -> Result<()> {
+    // In the undirected graph D and E are connected by a multi-edge,
+    // so the edge between them is not a bridge, while D is still an
+    // articulation point as it is the only neighbour of E.
+    let graph = build_graph(false, &[("D", "E", "x"), ("D", "E", "y")])?;
+    let mut expected_articulation_points = vec![
+        graph.get_node_id_from_node_name("C")?,
+        graph.get_node_id_from_node_name("D")?,
+    ];
+    expected_articulation_points.sort_unstable();
+    assert_eq!(
+        graph.get_articulation_points(),
+        expected_articulation_points
+    );
+    assert_eq!(
+        graph.get_bridges(),
+        vec![get_expected_bridge(&graph, "C", "D")]
+    );
+
+    // In the directed graph the two reciprocal edges between D and E
+    // are the same undirected edge, which is therefore a bridge.
+    let graph = build_graph(true, &[("D", "E", "x"), ("E", "D", "x")])?;
+    let mut expected_articulation_points = vec![
+        graph.get_node_id_from_node_name("C")?,
+        graph.get_node_id_from_node_name("D")?,
+    ];
+    expected_articulation_points.sort_unstable();
+    assert_eq!(
+        graph.get_articulation_points(),
+        expected_articulation_points
+    );
+    let mut expected_bridges = vec![
+        get_expected_bridge(&graph, "C", "D"),
+        get_expected_bridge(&graph, "D", "E"),
+    ];
+    expected_bridges.sort_unstable();
+    assert_eq!(graph.get_bridges(), expected_bridges);
+
+    Ok(())
+}
@@ -0,0 +1,47 @@
+extern crate graph;
+use graph::*;
+
+#[test]
+fn test_astar() -> Result<()> {
+    let mut builder = GraphBuilder::new(None, Some(false));
+    for (src, dst, weight) in [
+        ("A", "B", 1.0),
+        ("B", "D", 1.0),
+        ("A", "C", 1.0),
+        ("C", "D", 3.0),
+        ("A", "D", 5.0),
+        ("E", "F", 1.0),
+    ] {
+        builder.add_edge(src.to_string(), dst.to_string(), None, Some(weight))?;
+    }
+    let graph = builder.build()?;
+    let node_id = |node_name: &str| graph.get_node_id_from_node_name(node_name).unwrap();
+    let (a, b, d) = (node_id("A"), node_id("B"), node_id("D"));
+
+    // The heuristic is admissible for the destination D.
+    let mut heuristic = vec![0.0; 6];
+    for (node_name, value) in [("A", 2.0), ("B", 1.0), ("C", 2.0)] {
+        heuristic[node_id(node_name) as usize] = value;
+    }
+    let astar = graph.get_astar_from_node_ids(a, d, heuristic.clone(), Some(true))?;
+    assert_eq!(astar.get_distance_from_node_id(d)?, 2.0);
+    assert_eq!(astar.get_parent_from_node_id(d)?, Some(b));
+    assert_eq!(astar.get_parent_from_node_id(b)?, Some(a));
+
+    // Without the edge weights, the direct edge is the shortest path.
+    let astar = graph.get_astar_from_node_ids(a, d, vec![0.0; 6], None)?;
+    assert_eq!(astar.get_distance_from_node_id(d)?, 1.0);
+    assert_eq!(astar.get_parent_from_node_id(d)?, Some(a));
+
+    // Nodes in other components cannot be reached.
+    let astar = graph.get_astar_from_node_ids(a, node_id("E"), vec![0.0; 6], Some(true))?;
+    assert!(astar.get_distance_from_node_id(node_id("E"))?.is_infinite());
+
+    assert!(graph
+        .get_astar_from_node_ids(a, d, vec![0.0; 5], None)
+        .is_err());
+    assert!(graph
+        .get_astar_from_node_ids(a, d, vec![-1.0; 6], None)
+        .is_err());
+    Ok(())
+}
@@ -0,0 +1,65 @@
+extern crate graph;
+use graph::*;
+
+/// Returns the attribute of each node of the graph from the node names.
+fn get_attribute(graph: &Graph, attribute: &[(&str, f64)]) -> Result<Vec<f64>> {
+    let mut node_attribute = vec![0.0; graph.get_number_of_nodes() as usize];
+    for (node_name, value) in attribute {
+        node_attribute[graph.get_node_id_from_node_name(node_name)? as usize] = *value;
+    }
+    Ok(node_attribute)
+}
+
+#[test]
+fn test_attribute_assortativity() -> Result<()> {
+    let attribute = [("A", 1.0), ("B", 1.0), ("C", -1.0), ("D", -1.0)];
+
+    // Only the nodes with the same attribute are connected.
+    let graph = test_utilities::build_graph_from_edges(&[("A", "B"), ("C", "D")], false)?;
+    let node_attribute = get_attribute(&graph, &attribute)?;
+    assert!((graph.get_attribute_assortativity(&node_attribute)? - 1.0).abs() < 1e-10);
+
+    // Only the nodes with different attributes are connected.
+    let graph = test_utilities::build_graph_from_edges(&[("A", "C"), ("B", "D")], false)?;
+    let node_attribute = get_attribute(&graph, &attribute)?;
+    assert!((graph.get_attribute_assortativity(&node_attribute)? + 1.0).abs() < 1e-10);
+
+    // With the pairs (1, 1), (-1, -1) and (1, -1) in both directions, the
+    // covariance is 2/6 and the variance is one, hence the coefficient is 1/3.
+    let graph =
+        test_utilities::build_graph_from_edges(&[("A", "B"), ("C", "D"), ("A", "C")], false)?;
+    let node_attribute = get_attribute(&graph, &attribute)?;
+    assert!((graph.get_attribute_assortativity(&node_attribute)? - 1.0 / 3.0).abs() < 1e-10);
+
+    // Using the node degrees as attribute gives the degree assortativity.
+    let node_degrees = graph
+        .get_node_degrees()
+        .into_iter()
+        .map(|degree| degree as f64)
+        .collect::<Vec<f64>>();
+    assert!(
+        (graph.get_attribute_assortativity(&node_degrees)? - graph.get_degree_assortativity()?)
+            .abs()
+            < 1e-10
+    );
+
+    // The coefficient is not defined for constant attributes.
+    assert!(graph.get_attribute_assortativity(&[2.0; 4])?.is_nan());
+
+    // In directed graphs the edges are only considered in their direction.
+    let graph = test_utilities::build_graph_from_edges(&[("A", "B"), ("B", "C")], true)?;
+    let node_attribute = get_attribute(&graph, &[("A", 0.0), ("B", 1.0), ("C", 3.0)])?;
+    assert!((graph.get_attribute_assortativity(&node_attribute)? - 1.0).abs() < 1e-10);
+
+    assert!(graph.get_attribute_assortativity(&[0.0, 1.0]).is_err());
+    assert!(graph
+        .get_attribute_assortativity(&[0.0, f64::NAN, 1.0])
+        .is_err());
+    assert!(
+        test_utilities::build_graph_from_edges_and_singletons(&[], &["A"], false)?
+            .get_attribute_assortativity(&[1.0])
+            .is_err()
+    );
+
+    Ok(())
+}
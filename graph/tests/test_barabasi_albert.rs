@@ -0,0 +1,40 @@
+extern crate graph;
+use graph::*;
+
+#[test]
+fn test_barabasi_albert() -> Result<()> {
+    let nodes_number = 2000;
+    let edges_per_node = 3;
+    let graph = Graph::generate_barabasi_albert(nodes_number, edges_per_node, 42, None)?;
+    assert!(!graph.is_directed());
+    assert!(!graph.has_selfloops());
+    assert_eq!(graph.get_number_of_nodes(), nodes_number);
+    let number_of_edges = 6 + (nodes_number - 4) as EdgeT * 3;
+    assert_eq!(graph.get_number_of_directed_edges(), 2 * number_of_edges);
+    assert!(graph.iter_node_ids().all(|node_id| unsafe {
+        graph.get_unchecked_node_degree_from_node_id(node_id) >= edges_per_node
+    }));
+
+    // The same seed must reproduce the same graph.
+    let other = Graph::generate_barabasi_albert(nodes_number, edges_per_node, 42, None)?;
+    assert_eq!(
+        graph.iter_directed_edge_node_ids().collect::<Vec<_>>(),
+        other.iter_directed_edge_node_ids().collect::<Vec<_>>()
+    );
+
+    // An Erdos-Renyi graph with the same density, built as a single block model.
+    let probability =
+        2.0 * number_of_edges as f64 / (nodes_number as f64 * (nodes_number as f64 - 1.0));
+    let erdos_renyi =
+        Graph::generate_stochastic_block_model(vec![nodes_number], probability, 0.0, false, 42)?;
+    assert!(graph.get_maximum_node_degree()? > 3 * erdos_renyi.get_maximum_node_degree()?);
+
+    let directed = Graph::generate_barabasi_albert(100, 2, 42, Some(true))?;
+    assert!(directed.is_directed());
+    assert_eq!(directed.get_number_of_directed_edges(), 3 + 97 * 2);
+
+    assert!(Graph::generate_barabasi_albert(100, 0, 42, None).is_err());
+    assert!(Graph::generate_barabasi_albert(3, 3, 42, None).is_err());
+
+    Ok(())
+}
@@ -0,0 +1,60 @@
+extern crate graph;
+use graph::*;
+
+fn assert_centralities(
+    graph: &Graph,
+    centralities: &[f32],
+    expected: &[(&str, f32)],
+) -> Result<()> {
+    for &(node_name, expected_centrality) in expected {
+        let node_id = graph.get_node_id_from_node_name(node_name)?;
+        assert!((centralities[node_id as usize] - expected_centrality).abs() < 1e-6);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_undirected_betweenness_centrality() -> Result<()> {
+    let mut builder = GraphBuilder::new(None, Some(false));
+    for (src, dst) in [("A", "B"), ("B", "C"), ("C", "D"), ("B", "E")] {
+        builder.add_edge(src.to_string(), dst.to_string(), None, None)?;
+    }
+    let graph = builder.build()?;
+    // The pairs passing through B are (A, C), (A, D), (A, E), (C, E) and (D, E),
+    // while the ones passing through C are (A, D), (B, D) and (E, D).
+    let expected = [("A", 0.0), ("B", 5.0), ("C", 3.0), ("D", 0.0), ("E", 0.0)];
+    let centralities = graph.get_betweenness_centrality(None, None, Some(false))?;
+    assert_centralities(&graph, &centralities, &expected)?;
+    // On undirected graphs, the normalization is by (n - 1)(n - 2) / 2 = 6.
+    let normalized_centralities =
+        graph.get_betweenness_centrality(Some(true), None, Some(false))?;
+    assert_centralities(
+        &graph,
+        &normalized_centralities,
+        &expected.map(|(node_name, centrality)| (node_name, centrality / 6.0)),
+    )?;
+    Ok(())
+}
+
+#[test]
+fn test_directed_betweenness_centrality() -> Result<()> {
+    let mut builder = GraphBuilder::new(None, Some(true));
+    for (src, dst) in [("A", "B"), ("B", "C"), ("C", "D"), ("A", "E"), ("E", "C")] {
+        builder.add_edge(src.to_string(), dst.to_string(), None, None)?;
+    }
+    let graph = builder.build()?;
+    // The pair (A, C) has two shortest paths, through B and through E, and so
+    // does the pair (A, D), while the pairs (B, D) and (E, D) pass through C.
+    let expected = [("A", 0.0), ("B", 1.0), ("C", 3.0), ("D", 0.0), ("E", 1.0)];
+    let centralities = graph.get_betweenness_centrality(None, None, Some(false))?;
+    assert_centralities(&graph, &centralities, &expected)?;
+    // On directed graphs, the normalization is by (n - 1)(n - 2) = 12.
+    let normalized_centralities =
+        graph.get_betweenness_centrality(Some(true), None, Some(false))?;
+    assert_centralities(
+        &graph,
+        &normalized_centralities,
+        &expected.map(|(node_name, centrality)| (node_name, centrality / 12.0)),
+    )?;
+    Ok(())
+}
@@ -0,0 +1,66 @@
+extern crate graph;
+use graph::*;
+
+#[test]
+fn test_bfs_spanning_tree() -> Result<()> {
+    let mut builder = GraphBuilder::new(None, Some(false));
+    for (src, dst) in [("A", "B"), ("A", "C"), ("B", "D"), ("C", "D"), ("E", "F")] {
+        builder.add_edge(src, dst, None, None)?;
+    }
+    builder.add_node("G".to_string(), None)?;
+    let graph = builder.build()?;
+    let node_id = |name: &str| graph.get_node_id_from_node_name(name).unwrap();
+    // Returns the parent of each node reached by the given tree edges,
+    // checking that each node is reached once.
+    let get_parents = |edge_ids: &[EdgeT]| {
+        let mut parents = vec![None; graph.get_number_of_nodes() as usize];
+        for &edge_id in edge_ids {
+            let (src, dst) = graph.get_node_ids_from_edge_id(edge_id).unwrap();
+            assert!(parents[dst as usize].is_none());
+            parents[dst as usize] = Some(src);
+        }
+        parents
+    };
+
+    // The tree covers only the component of the root, following the
+    // shortest paths from the root, hence D is a child of either B or C.
+    let tree = graph.get_bfs_spanning_tree_from_node_id(node_id("A"))?;
+    assert_eq!(tree.len(), 3);
+    let parents = get_parents(&tree);
+    assert_eq!(parents[node_id("A") as usize], None);
+    assert_eq!(parents[node_id("B") as usize], Some(node_id("A")));
+    assert_eq!(parents[node_id("C") as usize], Some(node_id("A")));
+    assert!([Some(node_id("B")), Some(node_id("C"))].contains(&parents[node_id("D") as usize]));
+    for name in ["E", "F", "G"] {
+        assert_eq!(parents[node_id(name) as usize], None);
+    }
+
+    let tree = graph.get_bfs_spanning_tree_from_node_id(node_id("D"))?;
+    assert_eq!(tree.len(), 3);
+    let parents = get_parents(&tree);
+    assert_eq!(
+        parents[node_id("A") as usize].map(|parent| parents[parent as usize]),
+        Some(Some(node_id("D")))
+    );
+
+    assert!(graph
+        .get_bfs_spanning_tree_from_node_id(node_id("G"))?
+        .is_empty());
+    assert!(graph.get_bfs_spanning_tree_from_node_id(7).is_err());
+
+    // The forest has a tree for each of the three components.
+    let forest = graph.get_bfs_spanning_forest();
+    assert_eq!(forest.len(), 4);
+    let parents = get_parents(&forest);
+    assert_eq!(parents.iter().filter(|parent| parent.is_none()).count(), 3);
+
+    // In directed graphs the tree follows the outbound edges.
+    let mut builder = GraphBuilder::new(None, Some(true));
+    builder.add_edge("A", "B", None, None)?;
+    builder.add_edge("B", "C", None, None)?;
+    let graph = builder.build()?;
+    let tree = graph.get_bfs_spanning_tree_from_node_id(graph.get_node_id_from_node_name("B")?)?;
+    assert_eq!(tree, vec![graph.get_edge_id_from_node_names("B", "C")?]);
+
+    Ok(())
+}
@@ -0,0 +1,68 @@
+extern crate graph;
+use graph::*;
+
+#[test]
+fn test_bidirectional_breadth_first_search() -> Result<()> {
+    for random_state in 0..5 {
+        let first_component = Graph::generate_random_connected_graph(
+            Some(random_state),
+            None,
+            Some(1),
+            Some(2),
+            Some(60),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )?;
+        let second_component = Graph::generate_random_connected_graph(
+            Some(random_state + 100),
+            Some(60),
+            Some(1),
+            Some(2),
+            Some(20),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )?;
+        let graph = (&first_component | &second_component)?;
+        for src_node_id in (0..graph.get_number_of_nodes()).step_by(7) {
+            let bfs =
+                graph.get_breadth_first_search_from_node_ids(src_node_id, None, None, None)?;
+            for dst_node_id in graph.iter_node_ids() {
+                let expected_distance = if bfs.has_path_to_node_id(dst_node_id)? {
+                    Some(bfs.get_distance_from_node_id(dst_node_id)?)
+                } else {
+                    None
+                };
+                assert_eq!(
+                    graph.get_bidirectional_distance_from_node_ids(src_node_id, dst_node_id)?,
+                    expected_distance
+                );
+            }
+        }
+    }
+
+    let directed_graph = Graph::generate_random_connected_graph(
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(true),
+        None,
+    )?;
+    assert!(directed_graph
+        .get_bidirectional_distance_from_node_ids(0, 1)
+        .is_err());
+    Ok(())
+}
@@ -0,0 +1,53 @@
+extern crate graph;
+use graph::*;
+
+#[test]
+fn test_minimum_weight_bipartite_matching() -> Result<()> {
+    let mut builder = GraphBuilder::new(None, Some(false));
+    for (src, dst, weight) in [
+        ("L1", "R1", 5.0),
+        ("L1", "R2", 2.0),
+        ("L1", "R3", 4.0),
+        ("L2", "R1", 3.0),
+        ("L2", "R2", 1.0),
+        ("L2", "R3", 6.0),
+        ("L3", "R1", 4.0),
+        ("L3", "R2", 3.0),
+        ("L3", "R3", 3.0),
+        ("L3", "R4", 10.0),
+    ] {
+        builder.add_edge(src.to_string(), dst.to_string(), None, Some(weight))?;
+    }
+    let graph = builder.build()?;
+    let get_node_ids = |node_names: &[&str]| -> Result<Vec<NodeT>> {
+        node_names
+            .iter()
+            .map(|node_name| graph.get_node_id_from_node_name(node_name))
+            .collect()
+    };
+    let left_node_ids = get_node_ids(&["L1", "L2", "L3"])?;
+    let right_node_ids = get_node_ids(&["R1", "R2", "R3", "R4"])?;
+    let mut expected = get_node_ids(&["L1", "R2", "L2", "R1", "L3", "R3"])?
+        .chunks(2)
+        .map(|pair| (pair[0], pair[1]))
+        .collect::<Vec<(NodeT, NodeT)>>();
+    expected.sort_unstable();
+
+    let matching = graph.get_minimum_weight_bipartite_matching(&left_node_ids, &right_node_ids)?;
+    assert_eq!(matching, expected);
+
+    // Swapping the two sides, the larger set becomes the left one.
+    let mut swapped_expected = expected
+        .iter()
+        .map(|&(left, right)| (right, left))
+        .collect::<Vec<(NodeT, NodeT)>>();
+    swapped_expected.sort_unstable();
+    let swapped_matching =
+        graph.get_minimum_weight_bipartite_matching(&right_node_ids, &left_node_ids)?;
+    assert_eq!(swapped_matching, swapped_expected);
+
+    assert!(graph
+        .get_minimum_weight_bipartite_matching(&left_node_ids, &left_node_ids)
+        .is_err());
+    Ok(())
+}
@@ -0,0 +1,35 @@
+extern crate graph;
+use graph::*;
+
+#[test]
+fn test_bipartite_projection() -> Result<()> {
+    let mut builder = GraphBuilder::new(None, Some(false));
+    for (user, item) in [
+        ("U1", "I1"),
+        ("U1", "I2"),
+        ("U2", "I1"),
+        ("U2", "I2"),
+        ("U3", "I2"),
+        ("U4", "I3"),
+    ] {
+        builder.add_edge(user.to_string(), item.to_string(), None, None)?;
+    }
+    let graph = builder.build()?;
+    let user_node_ids = graph.get_node_ids_from_node_names(vec!["U1", "U2", "U3", "U4"])?;
+
+    let projection = graph.get_bipartite_projection(&user_node_ids, true)?;
+    assert_eq!(projection.get_number_of_nodes(), 4);
+    assert_eq!(projection.get_number_of_undirected_edges(), 3);
+    assert_eq!(projection.get_edge_weight_from_node_names("U1", "U2")?, 2.0);
+    assert_eq!(projection.get_edge_weight_from_node_names("U2", "U3")?, 1.0);
+    assert!(!projection.has_edge_from_node_names("U1", "U4"));
+
+    let unweighted_projection = graph.get_bipartite_projection(&user_node_ids, false)?;
+    assert!(!unweighted_projection.has_edge_weights());
+    assert_eq!(unweighted_projection.get_number_of_undirected_edges(), 3);
+
+    // The side must be an independent set.
+    let node_ids = graph.get_node_ids_from_node_names(vec!["U1", "I1"])?;
+    assert!(graph.get_bipartite_projection(&node_ids, false).is_err());
+    Ok(())
+}
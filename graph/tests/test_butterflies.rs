@@ -0,0 +1,25 @@
+extern crate graph;
+use graph::*;
+
+#[test]
+fn test_number_of_butterflies() -> Result<()> {
+    // The complete bipartite graph K_{2, 3} contains three butterflies.
+    let mut builder = GraphBuilder::new(None, Some(false));
+    for src in ["A", "B"] {
+        for dst in ["1", "2", "3"] {
+            builder.add_edge(src.to_string(), dst.to_string(), None, None)?;
+        }
+    }
+    let graph = builder.build()?;
+    assert_eq!(graph.get_number_of_butterflies()?, 3);
+    // The cached value is returned on the second call.
+    assert_eq!(graph.get_number_of_butterflies()?, 3);
+
+    // A triangle is not bipartite.
+    let mut builder = GraphBuilder::new(None, Some(false));
+    for (src, dst) in [("A", "B"), ("B", "C"), ("C", "A")] {
+        builder.add_edge(src.to_string(), dst.to_string(), None, None)?;
+    }
+    assert!(builder.build()?.get_number_of_butterflies().is_err());
+    Ok(())
+}
@@ -0,0 +1,79 @@
+extern crate graph;
+use graph::*;
+
+#[test]
+fn test_chains_with_endpoints() -> Result<()> {
+    let mut builder = GraphBuilder::new(None, Some(false));
+    for (src, dst) in [
+        // Two cliques of four nodes.
+        ("A", "B"),
+        ("A", "C"),
+        ("A", "D"),
+        ("B", "C"),
+        ("B", "D"),
+        ("C", "D"),
+        ("E", "F"),
+        ("E", "G"),
+        ("E", "H"),
+        ("F", "G"),
+        ("F", "H"),
+        ("G", "H"),
+        // A chain connecting the two cliques.
+        ("D", "M1"),
+        ("M1", "M2"),
+        ("M2", "E"),
+        // A chain starting and ending in the same anchor.
+        ("A", "L1"),
+        ("L1", "L2"),
+        ("L2", "A"),
+    ] {
+        builder.add_edge(src, dst, None, None)?;
+    }
+    let graph = builder.build()?;
+    let node_id = |name: &str| graph.get_node_id_from_node_name(name).unwrap();
+
+    let mut chains = graph
+        .get_chains(Some(1), None)?
+        .into_iter()
+        .map(|chain| chain.get_chain_node_names_with_endpoints())
+        .collect::<Vec<Vec<String>>>();
+    chains.sort();
+
+    let mut expected_bridge_chain = vec!["D", "M1", "M2", "E"];
+    if node_id("E") < node_id("D") {
+        expected_bridge_chain.reverse();
+    }
+    let mut expected_loop_chain = vec!["A", "L1", "L2", "A"];
+    if node_id("L2") < node_id("L1") {
+        expected_loop_chain.reverse();
+    }
+    let mut expected_chains = vec![expected_bridge_chain, expected_loop_chain]
+        .into_iter()
+        .map(|chain| {
+            chain
+                .into_iter()
+                .map(|node_name| node_name.to_string())
+                .collect::<Vec<String>>()
+        })
+        .collect::<Vec<Vec<String>>>();
+    expected_chains.sort();
+    assert_eq!(chains, expected_chains);
+
+    for chain in graph.get_chains(Some(1), Some(true))? {
+        let node_ids = chain.get_chain_node_ids_with_endpoints();
+        assert_eq!(node_ids.len(), chain.len() as usize + 2);
+        assert!(node_ids[0] <= node_ids[node_ids.len() - 1]);
+        assert_eq!(
+            node_ids[1..node_ids.len() - 1]
+                .iter()
+                .cloned()
+                .collect::<std::collections::HashSet<NodeT>>(),
+            chain
+                .get_chain_node_ids()
+                .into_iter()
+                .collect::<std::collections::HashSet<NodeT>>()
+        );
+    }
+
+    Ok(())
+}
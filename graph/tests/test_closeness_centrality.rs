@@ -0,0 +1,46 @@
+extern crate graph;
+use graph::*;
+
+#[test]
+fn test_closeness_centrality() -> Result<()> {
+    let mut builder = GraphBuilder::new(None, Some(false));
+    for (src, dst, weight) in [("A", "B", 2.0), ("B", "C", 1.0), ("D", "E", 3.0)] {
+        builder.add_edge(src.to_string(), dst.to_string(), None, Some(weight))?;
+    }
+    let graph = builder.build()?;
+    let closeness = |centralities: &[f64], node_name: &str| {
+        centralities[graph.get_node_id_from_node_name(node_name).unwrap() as usize]
+    };
+
+    // The Wasserman-Faust correction is r^2 / ((n - 1) d), with n = 5.
+    let centralities = graph.get_closeness_centrality(None, Some(false))?;
+    assert!((closeness(&centralities, "A") - 4.0 / (4.0 * 3.0)).abs() < 1e-12);
+    assert!((closeness(&centralities, "B") - 4.0 / (4.0 * 2.0)).abs() < 1e-12);
+    assert!((closeness(&centralities, "D") - 1.0 / (4.0 * 1.0)).abs() < 1e-12);
+
+    let weighted_centralities = graph.get_closeness_centrality(Some(true), Some(false))?;
+    assert!((closeness(&weighted_centralities, "A") - 4.0 / (4.0 * 5.0)).abs() < 1e-12);
+    assert!((closeness(&weighted_centralities, "C") - 4.0 / (4.0 * 4.0)).abs() < 1e-12);
+    assert!((closeness(&weighted_centralities, "E") - 1.0 / (4.0 * 3.0)).abs() < 1e-12);
+
+    // Only the weighted closeness is not defined with negative edge weights.
+    let mut builder = GraphBuilder::new(None, Some(false));
+    builder.add_edge("A", "B", None, Some(-1.0))?;
+    builder.add_edge("B", "C", None, Some(1.0))?;
+    let negative_graph = builder.build()?;
+    assert!(negative_graph
+        .get_closeness_centrality(Some(true), Some(false))
+        .is_err());
+    assert_eq!(
+        negative_graph
+            .get_closeness_centrality(None, Some(false))?
+            .len(),
+        3
+    );
+
+    let unweighted_graph = graph.remove_edge_weights()?;
+    assert!(unweighted_graph
+        .get_closeness_centrality(Some(true), Some(false))
+        .is_err());
+    Ok(())
+}
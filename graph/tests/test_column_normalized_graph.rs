@@ -0,0 +1,40 @@
+extern crate graph;
+use graph::*;
+
+#[test]
+fn test_column_normalized_graph() -> Result<()> {
+    let mut builder = GraphBuilder::new(None, Some(false));
+    for (src, dst, weight) in [
+        ("A", "B", 1.0),
+        ("B", "C", 3.0),
+        ("C", "A", 2.0),
+        ("C", "D", 5.0),
+    ] {
+        builder.add_edge(src.to_string(), dst.to_string(), None, Some(weight))?;
+    }
+    let graph = builder.build()?;
+    for normalized_graph in [
+        graph.get_column_normalized_graph()?,
+        graph.remove_edge_weights()?.get_column_normalized_graph()?,
+    ] {
+        assert!(normalized_graph.is_directed());
+        let mut inbound_weights = vec![0.0; graph.get_number_of_nodes() as usize];
+        for edge_id in 0..normalized_graph.get_number_of_directed_edges() {
+            let (_, dst) = normalized_graph.get_node_ids_from_edge_id(edge_id)?;
+            inbound_weights[dst as usize] +=
+                normalized_graph.get_edge_weight_from_edge_id(edge_id)? as f64;
+        }
+        for inbound_weight in inbound_weights {
+            assert!((inbound_weight - 1.0).abs() < 1e-6);
+        }
+    }
+
+    // The normalized graph must not share the cached weights with the original one.
+    assert_eq!(graph.get_maximum_edge_weight()?, 5.0);
+    let normalized_graph = graph.get_column_normalized_graph()?;
+    assert!(normalized_graph.get_maximum_edge_weight()? <= 1.0);
+    assert_eq!(graph.get_maximum_edge_weight()?, 5.0);
+    assert_eq!(graph.get_mininum_edge_weight()?, 1.0);
+    assert!(normalized_graph.get_mininum_edge_weight()? < 1.0);
+    Ok(())
+}
@@ -0,0 +1,31 @@
+extern crate graph;
+use graph::*;
+
+#[test]
+fn test_common_neighbours_coo_matrix() -> Result<()> {
+    for directed in [false, true] {
+        let mut builder = GraphBuilder::new(None, Some(directed));
+        for (src, dst) in [("A", "B"), ("B", "C"), ("C", "D"), ("E", "D")] {
+            builder.add_edge(src.to_string(), dst.to_string(), None, None)?;
+        }
+        let graph = builder.build()?;
+        let (pairs, scores) = graph.get_common_neighbours_coo_matrix(None)?;
+        // On the undirected path, the pairs sharing a neighbour are the ones at distance two,
+        // while in the directed path only C and E share the destination D.
+        assert_eq!(pairs.len(), if directed { 2 } else { 6 });
+        for ((src, dst), score) in pairs.into_iter().zip(scores.into_iter()) {
+            assert_ne!(src, dst);
+            assert_eq!(score, 1.0);
+        }
+
+        let node_id = |node_name: &str| graph.get_node_id_from_node_name(node_name).unwrap();
+        let candidate_pairs = [(node_id("A"), node_id("E")), (node_id("C"), node_id("E"))];
+        let (pairs, scores) = graph.get_common_neighbours_coo_matrix(Some(&candidate_pairs))?;
+        assert_eq!(pairs, candidate_pairs.to_vec());
+        assert_eq!(scores, vec![0.0, 1.0]);
+        assert!(graph
+            .get_common_neighbours_coo_matrix(Some(&[(0, 10)]))
+            .is_err());
+    }
+    Ok(())
+}
@@ -0,0 +1,37 @@
+extern crate graph;
+use graph::*;
+
+#[test]
+fn test_common_neighbours_zscore() -> Result<()> {
+    let mut builder = GraphBuilder::new(None, Some(false));
+    for (src, dst) in [
+        ("A", "C"),
+        ("A", "D"),
+        ("A", "E"),
+        ("B", "C"),
+        ("B", "D"),
+        ("B", "E"),
+        ("F", "G"),
+        ("G", "H"),
+    ] {
+        builder.add_edge(src.to_string(), dst.to_string(), None, None)?;
+    }
+    let graph = builder.build()?;
+    let node_id = |node_name: &str| graph.get_node_id_from_node_name(node_name).unwrap();
+
+    // The degrees are 3, 3, 2, 2, 2, 1, 2 and 1, hence the expected number
+    // of common neighbours of A and B is 9 / 16^2 * (36 - 18).
+    let expected: f64 = 9.0 / 256.0 * 18.0;
+    let variance = expected - (9.0f64 / 256.0).powi(2) * (228.0 - 162.0);
+    let zscore = graph.get_common_neighbours_zscore_from_node_ids(node_id("A"), node_id("B"))?;
+    assert!((zscore - (3.0 - expected) / variance.sqrt()).abs() < 1e-9);
+
+    let zscores = graph.get_common_neighbours_zscores(&[
+        (node_id("A"), node_id("B")),
+        (node_id("F"), node_id("A")),
+    ])?;
+    assert_eq!(zscores[0], zscore);
+    assert!(zscores[1] < 0.0);
+    assert!(graph.get_common_neighbours_zscores(&[(0, 100)]).is_err());
+    Ok(())
+}
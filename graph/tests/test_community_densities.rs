@@ -0,0 +1,73 @@
+extern crate graph;
+use graph::*;
+
+/// Returns the memberships of the nodes of the graph from their names.
+fn get_memberships(graph: &Graph, communities: &[(&str, NodeT)]) -> Result<Vec<NodeT>> {
+    let mut memberships = vec![0; graph.get_number_of_nodes() as usize];
+    for (node_name, community_id) in communities {
+        memberships[graph.get_node_id_from_node_name(node_name)? as usize] = *community_id;
+    }
+    Ok(memberships)
+}
+
+#[test]
+fn test_community_densities() -> Result<()> {
+    let mut builder = GraphBuilder::new(None, Some(false));
+    for (src, dst) in [
+        ("A", "B"),
+        ("B", "C"),
+        ("A", "C"),
+        ("C", "D"),
+        ("D", "E"),
+        ("E", "E"),
+    ] {
+        builder.add_edge(src, dst, None, None)?;
+    }
+    builder.add_node("F".to_string(), None)?;
+    builder.add_node("G".to_string(), None)?;
+    let graph = builder.build()?;
+
+    // The edge between the communities and the selfloop are ignored,
+    // and the community with a single node has zero density.
+    let memberships = get_memberships(
+        &graph,
+        &[
+            ("A", 0),
+            ("B", 0),
+            ("C", 0),
+            ("D", 1),
+            ("E", 1),
+            ("F", 1),
+            ("G", 2),
+        ],
+    )?;
+    let densities = graph.get_community_densities(&memberships)?;
+    assert_eq!(densities.len(), 3);
+    assert!((densities[0] - 1.0).abs() < 1e-10);
+    assert!((densities[1] - 1.0 / 3.0).abs() < 1e-10);
+    assert_eq!(densities[2], 0.0);
+
+    assert!(graph.get_community_densities(&memberships[1..]).is_err());
+    let non_contiguous = memberships
+        .iter()
+        .map(|&community_id| if community_id == 2 { 3 } else { community_id })
+        .collect::<Vec<NodeT>>();
+    assert!(graph.get_community_densities(&non_contiguous).is_err());
+
+    // In directed graphs each direction is counted separately, while parallel edges are counted once.
+    let mut builder = GraphBuilder::new(None, Some(true));
+    for (src, dst, edge_type) in [
+        ("A", "B", "t1"),
+        ("B", "A", "t1"),
+        ("B", "C", "t1"),
+        ("B", "C", "t2"),
+        ("C", "C", "t1"),
+    ] {
+        builder.add_edge(src, dst, Some(edge_type.to_string()), None)?;
+    }
+    let graph = builder.build()?;
+    let densities = graph.get_community_densities(&[0, 0, 0])?;
+    assert!((densities[0] - 0.5).abs() < 1e-10);
+
+    Ok(())
+}
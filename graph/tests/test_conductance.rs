@@ -0,0 +1,48 @@
+extern crate graph;
+use graph::*;
+
+#[test]
+fn test_conductance() -> Result<()> {
+    // Two cliques of four nodes connected by a bridge with weight two.
+    let mut builder = GraphBuilder::new(None, Some(false));
+    for clique in [["A", "B", "C", "D"], ["E", "F", "G", "H"]] {
+        for (i, src) in clique.iter().enumerate() {
+            for dst in &clique[i + 1..] {
+                builder.add_edge(*src, *dst, None, Some(1.0))?;
+            }
+        }
+    }
+    builder.add_edge("D", "E", None, Some(2.0))?;
+    let graph = builder.build()?;
+    let node_ids = |names: &[&str]| {
+        names
+            .iter()
+            .map(|name| graph.get_node_id_from_node_name(name).unwrap())
+            .collect::<Vec<NodeT>>()
+    };
+    let first_clique = node_ids(&["A", "B", "C", "D"]);
+
+    // The volume of the whole graph is 26, and each clique has volume 13.
+    assert!((graph.get_conductance(&first_clique, None)? - 1.0 / 13.0).abs() < 1e-12);
+    assert!((graph.get_conductance(&first_clique, Some(true))? - 2.0 / 14.0).abs() < 1e-12);
+    // The volume of the complement is smaller than the one of the set.
+    assert!(
+        (graph.get_conductance(&node_ids(&["A", "B", "C", "D", "E"]), None)? - 3.0 / 9.0).abs()
+            < 1e-12
+    );
+    // All the edges of a single node leave the set, and duplicates are ignored.
+    assert_eq!(graph.get_conductance(&node_ids(&["A"]), None)?, 1.0);
+    assert_eq!(graph.get_conductance(&node_ids(&["A", "A"]), None)?, 1.0);
+    // The whole graph has no complement.
+    assert_eq!(graph.get_conductance(&graph.get_node_ids(), None)?, 0.0);
+
+    assert!(graph.get_conductance(&[], None).is_err());
+    assert!(graph.get_conductance(&[8], None).is_err());
+
+    let mut builder = GraphBuilder::new(None, Some(false));
+    builder.add_edge("A", "B", None, None)?;
+    let graph = builder.build()?;
+    assert!(graph.get_conductance(&[0], Some(true)).is_err());
+
+    Ok(())
+}
@@ -0,0 +1,67 @@
+extern crate graph;
+use graph::*;
+
+#[test]
+fn test_connected_component_ids() -> Result<()> {
+    let mut builder = GraphBuilder::new(None, Some(true));
+    for (src, dst) in [("A", "B"), ("B", "A"), ("B", "C"), ("D", "E")] {
+        builder.add_edge(src.to_string(), dst.to_string(), None, None)?;
+    }
+    builder.add_node("F".to_string(), None)?;
+    let graph = builder.build()?;
+    let node_id = |name: &str| graph.get_node_id_from_node_name(name).unwrap() as usize;
+
+    let weak_component_ids = graph.get_connected_component_ids(None, None);
+    assert_eq!(weak_component_ids.len(), 6);
+    assert_eq!(
+        weak_component_ids[node_id("A")],
+        weak_component_ids[node_id("C")]
+    );
+    assert_eq!(
+        weak_component_ids[node_id("D")],
+        weak_component_ids[node_id("E")]
+    );
+    assert_ne!(
+        weak_component_ids[node_id("A")],
+        weak_component_ids[node_id("D")]
+    );
+    assert_ne!(
+        weak_component_ids[node_id("F")],
+        weak_component_ids[node_id("D")]
+    );
+    assert_eq!(
+        graph.get_connected_component_sizes(None, None),
+        vec![3, 2, 1]
+    );
+
+    let strong_component_ids = graph.get_connected_component_ids(None, Some(true));
+    assert_eq!(
+        strong_component_ids[node_id("A")],
+        strong_component_ids[node_id("B")]
+    );
+    assert_ne!(
+        strong_component_ids[node_id("A")],
+        strong_component_ids[node_id("C")]
+    );
+    assert_ne!(
+        strong_component_ids[node_id("D")],
+        strong_component_ids[node_id("E")]
+    );
+    assert_eq!(
+        graph.get_connected_component_sizes(None, Some(true)),
+        vec![2, 1, 1, 1, 1]
+    );
+
+    let mut builder = GraphBuilder::new(None, Some(false));
+    for (src, dst) in [("A", "B"), ("B", "C"), ("D", "E")] {
+        builder.add_edge(src.to_string(), dst.to_string(), None, None)?;
+    }
+    builder.add_node("F".to_string(), None)?;
+    let undirected = builder.build()?;
+    assert_eq!(
+        undirected.get_connected_component_sizes(None, Some(true)),
+        vec![3, 2, 1]
+    );
+
+    Ok(())
+}
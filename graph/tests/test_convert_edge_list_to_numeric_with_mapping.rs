@@ -0,0 +1,92 @@
+extern crate graph;
+use graph::*;
+use std::collections::HashMap;
+
+/// Returns the rows of the given file, without the header and split by tabs.
+fn read_rows(path: &str) -> Vec<Vec<String>> {
+    std::fs::read_to_string(path)
+        .unwrap()
+        .lines()
+        .skip(1)
+        .map(|line| line.split('\t').map(|value| value.to_string()).collect())
+        .collect()
+}
+
+#[test]
+fn test_convert_edge_list_to_numeric_with_mapping() -> Result<()> {
+    let directory = std::env::temp_dir();
+    let original_edge_path = directory.join("test_convert_with_mapping_edges.tsv");
+    let target_edge_path = directory.join("test_convert_with_mapping_numeric_edges.tsv");
+    let target_mapping_path = directory.join("test_convert_with_mapping_nodes.tsv");
+    let original_edge_path = original_edge_path.to_str().unwrap();
+    let target_edge_path = target_edge_path.to_str().unwrap();
+    let target_mapping_path = target_mapping_path.to_str().unwrap();
+
+    // The duplicated edges and the selfloops are streamed as they are.
+    let original_edges = [("B", "A"), ("A", "C"), ("C", "B"), ("B", "A"), ("D", "D")];
+    std::fs::write(
+        original_edge_path,
+        std::iter::once("subject\tobject".to_string())
+            .chain(
+                original_edges
+                    .iter()
+                    .map(|(src, dst)| format!("{}\t{}", src, dst)),
+            )
+            .collect::<Vec<String>>()
+            .join("\n"),
+    )
+    .unwrap();
+
+    let (number_of_nodes, number_of_edges) = convert_edge_list_to_numeric_with_mapping(
+        original_edge_path,
+        target_edge_path,
+        target_mapping_path,
+        Some('\t'),
+        Some(true),
+        Some(false),
+    )?;
+    assert_eq!(number_of_nodes, 4);
+    assert_eq!(number_of_edges, original_edges.len() as EdgeT);
+
+    // The node IDs are dense and assigned in order of first appearance.
+    let mapping = read_rows(target_mapping_path);
+    assert_eq!(
+        mapping,
+        [["B", "0"], ["A", "1"], ["C", "2"], ["D", "3"]]
+            .iter()
+            .map(|row| row.iter().map(|value| value.to_string()).collect())
+            .collect::<Vec<Vec<String>>>()
+    );
+    let node_names = mapping
+        .into_iter()
+        .map(|row| (row[1].clone(), row[0].clone()))
+        .collect::<HashMap<String, String>>();
+
+    // Mapping back the numeric edge list yields the original one.
+    assert_eq!(
+        read_rows(target_edge_path)
+            .into_iter()
+            .map(|row| (node_names[&row[0]].clone(), node_names[&row[1]].clone()))
+            .collect::<Vec<(String, String)>>(),
+        original_edges
+            .iter()
+            .map(|(src, dst)| (src.to_string(), dst.to_string()))
+            .collect::<Vec<(String, String)>>()
+    );
+
+    assert!(convert_edge_list_to_numeric_with_mapping(
+        original_edge_path,
+        original_edge_path,
+        target_mapping_path,
+        Some('\t'),
+        Some(true),
+        Some(false),
+    )
+    .is_err());
+
+    for path in [original_edge_path, target_edge_path, target_mapping_path] {
+        std::fs::remove_file(path).unwrap();
+    }
+
+    Ok(())
+}
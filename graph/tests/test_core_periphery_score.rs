@@ -0,0 +1,26 @@
+extern crate graph;
+use graph::*;
+
+#[test]
+fn test_core_periphery_score() -> Result<()> {
+    // A clique of four core nodes, each with a pendant periphery node.
+    let core = ["A", "B", "C", "D"];
+    let mut builder = GraphBuilder::new(None, Some(false));
+    for (i, src) in core.iter().enumerate() {
+        for dst in core.iter().skip(i + 1) {
+            builder.add_edge(src.to_string(), dst.to_string(), None, None)?;
+        }
+        builder.add_edge(src.to_string(), format!("{}_leaf", src), None, None)?;
+    }
+    let graph = builder.build()?;
+    let (coreness, fit) = graph.get_core_periphery_score(None, None)?;
+    for src in core {
+        let core_coreness = coreness[graph.get_node_id_from_node_name(src)? as usize];
+        let leaf_coreness =
+            coreness[graph.get_node_id_from_node_name(&format!("{}_leaf", src))? as usize];
+        assert!((core_coreness - 1.0).abs() < 1e-4);
+        assert!(leaf_coreness < core_coreness);
+    }
+    assert!(fit > 0.5 && fit <= 1.0 + 1e-6);
+    Ok(())
+}
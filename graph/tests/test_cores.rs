@@ -0,0 +1,65 @@
+extern crate graph;
+use graph::*;
+
+#[test]
+fn test_core_periphery_layers() -> Result<()> {
+    let lollipop_graph = Graph::generate_lollipop_graph(
+        None,
+        Some(5),
+        Some(3),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )?;
+    let layers = lollipop_graph.get_core_periphery_layers()?;
+    assert_eq!(layers.len(), 8);
+    assert_eq!(
+        lollipop_graph.get_number_of_nodes_per_core_periphery_layer()?,
+        vec![0, 3, 0, 0, 5]
+    );
+    for node_id in lollipop_graph.get_core_node_ids()? {
+        assert_eq!(layers[node_id as usize], 4);
+    }
+    assert_eq!(lollipop_graph.get_core_node_ids()?.len(), 5);
+    assert_eq!(lollipop_graph.get_periphery_node_ids()?.len(), 3);
+    Ok(())
+}
+
+#[test]
+fn test_k_core_subgraph() -> Result<()> {
+    let mut builder = GraphBuilder::new(None, Some(false));
+    for (src, dst, weight) in [
+        ("A", "B", 1.0),
+        ("B", "C", 2.0),
+        ("C", "A", 3.0),
+        ("A", "D", 4.0),
+        ("D", "E", 5.0),
+    ] {
+        builder.add_edge(src.to_string(), dst.to_string(), None, Some(weight))?;
+    }
+    let graph = builder.build()?;
+    let coreness = graph.get_coreness(Some(false))?;
+    for (node_name, core_number) in [("A", 2), ("B", 2), ("C", 2), ("D", 1), ("E", 1)] {
+        assert_eq!(
+            coreness[graph.get_node_id_from_node_name(node_name)? as usize],
+            core_number
+        );
+    }
+
+    // The 2-core is the triangle, with compact node IDs and the original weights.
+    let core = graph.get_k_core_subgraph(2)?;
+    assert_eq!(core.get_number_of_nodes(), 3);
+    assert_eq!(core.get_number_of_undirected_edges(), 3);
+    assert!(core.get_node_id_from_node_name("D").is_err());
+    for (src, dst, weight) in [("A", "B", 1.0), ("B", "C", 2.0), ("C", "A", 3.0)] {
+        assert_eq!(core.get_edge_weight_from_node_names(src, dst)?, weight);
+    }
+    assert_eq!(graph.get_k_core_subgraph(1)?.get_number_of_nodes(), 5);
+    Ok(())
+}
@@ -0,0 +1,46 @@
+extern crate graph;
+use graph::*;
+
+#[test]
+fn test_cut_summary() -> Result<()> {
+    for directed in [false, true] {
+        let mut builder = GraphBuilder::new(None, Some(directed));
+        let mut edges = vec![("A", "B"), ("A", "A"), ("B", "C"), ("C", "D"), ("D", "E")];
+        if directed {
+            edges.extend([("B", "A"), ("C", "B")]);
+        }
+        for (src, dst) in edges {
+            builder.add_edge(src, dst, None, None)?;
+        }
+        builder.add_node("F".to_string(), None)?;
+        let graph = builder.build()?;
+        let node_ids = |names: &[&str]| {
+            names
+                .iter()
+                .map(|name| graph.get_node_id_from_node_name(name).unwrap())
+                .collect::<Vec<NodeT>>()
+        };
+
+        // The edge between D and E is ignored, as E is in neither set.
+        // In directed graphs, the crossing edges are counted in both directions.
+        assert_eq!(
+            graph.get_cut_summary(&node_ids(&["A", "B"]), &node_ids(&["C", "D"]))?,
+            if directed { (3, 1, 2) } else { (2, 1, 1) }
+        );
+        assert_eq!(
+            graph.get_cut_summary(&node_ids(&["C", "D"]), &node_ids(&["A", "B"]))?,
+            if directed { (1, 3, 2) } else { (1, 2, 1) }
+        );
+        assert_eq!(
+            graph.get_cut_summary(&node_ids(&["A"]), &node_ids(&["F"]))?,
+            (1, 0, 0)
+        );
+
+        assert!(graph.get_cut_summary(&[], &node_ids(&["A"])).is_err());
+        assert!(graph.get_cut_summary(&node_ids(&["A"]), &[6]).is_err());
+        assert!(graph
+            .get_cut_summary(&node_ids(&["A", "B"]), &node_ids(&["B", "C"]))
+            .is_err());
+    }
+    Ok(())
+}
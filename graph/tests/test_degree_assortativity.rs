@@ -0,0 +1,111 @@
+extern crate graph;
+use graph::*;
+
+/// Returns the Pearson correlation of the given pairs of degrees.
+fn pearson(pairs: &[(f64, f64)]) -> f64 {
+    let count = pairs.len() as f64;
+    let x_mean = pairs.iter().map(|(x, _)| x).sum::<f64>() / count;
+    let y_mean = pairs.iter().map(|(_, y)| y).sum::<f64>() / count;
+    let covariance = pairs
+        .iter()
+        .map(|(x, y)| (x - x_mean) * (y - y_mean))
+        .sum::<f64>();
+    let x_variance = pairs.iter().map(|(x, _)| (x - x_mean).powi(2)).sum::<f64>();
+    let y_variance = pairs.iter().map(|(_, y)| (y - y_mean).powi(2)).sum::<f64>();
+    covariance / (x_variance * y_variance).sqrt()
+}
+
+/// Returns the pairs of degrees of the given edges, in both directions.
+fn symmetric(pairs: &[(f64, f64)]) -> Vec<(f64, f64)> {
+    pairs
+        .iter()
+        .flat_map(|&(x, y)| vec![(x, y), (y, x)])
+        .collect()
+}
+
+#[test]
+fn test_degree_assortativity() -> Result<()> {
+    // A star is perfectly disassortative.
+    let mut builder = GraphBuilder::new(None, Some(false));
+    for (src, dst) in [("H", "A"), ("H", "B"), ("H", "C")] {
+        builder.add_edge(src, dst, None, None)?;
+    }
+    assert!((builder.build()?.get_degree_assortativity()? + 1.0).abs() < 1e-10);
+
+    // The degrees are H: 3, A: 1, B: 1, C: 2, D: 2, E: 1, U: 1, V: 1.
+    let mut builder = GraphBuilder::new(None, Some(false));
+    for (node_name, node_types) in [
+        ("H", Some(vec!["hub"])),
+        ("A", Some(vec!["leaf"])),
+        ("B", Some(vec!["leaf"])),
+        ("C", Some(vec!["leaf", "bridge"])),
+        ("D", Some(vec!["bridge"])),
+        ("E", None),
+        ("U", None),
+        ("V", None),
+    ] {
+        builder.add_node(
+            node_name.to_string(),
+            node_types.map(|node_types| node_types.into_iter().map(|x| x.to_string()).collect()),
+        )?;
+    }
+    for (src, dst) in [
+        ("H", "A"),
+        ("H", "B"),
+        ("H", "C"),
+        ("C", "D"),
+        ("D", "E"),
+        ("U", "V"),
+    ] {
+        builder.add_edge(src, dst, None, None)?;
+    }
+    let graph = builder.build()?;
+
+    let h_a = (3.0, 1.0);
+    let h_b = (3.0, 1.0);
+    let h_c = (3.0, 2.0);
+    let c_d = (2.0, 2.0);
+    let d_e = (2.0, 1.0);
+    let u_v = (1.0, 1.0);
+    assert!(
+        (graph.get_degree_assortativity()? - pearson(&symmetric(&[h_a, h_b, h_c, c_d, d_e, u_v])))
+            .abs()
+            < 1e-10
+    );
+
+    // The edge between the untyped nodes U and V is not attributed to any node
+    // type, the edge between D and the untyped E is attributed to the node type
+    // of D, and the edge between C and D is counted once for their shared node type.
+    let assortativities = graph.get_degree_assortativity_per_node_type()?;
+    assert_eq!(assortativities.len(), 3);
+    for (node_type_name, pairs) in [
+        ("hub", vec![h_a, h_b, h_c]),
+        ("leaf", vec![h_a, h_b, h_c, c_d]),
+        ("bridge", vec![h_c, c_d, d_e]),
+    ] {
+        let node_type_id = graph.get_node_type_id_from_node_type_name(node_type_name)?;
+        assert!(
+            (assortativities[&node_type_id] - pearson(&symmetric(&pairs))).abs() < 1e-10,
+            "The assortativity of the node type {} is not as expected.",
+            node_type_name
+        );
+    }
+
+    // The correlation is not defined when all the degrees are equal.
+    let mut builder = GraphBuilder::new(None, Some(false));
+    builder.add_node("A".to_string(), Some(vec!["pair".to_string()]))?;
+    builder.add_node("B".to_string(), Some(vec!["pair".to_string()]))?;
+    builder.add_edge("A", "B", None, None)?;
+    let graph = builder.build()?;
+    assert!(graph.get_degree_assortativity()?.is_nan());
+    assert!(graph.get_degree_assortativity_per_node_type()?[&0].is_nan());
+
+    let mut builder = GraphBuilder::new(None, Some(false));
+    builder.add_edge("A", "B", None, None)?;
+    assert!(builder
+        .build()?
+        .get_degree_assortativity_per_node_type()
+        .is_err());
+
+    Ok(())
+}
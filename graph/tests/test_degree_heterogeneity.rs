@@ -0,0 +1,42 @@
+extern crate graph;
+use graph::*;
+
+#[test]
+fn test_degree_entropy_and_gini_coefficient() -> Result<()> {
+    // A star has degrees [4, 1, 1, 1, 1].
+    let star = test_utilities::build_graph_from_edges_and_singletons(
+        &[("H", "A"), ("H", "B"), ("H", "C"), ("H", "D")],
+        &[],
+        false,
+    )?;
+    let expected_entropy = -(0.8_f64 * 0.8_f64.ln() + 0.2_f64 * 0.2_f64.ln());
+    assert!((star.get_degree_entropy()? - expected_entropy).abs() < 1e-10);
+    assert!((star.get_degree_gini_coefficient()? - 0.3).abs() < 1e-10);
+    // The cached values are returned when the properties are requested again.
+    assert!((star.get_degree_entropy()? - expected_entropy).abs() < 1e-10);
+    assert!((star.get_degree_gini_coefficient()? - 0.3).abs() < 1e-10);
+
+    // All the nodes of a cycle have the same degree.
+    let cycle = test_utilities::build_graph_from_edges_and_singletons(
+        &[("A", "B"), ("B", "C"), ("C", "D"), ("D", "A")],
+        &[],
+        false,
+    )?;
+    assert_eq!(cycle.get_degree_entropy()?, 0.0);
+    assert_eq!(cycle.get_degree_gini_coefficient()?, 0.0);
+
+    // The singletons are included in the degree distribution, with degrees [1, 1, 0].
+    let graph =
+        test_utilities::build_graph_from_edges_and_singletons(&[("A", "B")], &["C"], false)?;
+    let expected_entropy =
+        -((2.0_f64 / 3.0) * (2.0_f64 / 3.0).ln() + (1.0_f64 / 3.0) * (1.0_f64 / 3.0).ln());
+    assert!((graph.get_degree_entropy()? - expected_entropy).abs() < 1e-10);
+    assert!((graph.get_degree_gini_coefficient()? - 1.0 / 3.0).abs() < 1e-10);
+
+    // A graph without edges has null entropy, while its Gini coefficient is undefined.
+    let graph = test_utilities::build_graph_from_edges_and_singletons(&[], &["A", "B"], false)?;
+    assert_eq!(graph.get_degree_entropy()?, 0.0);
+    assert!(graph.get_degree_gini_coefficient().is_err());
+
+    Ok(())
+}
@@ -0,0 +1,91 @@
+extern crate graph;
+use graph::*;
+
+fn sample_negatives(
+    graph: &Graph,
+    random_state: u64,
+    negative_sampling_strategy: Option<&str>,
+    degree_exponent: Option<f64>,
+) -> Result<Graph> {
+    graph.sample_negative_graph(
+        8,
+        Some(random_state),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        negative_sampling_strategy,
+        degree_exponent,
+        None,
+        None,
+        None,
+        None,
+    )
+}
+
+#[test]
+fn test_degree_proportional_negative_sampling() -> Result<()> {
+    let mut builder = GraphBuilder::new(None, Some(false));
+    for (src, dst) in [("A", "B"), ("B", "C"), ("C", "D"), ("D", "E"), ("E", "F")] {
+        builder.add_edge(src.to_string(), dst.to_string(), None, None)?;
+    }
+    for node_name in ["S1", "S2", "S3", "S4"] {
+        builder.add_node(node_name.to_string(), None)?;
+    }
+    let graph = builder.build()?;
+
+    for random_state in 0..10 {
+        for degree_exponent in [None, Some(0.0), Some(1.0)] {
+            let negatives = sample_negatives(
+                &graph,
+                random_state,
+                Some("degree_proportional"),
+                degree_exponent,
+            )?;
+            assert!(negatives.get_number_of_edges() > 0);
+            for (_, src, dst) in negatives.iter_directed_edge_node_ids() {
+                assert_ne!(src, dst);
+                assert!(!graph.has_edge_from_node_ids(src, dst));
+                // The nodes without edges are never sampled.
+                assert!(!graph.is_singleton_from_node_id(src)?);
+                assert!(!graph.is_singleton_from_node_id(dst)?);
+            }
+        }
+    }
+
+    assert!(sample_negatives(&graph, 42, Some("degree_proportional"), Some(-0.5)).is_err());
+    assert!(sample_negatives(&graph, 42, Some("unknown"), None).is_err());
+    assert!(graph
+        .sample_negative_graph(
+            8,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(true),
+            Some("uniform"),
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .is_err());
+    Ok(())
+}
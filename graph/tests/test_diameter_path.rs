@@ -0,0 +1,53 @@
+extern crate graph;
+use graph::*;
+
+/// Returns the names of the nodes of the given path.
+fn get_node_names(graph: &Graph, path: &[NodeT]) -> Vec<String> {
+    path.iter()
+        .map(|&node_id| graph.get_node_name_from_node_id(node_id).unwrap())
+        .collect()
+}
+
+#[test]
+fn test_diameter_path() -> Result<()> {
+    let mut builder = GraphBuilder::new(None, Some(false));
+    // The largest component, whose unweighted diameter path goes from A to E
+    // while the weighted one goes from E to F.
+    for (src, dst, weight) in [
+        ("A", "B", 1.0),
+        ("B", "C", 1.0),
+        ("C", "D", 1.0),
+        ("D", "E", 2.0),
+        ("C", "F", 10.0),
+    ] {
+        builder.add_edge(src, dst, None, Some(weight))?;
+    }
+    // A smaller component, with an equally long unweighted diameter
+    // and a longer weighted one, which must be ignored.
+    for (src, dst) in [("P", "Q"), ("Q", "R"), ("R", "S"), ("S", "T")] {
+        builder.add_edge(src, dst, None, Some(100.0))?;
+    }
+    let graph = builder.build()?;
+
+    let path = get_node_names(&graph, &graph.get_diameter_path(None)?);
+    assert!(
+        path == ["A", "B", "C", "D", "E"] || path == ["E", "D", "C", "B", "A"],
+        "Unexpected diameter path {:?}.",
+        path
+    );
+
+    let path = get_node_names(&graph, &graph.get_diameter_path(Some(true))?);
+    assert!(
+        path == ["E", "D", "C", "F"] || path == ["F", "C", "D", "E"],
+        "Unexpected weighted diameter path {:?}.",
+        path
+    );
+
+    let mut builder = GraphBuilder::new(None, Some(false));
+    builder.add_edge("A", "B", None, None)?;
+    let graph = builder.build()?;
+    assert_eq!(graph.get_diameter_path(None)?.len(), 2);
+    assert!(graph.get_diameter_path(Some(true)).is_err());
+
+    Ok(())
+}
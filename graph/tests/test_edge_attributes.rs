@@ -0,0 +1,111 @@
+extern crate graph;
+use graph::*;
+
+fn get_reader(path: &str) -> Result<EdgeFileReader> {
+    Ok(EdgeFileReader::new(path)?
+        .set_header(Some(true))?
+        .set_separator(Some(','))?
+        .set_parallel(Some(false))
+        .set_verbose(Some(false)))
+}
+
+fn load_graph(reader: &EdgeFileReader, directed: bool) -> Result<Graph> {
+    Graph::from_file_readers(
+        Some(reader.clone()),
+        None,
+        None,
+        None,
+        true,
+        true,
+        directed,
+        "EdgeAttributes",
+    )
+}
+
+#[test]
+fn test_edge_attributes() -> Result<()> {
+    let reader = get_reader("tests/data/test_edge_attributes.csv")?
+        .set_additional_edge_attribute_columns(Some(vec!["timestamp", "confidence"]))?;
+    assert_eq!(
+        reader.get_additional_edge_attribute_column_numbers(),
+        Some(vec![3, 4])
+    );
+    let graph = load_graph(&reader, true)?;
+    assert!(graph.has_edge_attributes());
+    assert_eq!(
+        graph.get_edge_attributes()?,
+        vec![
+            vec![1609459200.0, 0.9],
+            vec![1609545600.0, 0.75],
+            vec![1609632000.0, 0.1]
+        ]
+    );
+
+    // The rows follow the edge IDs also for unsorted edge lists of undirected graphs.
+    let reader = get_reader("tests/data/test_edge_attributes_unsorted.csv")?
+        .set_additional_edge_attribute_columns(Some(vec!["timestamp", "confidence"]))?;
+    let expected_attributes = |src_name: &str, dst_name: &str| {
+        let mut names = [src_name.to_string(), dst_name.to_string()];
+        names.sort();
+        match (names[0].as_str(), names[1].as_str()) {
+            ("A", "B") => vec![1609459200.0, 0.9],
+            ("A", "C") => vec![1609545600.0, 0.75],
+            _ => vec![1609632000.0, 0.1],
+        }
+    };
+    for directed in [false, true] {
+        let graph = load_graph(&reader, directed)?;
+        let edge_attributes = graph.get_edge_attributes()?;
+        assert_eq!(
+            edge_attributes.len() as EdgeT,
+            graph.get_number_of_directed_edges()
+        );
+        for (edge_id, attributes) in edge_attributes.into_iter().enumerate() {
+            let (src_name, dst_name) = graph.get_node_names_from_edge_id(edge_id as EdgeT)?;
+            assert_eq!(attributes, expected_attributes(&src_name, &dst_name));
+        }
+    }
+
+    // The parallel edges of multigraphs get the values of their own lines.
+    let reader = get_reader("tests/data/test_edge_attributes_multigraph.csv")?
+        .set_edge_types_column(Some("edge_type"))?
+        .set_weights_column(Some("weight"))?
+        .set_additional_edge_attribute_columns(Some(vec!["timestamp"]))?;
+    for directed in [false, true] {
+        let graph = load_graph(&reader, directed)?;
+        assert!(graph.is_multigraph());
+        let edge_attributes = graph.get_edge_attributes()?;
+        for edge_id in 0..graph.get_number_of_directed_edges() {
+            let (src_name, dst_name) = graph.get_node_names_from_edge_id(edge_id)?;
+            let expected_timestamp = if src_name == "C" || dst_name == "C" {
+                40.0
+            } else {
+                match (
+                    graph
+                        .get_edge_type_name_from_edge_id(edge_id)?
+                        .unwrap()
+                        .as_str(),
+                    graph.get_edge_weight_from_edge_id(edge_id)?,
+                ) {
+                    ("friend", weight) if weight == 2.0 => 10.0,
+                    ("friend", _) => 20.0,
+                    _ => 30.0,
+                }
+            };
+            assert_eq!(edge_attributes[edge_id as usize], vec![expected_timestamp]);
+        }
+    }
+
+    let reader = get_reader("tests/data/test_edge_attributes.csv")?;
+    assert!(!load_graph(&reader, true)?.has_edge_attributes());
+    assert!(load_graph(&reader, true)?.get_edge_attributes().is_err());
+    assert!(reader
+        .set_additional_edge_attribute_columns(Some(vec!["score"]))
+        .is_err());
+
+    let reader = get_reader("tests/data/test_edge_attributes_unparsable.csv")?
+        .set_additional_edge_attribute_columns(Some(vec!["weight", "timestamp"]))?;
+    let error = load_graph(&reader, true).unwrap_err();
+    assert!(error.contains("`yesterday`") && error.contains("line number is 1"));
+    Ok(())
+}
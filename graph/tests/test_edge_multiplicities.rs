@@ -0,0 +1,69 @@
+extern crate graph;
+use graph::*;
+use std::collections::HashMap;
+
+#[test]
+fn test_edge_multiplicities() -> Result<()> {
+    let mut builder = GraphBuilder::new(None, Some(true));
+    for (src, dst, edge_type) in [
+        ("A", "B", "t1"),
+        ("A", "B", "t2"),
+        ("A", "B", "t3"),
+        ("A", "C", "t1"),
+        ("B", "C", "t1"),
+        ("B", "C", "t2"),
+        ("C", "A", "t1"),
+    ] {
+        builder.add_edge(src, dst, Some(edge_type.to_string()), None)?;
+    }
+    let graph = builder.build()?;
+
+    let (edge_ids, multiplicities) = graph.get_edge_multiplicities();
+    assert!(edge_ids.windows(2).all(|window| window[0] < window[1]));
+    let mut observed = HashMap::new();
+    for (&edge_id, &multiplicity) in edge_ids.iter().zip(multiplicities.iter()) {
+        let node_ids = graph.get_node_ids_from_edge_id(edge_id)?;
+        // The representative edge is the first of its parallel edges.
+        assert!(edge_id == 0 || graph.get_node_ids_from_edge_id(edge_id - 1)? != node_ids);
+        observed.insert(
+            (
+                graph.get_node_name_from_node_id(node_ids.0)?,
+                graph.get_node_name_from_node_id(node_ids.1)?,
+            ),
+            multiplicity,
+        );
+    }
+    let expected = [
+        (("A", "B"), 3),
+        (("A", "C"), 1),
+        (("B", "C"), 2),
+        (("C", "A"), 1),
+    ]
+    .iter()
+    .map(|&((src, dst), multiplicity)| ((src.to_string(), dst.to_string()), multiplicity))
+    .collect::<HashMap<(String, String), EdgeT>>();
+    assert_eq!(observed, expected);
+    assert_eq!(graph.get_maximum_edge_multiplicity(), 3);
+
+    // In graphs that are not multigraphs all the multiplicities are one.
+    let mut builder = GraphBuilder::new(None, Some(false));
+    for (src, dst) in [("A", "B"), ("B", "C"), ("C", "C")] {
+        builder.add_edge(src, dst, None, None)?;
+    }
+    let graph = builder.build()?;
+    let (edge_ids, multiplicities) = graph.get_edge_multiplicities();
+    assert_eq!(
+        edge_ids,
+        (0..graph.get_number_of_directed_edges()).collect::<Vec<EdgeT>>()
+    );
+    assert!(multiplicities.iter().all(|&multiplicity| multiplicity == 1));
+    assert_eq!(graph.get_maximum_edge_multiplicity(), 1);
+
+    let mut builder = GraphBuilder::new(None, Some(false));
+    builder.add_node("A".to_string(), None)?;
+    let graph = builder.build()?;
+    assert_eq!(graph.get_edge_multiplicities(), (vec![], vec![]));
+    assert_eq!(graph.get_maximum_edge_multiplicity(), 0);
+
+    Ok(())
+}
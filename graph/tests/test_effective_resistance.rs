@@ -0,0 +1,20 @@
+extern crate graph;
+use graph::*;
+
+#[test]
+fn test_effective_resistance() -> Result<()> {
+    let chain_graph =
+        Graph::generate_chain_graph(None, Some(5), None, None, None, None, None, None)?;
+    assert!((chain_graph.get_effective_resistance(0, 4)? - 4.0).abs() < 1e-8);
+    assert_eq!(chain_graph.get_effective_resistance(2, 2)?, 0.0);
+
+    let complete_graph =
+        Graph::generate_complete_graph(None, Some(10), None, None, None, None, None, None)?;
+    assert!((complete_graph.get_effective_resistance(3, 7)? - 0.2).abs() < 1e-8);
+
+    let circle_graph =
+        Graph::generate_circle_graph(None, Some(1000), None, None, None, None, None, None)?;
+    // Two resistors of 1 and 999 units in parallel.
+    assert!((circle_graph.get_effective_resistance(0, 1)? - 0.999).abs() < 1e-6);
+    Ok(())
+}
@@ -0,0 +1,111 @@
+extern crate graph;
+use graph::*;
+use std::collections::HashMap;
+
+/// Returns the expected ranking, with the ties sorted by node ID.
+fn get_expected_ranking(graph: &Graph, densities: &[(&str, f64)]) -> Result<Vec<(NodeT, f64)>> {
+    let mut ranking = densities
+        .iter()
+        .map(|&(node_name, density)| Ok((graph.get_node_id_from_node_name(node_name)?, density)))
+        .collect::<Result<Vec<(NodeT, f64)>>>()?;
+    ranking.sort_by(|(a, a_density), (b, b_density)| {
+        b_density.partial_cmp(a_density).unwrap().then(a.cmp(b))
+    });
+    Ok(ranking)
+}
+
+fn assert_ranking(ranking: &[(NodeT, f64)], expected: &[(NodeT, f64)]) {
+    assert_eq!(ranking.len(), expected.len());
+    for ((node_id, density), (expected_node_id, expected_density)) in
+        ranking.iter().zip(expected.iter())
+    {
+        assert_eq!(node_id, expected_node_id);
+        assert!((density - expected_density).abs() < 1e-10);
+    }
+}
+
+#[test]
+fn test_nodes_ranked_by_ego_density() -> Result<()> {
+    // A clique on A, B, C and D, with the tail D-E-F and the singleton S.
+    let graph = test_utilities::build_graph_from_edges_and_singletons(
+        &[
+            ("A", "B"),
+            ("A", "C"),
+            ("A", "D"),
+            ("B", "C"),
+            ("B", "D"),
+            ("C", "D"),
+            ("D", "E"),
+            ("E", "F"),
+        ],
+        &["S"],
+        false,
+    )?;
+    assert_ranking(
+        &graph.get_nodes_ranked_by_ego_density(1, None, None)?,
+        &get_expected_ranking(
+            &graph,
+            &[
+                ("A", 1.0),
+                ("B", 1.0),
+                ("C", 1.0),
+                ("D", 0.7),
+                ("E", 4.0 / 6.0),
+                ("F", 1.0),
+                ("S", 0.0),
+            ],
+        )?,
+    );
+
+    // Within two hops, the ego network of F is E, D and F itself.
+    let ranking = graph
+        .get_nodes_ranked_by_ego_density(2, None, None)?
+        .into_iter()
+        .collect::<HashMap<NodeT, f64>>();
+    assert!((ranking[&graph.get_node_id_from_node_name("F")?] - 4.0 / 6.0).abs() < 1e-10);
+
+    // When capped, the ego network of the hub D only contains its closest neighbour.
+    let ranking = graph
+        .get_nodes_ranked_by_ego_density(1, Some(2), None)?
+        .into_iter()
+        .collect::<HashMap<NodeT, f64>>();
+    assert_eq!(ranking[&graph.get_node_id_from_node_name("D")?], 1.0);
+    assert!(graph
+        .get_nodes_ranked_by_ego_density(1, Some(1), None)?
+        .iter()
+        .all(|&(_, density)| density == 0.0));
+
+    // With the edge weights, the density is the sum of the internal weights.
+    let graph = test_utilities::build_graph_from_weighted_edges(
+        &[("A", "B", Some(4.0)), ("B", "C", Some(2.0))],
+        false,
+    )?;
+    assert_ranking(
+        &graph.get_nodes_ranked_by_ego_density(1, None, Some(true))?,
+        &get_expected_ranking(&graph, &[("A", 4.0), ("B", 2.0), ("C", 2.0)])?,
+    );
+
+    // In directed graphs the ego networks follow the direction of the edges.
+    let graph = test_utilities::build_graph_from_weighted_edges(
+        &[
+            ("A", "B", None),
+            ("B", "A", None),
+            ("B", "C", None),
+            ("C", "A", None),
+        ],
+        true,
+    )?;
+    assert_ranking(
+        &graph.get_nodes_ranked_by_ego_density(1, None, None)?,
+        &get_expected_ranking(&graph, &[("A", 1.0), ("B", 4.0 / 6.0), ("C", 0.5)])?,
+    );
+
+    assert!(graph
+        .get_nodes_ranked_by_ego_density(1, Some(0), None)
+        .is_err());
+    assert!(graph
+        .get_nodes_ranked_by_ego_density(1, None, Some(true))
+        .is_err());
+
+    Ok(())
+}
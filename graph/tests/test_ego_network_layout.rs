@@ -0,0 +1,59 @@
+extern crate graph;
+use graph::*;
+
+#[test]
+fn test_ego_network_layout() -> Result<()> {
+    let mut builder = GraphBuilder::new(None, Some(false));
+    for (src, dst) in [("C", "A"), ("C", "B"), ("A", "D"), ("D", "E"), ("B", "B")] {
+        builder.add_edge(src, dst, None, None)?;
+    }
+    let graph = builder.build()?;
+    let node_id = |name: &str| graph.get_node_id_from_node_name(name).unwrap();
+    let center = node_id("C");
+
+    for layout in ["force_directed", "spectral"] {
+        let (node_ids, positions, edges) =
+            graph.get_ego_network_layout(center, None, Some(layout), None, None, None)?;
+        // The center comes first, followed by its neighbours sorted by node ID.
+        let mut neighbours = vec![node_id("A"), node_id("B")];
+        neighbours.sort_unstable();
+        assert_eq!(node_ids, vec![center, neighbours[0], neighbours[1]]);
+        assert_eq!(positions.len(), 3);
+        assert!(positions
+            .iter()
+            .all(|&(x, y)| (0.0..=1.0).contains(&x) && (0.0..=1.0).contains(&y)));
+        // The edges are listed once, without the selfloop of B.
+        assert_eq!(edges, vec![(0, 1), (0, 2)]);
+        assert_eq!(
+            (node_ids, positions, edges),
+            graph.get_ego_network_layout(center, None, Some(layout), None, None, None)?
+        );
+
+        let (node_ids, positions, edges) =
+            graph.get_ego_network_layout(center, Some(2), Some(layout), None, None, None)?;
+        assert_eq!(node_ids.len(), 4);
+        assert_eq!(node_ids[3], node_id("D"));
+        assert_eq!(positions.len(), 4);
+        assert_eq!(edges.len(), 3);
+        let local_a = node_ids.iter().position(|&id| id == node_id("A")).unwrap() as NodeT;
+        assert!(edges.contains(&(local_a, 3)));
+    }
+
+    // The ego network is truncated to the closest nodes.
+    let (node_ids, _, edges) =
+        graph.get_ego_network_layout(center, Some(2), None, Some(2), None, None)?;
+    assert_eq!(node_ids, vec![center, node_id("A").min(node_id("B"))]);
+    assert_eq!(edges, vec![(0, 1)]);
+
+    assert!(graph
+        .get_ego_network_layout(5, None, None, None, None, None)
+        .is_err());
+    assert!(graph
+        .get_ego_network_layout(center, None, None, Some(0), None, None)
+        .is_err());
+    assert!(graph
+        .get_ego_network_layout(center, None, Some("circular"), None, None, None)
+        .is_err());
+
+    Ok(())
+}
@@ -0,0 +1,56 @@
+extern crate graph;
+use graph::*;
+
+#[test]
+fn test_filter_from_node_type_names() -> Result<()> {
+    let mut builder = GraphBuilder::new(None, Some(false));
+    for (node_name, node_types) in [
+        ("A", Some(vec!["protein"])),
+        ("B", Some(vec!["gene", "protein"])),
+        ("C", Some(vec!["gene"])),
+        ("D", None),
+    ] {
+        builder.add_node(
+            node_name.to_string(),
+            node_types.map(|node_types| node_types.into_iter().map(|x| x.to_string()).collect()),
+        )?;
+    }
+    for (src, dst, edge_type, weight) in [
+        ("A", "B", "binds", 1.0),
+        ("B", "C", "encodes", 2.0),
+        ("C", "D", "binds", 3.0),
+        ("A", "D", "binds", 4.0),
+    ] {
+        builder.add_edge(
+            src.to_string(),
+            dst.to_string(),
+            Some(edge_type.to_string()),
+            Some(weight),
+        )?;
+    }
+    let graph = builder.build()?;
+
+    let proteins = graph.filter_from_node_type_names(vec!["protein".to_string()], true)?;
+    assert_eq!(proteins.get_node_names(), vec!["A", "B"]);
+    assert_eq!(proteins.get_number_of_edges(), 1);
+    assert_eq!(proteins.get_edge_weight_from_node_names("A", "B")?, 1.0);
+    assert_eq!(
+        proteins.get_edge_type_name_from_edge_id(0)?,
+        Some("binds".to_string())
+    );
+
+    let not_proteins = graph.filter_from_node_type_names(vec!["protein".to_string()], false)?;
+    assert_eq!(not_proteins.get_node_names(), vec!["C", "D"]);
+    assert_eq!(not_proteins.get_edge_weight_from_node_names("C", "D")?, 3.0);
+
+    assert!(graph
+        .filter_from_node_type_names(vec!["metabolite".to_string()], true)
+        .is_err());
+    let mut builder = GraphBuilder::new(None, Some(false));
+    builder.add_edge("A".to_string(), "B".to_string(), None, None)?;
+    assert!(builder
+        .build()?
+        .filter_from_node_type_names(vec!["protein".to_string()], true)
+        .is_err());
+    Ok(())
+}
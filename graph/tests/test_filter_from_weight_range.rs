@@ -0,0 +1,36 @@
+extern crate graph;
+use graph::*;
+
+#[test]
+fn test_filter_from_weight_range() -> Result<()> {
+    let mut builder = GraphBuilder::new(None, Some(false));
+    for (src, dst, weight) in [("A", "B", 0.1), ("B", "C", 0.5), ("C", "D", 0.9)] {
+        builder.add_edge(src.to_string(), dst.to_string(), None, Some(weight))?;
+    }
+    let graph = builder.build()?;
+
+    let closed = graph.filter_from_weight_range(Some(0.5), Some(0.9), None, None, None)?;
+    assert_eq!(closed.get_number_of_edges(), 2);
+    assert_eq!(closed.get_number_of_nodes(), 4);
+    assert!(!closed.has_edge_from_node_names("A", "B"));
+
+    let half_open =
+        graph.filter_from_weight_range(Some(0.5), Some(0.9), Some(false), None, Some(true))?;
+    assert_eq!(half_open.get_number_of_edges(), 1);
+    assert_eq!(half_open.get_node_names(), vec!["C", "D"]);
+
+    let below = graph.filter_from_weight_range(None, Some(0.5), None, Some(false), None)?;
+    assert_eq!(below.get_number_of_edges(), 1);
+    assert!(below.has_edge_from_node_names("B", "A"));
+
+    assert!(graph
+        .filter_from_weight_range(Some(0.9), Some(0.1), None, None, None)
+        .is_err());
+    let mut builder = GraphBuilder::new(None, Some(false));
+    builder.add_edge("A".to_string(), "B".to_string(), None, None)?;
+    assert!(builder
+        .build()?
+        .filter_from_weight_range(Some(0.5), None, None, None, None)
+        .is_err());
+    Ok(())
+}
@@ -0,0 +1,36 @@
+extern crate graph;
+use graph::*;
+
+#[test]
+fn test_force_directed_layout() -> Result<()> {
+    let mut builder = GraphBuilder::new(None, Some(false));
+    for (src, dst) in [("A", "B"), ("B", "C"), ("C", "A"), ("C", "D"), ("D", "D")] {
+        builder.add_edge(src.to_string(), dst.to_string(), None, None)?;
+    }
+    builder.add_node("E".to_string(), None)?;
+    let graph = builder.build()?;
+
+    for dimensions in [2, 3] {
+        let coordinates = graph.compute_force_directed_layout(dimensions, 50, 42)?;
+        assert_eq!(coordinates.len(), 5);
+        assert!(coordinates.iter().all(|node_coordinates| {
+            node_coordinates.len() == dimensions
+                && node_coordinates
+                    .iter()
+                    .all(|&value| (0.0..=1.0).contains(&value))
+        }));
+        assert_eq!(
+            coordinates,
+            graph.compute_force_directed_layout(dimensions, 50, 42)?
+        );
+        assert_ne!(
+            coordinates,
+            graph.compute_force_directed_layout(dimensions, 50, 43)?
+        );
+    }
+
+    assert!(graph.compute_force_directed_layout(1, 50, 42).is_err());
+    assert!(graph.compute_force_directed_layout(4, 50, 42).is_err());
+
+    Ok(())
+}
@@ -0,0 +1,39 @@
+extern crate graph;
+use graph::*;
+
+#[test]
+fn test_girth() -> Result<()> {
+    let pentagon_with_tail = [
+        ("A", "B"),
+        ("B", "C"),
+        ("C", "D"),
+        ("D", "E"),
+        ("E", "A"),
+        ("E", "F"),
+    ];
+    assert_eq!(
+        test_utilities::build_graph_from_edges(&pentagon_with_tail, false)?.get_girth(),
+        Some(5)
+    );
+    assert_eq!(
+        test_utilities::build_graph_from_edges(&pentagon_with_tail, true)?.get_girth(),
+        Some(5)
+    );
+
+    let tree = [("A", "B"), ("A", "C"), ("C", "D")];
+    assert_eq!(
+        test_utilities::build_graph_from_edges(&tree, false)?.get_girth(),
+        None
+    );
+    assert_eq!(
+        test_utilities::build_graph_from_edges(&tree, true)?.get_girth(),
+        None
+    );
+
+    let directed_two_cycle = [("A", "B"), ("B", "A"), ("B", "C")];
+    assert_eq!(
+        test_utilities::build_graph_from_edges(&directed_two_cycle, true)?.get_girth(),
+        Some(2)
+    );
+    Ok(())
+}
@@ -0,0 +1,45 @@
+extern crate graph;
+use graph::*;
+
+#[test]
+fn test_graph_builder_incremental() -> Result<()> {
+    let mut builder = GraphBuilder::new(None, Some(false));
+    builder.add_node("E".to_string(), None)?;
+    for (src, dst, weight) in [
+        ("B", "A", 1.0),
+        ("A", "C", 2.0),
+        ("A", "B", 3.0),
+        ("C", "D", 4.0),
+    ] {
+        builder.add_edge(src, dst, None, Some(weight))?;
+    }
+    builder.add_edge("D", "A", Some("binds".to_string()), None)?;
+    assert!(builder.add_edge("A", "", None, None).is_err());
+    assert!(builder.add_edge("A", "F", None, Some(f32::NAN)).is_err());
+    builder.remove_edge("C".to_string(), "D".to_string(), None, None)?;
+    assert_eq!(builder.iter_edges().count(), 4);
+    assert_eq!(builder.iter_edges().next().unwrap().src, "A");
+
+    let graph = builder.build()?;
+    assert_eq!(graph.get_number_of_nodes(), 5);
+    assert_eq!(graph.get_number_of_edges(), 6);
+    assert!(graph.has_node_name("D"));
+    assert!(!graph.has_node_name("F"));
+    let (src, dst) = (
+        graph.get_node_id_from_node_name("A")?,
+        graph.get_node_id_from_node_name("B")?,
+    );
+    // The first inserted of the duplicated edges is kept.
+    assert_eq!(graph.get_edge_weight_from_node_ids(src, dst)?, 1.0);
+
+    let mut builder = GraphBuilder::new(None, Some(true));
+    builder.set_ignore_duplicated_edges(false);
+    builder.add_edge("A", "B", None, None)?;
+    builder.add_edge("B", "A", None, None)?;
+    builder.add_edge("A", "B", Some("binds".to_string()), None)?;
+    assert!(builder.clone().build().is_ok());
+    builder.add_edge("A", "B", None, None)?;
+    assert!(builder.build().is_err());
+
+    Ok(())
+}
@@ -0,0 +1,101 @@
+extern crate graph;
+use graph::*;
+
+#[test]
+fn test_graph_level_feature_vector() -> Result<()> {
+    // A triangle with a tail, plus a separate edge.
+    let mut builder = GraphBuilder::new(None, Some(false));
+    for (src, dst) in [("A", "B"), ("B", "C"), ("C", "A"), ("C", "D"), ("E", "F")] {
+        builder.add_edge(src, dst, None, None)?;
+    }
+    let graph = builder.build()?;
+
+    let names = graph.get_graph_level_feature_names(None, None);
+    assert_eq!(
+        names,
+        vec![
+            "number_of_nodes",
+            "number_of_edges",
+            "density",
+            "node_degrees_mean",
+            "node_degrees_variance",
+            "transitivity",
+            "degree_assortativity",
+            "number_of_connected_components",
+            "diameter",
+            "average_clustering_coefficient",
+        ]
+    );
+    let features = graph.get_graph_level_feature_vector(None, None, None)?;
+    assert_eq!(features.len(), names.len());
+    // The node degrees are [2, 2, 3, 1, 1, 1].
+    let expected = [
+        6.0,
+        5.0,
+        graph.get_density()?,
+        5.0 / 3.0,
+        5.0 / 9.0,
+        graph.get_transitivity(None),
+        graph.get_degree_assortativity()?,
+        2.0,
+        2.0,
+        graph.get_average_clustering_coefficient(None, None, None),
+    ];
+    for ((name, feature), expected) in names.iter().zip(features.iter()).zip(expected.iter()) {
+        assert!(
+            (feature - expected).abs() < 1e-6,
+            "The feature {} is {}, while {} was expected.",
+            name,
+            feature,
+            expected
+        );
+    }
+
+    // The excluded features are omitted, keeping the order of the others.
+    for (include_diameter, include_triangle_features) in
+        [(false, true), (true, false), (false, false)]
+    {
+        let reduced_names = graph
+            .get_graph_level_feature_names(Some(include_diameter), Some(include_triangle_features));
+        let reduced_features = graph.get_graph_level_feature_vector(
+            Some(include_diameter),
+            Some(include_triangle_features),
+            None,
+        )?;
+        assert_eq!(reduced_names.len(), reduced_features.len());
+        let expected_features = names
+            .iter()
+            .zip(features.iter())
+            .filter(|(name, _)| reduced_names.contains(name))
+            .map(|(_, &feature)| feature)
+            .collect::<Vec<f64>>();
+        assert_eq!(reduced_features, expected_features);
+    }
+    assert_eq!(
+        graph
+            .get_graph_level_feature_names(Some(false), Some(false))
+            .len(),
+        6
+    );
+
+    // The undefined features of a graph without edges are zero.
+    let mut builder = GraphBuilder::new(None, Some(false));
+    builder.add_node("A".to_string(), None)?;
+    builder.add_node("B".to_string(), None)?;
+    let features = builder
+        .build()?
+        .get_graph_level_feature_vector(None, None, None)?;
+    assert_eq!(
+        features,
+        vec![2.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 2.0, 0.0, 0.0]
+    );
+
+    let mut builder = GraphBuilder::new(None, Some(true));
+    builder.add_edge("A", "B", None, None)?;
+    assert!(builder
+        .build()?
+        .get_graph_level_feature_vector(None, None, None)
+        .is_err());
+
+    Ok(())
+}
@@ -0,0 +1,42 @@
+extern crate graph;
+use graph::*;
+
+#[test]
+fn test_graphml() -> Result<()> {
+    let path = std::env::temp_dir().join("test_graphml.graphml");
+    let path = path.to_str().unwrap();
+    for directed in [true, false] {
+        let mut builder = GraphBuilder::new(None, Some(directed));
+        builder.add_node("A&B".to_string(), Some(vec!["<protein>".to_string()]))?;
+        builder.add_node("C".to_string(), None)?;
+        builder.add_edge(
+            "A&B".to_string(),
+            "C".to_string(),
+            Some("binds".to_string()),
+            Some(2.0),
+        )?;
+        let graph = builder.build()?;
+        graph.to_graphml(path)?;
+        let graphml = std::fs::read_to_string(path).unwrap();
+        assert!(graphml.contains(&format!(
+            "edgedefault=\"{}\"",
+            if directed { "directed" } else { "undirected" }
+        )));
+        assert!(graphml.contains("<data key=\"name\">A&amp;B</data>"));
+        assert!(graphml.contains("<data key=\"node_type\">&lt;protein&gt;</data>"));
+        assert!(graphml.contains("<key id=\"edge_type\""));
+        assert!(graphml.contains("<data key=\"edge_type\">binds</data>"));
+        assert!(graphml.contains("<data key=\"weight\">2</data>"));
+        assert_eq!(graphml.matches("<edge ").count(), 1);
+    }
+
+    let mut builder = GraphBuilder::new(None, Some(false));
+    builder.add_edge("A".to_string(), "B".to_string(), None, None)?;
+    builder.build()?.to_graphml(path)?;
+    let graphml = std::fs::read_to_string(path).unwrap();
+    assert!(!graphml.contains("node_type"));
+    assert!(!graphml.contains("edge_type"));
+    assert!(!graphml.contains("weight"));
+    std::fs::remove_file(path).unwrap();
+    Ok(())
+}
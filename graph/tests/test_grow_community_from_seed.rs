@@ -0,0 +1,69 @@
+extern crate graph;
+use graph::*;
+
+/// Returns two cliques of four nodes connected by a single bridge.
+fn get_two_cliques(directed: bool) -> Result<Graph> {
+    let mut builder = GraphBuilder::new(None, Some(directed));
+    for clique in [["A", "B", "C", "D"], ["E", "F", "G", "H"]] {
+        for (i, src) in clique.iter().enumerate() {
+            for dst in &clique[i + 1..] {
+                builder.add_edge(*src, *dst, None, None)?;
+            }
+        }
+    }
+    builder.add_edge("D", "E", None, None)?;
+    builder.build()
+}
+
+#[test]
+fn test_grow_community_from_seed() -> Result<()> {
+    let graph = get_two_cliques(false)?;
+    let node_id = |name: &str| graph.get_node_id_from_node_name(name).unwrap();
+    let mut first_clique = ["A", "B", "C", "D"].map(node_id).to_vec();
+    first_clique.sort_unstable();
+
+    // The growth stops at the bridge, as adding E would worsen the score:
+    // only the bridge leaves the first clique, hence the local modularity
+    // is 3 / 4 and the conductance is 1 / 13.
+    for seed in ["A", "D"] {
+        let (community, score) =
+            graph.grow_community_from_seed(&[node_id(seed)], 8, Some("local_modularity"), None)?;
+        assert_eq!(community, first_clique);
+        assert_eq!(score, 0.75);
+        let (community, score) =
+            graph.grow_community_from_seed(&[node_id(seed)], 8, Some("conductance"), None)?;
+        assert_eq!(community, first_clique);
+        assert!((score - 1.0 / 13.0).abs() < 1e-12);
+    }
+
+    // The growth stops at the maximum size. The node B and the node C
+    // are equivalent, and the tie is broken by the random state.
+    let (community, score) = graph.grow_community_from_seed(&[node_id("A")], 2, None, Some(7))?;
+    assert_eq!(community.len(), 2);
+    assert!(community.contains(&node_id("A")));
+    assert!(community.contains(&node_id("B")) || community.contains(&node_id("C")));
+    assert!((score - 0.2).abs() < 1e-12);
+    assert_eq!(
+        graph.grow_community_from_seed(&[node_id("A")], 2, None, Some(7))?,
+        (community, score)
+    );
+
+    // The seeds are always kept, even when they do not improve the score.
+    let (community, _) =
+        graph.grow_community_from_seed(&[node_id("A"), node_id("H")], 2, None, None)?;
+    assert_eq!(community.len(), 2);
+
+    assert!(graph.grow_community_from_seed(&[], 4, None, None).is_err());
+    assert!(graph.grow_community_from_seed(&[8], 4, None, None).is_err());
+    assert!(graph
+        .grow_community_from_seed(&[node_id("A"), node_id("B")], 1, None, None)
+        .is_err());
+    assert!(graph
+        .grow_community_from_seed(&[node_id("A")], 4, Some("modularity"), None)
+        .is_err());
+    assert!(get_two_cliques(true)?
+        .grow_community_from_seed(&[0], 4, None, None)
+        .is_err());
+
+    Ok(())
+}
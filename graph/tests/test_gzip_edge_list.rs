@@ -0,0 +1,44 @@
+extern crate graph;
+use graph::*;
+
+fn read_edge_list(path: &str, parallel: bool, compression: Option<&str>) -> Result<Vec<String>> {
+    let reader = EdgeFileReader::new(path)?
+        .set_compression(compression.map(|compression| compression.to_string()))?
+        .set_header(Some(false))?
+        .set_separator(Some(','))?
+        .set_parallel(Some(parallel))
+        .set_verbose(Some(false));
+    let lines = reader.read_lines()?;
+    // The compressed files are read with the requested reader as the plain ones.
+    assert_eq!(lines.is_parallel(), parallel);
+    let mut lines = lines
+        .collect::<Result<Vec<_>>>()?
+        .into_iter()
+        .map(|line| format!("{:?}", line))
+        .collect::<Vec<String>>();
+    lines.sort();
+    Ok(lines)
+}
+
+#[test]
+fn test_gzip_edge_list() -> Result<()> {
+    for parallel in [true, false] {
+        let plain = read_edge_list("tests/data/test_components.csv", parallel, None)?;
+        assert!(!plain.is_empty());
+        assert_eq!(
+            read_edge_list("tests/data/test_components.csv.gz", parallel, None)?,
+            plain
+        );
+        assert_eq!(
+            read_edge_list("tests/data/test_components.csv.gz", parallel, Some("gzip"))?,
+            plain
+        );
+        let error =
+            read_edge_list("tests/data/test_components.csv", parallel, Some("gzip")).unwrap_err();
+        assert!(error.contains("not a valid gzip file"));
+    }
+    assert!(EdgeFileReader::new("tests/data/test_components.csv")?
+        .set_compression(Some("bzip2".to_string()))
+        .is_err());
+    Ok(())
+}
@@ -0,0 +1,54 @@
+extern crate graph;
+use graph::*;
+
+#[test]
+fn test_holdout_per_edge_type() -> Result<()> {
+    let mut builder = GraphBuilder::new(None, Some(false));
+    let node_names = ["A", "B", "C", "D", "E", "F"];
+    for (i, src) in node_names.iter().enumerate() {
+        for dst in node_names[i + 1..].iter() {
+            builder.add_edge(*src, *dst, Some("common".to_string()), None)?;
+        }
+    }
+    // The selfloops are never part of the spanning tree.
+    for node_name in ["A", "B", "C", "D"] {
+        builder.add_edge(node_name, node_name, Some("rare".to_string()), None)?;
+    }
+    let graph = builder.build()?;
+
+    for random_state in 0..10 {
+        let (train, test) = graph.get_holdout_per_edge_type(0.6, Some(random_state), None)?;
+        // The validation rate is applied to the 30 directed common edges
+        // and to the 4 rare selfloops separately.
+        assert_eq!(test.get_edge_count_from_edge_type_name(Some("common"))?, 12);
+        assert_eq!(test.get_edge_count_from_edge_type_name(Some("rare"))?, 1);
+        assert_eq!(
+            train.get_edge_count_from_edge_type_name(Some("common"))?,
+            18
+        );
+        assert_eq!(train.get_edge_count_from_edge_type_name(Some("rare"))?, 3);
+        assert_eq!(train.get_number_of_nodes(), graph.get_number_of_nodes());
+        assert_eq!(train.get_number_of_connected_components(None).0, 1);
+    }
+
+    // The validation sizes are computed on the 15 undirected common edges,
+    // so that the 4.5 expected edges do not round up to 5 undirected edges.
+    for random_state in 0..10 {
+        let (_, test) = graph.get_holdout_per_edge_type(0.7, Some(random_state), None)?;
+        assert_eq!(test.get_edge_count_from_edge_type_name(Some("common"))?, 8);
+        assert_eq!(test.get_edge_count_from_edge_type_name(Some("rare"))?, 1);
+    }
+
+    // Too few edges outside of the spanning tree for the requested validation size.
+    assert!(graph
+        .get_holdout_per_edge_type(0.1, Some(42), None)
+        .is_err());
+    assert!(graph
+        .get_holdout_per_edge_type(1.0, Some(42), None)
+        .is_err());
+    assert!(graph
+        .remove_edge_types()?
+        .get_holdout_per_edge_type(0.6, Some(42), None)
+        .is_err());
+    Ok(())
+}
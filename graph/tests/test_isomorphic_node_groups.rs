@@ -0,0 +1,56 @@
+extern crate graph;
+use graph::*;
+
+#[test]
+fn test_isomorphic_node_groups() -> Result<()> {
+    let mut builder = GraphBuilder::new(None, Some(false));
+    for (src, dst) in [
+        // The leaves share the hub as their only neighbour.
+        ("H", "L1"),
+        ("H", "L2"),
+        ("H", "L3"),
+        // X and Y share the hub and are connected to one another.
+        ("H", "X"),
+        ("H", "Y"),
+        ("X", "Y"),
+        ("Y", "Y"),
+        // Z is connected to the hub through an additional node.
+        ("H", "W"),
+        ("W", "Z"),
+    ] {
+        builder.add_edge(src, dst, None, None)?;
+    }
+    builder.add_node("S1".to_string(), None)?;
+    builder.add_node("S2".to_string(), None)?;
+    let graph = builder.build()?;
+    let get_node_names_groups = |groups: Vec<Vec<NodeT>>| {
+        let mut groups = groups
+            .into_iter()
+            .map(|group| {
+                group
+                    .into_iter()
+                    .map(|node_id| graph.get_node_name_from_node_id(node_id).unwrap())
+                    .collect::<Vec<String>>()
+                    .join(",")
+            })
+            .collect::<Vec<String>>();
+        groups.sort();
+        groups
+    };
+
+    let groups = graph.get_isomorphic_node_groups(None)?;
+    assert!(groups
+        .iter()
+        .all(|group| group.windows(2).all(|pair| pair[0] < pair[1])));
+    assert_eq!(
+        get_node_names_groups(groups),
+        vec!["L1,L2,L3", "S1,S2", "X,Y"]
+    );
+    assert_eq!(
+        get_node_names_groups(graph.get_isomorphic_node_groups(Some(3))?),
+        vec!["L1,L2,L3"]
+    );
+    assert!(graph.get_isomorphic_node_groups(Some(1)).is_err());
+
+    Ok(())
+}
@@ -0,0 +1,54 @@
+extern crate graph;
+use graph::*;
+
+#[test]
+fn test_jaccard_similarity_to_node() -> Result<()> {
+    let mut builder = GraphBuilder::new(None, Some(false));
+    for (src, dst) in [
+        ("R", "A"),
+        ("R", "B"),
+        ("R", "C"),
+        ("X", "A"),
+        ("X", "B"),
+        ("Y", "C"),
+        ("Z", "D"),
+    ] {
+        builder.add_edge(src, dst, None, None)?;
+    }
+    let graph = builder.build()?;
+    let node_id = |name: &str| graph.get_node_id_from_node_name(name).unwrap();
+    let reference = node_id("R");
+
+    // The similarities are aligned with the candidates.
+    let candidates = [node_id("X"), node_id("Y"), node_id("Z"), reference];
+    let similarities = graph.get_jaccard_similarity_to_node(reference, Some(&candidates))?;
+    let expected = [2.0 / 3.0, 1.0 / 3.0, 0.0, 1.0];
+    assert_eq!(similarities.len(), expected.len());
+    for (similarity, expected) in similarities.iter().zip(expected.iter()) {
+        assert!((similarity - expected).abs() < 1e-12);
+    }
+
+    // By default, the similarities to all the nodes are returned,
+    // consistently with the pairwise Jaccard coefficient.
+    let similarities = graph.get_jaccard_similarity_to_node(reference, None)?;
+    assert_eq!(similarities.len(), graph.get_number_of_nodes() as usize);
+    for (node_id, similarity) in similarities.into_iter().enumerate() {
+        assert!(
+            (similarity
+                - graph.get_jaccard_coefficient_from_node_ids(reference, node_id as NodeT)? as f64)
+                .abs()
+                < 1e-6
+        );
+    }
+    assert_eq!(
+        graph.get_jaccard_similarity_to_node(reference, Some(&[]))?,
+        Vec::<f64>::new()
+    );
+
+    assert!(graph.get_jaccard_similarity_to_node(100, None).is_err());
+    assert!(graph
+        .get_jaccard_similarity_to_node(reference, Some(&[node_id("X"), 100]))
+        .is_err());
+
+    Ok(())
+}
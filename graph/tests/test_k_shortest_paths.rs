@@ -0,0 +1,73 @@
+extern crate graph;
+use graph::*;
+use std::collections::HashSet;
+
+#[test]
+fn test_k_shortest_paths() -> Result<()> {
+    let mut builder = GraphBuilder::new(None, Some(true));
+    for (src, dst, weight) in [
+        ("C", "D", 3.0),
+        ("C", "E", 2.0),
+        ("D", "F", 4.0),
+        ("E", "D", 1.0),
+        ("E", "F", 2.0),
+        ("E", "G", 3.0),
+        ("F", "G", 2.0),
+        ("F", "H", 1.0),
+        ("G", "H", 2.0),
+    ] {
+        builder.add_edge(src.to_string(), dst.to_string(), None, Some(weight))?;
+    }
+    let graph = builder.build()?;
+    let node_id = |node_name: &str| graph.get_node_id_from_node_name(node_name).unwrap();
+    let path_from_names = |node_names: &[&str]| {
+        node_names
+            .iter()
+            .map(|name| node_id(name))
+            .collect::<Vec<NodeT>>()
+    };
+    let path_length = |path: &[NodeT]| {
+        path.windows(2)
+            .map(|edge| {
+                graph
+                    .get_edge_weight_from_node_ids(edge[0], edge[1])
+                    .unwrap()
+            })
+            .sum::<f32>()
+    };
+
+    let paths =
+        graph.get_k_shortest_paths_from_node_ids(node_id("C"), node_id("H"), 2, Some(true))?;
+    assert_eq!(
+        paths,
+        vec![
+            path_from_names(&["C", "E", "F", "H"]),
+            path_from_names(&["C", "E", "G", "H"])
+        ]
+    );
+
+    // There are only seven loopless paths from C to H.
+    let paths =
+        graph.get_k_shortest_paths_from_node_ids(node_id("C"), node_id("H"), 10, Some(true))?;
+    assert_eq!(paths.len(), 7);
+    assert_eq!(paths.iter().collect::<HashSet<_>>().len(), 7);
+    for path in paths.iter() {
+        assert_eq!(path.iter().collect::<HashSet<_>>().len(), path.len());
+    }
+    for consecutive_paths in paths.windows(2) {
+        assert!(path_length(&consecutive_paths[0]) <= path_length(&consecutive_paths[1]));
+    }
+
+    // Without the edge weights, the path through D has as few hops as the shortest one.
+    let paths = graph.get_k_shortest_paths_from_node_ids(node_id("C"), node_id("H"), 7, None)?;
+    assert_eq!(paths.len(), 7);
+    assert!(paths.iter().take(3).all(|path| path.len() == 4));
+
+    assert!(graph
+        .get_k_shortest_paths_from_node_ids(node_id("H"), node_id("C"), 3, None)?
+        .is_empty());
+    assert!(graph
+        .get_k_shortest_paths_from_node_ids(node_id("C"), node_id("H"), 0, None)
+        .is_err());
+    Ok(())
+}
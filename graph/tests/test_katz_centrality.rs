@@ -0,0 +1,35 @@
+extern crate graph;
+use graph::*;
+
+#[test]
+fn test_katz_centrality() -> Result<()> {
+    let mut builder = GraphBuilder::new(None, Some(false));
+    builder.add_edge("A".to_string(), "B".to_string(), None, None)?;
+    builder.add_edge("B".to_string(), "C".to_string(), None, None)?;
+    let graph = builder.build()?;
+    // On the path A - B - C with attenuation factor a, the fixpoint is
+    // x_A = x_C = (1 + a) / (1 - 2a^2) and x_B = 1 + 2a x_A.
+    let attenuation_factor = 0.25;
+    let centralities = graph.get_katz_centrality(Some(attenuation_factor), None, Some(1e-12))?;
+    let expected_leaf_centrality =
+        (1.0 + attenuation_factor) / (1.0 - 2.0 * attenuation_factor * attenuation_factor);
+    let expected_centre_centrality = 1.0 + 2.0 * attenuation_factor * expected_leaf_centrality;
+    for (node_name, expected_centrality) in [
+        ("A", expected_leaf_centrality),
+        ("B", expected_centre_centrality),
+        ("C", expected_leaf_centrality),
+    ] {
+        let centrality = centralities[graph.get_node_id_from_node_name(node_name)? as usize];
+        assert!((centrality - expected_centrality).abs() < 1e-9);
+    }
+    // The default attenuation factor is half the reciprocal of the maximum degree.
+    assert_eq!(
+        graph.get_katz_centrality(None, None, None)?,
+        graph.get_katz_centrality(Some(0.25), None, None)?
+    );
+    // The series does not converge for attenuation factors above the reciprocal of the maximum degree.
+    assert!(graph.get_katz_centrality(Some(0.5), None, None).is_err());
+    assert!(graph.get_katz_centrality(Some(0.0), None, None).is_err());
+    assert!(graph.get_katz_centrality(None, Some(1), None).is_err());
+    Ok(())
+}
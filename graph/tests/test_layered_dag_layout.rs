@@ -0,0 +1,24 @@
+extern crate graph;
+use graph::*;
+
+#[test]
+fn test_layered_dag_layout() -> Result<()> {
+    let mut builder = GraphBuilder::new(None, Some(true));
+    for (src, dst) in [("A", "B"), ("A", "C"), ("B", "D"), ("C", "D"), ("A", "D")] {
+        builder.add_edge(src.to_string(), dst.to_string(), None, None)?;
+    }
+    let graph = builder.build()?;
+    let (x, y) = graph.get_layered_dag_layout(None)?;
+    for (node_name, layer) in [("A", 0.0), ("B", 1.0), ("C", 1.0), ("D", 2.0)] {
+        assert_eq!(
+            y[graph.get_node_id_from_node_name(node_name)? as usize],
+            layer
+        );
+    }
+    let first = x[graph.get_node_id_from_node_name("B")? as usize];
+    let second = x[graph.get_node_id_from_node_name("C")? as usize];
+    assert_eq!(first.abs(), 0.5);
+    assert_eq!(first, -second);
+    assert!(graph.to_undirected().get_layered_dag_layout(None).is_err());
+    Ok(())
+}
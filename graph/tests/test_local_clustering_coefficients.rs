@@ -0,0 +1,49 @@
+extern crate graph;
+use graph::*;
+
+#[test]
+fn test_local_clustering_coefficients() -> Result<()> {
+    // In a triangle all the pairs of neighbours are connected.
+    let triangle = test_utilities::build_graph_from_edges(
+        &[("A", "B"), ("B", "C"), ("C", "A"), ("A", "A")],
+        false,
+    )?;
+    assert_eq!(
+        triangle.get_local_clustering_coefficients(Some(false))?,
+        vec![1.0, 1.0, 1.0]
+    );
+    assert_eq!(
+        triangle.get_average_local_clustering_coefficient(Some(false))?,
+        1.0
+    );
+
+    // In a star no pair of neighbours is connected.
+    let star =
+        test_utilities::build_graph_from_edges(&[("H", "L1"), ("H", "L2"), ("H", "L3")], false)?;
+    assert_eq!(
+        star.get_local_clustering_coefficients(Some(false))?,
+        vec![0.0; 4]
+    );
+    assert_eq!(
+        star.get_average_local_clustering_coefficient(Some(false))?,
+        0.0
+    );
+
+    // Adding an edge between two leaves closes one of the three pairs of the hub.
+    let star = test_utilities::build_graph_from_edges(
+        &[("H", "L1"), ("H", "L2"), ("H", "L3"), ("L1", "L2")],
+        false,
+    )?;
+    let coefficients = star.get_local_clustering_coefficients(Some(false))?;
+    let node_id = |name: &str| star.get_node_id_from_node_name(name).unwrap() as usize;
+    assert!((coefficients[node_id("H")] - 1.0 / 3.0).abs() < f64::EPSILON);
+    assert_eq!(coefficients[node_id("L1")], 1.0);
+    assert_eq!(coefficients[node_id("L3")], 0.0);
+
+    assert!(star
+        .to_directed()
+        .get_local_clustering_coefficients(Some(false))
+        .is_err());
+
+    Ok(())
+}
@@ -0,0 +1,51 @@
+extern crate graph;
+use graph::*;
+
+/// Returns the local reaching centrality of each of the given nodes.
+fn get_centralities(graph: &Graph, node_names: &[&str]) -> Vec<f64> {
+    let centralities = graph.get_local_reaching_centrality();
+    assert_eq!(centralities.len(), graph.get_number_of_nodes() as usize);
+    node_names
+        .iter()
+        .map(|name| centralities[graph.get_node_id_from_node_name(name).unwrap() as usize])
+        .collect()
+}
+
+#[test]
+fn test_local_reaching_centrality() -> Result<()> {
+    // A directed acyclic graph plus a selfloop, which does not make D reach itself.
+    let mut builder = GraphBuilder::new(None, Some(true));
+    for (src, dst) in [
+        ("A", "B"),
+        ("A", "C"),
+        ("B", "D"),
+        ("C", "D"),
+        ("D", "D"),
+        ("E", "F"),
+    ] {
+        builder.add_edge(src, dst, None, None)?;
+    }
+    let graph = builder.build()?;
+    assert_eq!(
+        get_centralities(&graph, &["A", "B", "C", "D", "E", "F"]),
+        vec![0.6, 0.2, 0.2, 0.0, 0.2, 0.0]
+    );
+
+    // In undirected graphs, every node reaches its whole component.
+    let mut builder = GraphBuilder::new(None, Some(false));
+    for (src, dst) in [("A", "B"), ("B", "C"), ("D", "E")] {
+        builder.add_edge(src, dst, None, None)?;
+    }
+    builder.add_node("F".to_string(), None)?;
+    let graph = builder.build()?;
+    assert_eq!(
+        get_centralities(&graph, &["A", "B", "C", "D", "E", "F"]),
+        vec![0.4, 0.4, 0.4, 0.2, 0.2, 0.0]
+    );
+
+    let mut builder = GraphBuilder::new(None, Some(false));
+    builder.add_node("A".to_string(), None)?;
+    assert_eq!(builder.build()?.get_local_reaching_centrality(), vec![0.0]);
+
+    Ok(())
+}
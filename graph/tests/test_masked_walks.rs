@@ -0,0 +1,31 @@
+extern crate graph;
+use graph::*;
+use rayon::prelude::*;
+
+#[test]
+fn test_masked_walks() -> Result<()> {
+    let chain_graph =
+        Graph::generate_chain_graph(None, Some(10), None, None, None, None, None, None).unwrap();
+    // We mask out the fifth node, splitting the chain in two.
+    let node_mask = (0..10).map(|node_id| node_id != 4).collect::<Vec<bool>>();
+    let parameters = WalksParameters::new(20)?.set_node_mask(Some(node_mask.clone()));
+    let walks = chain_graph
+        .par_iter_complete_walks(&parameters)?
+        .collect::<Vec<Vec<NodeT>>>();
+    assert_eq!(walks.len(), 10);
+    walks.iter().for_each(|walk| {
+        assert_eq!(walk.len(), 20);
+        if walk[0] == 4 {
+            assert!(walk.iter().all(|&node_id| node_id == 4));
+        } else {
+            assert!(walk.iter().all(|&node_id| node_mask[node_id as usize]));
+            assert!(walk.iter().all(|&node_id| (node_id < 4) == (walk[0] < 4)));
+        }
+    });
+
+    let wrong_parameters = WalksParameters::new(20)?.set_node_mask(Some(vec![true; 5]));
+    assert!(chain_graph
+        .par_iter_complete_walks(&wrong_parameters)
+        .is_err());
+    Ok(())
+}
@@ -0,0 +1,62 @@
+extern crate graph;
+use graph::*;
+
+#[test]
+fn test_max_cliques() -> Result<()> {
+    let mut builder = GraphBuilder::new(None, Some(false));
+    for (src, dst) in [
+        ("A", "B"),
+        ("A", "C"),
+        ("A", "D"),
+        ("B", "C"),
+        ("B", "D"),
+        ("C", "D"),
+        ("D", "E"),
+        ("D", "F"),
+        ("E", "F"),
+        ("F", "G"),
+        ("G", "G"),
+    ] {
+        builder.add_edge(src, dst, None, None)?;
+    }
+    builder.add_node("H".to_string(), None)?;
+    let graph = builder.build()?;
+    let get_sorted_cliques = |cliques: Vec<Clique>| {
+        let mut cliques = cliques
+            .into_iter()
+            .map(|clique| {
+                let mut node_names = clique.get_node_names();
+                node_names.sort();
+                node_names.join("")
+            })
+            .collect::<Vec<String>>();
+        cliques.sort();
+        cliques
+    };
+
+    assert_eq!(
+        get_sorted_cliques(graph.get_max_cliques(1, None)?),
+        vec!["ABCD", "DEF", "FG", "H"]
+    );
+    assert_eq!(
+        get_sorted_cliques(graph.get_max_cliques(3, None)?),
+        vec!["ABCD", "DEF"]
+    );
+
+    // The clique with four nodes is truncated to some of its triangles.
+    let bounded_cliques = get_sorted_cliques(graph.get_max_cliques(2, Some(3))?);
+    assert!(bounded_cliques.contains(&"DEF".to_string()));
+    assert!(bounded_cliques.contains(&"FG".to_string()));
+    assert!(bounded_cliques
+        .iter()
+        .all(|clique| clique.len() >= 2 && clique.len() <= 3));
+    assert!(bounded_cliques
+        .iter()
+        .any(|clique| clique.len() == 3
+            && clique.chars().all(|node_name| "ABCD".contains(node_name))));
+
+    assert!(graph.get_max_cliques(3, Some(2)).is_err());
+    assert!(graph.to_directed().get_max_cliques(3, None).is_err());
+
+    Ok(())
+}
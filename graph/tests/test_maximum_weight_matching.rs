@@ -0,0 +1,40 @@
+extern crate graph;
+use graph::*;
+
+#[test]
+fn test_maximum_weight_matching() -> Result<()> {
+    // A triangle with a pendant node, whose maximum weight matching is
+    // composed of the edges A-B and C-D, with total weight 5.5.
+    let mut builder = GraphBuilder::new(None, Some(false));
+    for (src, dst, weight) in [
+        ("A", "B", 3.0),
+        ("B", "C", 2.0),
+        ("C", "A", 1.0),
+        ("C", "D", 2.5),
+    ] {
+        builder.add_edge(src.to_string(), dst.to_string(), None, Some(weight))?;
+    }
+    let graph = builder.build()?;
+    let matching = graph.get_maximum_weight_matching()?;
+    let total_weight = matching
+        .iter()
+        .map(|&edge_id| graph.get_edge_weight_from_edge_id(edge_id).unwrap())
+        .sum::<WeightT>();
+    assert_eq!(matching.len(), 2);
+    assert_eq!(total_weight, 5.5);
+
+    // On a path whose central edge is the heaviest one, the greedy matching
+    // is not optimal but is within the approximation ratio.
+    let mut builder = GraphBuilder::new(None, Some(false));
+    for (src, dst, weight) in [("A", "B", 1.0), ("B", "C", 1.5), ("C", "D", 1.0)] {
+        builder.add_edge(src.to_string(), dst.to_string(), None, Some(weight))?;
+    }
+    let graph = builder.build()?;
+    let matching = graph.get_maximum_weight_matching()?;
+    assert_eq!(matching.len(), 1);
+    assert!(
+        graph.get_edge_weight_from_edge_id(matching[0])? as f64
+            >= MAXIMUM_WEIGHT_MATCHING_APPROXIMATION_RATIO * 2.0
+    );
+    Ok(())
+}
@@ -0,0 +1,27 @@
+extern crate graph;
+use graph::*;
+
+#[test]
+fn test_candidate_duplicate_node_pairs() -> Result<()> {
+    let mut builder = GraphBuilder::new(None, Some(false));
+    // The nodes A and B have the same neighbourhood, while E shares no neighbour with them.
+    for (src, dst) in [("A", "C"), ("A", "D"), ("B", "C"), ("B", "D"), ("E", "F")] {
+        builder.add_edge(src.to_string(), dst.to_string(), None, None)?;
+    }
+    let graph = builder.build()?;
+    let signatures = graph.get_node_minhash_signatures(16, Some(7))?;
+    let first = graph.get_node_id_from_node_name("A")?;
+    let second = graph.get_node_id_from_node_name("B")?;
+    assert_eq!(signatures[first as usize], signatures[second as usize]);
+
+    let candidate_pairs = graph.get_candidate_duplicate_node_pairs(16, 4, Some(7))?;
+    assert!(candidate_pairs.contains(&(first.min(second), first.max(second))));
+    let third = graph.get_node_id_from_node_name("E")?;
+    assert!(candidate_pairs
+        .iter()
+        .all(|&(src, dst)| src < dst && src != third && dst != third));
+    assert!(graph
+        .get_candidate_duplicate_node_pairs(16, 3, None)
+        .is_err());
+    Ok(())
+}
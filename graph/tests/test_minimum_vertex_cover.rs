@@ -0,0 +1,38 @@
+extern crate graph;
+use graph::*;
+
+#[test]
+fn test_minimum_vertex_cover() -> Result<()> {
+    // A star with five leaves, attached to a triangle through one of its leaves,
+    // and a further node with a selfloop.
+    let mut builder = GraphBuilder::new(None, Some(false));
+    for (src, dst) in [
+        ("H", "L1"),
+        ("H", "L2"),
+        ("H", "L3"),
+        ("H", "L4"),
+        ("H", "L5"),
+        ("L5", "A"),
+        ("A", "B"),
+        ("B", "C"),
+        ("C", "A"),
+        ("S", "S"),
+    ] {
+        builder.add_edge(src, dst, None, None)?;
+    }
+    let graph = builder.build()?;
+
+    let cover = graph.get_minimum_vertex_cover(None)?;
+    assert_eq!(cover.len(), 4);
+    assert!(cover.windows(2).all(|pair| pair[0] < pair[1]));
+    assert!(cover.contains(&graph.get_node_id_from_node_name("H")?));
+    assert!(cover.contains(&graph.get_node_id_from_node_name("S")?));
+    assert!(graph
+        .iter_directed_edge_node_ids()
+        .all(|(_, src, dst)| cover.contains(&src) || cover.contains(&dst)));
+
+    assert_eq!(graph.to_directed().get_minimum_vertex_cover(None)?.len(), 4);
+    assert!(graph.get_minimum_vertex_cover(Some(5)).is_err());
+
+    Ok(())
+}
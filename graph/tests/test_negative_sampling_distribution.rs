@@ -0,0 +1,102 @@
+extern crate graph;
+use graph::*;
+
+#[test]
+fn test_negative_sampling_distribution() -> Result<()> {
+    let mut builder = GraphBuilder::new(None, Some(false));
+    builder.add_edge("H", "A", None, None)?;
+    builder.add_edge("H", "B", None, None)?;
+    builder.add_node("S".to_string(), None)?;
+    let graph = builder.build()?;
+    let node_id = |name: &str| graph.get_node_id_from_node_name(name).unwrap() as usize;
+    let (h, a, b, s) = (node_id("H"), node_id("A"), node_id("B"), node_id("S"));
+
+    let assert_distribution = |distribution: &[f64], expected: [f64; 4]| {
+        for (node_id, expected) in [h, a, b, s].iter().zip(expected.iter()) {
+            assert!((distribution[*node_id] - expected).abs() < 1e-10);
+        }
+    };
+    let total = 2.0_f64.powf(0.75) + 2.0;
+    assert_distribution(
+        &graph.get_negative_sampling_distribution(None)?,
+        [2.0_f64.powf(0.75) / total, 1.0 / total, 1.0 / total, 0.0],
+    );
+    assert_distribution(
+        &graph.get_negative_sampling_distribution(Some(1.0))?,
+        [0.5, 0.25, 0.25, 0.0],
+    );
+    // With a zero exponent the distribution is uniform, singletons included.
+    assert_distribution(
+        &graph.get_negative_sampling_distribution(Some(0.0))?,
+        [0.25, 0.25, 0.25, 0.25],
+    );
+    assert!(graph
+        .get_negative_sampling_distribution(Some(-1.0))
+        .is_err());
+    assert!(graph
+        .get_negative_sampling_distribution(Some(f64::NAN))
+        .is_err());
+
+    let mut builder = GraphBuilder::new(None, Some(false));
+    builder.add_node("A".to_string(), None)?;
+    let singletons = builder.build()?;
+    assert!(singletons.get_negative_sampling_distribution(None).is_err());
+    assert_eq!(
+        singletons.get_negative_sampling_distribution(Some(0.0))?,
+        vec![1.0]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_sample_nodes_with_weights() -> Result<()> {
+    let mut builder = GraphBuilder::new(None, Some(false));
+    builder.add_edge("H", "A", None, None)?;
+    builder.add_edge("H", "B", None, None)?;
+    builder.add_node("S".to_string(), None)?;
+    let graph = builder.build()?;
+    let distribution = graph.get_negative_sampling_distribution(Some(1.0))?;
+
+    let number_of_samples = 10_000;
+    let samples = graph.sample_nodes_with_weights(&distribution, number_of_samples, Some(7))?;
+    assert_eq!(samples.len(), number_of_samples);
+    assert_eq!(
+        samples,
+        graph.sample_nodes_with_weights(&distribution, number_of_samples, Some(7))?
+    );
+    // The frequencies of the samples follow the distribution,
+    // and the nodes with zero probability are never sampled.
+    let mut counts = vec![0; graph.get_number_of_nodes() as usize];
+    samples
+        .iter()
+        .for_each(|&node_id| counts[node_id as usize] += 1);
+    for (count, probability) in counts.iter().zip(distribution.iter()) {
+        assert!((*count as f64 / number_of_samples as f64 - probability).abs() < 0.03);
+        if *probability == 0.0 {
+            assert_eq!(*count, 0);
+        }
+    }
+    // The weights do not need to be normalized, and scaling them by a power
+    // of two keeps the cumulative weights exact.
+    let weights = distribution
+        .iter()
+        .map(|probability| probability * 8.0)
+        .collect::<Vec<f64>>();
+    assert_eq!(
+        samples,
+        graph.sample_nodes_with_weights(&weights, number_of_samples, Some(7))?
+    );
+
+    assert!(graph
+        .sample_nodes_with_weights(&distribution[1..], 10, None)
+        .is_err());
+    assert!(graph
+        .sample_nodes_with_weights(&[1.0, -1.0, 1.0, 1.0], 10, None)
+        .is_err());
+    assert!(graph
+        .sample_nodes_with_weights(&[0.0; 4], 10, None)
+        .is_err());
+
+    Ok(())
+}
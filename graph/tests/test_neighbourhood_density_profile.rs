@@ -0,0 +1,60 @@
+extern crate graph;
+use graph::*;
+
+fn assert_profile(profile: &[f64], expected: &[f64]) {
+    assert_eq!(profile.len(), expected.len());
+    for (value, expected) in profile.iter().zip(expected.iter()) {
+        assert!(
+            (value - expected).abs() < 1e-10,
+            "{:?} != {:?}",
+            profile,
+            expected
+        );
+    }
+}
+
+#[test]
+fn test_neighbourhood_density_profile() -> Result<()> {
+    // From all the sources of the path A-B-C-D-E there are
+    // 5, 8, 6, 4 and 2 nodes at 0, 1, 2, 3 and 4 hops.
+    let path = test_utilities::build_graph_from_edges(
+        &[("A", "B"), ("B", "C"), ("C", "D"), ("D", "E")],
+        false,
+    )?;
+    assert_profile(
+        &path.get_neighbourhood_density_profile(4, None, None)?,
+        &[8.0 / 5.0, 6.0 / 8.0, 4.0 / 6.0, 2.0 / 4.0],
+    );
+    assert_profile(
+        &path.get_neighbourhood_density_profile(2, None, None)?,
+        &[8.0 / 5.0, 6.0 / 8.0],
+    );
+
+    // The sources of a star saturate within two hops, after which the profile is zero.
+    let star =
+        test_utilities::build_graph_from_edges(&[("H", "A"), ("H", "B"), ("H", "C")], false)?;
+    assert_profile(
+        &star.get_neighbourhood_density_profile(4, None, None)?,
+        &[6.0 / 4.0, 1.0, 0.0, 0.0],
+    );
+
+    // The sampled profile is reproducible given the random state.
+    let profile = path.get_neighbourhood_density_profile(3, Some(20), Some(7))?;
+    assert_eq!(profile.len(), 3);
+    assert!(profile
+        .iter()
+        .all(|value| value.is_finite() && *value >= 0.0));
+    assert_eq!(
+        profile,
+        path.get_neighbourhood_density_profile(3, Some(20), Some(7))?
+    );
+
+    assert!(path
+        .get_neighbourhood_density_profile(0, None, None)
+        .is_err());
+    assert!(path
+        .get_neighbourhood_density_profile(2, Some(0), None)
+        .is_err());
+
+    Ok(())
+}
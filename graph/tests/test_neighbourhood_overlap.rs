@@ -0,0 +1,41 @@
+extern crate graph;
+use graph::*;
+
+#[test]
+fn test_neighbourhood_overlap() -> Result<()> {
+    let mut builder = GraphBuilder::new(None, Some(false));
+    builder.add_edge("A", "B", None, None)?;
+    builder.add_edge("A", "C", None, None)?;
+    builder.add_edge("B", "C", None, None)?;
+    builder.add_edge("B", "D", None, None)?;
+    builder.add_edge("A", "A", None, None)?;
+    let graph = builder.build()?;
+    let node_id = |name: &str| graph.get_node_id_from_node_name(name).unwrap();
+    let overlap = |src: &str, dst: &str| unsafe {
+        graph.get_unchecked_neighbourhood_overlap_from_node_ids(node_id(src), node_id(dst))
+    };
+
+    // The selfloop of A does not add A to its own neighbourhood:
+    // the neighbourhoods are {C} and {C, D}.
+    assert_eq!(overlap("A", "B"), 0.5);
+    assert_eq!(overlap("B", "A"), 0.5);
+    // The neighbourhoods are {B} and {B}.
+    assert_eq!(overlap("A", "C"), 1.0);
+    // The neighbourhood of D, the other endpoint excluded, is empty.
+    assert_eq!(overlap("B", "D"), 0.0);
+    assert_eq!(overlap("A", "A"), 0.0);
+
+    let overlaps = graph.get_edge_neighbourhood_overlap();
+    assert_eq!(
+        overlaps.len(),
+        graph.get_number_of_directed_edges() as usize
+    );
+    for (edge_id, score) in overlaps.into_iter().enumerate() {
+        let (src, dst) = graph.get_node_ids_from_edge_id(edge_id as EdgeT)?;
+        assert_eq!(score, unsafe {
+            graph.get_unchecked_neighbourhood_overlap_from_node_ids(src, dst)
+        });
+    }
+
+    Ok(())
+}
@@ -0,0 +1,67 @@
+extern crate graph;
+use graph::*;
+
+#[test]
+fn test_node_weights_from_centrality() -> Result<()> {
+    let mut builder = GraphBuilder::new(None, Some(false));
+    for (src, dst, weight) in [
+        ("A", "B", 1.0),
+        ("B", "C", 2.0),
+        ("C", "D", 1.0),
+        ("D", "A", 3.0),
+    ] {
+        builder.add_edge(src.to_string(), dst.to_string(), None, Some(weight))?;
+    }
+    let graph = builder.build()?;
+    assert!(!graph.has_node_weights());
+
+    let weighted_graph =
+        graph.set_node_weights_from_centrality(CentralityType::WeightedPageRank)?;
+    let node_weights = weighted_graph.get_node_weights().unwrap();
+    assert_eq!(node_weights.len(), 4);
+    assert!((node_weights.iter().sum::<f64>() - 1.0).abs() < 1e-6);
+
+    // The node weights follow the nodes when the graph is remapped.
+    let remapped_graph = weighted_graph.remap_from_node_ids(vec![3, 2, 1, 0])?;
+    let remapped_node_weights = remapped_graph.get_node_weights().unwrap();
+    for node_name in ["A", "B", "C", "D"] {
+        assert_eq!(
+            node_weights[weighted_graph.get_node_id_from_node_name(node_name)? as usize],
+            remapped_node_weights[remapped_graph.get_node_id_from_node_name(node_name)? as usize]
+        );
+    }
+
+    assert!(graph.set_node_weights(vec![1.0; 3]).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_node_features() -> Result<()> {
+    let mut builder = GraphBuilder::new(None, Some(false));
+    for (src, dst) in [("A", "B"), ("B", "C"), ("C", "D")] {
+        builder.add_edge(src.to_string(), dst.to_string(), None, Some(1.0))?;
+    }
+    let graph = builder.build()?;
+    assert!(!graph.has_node_features());
+    assert!(graph.attach_node_features(vec![vec![1.0, 2.0]; 3]).is_err());
+    assert!(graph
+        .attach_node_features(vec![vec![1.0], vec![1.0, 2.0], vec![1.0], vec![1.0]])
+        .is_err());
+
+    let features = (0..4)
+        .map(|node_id| vec![node_id as f64, 2.0 * node_id as f64])
+        .collect::<Vec<Vec<f64>>>();
+    let graph = graph.attach_node_features(features.clone())?;
+    assert_eq!(graph.get_node_features(), Some(features.clone()));
+
+    // The node features follow the nodes when the graph is remapped.
+    let remapped_graph = graph.remap_from_node_ids(vec![3, 2, 1, 0])?;
+    let remapped_features = remapped_graph.get_node_features().unwrap();
+    for node_name in ["A", "B", "C", "D"] {
+        assert_eq!(
+            features[graph.get_node_id_from_node_name(node_name)? as usize],
+            remapped_features[remapped_graph.get_node_id_from_node_name(node_name)? as usize]
+        );
+    }
+    Ok(())
+}
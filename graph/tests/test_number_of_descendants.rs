@@ -0,0 +1,51 @@
+extern crate graph;
+use graph::*;
+
+#[test]
+fn test_number_of_descendants_per_node() -> Result<()> {
+    let mut builder = GraphBuilder::new(None, Some(true));
+    for (src, dst) in [
+        ("R", "A"),
+        ("R", "B"),
+        ("A", "C"),
+        ("B", "C"),
+        ("C", "D"),
+        ("R", "D"),
+    ] {
+        builder.add_edge(src, dst, None, None)?;
+    }
+    builder.add_node("S".to_string(), None)?;
+    let graph = builder.build()?;
+
+    // The descendants reachable through multiple paths are counted once.
+    let expected = [("R", 4), ("A", 2), ("B", 2), ("C", 1), ("D", 0), ("S", 0)];
+    let number_of_descendants = graph.get_number_of_descendants_per_node()?;
+    // With many registers, the small counts are estimated almost exactly.
+    let approximated_number_of_descendants =
+        graph.get_approximated_number_of_descendants_per_node(Some(12), None)?;
+    assert_eq!(number_of_descendants.len(), expected.len());
+    assert_eq!(approximated_number_of_descendants.len(), expected.len());
+    for (node_name, expected) in expected {
+        let node_id = graph.get_node_id_from_node_name(node_name)? as usize;
+        assert_eq!(number_of_descendants[node_id], expected);
+        assert!(
+            (approximated_number_of_descendants[node_id] - expected as f32).abs() < 0.5,
+            "The approximated number of descendants of {} is {}, while {} was expected.",
+            node_name,
+            approximated_number_of_descendants[node_id],
+            expected
+        );
+    }
+
+    let mut builder = GraphBuilder::new(None, Some(true));
+    for (src, dst) in [("A", "B"), ("B", "C"), ("C", "A")] {
+        builder.add_edge(src, dst, None, None)?;
+    }
+    let cyclic = builder.build()?;
+    assert!(cyclic.get_number_of_descendants_per_node().is_err());
+    assert!(cyclic
+        .get_approximated_number_of_descendants_per_node(None, None)
+        .is_err());
+
+    Ok(())
+}
@@ -0,0 +1,16 @@
+extern crate graph;
+use graph::*;
+
+#[test]
+fn test_pendant_nodes() -> Result<()> {
+    let star_graph =
+        Graph::generate_star_graph(None, Some(10), None, None, None, None, None, None)?;
+    assert_eq!(star_graph.get_pendant_node_ids(None)?, (1..10).collect::<Vec<NodeT>>());
+    assert!(star_graph.get_isolated_node_ids().is_empty());
+
+    let chain_graph =
+        Graph::generate_chain_graph(None, Some(10), None, None, None, None, None, None)?;
+    assert_eq!(chain_graph.get_pendant_node_ids(Some("total"))?, vec![0, 9]);
+    assert!(chain_graph.get_pendant_node_ids(Some("unsupported")).is_err());
+    Ok(())
+}
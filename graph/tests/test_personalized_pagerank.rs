@@ -0,0 +1,41 @@
+extern crate graph;
+use graph::*;
+
+#[test]
+fn test_personalized_pagerank() -> Result<()> {
+    let mut builder = GraphBuilder::new(None, Some(true));
+    for (src, dst) in [("A", "B"), ("B", "C"), ("D", "A")] {
+        builder.add_edge(src.to_string(), dst.to_string(), None, None)?;
+    }
+    let graph = builder.build()?;
+    let node_id = |node_name: &str| graph.get_node_id_from_node_name(node_name).unwrap();
+
+    let scores = graph.get_pagerank(None, None, None, Some(1e-12))?;
+    assert!((scores.iter().sum::<f64>() - 1.0).abs() < 1e-9);
+
+    // When teleporting only to A, the trap C sends its mass back to A
+    // and D, which has no inbound edges, has zero score.
+    let damping_factor: f64 = 0.85;
+    let scores = graph.get_pagerank(
+        Some(damping_factor),
+        Some(vec![(node_id("A"), 2.0)]),
+        None,
+        Some(1e-12),
+    )?;
+    let expected_score = (1.0 - damping_factor) / (1.0 - damping_factor.powi(3));
+    assert!((scores[node_id("A") as usize] - expected_score).abs() < 1e-9);
+    assert!((scores[node_id("B") as usize] - damping_factor * expected_score).abs() < 1e-9);
+    assert!((scores[node_id("C") as usize] - damping_factor.powi(2) * expected_score).abs() < 1e-9);
+    assert_eq!(scores[node_id("D") as usize], 0.0);
+
+    assert!(graph
+        .get_pagerank(None, Some(vec![(10, 1.0)]), None, None)
+        .is_err());
+    assert!(graph
+        .get_pagerank(None, Some(vec![(node_id("A"), -1.0)]), None, None)
+        .is_err());
+    assert!(graph
+        .get_pagerank(None, Some(vec![(node_id("A"), 0.0)]), None, None)
+        .is_err());
+    Ok(())
+}
@@ -0,0 +1,52 @@
+extern crate graph;
+use graph::*;
+
+#[test]
+fn test_degree_rank_plot_data() -> Result<()> {
+    let mut builder = GraphBuilder::new(None, Some(false));
+    for (src, dst) in [("H", "A"), ("H", "B"), ("H", "C"), ("H", "D"), ("A", "B")] {
+        builder.add_edge(src, dst, None, None)?;
+    }
+    builder.add_node("E".to_string(), None)?;
+    let graph = builder.build()?;
+    assert_eq!(
+        graph.get_degree_rank_plot_data(),
+        (vec![4, 2, 2, 1, 1, 0], vec![1, 2, 3, 4, 5, 6])
+    );
+    Ok(())
+}
+
+#[test]
+fn test_power_law_exponent() -> Result<()> {
+    // The non-zero node degrees are 4, 2, 2, 1 and 1. The minimum degree 4 is
+    // not considered as it leaves a single degree in the tail, while the
+    // minimum degree 1 has a lower Kolmogorov-Smirnov distance than 2.
+    let mut builder = GraphBuilder::new(None, Some(false));
+    for (src, dst) in [("H", "A"), ("H", "B"), ("H", "C"), ("H", "D"), ("A", "B")] {
+        builder.add_edge(src, dst, None, None)?;
+    }
+    builder.add_node("E".to_string(), None)?;
+    let (exponent, minimum_degree) = builder.build()?.estimate_power_law_exponent()?;
+    assert_eq!(minimum_degree, 1);
+    assert!((exponent - (1.0 + 5.0 / (9.0 * 2.0_f64.ln()))).abs() < 1e-12);
+
+    // When all the nodes have the same degree, it is the only candidate.
+    let mut builder = GraphBuilder::new(None, Some(false));
+    for (src, dst) in [("A", "B"), ("B", "C"), ("C", "D"), ("D", "E"), ("E", "A")] {
+        builder.add_edge(src, dst, None, None)?;
+    }
+    let (exponent, minimum_degree) = builder.build()?.estimate_power_law_exponent()?;
+    assert_eq!(minimum_degree, 2);
+    assert!((exponent - (1.0 + 1.0 / (4.0_f64 / 3.0).ln())).abs() < 1e-12);
+
+    // A single directed edge leaves a single non-zero outbound degree.
+    let mut builder = GraphBuilder::new(None, Some(true));
+    builder.add_edge("A", "B", None, None)?;
+    assert!(builder.build()?.estimate_power_law_exponent().is_err());
+
+    let mut builder = GraphBuilder::new(None, Some(false));
+    builder.add_node("A".to_string(), None)?;
+    assert!(builder.build()?.estimate_power_law_exponent().is_err());
+
+    Ok(())
+}
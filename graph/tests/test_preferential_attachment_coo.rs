@@ -0,0 +1,26 @@
+extern crate graph;
+use graph::*;
+
+#[test]
+fn test_preferential_attachment_coo_matrix() -> Result<()> {
+    let mut builder = GraphBuilder::new(None, Some(false));
+    for (src, dst) in [("A", "B"), ("B", "C"), ("C", "D")] {
+        builder.add_edge(src.to_string(), dst.to_string(), None, None)?;
+    }
+    let graph = builder.build()?;
+    let (pairs, scores) = graph.get_preferential_attachment_coo_matrix(None)?;
+    // On a path of four nodes, only the two endpoints are at distance three.
+    assert_eq!(pairs.len(), 10);
+    for ((src, dst), score) in pairs.into_iter().zip(scores.into_iter()) {
+        assert_ne!(src, dst);
+        assert_eq!(
+            score,
+            (graph.get_node_degree_from_node_id(src)? * graph.get_node_degree_from_node_id(dst)?)
+                as WeightT
+        );
+    }
+    assert!(graph
+        .get_preferential_attachment_coo_matrix(Some(&[(0, 10)]))
+        .is_err());
+    Ok(())
+}
@@ -0,0 +1,47 @@
+extern crate graph;
+use graph::*;
+
+#[test]
+fn test_propagate_node_features() -> Result<()> {
+    let mut builder = GraphBuilder::new(None, Some(false));
+    for (src, dst) in [("A", "B"), ("B", "C")] {
+        builder.add_edge(src, dst, None, None)?;
+    }
+    let graph = builder.build()?;
+    let node_id = |name: &str| graph.get_node_id_from_node_name(name).unwrap() as usize;
+    let mut features = vec![vec![0.0, 1.0]; 3];
+    features[node_id("A")] = vec![1.0, 0.0];
+
+    assert_eq!(
+        graph.propagate_node_features(features.clone(), 0, 0.5, None)?,
+        features
+    );
+
+    let propagated = graph.propagate_node_features(features.clone(), 1, 0.5, None)?;
+    let expected_b = 0.5 / 2.0_f64.sqrt();
+    assert!((propagated[node_id("A")][0] - 0.5).abs() < 1e-12);
+    assert!((propagated[node_id("A")][1] - expected_b).abs() < 1e-12);
+    assert!((propagated[node_id("B")][0] - expected_b).abs() < 1e-12);
+    assert!((propagated[node_id("B")][1] - (0.5 + expected_b)).abs() < 1e-12);
+    assert!(propagated[node_id("C")][0].abs() < 1e-12);
+
+    // With alpha equal to zero the features are unchanged.
+    assert_eq!(
+        graph.propagate_node_features(features.clone(), 5, 0.0, None)?,
+        features
+    );
+
+    let normalized = graph.propagate_node_features(features.clone(), 3, 0.9, Some(true))?;
+    assert!(normalized
+        .iter()
+        .all(|row| (row.iter().sum::<f64>() - 1.0).abs() < 1e-12));
+
+    assert!(graph
+        .propagate_node_features(features.clone(), 1, 1.5, None)
+        .is_err());
+    assert!(graph
+        .propagate_node_features(vec![vec![1.0]; 2], 1, 0.5, None)
+        .is_err());
+
+    Ok(())
+}
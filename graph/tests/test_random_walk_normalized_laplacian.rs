@@ -0,0 +1,46 @@
+extern crate graph;
+use graph::*;
+
+#[test]
+fn test_random_walk_normalized_laplacian() -> Result<()> {
+    let mut builder = GraphBuilder::new(None, Some(false));
+    for (src, dst) in [("A", "B"), ("A", "C"), ("C", "C")] {
+        builder.add_edge(src, dst, None, None)?;
+    }
+    builder.add_node("D".to_string(), None)?;
+    let graph = builder.build()?;
+    let node_id = |name: &str| graph.get_node_id_from_node_name(name).unwrap();
+    let (a, b, c, d) = (node_id("A"), node_id("B"), node_id("C"), node_id("D"));
+
+    let (edges, weights) = graph.get_random_walk_normalized_laplacian_coo_matrix();
+    assert_eq!(edges.len(), weights.len());
+    let get_value = |src: NodeT, dst: NodeT| {
+        edges
+            .iter()
+            .zip(weights.iter())
+            .filter(|(&edge, _)| edge == (src, dst))
+            .map(|(_, &weight)| weight)
+            .sum::<WeightT>()
+    };
+    assert_eq!(get_value(a, b), -0.5);
+    assert_eq!(get_value(b, a), -1.0);
+    assert_eq!(get_value(c, a), -0.5);
+    assert_eq!(get_value(a, a), 1.0);
+    assert_eq!(get_value(b, b), 1.0);
+    assert_eq!(get_value(c, c), 0.5);
+    // The singleton node has a zero row.
+    assert!(edges.iter().all(|&(src, _)| src != d));
+
+    // Each row with a non-zero degree sums to zero.
+    for src in [a, b, c] {
+        let row_sum: WeightT = edges
+            .iter()
+            .zip(weights.iter())
+            .filter(|(&(edge_src, _), _)| edge_src == src)
+            .map(|(_, &weight)| weight)
+            .sum();
+        assert!(row_sum.abs() < 1e-6);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,58 @@
+extern crate graph;
+use graph::*;
+
+#[test]
+fn test_number_of_reachable_node_pairs() -> Result<()> {
+    let directed_graph = test_utilities::build_graph_from_edges(&[("A", "B"), ("B", "C")], true)?;
+    assert_eq!(
+        directed_graph.get_number_of_reachable_node_pairs(None, None)?,
+        3.0
+    );
+
+    let undirected_graph =
+        test_utilities::build_graph_from_edges(&[("A", "B"), ("C", "D"), ("D", "E")], false)?;
+    assert_eq!(
+        undirected_graph.get_number_of_reachable_node_pairs(None, None)?,
+        8.0
+    );
+    // The count of undirected graphs is exact even when sampling is requested.
+    assert_eq!(
+        undirected_graph.get_number_of_reachable_node_pairs(Some(2), Some(7))?,
+        8.0
+    );
+    assert!(undirected_graph
+        .get_number_of_reachable_node_pairs(Some(0), None)
+        .is_err());
+    Ok(())
+}
+
+#[test]
+fn test_sampled_number_of_reachable_node_pairs() -> Result<()> {
+    // Every node of a directed cycle reaches the other two, so any sample
+    // of source nodes gives the exact count.
+    let cycle =
+        test_utilities::build_graph_from_edges(&[("A", "B"), ("B", "C"), ("C", "A")], true)?;
+    for number_of_samples in [1, 2, 10] {
+        assert_eq!(
+            cycle.get_number_of_reachable_node_pairs(Some(number_of_samples), Some(7))?,
+            6.0
+        );
+    }
+
+    // In a directed path the estimate is the average reach of the sampled
+    // sources, that is two, one or zero, scaled by the number of nodes.
+    let path = test_utilities::build_graph_from_edges(&[("A", "B"), ("B", "C")], true)?;
+    let estimate = path.get_number_of_reachable_node_pairs(Some(4), Some(7))?;
+    assert!((0.0..=6.0).contains(&estimate));
+    let total_reach = estimate * 4.0 / 3.0;
+    assert!((total_reach - total_reach.round()).abs() < 1e-10);
+    assert_eq!(
+        estimate,
+        path.get_number_of_reachable_node_pairs(Some(4), Some(7))?
+    );
+
+    assert!(path
+        .get_number_of_reachable_node_pairs(Some(0), None)
+        .is_err());
+    Ok(())
+}
@@ -0,0 +1,33 @@
+extern crate graph;
+use graph::*;
+
+#[test]
+fn test_recursive_structural_features() -> Result<()> {
+    // A star with four leaves.
+    let mut builder = GraphBuilder::new(None, Some(false));
+    for leaf in ["B", "C", "D", "E"] {
+        builder.add_edge("A".to_string(), leaf.to_string(), None, None)?;
+    }
+    let graph = builder.build()?;
+    let center = graph.get_node_id_from_node_name("A")? as usize;
+
+    let (features, feature_names) = graph.get_recursive_structural_features(None, None, None)?;
+    // The number of internal egonet edges has the same bins of the degree,
+    // and all the aggregated features are redundant.
+    assert_eq!(
+        feature_names,
+        vec!["degree", "clustering_coefficient", "egonet_boundary_edges"]
+    );
+    assert_eq!(features[center], vec![4.0, 0.0, 0.0]);
+    assert_eq!(features[(center + 1) % 5], vec![1.0, 0.0, 3.0]);
+
+    let (features, feature_names) = graph.get_recursive_structural_features(None, None, Some(1))?;
+    assert_eq!(feature_names, vec!["degree"]);
+    assert!(features
+        .iter()
+        .all(|node_features| node_features.len() == 1));
+    assert!(graph
+        .get_recursive_structural_features(None, Some(1.0), None)
+        .is_err());
+    Ok(())
+}
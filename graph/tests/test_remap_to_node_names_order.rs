@@ -0,0 +1,48 @@
+extern crate graph;
+use graph::*;
+
+#[test]
+fn test_remap_to_node_names_order() -> Result<()> {
+    let mut builder = GraphBuilder::new(None, Some(false));
+    builder.add_edge("A", "B", None, Some(1.0))?;
+    builder.add_edge("B", "C", None, Some(2.0))?;
+    let graph = builder.build()?;
+
+    let node_names = vec!["X", "C", "B", "Y", "A"]
+        .into_iter()
+        .map(|node_name| node_name.to_string())
+        .collect::<Vec<String>>();
+    let remapped = graph.remap_to_node_names_order(node_names.clone())?;
+    assert_eq!(remapped.get_node_names(), node_names);
+    assert_eq!(remapped.get_number_of_singleton_nodes(), 2);
+    assert_eq!(
+        remapped.get_number_of_directed_edges(),
+        graph.get_number_of_directed_edges()
+    );
+    assert!(remapped.has_edge_from_node_ids(4, 2));
+    assert!(remapped.has_edge_from_node_ids(2, 1));
+    assert!(!remapped.has_edge_from_node_ids(4, 1));
+    assert_eq!(
+        remapped.get_edge_weight_from_node_ids(1, 2)?,
+        graph.get_edge_weight_from_node_ids(
+            graph.get_node_id_from_node_name("B")?,
+            graph.get_node_id_from_node_name("C")?
+        )?
+    );
+
+    // The nodes of the graph must all appear in the given node names.
+    assert!(graph
+        .remap_to_node_names_order(vec!["A".to_string(), "B".to_string()])
+        .is_err());
+    // The given node names must be unique.
+    assert!(graph
+        .remap_to_node_names_order(
+            vec!["A", "B", "C", "A"]
+                .into_iter()
+                .map(|node_name| node_name.to_string())
+                .collect()
+        )
+        .is_err());
+
+    Ok(())
+}
@@ -0,0 +1,53 @@
+extern crate graph;
+use graph::*;
+
+#[test]
+fn test_remove_edges_below_weight_percentile() -> Result<()> {
+    let mut builder = GraphBuilder::new(None, Some(false));
+    for (src, dst, weight) in [
+        ("A", "B", 1.0),
+        ("B", "C", 2.0),
+        ("C", "D", 2.0),
+        ("D", "E", 3.0),
+        ("E", "F", 4.0),
+    ] {
+        builder.add_edge(src, dst, None, Some(weight))?;
+    }
+    let graph = builder.build()?;
+
+    assert_eq!(
+        graph
+            .remove_edges_below_weight_percentile(0.0, None)?
+            .get_number_of_undirected_edges(),
+        5
+    );
+    // The ties at the threshold are all kept.
+    let filtered = graph.remove_edges_below_weight_percentile(30.0, None)?;
+    assert_eq!(filtered.get_number_of_undirected_edges(), 4);
+    assert_eq!(filtered.get_number_of_nodes(), 6);
+    assert_eq!(filtered.get_number_of_singleton_nodes(), 1);
+    assert!(filtered
+        .get_directed_edge_weights()?
+        .iter()
+        .all(|&weight| weight >= 2.0));
+
+    let filtered = graph.remove_edges_below_weight_percentile(100.0, Some(true))?;
+    assert_eq!(filtered.get_number_of_undirected_edges(), 1);
+    assert_eq!(filtered.get_number_of_nodes(), 2);
+
+    assert!(graph
+        .remove_edges_below_weight_percentile(-1.0, None)
+        .is_err());
+    assert!(graph
+        .remove_edges_below_weight_percentile(100.5, None)
+        .is_err());
+    assert!(graph
+        .remove_edges_below_weight_percentile(f64::NAN, None)
+        .is_err());
+    let unweighted = graph::test_utilities::load_ppi(true, true, false, false, false, false);
+    assert!(unweighted
+        .remove_edges_below_weight_percentile(50.0, None)
+        .is_err());
+
+    Ok(())
+}
@@ -0,0 +1,52 @@
+extern crate graph;
+use graph::*;
+
+#[test]
+fn test_report_json() -> Result<()> {
+    let mut builder = GraphBuilder::new(None, Some(false));
+    for (src, dst, weight) in [("A", "B", 0.5), ("B", "C", 1.5), ("D", "E", 2.0)] {
+        builder.add_edge(src.to_string(), dst.to_string(), None, Some(weight))?;
+    }
+    builder.add_node("F".to_string(), None)?;
+    let graph = builder.build()?;
+    let report = graph.get_report_json();
+
+    assert!(report.starts_with('{') && report.ends_with('}'));
+    assert!(report.contains("\"schema_version\": 1,"));
+    assert!(report.contains("\"directed\": false,"));
+    assert!(report.contains(&format!(
+        "\"number_of_nodes\": {},",
+        graph.get_number_of_nodes()
+    )));
+    assert!(report.contains(&format!(
+        "\"number_of_edges\": {},",
+        graph.get_number_of_edges()
+    )));
+    assert!(report.contains("\"number_of_singleton_nodes\": 1,"));
+    let (number_of_components, minimum_size, maximum_size) =
+        graph.get_number_of_connected_components(None);
+    assert!(report.contains(&format!(
+        "\"number_of_connected_components\": {},",
+        number_of_components
+    )));
+    assert!(report.contains(&format!(
+        "\"minimum_connected_component_size\": {},",
+        minimum_size
+    )));
+    assert!(report.contains(&format!(
+        "\"maximum_connected_component_size\": {},",
+        maximum_size
+    )));
+    assert!(report.contains(&format!("\"density\": {:?},", graph.get_density()?)));
+    assert!(report.contains("\"maximum_edge_weight\": 2.0,"));
+    assert!(report.contains("\"number_of_node_types\": null,"));
+    assert!(report.ends_with("\"number_of_edges_with_known_edge_types\": null\n}"));
+
+    let mut builder = GraphBuilder::new(None, Some(true));
+    builder.add_edge("A".to_string(), "B".to_string(), None, None)?;
+    let report = builder.build()?.get_report_json();
+    assert!(report.contains("\"number_of_connected_components\": null,"));
+    assert!(report.contains("\"minimum_edge_weight\": null,"));
+
+    Ok(())
+}
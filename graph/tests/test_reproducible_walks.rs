@@ -0,0 +1,35 @@
+extern crate graph;
+use graph::*;
+use rayon::prelude::*;
+
+#[test]
+fn test_reproducible_walks_across_thread_counts() -> Result<()> {
+    let graph = graph::test_utilities::load_ppi(true, true, true, false, false, false);
+    let parameters = WalksParameters::new(16)?
+        .set_iterations(Some(2))?
+        .set_return_weight(Some(2.0))?
+        .set_random_state(Some(u64::MAX - 7));
+
+    let get_walks = |number_of_threads: usize| -> Result<(Vec<Vec<NodeT>>, Vec<Vec<NodeT>>)> {
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(number_of_threads)
+            .build()
+            .map_err(|error| error.to_string())?;
+        pool.install(|| {
+            Ok((
+                graph.par_iter_complete_walks(&parameters)?.collect(),
+                graph.par_iter_random_walks(100, &parameters)?.collect(),
+            ))
+        })
+    };
+
+    let (complete_walks, random_walks) = get_walks(1)?;
+    assert_eq!((complete_walks.clone(), random_walks), get_walks(4)?);
+    assert_eq!(
+        complete_walks,
+        graph
+            .iter_complete_walks(&parameters)?
+            .collect::<Vec<Vec<NodeT>>>()
+    );
+    Ok(())
+}
@@ -0,0 +1,105 @@
+extern crate graph;
+use graph::*;
+
+fn assert_close(first: f64, second: f64) {
+    assert!(
+        (first - second).abs() < 1e-12,
+        "{} is not close to {}.",
+        first,
+        second
+    );
+}
+
+#[test]
+fn test_semantic_similarity() -> Result<()> {
+    // The edges go from the more general terms to the more specific ones.
+    let mut builder = GraphBuilder::new(None, Some(true));
+    for (src, dst) in [("R", "A"), ("R", "B"), ("A", "C"), ("A", "D"), ("B", "D")] {
+        builder.add_edge(src, dst, None, None)?;
+    }
+    let graph = builder.build()?;
+    let node_id = |name: &str| graph.get_node_id_from_node_name(name).unwrap();
+
+    let mut node_counts = vec![0; 5];
+    for (name, count) in [("A", 1), ("B", 1), ("C", 2), ("D", 2)] {
+        node_counts[node_id(name) as usize] = count;
+    }
+    // The propagated counts are R: 6, A: 5, B: 3, C: 2 and D: 2,
+    // where the count of D is propagated once to R.
+    let information_contents = graph.get_information_contents_from_node_counts(&node_counts)?;
+    for (name, count) in [("R", 6.0), ("A", 5.0), ("B", 3.0), ("C", 2.0), ("D", 2.0)] {
+        assert_close(
+            information_contents[node_id(name) as usize],
+            -(count / 6.0_f64).ln(),
+        );
+    }
+
+    let resnik = |first: &str, second: &str| {
+        graph
+            .get_resnik_similarity_from_node_ids(
+                node_id(first),
+                node_id(second),
+                &information_contents,
+            )
+            .unwrap()
+    };
+    let lin = |first: &str, second: &str| {
+        graph
+            .get_lin_similarity_from_node_ids(
+                node_id(first),
+                node_id(second),
+                &information_contents,
+            )
+            .unwrap()
+    };
+    // The most informative common ancestor of C and D is A.
+    assert_close(resnik("C", "D"), (6.0_f64 / 5.0).ln());
+    assert_close(lin("C", "D"), (6.0_f64 / 5.0).ln() / 3.0_f64.ln());
+    // The most informative common ancestor of D and B is B itself.
+    assert_close(resnik("D", "B"), 2.0_f64.ln());
+    assert_close(lin("D", "B"), 2.0 * 2.0_f64.ln() / 6.0_f64.ln());
+    // The only common ancestor of C and B is the root.
+    assert_close(resnik("C", "B"), 0.0);
+    assert_close(lin("C", "B"), 0.0);
+    assert_close(resnik("C", "C"), 3.0_f64.ln());
+    assert_close(lin("C", "C"), 1.0);
+    assert_close(lin("R", "R"), 0.0);
+
+    let pairs = [
+        (node_id("C"), node_id("D")),
+        (node_id("D"), node_id("B")),
+        (node_id("C"), node_id("B")),
+    ];
+    assert_eq!(
+        graph.get_resnik_similarities_from_node_ids(&pairs, &information_contents)?,
+        vec![resnik("C", "D"), resnik("D", "B"), resnik("C", "B")]
+    );
+    assert_eq!(
+        graph.get_lin_similarities_from_node_ids(&pairs, &information_contents)?,
+        vec![lin("C", "D"), lin("D", "B"), lin("C", "B")]
+    );
+
+    assert!(graph
+        .get_information_contents_from_node_counts(&[0; 5])
+        .is_err());
+    assert!(graph
+        .get_information_contents_from_node_counts(&[1; 4])
+        .is_err());
+    assert!(graph
+        .get_resnik_similarity_from_node_ids(0, 5, &information_contents)
+        .is_err());
+    assert!(graph
+        .get_lin_similarity_from_node_ids(0, 1, &information_contents[..4])
+        .is_err());
+
+    let mut builder = GraphBuilder::new(None, Some(true));
+    for (src, dst) in [("A", "B"), ("B", "C"), ("C", "A")] {
+        builder.add_edge(src, dst, None, None)?;
+    }
+    let cyclic_graph = builder.build()?;
+    assert!(cyclic_graph
+        .get_resnik_similarity_from_node_ids(0, 1, &[1.0; 3])
+        .is_err());
+
+    Ok(())
+}
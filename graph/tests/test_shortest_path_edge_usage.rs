@@ -0,0 +1,47 @@
+extern crate graph;
+use graph::*;
+
+#[test]
+fn test_shortest_path_edge_usage() -> Result<()> {
+    let mut builder = GraphBuilder::new(None, Some(false));
+    for (src, dst) in [("A", "B"), ("B", "C"), ("C", "D"), ("B", "E")] {
+        builder.add_edge(src.to_string(), dst.to_string(), None, Some(1.0))?;
+    }
+    let graph = builder.build()?;
+    let node_id = |node_name: &str| graph.get_node_id_from_node_name(node_name).unwrap();
+    let edge_id = |src: &str, dst: &str| {
+        graph
+            .get_edge_id_from_node_ids(node_id(src), node_id(dst))
+            .unwrap()
+    };
+    let targets = [node_id("C"), node_id("D"), node_id("E")];
+
+    for use_edge_weights in [false, true] {
+        let counts = graph.get_shortest_path_edge_usage_counts_from_node_id(
+            node_id("A"),
+            &targets,
+            Some(use_edge_weights),
+        )?;
+        assert_eq!(counts[edge_id("A", "B") as usize], 3);
+        assert_eq!(counts[edge_id("B", "C") as usize], 2);
+        assert_eq!(counts[edge_id("C", "D") as usize], 1);
+        assert_eq!(counts[edge_id("B", "E") as usize], 1);
+        assert_eq!(counts.iter().sum::<EdgeT>(), 7);
+    }
+
+    let mut expected_edge_ids = vec![
+        edge_id("A", "B"),
+        edge_id("B", "C"),
+        edge_id("C", "D"),
+        edge_id("B", "E"),
+    ];
+    expected_edge_ids.sort_unstable();
+    assert_eq!(
+        graph.get_shortest_path_edge_usage_from_node_id(node_id("A"), &targets, None)?,
+        expected_edge_ids
+    );
+    assert!(graph
+        .get_shortest_path_edge_usage_from_node_id(node_id("A"), &[10], None)
+        .is_err());
+    Ok(())
+}
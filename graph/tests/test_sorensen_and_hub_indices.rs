@@ -0,0 +1,53 @@
+extern crate graph;
+use graph::*;
+
+#[test]
+fn test_sorensen_and_hub_indices() -> Result<()> {
+    let mut builder = GraphBuilder::new(None, Some(false));
+    for (src, dst) in [("A", "B"), ("A", "C"), ("A", "D"), ("B", "C"), ("E", "F")] {
+        builder.add_edge(src.to_string(), dst.to_string(), None, None)?;
+    }
+    let graph = builder.build()?;
+    let node_id = |node_name: &str| graph.get_node_id_from_node_name(node_name).unwrap();
+
+    // The nodes A and B share the neighbour C and have degrees 3 and 2.
+    let (a, b) = (node_id("A"), node_id("B"));
+    assert!((graph.get_sorensen_index_from_node_ids(a, b)? - 2.0 / 5.0).abs() < 1e-12);
+    assert!((graph.get_hub_promoted_index_from_node_ids(a, b)? - 1.0 / 2.0).abs() < 1e-12);
+    assert!((graph.get_hub_depressed_index_from_node_ids(a, b)? - 1.0 / 3.0).abs() < 1e-12);
+
+    // The nodes D and B share the neighbour A and have degrees 1 and 2.
+    let d = node_id("D");
+    assert!((graph.get_sorensen_index_from_node_ids(d, b)? - 2.0 / 3.0).abs() < 1e-12);
+    assert!((graph.get_hub_promoted_index_from_node_ids(d, b)? - 1.0).abs() < 1e-12);
+    assert!((graph.get_hub_depressed_index_from_node_ids(d, b)? - 1.0 / 2.0).abs() < 1e-12);
+
+    // The nodes A and E do not share any neighbour.
+    let e = node_id("E");
+    assert_eq!(graph.get_sorensen_index_from_node_ids(a, e)?, 0.0);
+    assert_eq!(graph.get_hub_promoted_index_from_node_ids(a, e)?, 0.0);
+    assert_eq!(graph.get_hub_depressed_index_from_node_ids(a, e)?, 0.0);
+    assert!(graph.get_sorensen_index_from_node_ids(a, 100).is_err());
+
+    for (get_coo_matrix, get_score) in [
+        (
+            Graph::get_sorensen_index_coo_matrix as fn(&Graph) -> _,
+            Graph::get_sorensen_index_from_node_ids as fn(&Graph, NodeT, NodeT) -> _,
+        ),
+        (
+            Graph::get_hub_promoted_index_coo_matrix,
+            Graph::get_hub_promoted_index_from_node_ids,
+        ),
+        (
+            Graph::get_hub_depressed_index_coo_matrix,
+            Graph::get_hub_depressed_index_from_node_ids,
+        ),
+    ] {
+        let (pairs, scores) = get_coo_matrix(&graph);
+        assert!(!pairs.is_empty());
+        for ((src, dst), score) in pairs.into_iter().zip(scores.into_iter()) {
+            assert!((get_score(&graph, src, dst)? as WeightT - score).abs() < 1e-6);
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,27 @@
+extern crate graph;
+use graph::*;
+
+#[test]
+fn test_number_of_spanning_trees() -> Result<()> {
+    // By Cayley's formula, the complete graph on n nodes has n^(n - 2) spanning trees.
+    let number_of_nodes = 6;
+    let mut builder = GraphBuilder::new(None, Some(false));
+    for src in 0..number_of_nodes {
+        for dst in src + 1..number_of_nodes {
+            builder.add_edge(src.to_string(), dst.to_string(), None, None)?;
+        }
+    }
+    let graph = builder.build()?;
+    let expected = (number_of_nodes as f64).powi(number_of_nodes - 2);
+    assert!((graph.get_number_of_spanning_trees(None)? - expected).abs() < 1e-6 * expected);
+    assert!((graph.get_log_number_of_spanning_trees(None)? - expected.ln()).abs() < 1e-9);
+    assert!(graph.get_number_of_spanning_trees(Some(5)).is_err());
+
+    // A cycle on n nodes has n spanning trees.
+    let mut builder = GraphBuilder::new(None, Some(false));
+    for (src, dst) in [("A", "B"), ("B", "C"), ("C", "D"), ("D", "A")] {
+        builder.add_edge(src.to_string(), dst.to_string(), None, None)?;
+    }
+    assert!((builder.build()?.get_number_of_spanning_trees(None)? - 4.0).abs() < 1e-9);
+    Ok(())
+}
@@ -0,0 +1,46 @@
+extern crate graph;
+use graph::*;
+
+#[test]
+fn test_stochastic_block_model() -> Result<()> {
+    let graph = Graph::generate_stochastic_block_model(vec![3, 4, 5], 1.0, 0.0, false, 42)?;
+    assert_eq!(graph.get_number_of_nodes(), 12);
+    assert!(!graph.is_directed());
+    assert!(!graph.has_selfloops());
+    // With certain within edges and no between edges every block is a clique.
+    assert_eq!(graph.get_number_of_directed_edges(), 2 * (3 + 6 + 10));
+    for node_id in graph.iter_node_ids() {
+        let block_id = match node_id {
+            0..=2 => 0,
+            3..=6 => 1,
+            _ => 2,
+        };
+        assert_eq!(
+            graph.get_node_type_ids_from_node_id(node_id)?,
+            Some(&[block_id][..])
+        );
+    }
+    assert_eq!(
+        graph.get_connected_component_sizes(None, None),
+        vec![5, 4, 3]
+    );
+
+    let graph = Graph::generate_stochastic_block_model(vec![4, 4], 0.0, 1.0, true, 42)?;
+    assert!(graph.is_directed());
+    assert_eq!(graph.get_number_of_directed_edges(), 2 * 4 * 4);
+
+    let first = Graph::generate_stochastic_block_model(vec![10, 20], 0.5, 0.1, false, 7)?;
+    let second = Graph::generate_stochastic_block_model(vec![10, 20], 0.5, 0.1, false, 7)?;
+    assert_eq!(
+        first.iter_directed_edge_node_ids().collect::<Vec<_>>(),
+        second.iter_directed_edge_node_ids().collect::<Vec<_>>()
+    );
+
+    assert!(Graph::generate_stochastic_block_model(vec![3, 4], 1.5, 0.0, false, 42).is_err());
+    assert!(Graph::generate_stochastic_block_model(vec![3, 4], 0.5, -0.1, false, 42).is_err());
+    assert!(Graph::generate_stochastic_block_model(vec![3, 4], f64::NAN, 0.1, false, 42).is_err());
+    assert!(Graph::generate_stochastic_block_model(vec![], 0.5, 0.1, false, 42).is_err());
+    assert!(Graph::generate_stochastic_block_model(vec![3, 0], 0.5, 0.1, false, 42).is_err());
+
+    Ok(())
+}
@@ -0,0 +1,86 @@
+extern crate graph;
+use graph::*;
+
+#[test]
+fn test_stratified_connected_holdout() -> Result<()> {
+    let mut builder = GraphBuilder::new(None, Some(false));
+    let node_names = ["A", "B", "C", "D", "E"];
+    for (i, src) in node_names.iter().enumerate() {
+        for dst in node_names[i + 1..].iter() {
+            builder.add_edge(
+                src.to_string(),
+                dst.to_string(),
+                Some("common".to_string()),
+                None,
+            )?;
+        }
+    }
+    // The selfloops are never part of the spanning tree.
+    for node_name in ["A", "B", "C"] {
+        builder.add_edge(
+            node_name.to_string(),
+            node_name.to_string(),
+            Some("rare".to_string()),
+            None,
+        )?;
+    }
+    let graph = builder.build()?;
+
+    for random_state in 0..10 {
+        let (train, test) = graph.connected_holdout(
+            0.9,
+            Some(random_state),
+            None,
+            None,
+            None,
+            None,
+            Some(true),
+            None,
+        )?;
+        // Less than one rare edge would be expected without the stratification,
+        // while one of the 10 undirected common edges is expected.
+        assert_eq!(test.get_edge_count_from_edge_type_name(Some("rare"))?, 1);
+        assert_eq!(test.get_edge_count_from_edge_type_name(Some("common"))?, 2);
+        assert_eq!(train.get_number_of_connected_components(None).0, 1);
+        assert_eq!(
+            train.get_number_of_directed_edges() + test.get_number_of_directed_edges(),
+            graph.get_number_of_directed_edges()
+        );
+
+        // Only 6 of the 9 expected undirected common edges are outside of the
+        // spanning tree, and they are all put in the validation set.
+        let (train, test) = graph.connected_holdout(
+            0.1,
+            Some(random_state),
+            None,
+            None,
+            None,
+            None,
+            Some(true),
+            None,
+        )?;
+        assert_eq!(test.get_edge_count_from_edge_type_name(Some("rare"))?, 2);
+        assert_eq!(test.get_edge_count_from_edge_type_name(Some("common"))?, 12);
+        assert_eq!(train.get_number_of_connected_components(None).0, 1);
+    }
+
+    // The only bridge edge is always required to keep the training graph connected.
+    let mut builder = GraphBuilder::new(None, Some(false));
+    for (src, dst) in [("A", "B"), ("B", "C"), ("C", "A")] {
+        builder.add_edge(src, dst, Some("common".to_string()), None)?;
+    }
+    builder.add_edge("A", "D", Some("bridge".to_string()), None)?;
+    assert!(builder
+        .build()?
+        .connected_holdout(0.5, None, None, None, None, None, Some(true), None)
+        .is_err());
+
+    assert!(graph
+        .connected_holdout(0.9, None, None, Some(true), None, None, Some(true), None)
+        .is_err());
+    assert!(graph
+        .remove_edge_types()?
+        .connected_holdout(0.9, None, None, None, None, None, Some(true), None)
+        .is_err());
+    Ok(())
+}
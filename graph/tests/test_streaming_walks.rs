@@ -0,0 +1,20 @@
+extern crate graph;
+use graph::*;
+use rayon::prelude::*;
+
+#[test]
+fn test_streaming_random_walks() -> Result<()> {
+    let graph = graph::test_utilities::load_ppi(true, true, true, false, false, false);
+    for parameters in [
+        WalksParameters::new(10)?.set_iterations(Some(2))?,
+        WalksParameters::new(10)?.set_explore_weight(Some(2.0))?,
+    ] {
+        let walks = graph
+            .par_iter_random_walks(50, &parameters)?
+            .collect::<Vec<Vec<NodeT>>>();
+        // The iterator does not borrow the graph.
+        let iterator = graph.clone().iter_random_walks(50, &parameters)?;
+        assert_eq!(iterator.collect::<Vec<Vec<NodeT>>>(), walks);
+    }
+    Ok(())
+}
@@ -0,0 +1,32 @@
+extern crate graph;
+use graph::*;
+
+#[test]
+fn test_structural_fingerprint() -> Result<()> {
+    // A square and an isolated edge.
+    let mut builder = GraphBuilder::new(None, Some(false));
+    for (src, dst) in [("A", "B"), ("B", "C"), ("C", "D"), ("D", "A"), ("E", "F")] {
+        builder.add_edge(src.to_string(), dst.to_string(), None, None)?;
+    }
+    let graph = builder.build()?;
+    assert!((graph.get_spectral_radius(None, None)? - 2.0).abs() < 1e-4);
+
+    let fingerprint = graph.get_structural_fingerprint()?;
+    let feature_names = graph.get_structural_fingerprint_feature_names();
+    assert_eq!(fingerprint.len(), feature_names.len());
+    let feature = |feature_name: &str| {
+        fingerprint[feature_names
+            .iter()
+            .position(|name| name == feature_name)
+            .unwrap()]
+    };
+    assert_eq!(feature("number_of_nodes"), 6.0);
+    assert_eq!(feature("number_of_connected_components"), 2.0);
+    assert_eq!(
+        feature("largest_connected_component_nodes_fraction"),
+        4.0 / 6.0
+    );
+    assert_eq!(feature("connected_component_sizes_mean"), 3.0);
+    assert_eq!(feature("singleton_connected_components_fraction"), 0.0);
+    Ok(())
+}
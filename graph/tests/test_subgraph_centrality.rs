@@ -0,0 +1,17 @@
+extern crate graph;
+use graph::*;
+
+#[test]
+fn test_subgraph_centrality() -> Result<()> {
+    let mut builder = GraphBuilder::new(None, Some(false));
+    builder.add_edge("A".to_string(), "B".to_string(), None, None)?;
+    let graph = builder.build()?;
+    // The matrix exponential of a single edge has cosh(1) on the diagonal.
+    let centralities = graph.get_subgraph_centrality(None, Some(false))?;
+    for centrality in centralities {
+        assert!((centrality - 1.0f64.cosh()).abs() < 1e-12);
+    }
+    let estrada_index = graph.get_estrada_index(None, Some(false))?;
+    assert!((estrada_index - 2.0 * 1.0f64.cosh()).abs() < 1e-12);
+    Ok(())
+}
@@ -0,0 +1,41 @@
+extern crate graph;
+use graph::*;
+
+#[test]
+fn test_temporal_holdout() -> Result<()> {
+    for directed in [true, false] {
+        let mut builder = GraphBuilder::new(None, Some(directed));
+        for (src, dst) in [("A", "B"), ("B", "C"), ("C", "D"), ("D", "E"), ("E", "F")] {
+            builder.add_edge(src.to_string(), dst.to_string(), None, None)?;
+        }
+        let graph = builder.build()?;
+        // The most recent edges are the ones with the smallest source node names.
+        let timestamps = graph
+            .iter_directed_edge_node_ids()
+            .map(|(_, src, dst)| -(src.min(dst) as f64))
+            .collect::<Vec<f64>>();
+        let (train, test) = graph.get_temporal_holdout(0.6, timestamps)?;
+        for (src, dst) in [("C", "D"), ("D", "E"), ("E", "F")] {
+            assert!(train.has_edge_from_node_names(src, dst));
+            assert!(!test.has_edge_from_node_names(src, dst));
+        }
+        for (src, dst) in [("A", "B"), ("B", "C")] {
+            assert!(test.has_edge_from_node_names(src, dst));
+            assert!(!train.has_edge_from_node_names(src, dst));
+        }
+        if !directed {
+            assert!(test.has_edge_from_node_names("B", "A"));
+            assert!(train.has_edge_from_node_names("F", "E"));
+        }
+        assert_eq!(
+            train.get_number_of_directed_edges() + test.get_number_of_directed_edges(),
+            graph.get_number_of_directed_edges()
+        );
+
+        assert!(graph.get_temporal_holdout(0.6, vec![0.0; 3]).is_err());
+        let mut timestamps = vec![0.0; graph.get_number_of_directed_edges() as usize];
+        timestamps[0] = f64::NAN;
+        assert!(graph.get_temporal_holdout(0.6, timestamps).is_err());
+    }
+    Ok(())
+}
@@ -0,0 +1,71 @@
+extern crate graph;
+use graph::*;
+
+#[test]
+fn test_tfidf_reweighted_graph() -> Result<()> {
+    // Every source node points to the hub H.
+    let mut builder = GraphBuilder::new(None, Some(true));
+    for (src, dst) in [("X", "H"), ("X", "A"), ("Y", "H"), ("Y", "B"), ("Z", "H")] {
+        builder.add_edge(src, dst, None, None)?;
+    }
+    let graph = builder.build()?;
+    let reweighted = graph.get_tfidf_reweighted_graph(None, None, Some(false))?;
+
+    assert!(reweighted.has_edge_weights());
+    assert!(!graph.has_edge_weights());
+    assert_eq!(
+        reweighted.get_directed_edge_node_ids(),
+        graph.get_directed_edge_node_ids()
+    );
+
+    // There are six documents, one for each node, with average length 5 / 6.
+    let (k1, b) = (1.5_f32, 0.75_f32);
+    let average_document_len = 5.0 / 6.0;
+    let expected_weight = |document_len: f32, occurrences: f32| {
+        let word_frequency = 1.0 / document_len;
+        let inverse_document_frequency =
+            (1.0 + (6.0 - occurrences + 0.5) / (occurrences + 0.5)).ln();
+        inverse_document_frequency * word_frequency * (k1 + 1.0)
+            / (word_frequency + k1 * (1.0 - b + b * document_len / average_document_len))
+    };
+    for (src, dst, document_len, occurrences) in [
+        ("X", "H", 2.0, 3.0),
+        ("X", "A", 2.0, 1.0),
+        ("Y", "B", 2.0, 1.0),
+        ("Z", "H", 1.0, 3.0),
+    ] {
+        let weight = reweighted.get_edge_weight_from_node_names(src, dst)?;
+        assert!((weight - expected_weight(document_len, occurrences)).abs() < 1e-5);
+    }
+    // The edges towards the hub are downweighted.
+    assert!(
+        reweighted.get_edge_weight_from_node_names("X", "H")?
+            < reweighted.get_edge_weight_from_node_names("X", "A")?
+    );
+
+    // In undirected graphs the weights remain symmetric.
+    let mut builder = GraphBuilder::new(None, Some(false));
+    for (src, dst) in [("X", "H"), ("X", "A"), ("Y", "H"), ("Y", "B"), ("Z", "H")] {
+        builder.add_edge(src, dst, None, None)?;
+    }
+    let reweighted = builder
+        .build()?
+        .get_tfidf_reweighted_graph(None, None, Some(false))?;
+    for (_, src, dst, _, weight) in
+        reweighted.iter_edge_node_ids_and_edge_type_id_and_edge_weight(true)
+    {
+        assert_eq!(
+            Some(reweighted.get_edge_weight_from_node_ids(dst, src)?),
+            weight
+        );
+    }
+
+    let mut builder = GraphBuilder::new(None, Some(true));
+    builder.add_node("A".to_string(), None)?;
+    assert!(builder
+        .build()?
+        .get_tfidf_reweighted_graph(None, None, Some(false))
+        .is_err());
+
+    Ok(())
+}
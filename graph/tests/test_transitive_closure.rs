@@ -0,0 +1,58 @@
+extern crate graph;
+use graph::*;
+
+#[test]
+fn test_directed_acyclic_transitive_closure() -> Result<()> {
+    let graph = test_utilities::build_graph_from_edges_and_singletons(
+        &[("R", "A"), ("R", "B"), ("A", "C"), ("B", "C"), ("C", "D")],
+        &["S"],
+        true,
+    )?;
+    let closure = graph.get_directed_acyclic_transitive_closure(None)?;
+
+    let descendants = [
+        ("R", vec!["A", "B", "C", "D"]),
+        ("A", vec!["C", "D"]),
+        ("B", vec!["C", "D"]),
+        ("C", vec!["D"]),
+        ("D", vec![]),
+        ("S", vec![]),
+    ];
+    assert_eq!(closure.get_number_of_nodes(), graph.get_number_of_nodes());
+    assert_eq!(closure.get_number_of_directed_edges(), 9);
+    for (src, src_descendants) in descendants.iter() {
+        for dst in ["R", "A", "B", "C", "D", "S"] {
+            assert_eq!(
+                closure.has_edge_from_node_names(src, dst),
+                src_descendants.contains(&dst),
+                "The edge from {} to {} is not as expected.",
+                src,
+                dst
+            );
+        }
+    }
+
+    // The closure of a graph is its own closure.
+    assert_eq!(
+        closure
+            .get_directed_acyclic_transitive_closure(None)?
+            .get_directed_edge_node_ids(),
+        closure.get_directed_edge_node_ids()
+    );
+
+    assert!(graph
+        .get_directed_acyclic_transitive_closure(Some(5))
+        .is_err());
+    assert!(
+        test_utilities::build_graph_from_edges(&[("A", "B"), ("B", "C"), ("C", "A")], true)?
+            .get_directed_acyclic_transitive_closure(None)
+            .is_err()
+    );
+    assert!(
+        test_utilities::build_graph_from_edges(&[("A", "B"), ("B", "C")], false)?
+            .get_directed_acyclic_transitive_closure(None)
+            .is_err()
+    );
+
+    Ok(())
+}
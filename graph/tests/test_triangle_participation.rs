@@ -0,0 +1,26 @@
+extern crate graph;
+use graph::*;
+
+#[test]
+fn test_node_triangle_participation() -> Result<()> {
+    let mut builder = GraphBuilder::new(None, Some(false));
+    for (src, dst) in [
+        ("A", "B"),
+        ("B", "C"),
+        ("C", "A"),
+        ("C", "D"),
+        ("A", "D"),
+        ("D", "E"),
+    ] {
+        builder.add_edge(src.to_string(), dst.to_string(), None, None)?;
+    }
+    let graph = builder.build()?;
+    let triangle_participation = graph.get_node_triangle_participation()?;
+    for (node_name, expected) in [("A", 2), ("B", 1), ("C", 2), ("D", 1), ("E", 0)] {
+        assert_eq!(
+            triangle_participation[graph.get_node_id_from_node_name(node_name)? as usize],
+            expected
+        );
+    }
+    Ok(())
+}
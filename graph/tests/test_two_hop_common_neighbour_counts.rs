@@ -0,0 +1,129 @@
+extern crate graph;
+use graph::*;
+
+/// Returns the expected pairs and counts, sorted by node IDs.
+fn get_expected(
+    graph: &Graph,
+    expected: &[(&str, &str, NodeT)],
+) -> Result<(Vec<(NodeT, NodeT)>, Vec<NodeT>)> {
+    let mut expected = expected
+        .iter()
+        .map(|&(src, dst, count)| {
+            let src = graph.get_node_id_from_node_name(src)?;
+            let dst = graph.get_node_id_from_node_name(dst)?;
+            Ok(if graph.is_directed() {
+                ((src, dst), count)
+            } else {
+                ((src.min(dst), src.max(dst)), count)
+            })
+        })
+        .collect::<Result<Vec<((NodeT, NodeT), NodeT)>>>()?;
+    expected.sort_unstable();
+    Ok(expected.into_iter().unzip())
+}
+
+#[test]
+fn test_two_hop_common_neighbour_counts() -> Result<()> {
+    let graph = test_utilities::build_graph_from_edges(
+        &[
+            ("A", "B"),
+            ("A", "C"),
+            ("B", "C"),
+            ("B", "D"),
+            ("C", "D"),
+            ("D", "E"),
+            ("A", "A"),
+        ],
+        false,
+    )?;
+    // Connected pairs sharing a neighbour are included, the selfloop is
+    // ignored and the pairs without common neighbours are not returned.
+    assert_eq!(
+        graph.get_two_hop_common_neighbour_counts(None),
+        get_expected(
+            &graph,
+            &[
+                ("A", "B", 1),
+                ("A", "C", 1),
+                ("A", "D", 2),
+                ("B", "C", 2),
+                ("B", "D", 1),
+                ("B", "E", 1),
+                ("C", "D", 1),
+                ("C", "E", 1),
+            ]
+        )?
+    );
+    // The symmetric pairs are dropped before the top pairs are selected, so each
+    // source node keeps its best pair among the ones where it has the smaller node ID.
+    let (all_pairs, all_counts) = graph.get_two_hop_common_neighbour_counts(None);
+    let mut expected: Vec<((NodeT, NodeT), NodeT)> = Vec::new();
+    for (&(src, dst), &count) in all_pairs.iter().zip(all_counts.iter()) {
+        match expected.last_mut() {
+            Some(((last_src, last_dst), last_count)) if *last_src == src => {
+                if count > *last_count {
+                    *last_dst = dst;
+                    *last_count = count;
+                }
+            }
+            _ => expected.push(((src, dst), count)),
+        }
+    }
+    let (pairs, counts) = graph.get_two_hop_common_neighbour_counts(Some(1));
+    assert_eq!(
+        pairs
+            .into_iter()
+            .zip(counts.into_iter())
+            .collect::<Vec<_>>(),
+        expected
+    );
+
+    let graph = test_utilities::build_graph_from_edges(
+        &[
+            ("X", "M1"),
+            ("X", "M2"),
+            ("M1", "Y"),
+            ("M2", "Y"),
+            ("M1", "Z"),
+            ("Z", "X"),
+        ],
+        true,
+    )?;
+    // In directed graphs only the paths following the direction of the edges are counted.
+    assert_eq!(
+        graph.get_two_hop_common_neighbour_counts(None),
+        get_expected(
+            &graph,
+            &[
+                ("X", "Y", 2),
+                ("X", "Z", 1),
+                ("M1", "X", 1),
+                ("Z", "M1", 1),
+                ("Z", "M2", 1),
+            ]
+        )?
+    );
+    // Only the pairs with the highest counts are kept for each source node,
+    // breaking the ties by the destination node ID.
+    let m = graph
+        .get_node_id_from_node_name("M1")?
+        .min(graph.get_node_id_from_node_name("M2")?);
+    let (pairs, counts) = graph.get_two_hop_common_neighbour_counts(Some(1));
+    let (expected_pairs, expected_counts) = get_expected(&graph, &[("X", "Y", 2), ("M1", "X", 1)])?;
+    let z = graph.get_node_id_from_node_name("Z")?;
+    let mut expected = expected_pairs
+        .into_iter()
+        .zip(expected_counts.into_iter())
+        .chain(std::iter::once(((z, m), 1)))
+        .collect::<Vec<((NodeT, NodeT), NodeT)>>();
+    expected.sort_unstable();
+    assert_eq!(
+        pairs
+            .into_iter()
+            .zip(counts.into_iter())
+            .collect::<Vec<_>>(),
+        expected
+    );
+
+    Ok(())
+}
@@ -0,0 +1,38 @@
+extern crate graph;
+use graph::*;
+
+#[test]
+fn test_undirected_triad_census() -> Result<()> {
+    let mut builder = GraphBuilder::new(None, Some(false));
+    for (src, dst) in [("A", "B"), ("B", "C"), ("C", "A"), ("C", "D"), ("D", "D")] {
+        builder.add_edge(src, dst, None, None)?;
+    }
+    builder.add_node("E".to_string(), None)?;
+    let graph = builder.build()?;
+
+    let (census, triads) = graph.get_undirected_triad_census(None, None)?;
+    assert_eq!(census, [2, 5, 2, 1]);
+    assert!(triads.is_none());
+
+    let (listed_census, triads) = graph.get_undirected_triad_census(Some(true), None)?;
+    assert_eq!(listed_census, census);
+    let triads = triads.unwrap();
+    assert_eq!(triads.len(), 4);
+    for (triads, &count) in triads.iter().zip(census.iter()) {
+        assert_eq!(triads.len() as u64, count);
+    }
+    let node_id = |name: &str| graph.get_node_id_from_node_name(name).unwrap();
+    let mut triangle = [node_id("A"), node_id("B"), node_id("C")];
+    triangle.sort_unstable();
+    assert_eq!(triads[3], vec![triangle]);
+
+    assert!(graph
+        .get_undirected_triad_census(Some(true), Some(4))
+        .is_err());
+    assert!(graph
+        .to_directed()
+        .get_undirected_triad_census(None, None)
+        .is_err());
+
+    Ok(())
+}
@@ -0,0 +1,68 @@
+extern crate graph;
+use graph::*;
+
+#[test]
+fn test_union_weight_merge_strategy() -> Result<()> {
+    let left = test_utilities::build_graph_from_weighted_edges(
+        &[
+            ("A", "B", Some(1.0)),
+            ("B", "C", Some(2.0)),
+            ("C", "D", Some(5.0)),
+        ],
+        false,
+    )?;
+    // The first right graph has the same nodes of the left one, the second has a new node.
+    for right in [
+        test_utilities::build_graph_from_weighted_edges(
+            &[
+                ("A", "B", Some(3.0)),
+                ("B", "C", Some(6.0)),
+                ("A", "D", Some(7.0)),
+            ],
+            false,
+        )?,
+        test_utilities::build_graph_from_weighted_edges(
+            &[
+                ("A", "B", Some(3.0)),
+                ("B", "C", Some(6.0)),
+                ("D", "E", Some(7.0)),
+            ],
+            false,
+        )?,
+    ] {
+        for (strategy, expected_overlap_weights) in [
+            (None, [1.0, 2.0]),
+            (Some(WeightMergeStrategy::Sum), [4.0, 8.0]),
+            (Some(WeightMergeStrategy::Max), [3.0, 6.0]),
+            (Some(WeightMergeStrategy::Min), [1.0, 2.0]),
+            (Some(WeightMergeStrategy::Mean), [2.0, 4.0]),
+            (Some(WeightMergeStrategy::KeepLeft), [1.0, 2.0]),
+            (Some(WeightMergeStrategy::KeepRight), [3.0, 6.0]),
+        ] {
+            let union = left.union(&right, strategy)?;
+            assert_eq!(union.get_number_of_edges(), 4);
+            for ((src, dst), expected_weight) in [("A", "B"), ("B", "C")]
+                .iter()
+                .zip(expected_overlap_weights.iter())
+            {
+                assert_eq!(
+                    union.get_edge_weight_from_node_names(src, dst)?,
+                    *expected_weight
+                );
+                assert_eq!(
+                    union.get_edge_weight_from_node_names(dst, src)?,
+                    *expected_weight
+                );
+            }
+            assert_eq!(union.get_edge_weight_from_node_names("C", "D")?, 5.0);
+        }
+    }
+
+    assert!(left
+        .union(
+            &test_utilities::build_graph_from_edges(&[("A", "B")], false)?,
+            Some(WeightMergeStrategy::Sum)
+        )
+        .is_err());
+    Ok(())
+}
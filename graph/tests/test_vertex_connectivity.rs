@@ -0,0 +1,42 @@
+extern crate graph;
+use graph::*;
+
+#[test]
+fn test_vertex_connectivity() -> Result<()> {
+    // Two triangles sharing a single node have vertex connectivity one.
+    let mut builder = GraphBuilder::new(None, Some(false));
+    for (src, dst) in [
+        ("A", "B"),
+        ("B", "C"),
+        ("C", "A"),
+        ("C", "D"),
+        ("D", "E"),
+        ("E", "C"),
+    ] {
+        builder.add_edge(src.to_string(), dst.to_string(), None, None)?;
+    }
+    let graph = builder.build()?;
+    assert_eq!(graph.get_vertex_connectivity(None, None, None)?, 1);
+
+    // A cycle has vertex connectivity two.
+    let mut builder = GraphBuilder::new(None, Some(false));
+    for (src, dst) in [("A", "B"), ("B", "C"), ("C", "D"), ("D", "E"), ("E", "A")] {
+        builder.add_edge(src.to_string(), dst.to_string(), None, None)?;
+    }
+    let graph = builder.build()?;
+    assert_eq!(graph.get_vertex_connectivity(None, None, None)?, 2);
+    assert_eq!(graph.get_vertex_connectivity(Some(20), None, None)?, 2);
+    assert!(graph.get_vertex_connectivity(None, None, Some(3)).is_err());
+
+    // The complete graph on five nodes has vertex connectivity four.
+    let mut builder = GraphBuilder::new(None, Some(false));
+    let node_names = ["A", "B", "C", "D", "E"];
+    for (i, src) in node_names.iter().enumerate() {
+        for dst in node_names.iter().skip(i + 1) {
+            builder.add_edge(src.to_string(), dst.to_string(), None, None)?;
+        }
+    }
+    let graph = builder.build()?;
+    assert_eq!(graph.get_vertex_connectivity(None, None, None)?, 4);
+    Ok(())
+}
@@ -0,0 +1,34 @@
+extern crate graph;
+use graph::*;
+
+#[test]
+fn test_number_of_wedges() -> Result<()> {
+    // Only the center of a star with four leaves has wedges, that are C(4, 2).
+    let star = test_utilities::build_graph_from_edges(
+        &[("H", "A"), ("H", "B"), ("H", "C"), ("H", "D")],
+        false,
+    )?;
+    assert_eq!(star.get_number_of_wedges(), 6);
+    assert_eq!(2 * star.get_number_of_wedges(), star.get_number_of_triads());
+
+    // The inner nodes of a path with four nodes have one wedge each.
+    let path =
+        test_utilities::build_graph_from_edges(&[("A", "B"), ("B", "C"), ("C", "D")], false)?;
+    assert_eq!(path.get_number_of_wedges(), 2);
+
+    // The selfloops are counted as any other edge.
+    let graph = test_utilities::build_graph_from_edges(&[("A", "A"), ("A", "B")], false)?;
+    assert_eq!(graph.get_number_of_wedges(), 1);
+
+    // In directed graphs only the outbound edges are considered.
+    let graph = test_utilities::build_graph_from_edges(
+        &[("A", "B"), ("A", "C"), ("A", "D"), ("B", "C"), ("D", "B")],
+        true,
+    )?;
+    assert_eq!(graph.get_number_of_wedges(), 3);
+
+    let graph = test_utilities::build_graph_from_edges_and_singletons(&[], &["A", "B"], false)?;
+    assert_eq!(graph.get_number_of_wedges(), 0);
+
+    Ok(())
+}
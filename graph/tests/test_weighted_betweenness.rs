@@ -0,0 +1,26 @@
+extern crate graph;
+use graph::*;
+
+#[test]
+fn test_weighted_betweenness_with_ties() -> Result<()> {
+    let mut builder = GraphBuilder::new(None, Some(false));
+    for (src, dst, weight) in [
+        ("A", "B", 1.0),
+        ("B", "D", 1.0),
+        ("A", "C", 1.0),
+        ("C", "D", 1.0),
+        ("A", "D", 2.0),
+    ] {
+        builder.add_edge(src.to_string(), dst.to_string(), None, Some(weight))?;
+    }
+    let graph = builder.build()?;
+    let centralities = graph.get_weighted_betweenness_centrality(None, Some(false))?;
+    // The pair (A, D) has three shortest paths of weight 2: the direct edge,
+    // the one through B and the one through C. The pair (B, C) has two shortest
+    // paths of weight 2: the one through A and the one through D.
+    for (node_name, expected) in [("A", 0.5), ("B", 1.0 / 3.0), ("C", 1.0 / 3.0), ("D", 0.5)] {
+        let node_id = graph.get_node_id_from_node_name(node_name)?;
+        assert!((centralities[node_id as usize] - expected).abs() < 1e-6);
+    }
+    Ok(())
+}
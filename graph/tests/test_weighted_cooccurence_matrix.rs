@@ -0,0 +1,84 @@
+extern crate graph;
+use graph::*;
+use rayon::prelude::*;
+use std::collections::HashMap;
+
+#[test]
+fn test_weighted_cooccurence_matrix() -> Result<()> {
+    let graph = graph::test_utilities::load_ppi(false, false, false, false, false, false);
+    let walks_parameters = WalksParameters::new(16)?
+        .set_iterations(Some(1))?
+        .set_random_state(Some(42));
+
+    // The counts of the single walks, aggregated over the whole corpus.
+    let walk_counts = graph
+        .par_iter_cooccurence_matrix(&walks_parameters, 3, None)?
+        .collect::<Vec<(NodeT, NodeT, NodeT)>>();
+    let mut total_counts: HashMap<(NodeT, NodeT), NodeT> = HashMap::new();
+    for &(src, dst, count) in walk_counts.iter() {
+        *total_counts.entry((src, dst)).or_insert(0) += count;
+    }
+    // Some pairs of nodes must appear in more than one of the walks.
+    assert!(walk_counts.len() > total_counts.len());
+
+    // Without the weighting, the counts of the single walks are returned.
+    let mut unweighted_counts = graph
+        .par_iter_weighted_cooccurence_matrix(&walks_parameters, 3, None, None, None)?
+        .map(|(src, dst, count, weighting)| {
+            assert!(weighting.is_none());
+            (src, dst, count)
+        })
+        .collect::<Vec<(NodeT, NodeT, NodeT)>>();
+    let mut sorted_walk_counts = walk_counts.clone();
+    unweighted_counts.sort_unstable();
+    sorted_walk_counts.sort_unstable();
+    assert_eq!(unweighted_counts, sorted_walk_counts);
+
+    let weighted_counts = graph
+        .par_iter_weighted_cooccurence_matrix(&walks_parameters, 3, None, Some(2.0), Some(0.5))?
+        .collect::<Vec<(NodeT, NodeT, NodeT, Option<f64>)>>();
+    assert_eq!(weighted_counts.len(), total_counts.len());
+    for (src, dst, count, weighting) in weighted_counts {
+        // The weighting is applied to the total count of the pair.
+        assert_eq!(count, total_counts[&(src, dst)]);
+        let weighting = weighting.unwrap();
+        assert!((weighting - (count as f64 / 2.0).sqrt().min(1.0)).abs() < 1e-12);
+        assert!(weighting > 0.0 && weighting <= 1.0);
+    }
+
+    let (node_ids, counts, weightings) =
+        graph.get_weighted_cooccurence_matrix(&walks_parameters, 3, None, Some(2.0), Some(0.5))?;
+    let weightings = weightings.unwrap();
+    assert_eq!(node_ids.len(), total_counts.len());
+    assert_eq!(counts.len(), total_counts.len());
+    assert_eq!(weightings.len(), total_counts.len());
+    for ((pair, count), weighting) in node_ids.iter().zip(counts.iter()).zip(weightings.iter()) {
+        assert_eq!(*count, total_counts[pair]);
+        assert!((weighting - (*count as f64 / 2.0).sqrt().min(1.0)).abs() < 1e-12);
+    }
+    let (node_ids, counts, weightings) =
+        graph.get_weighted_cooccurence_matrix(&walks_parameters, 3, None, None, None)?;
+    assert!(weightings.is_none());
+    assert_eq!(node_ids.len(), walk_counts.len());
+    assert_eq!(
+        counts.iter().sum::<NodeT>(),
+        total_counts.values().sum::<NodeT>()
+    );
+
+    // When only one of the two parameters is given, the other one uses its default.
+    assert!(graph
+        .par_iter_weighted_cooccurence_matrix(&walks_parameters, 3, None, None, Some(1.0))?
+        .all(|(_, _, count, weighting)| weighting == Some((count as f64 / 100.0).min(1.0))));
+
+    assert!(graph
+        .par_iter_weighted_cooccurence_matrix(&walks_parameters, 3, None, Some(0.0), None)
+        .is_err());
+    assert!(graph
+        .par_iter_weighted_cooccurence_matrix(&walks_parameters, 3, None, None, Some(-1.0))
+        .is_err());
+    assert!(graph
+        .get_weighted_cooccurence_matrix(&walks_parameters, 3, None, Some(f64::NAN), None)
+        .is_err());
+
+    Ok(())
+}
@@ -0,0 +1,45 @@
+extern crate graph;
+use graph::*;
+
+#[test]
+fn test_weighted_walks() -> Result<()> {
+    let mut builder = GraphBuilder::new(None, Some(false));
+    for (src, dst, weight) in [("A", "B", 1000.0), ("A", "C", 0.001), ("B", "C", 1.0)] {
+        builder.add_edge(src.to_string(), dst.to_string(), None, Some(weight))?;
+    }
+    let graph = builder.build()?;
+    let node_a = graph.get_node_id_from_node_name("A")?;
+    let node_b = graph.get_node_id_from_node_name("B")?;
+
+    // The walks starting from A almost always move to B.
+    let parameters = WalksParameters::new(5)?
+        .set_iterations(Some(20))?
+        .set_use_edge_weights_as_probabilities(Some(true));
+    assert!(parameters.uses_edge_weights(&graph));
+    let walks = graph
+        .iter_complete_walks(&parameters)?
+        .collect::<Vec<Vec<NodeT>>>();
+    walks
+        .iter()
+        .filter(|walk| walk[0] == node_a)
+        .for_each(|walk| assert_eq!(walk[1], node_b));
+
+    // When the edge weights are ignored, the walks match the ones of the unweighted graph.
+    let unweighted_graph = graph.remove_edge_weights()?;
+    let parameters = WalksParameters::new(5)?
+        .set_iterations(Some(20))?
+        .set_use_edge_weights_as_probabilities(Some(false));
+    assert_eq!(
+        graph
+            .iter_complete_walks(&parameters)?
+            .collect::<Vec<Vec<NodeT>>>(),
+        unweighted_graph
+            .iter_complete_walks(&parameters)?
+            .collect::<Vec<Vec<NodeT>>>()
+    );
+
+    // Requesting the edge weights on a graph without them is an error.
+    let parameters = WalksParameters::new(5)?.set_use_edge_weights_as_probabilities(Some(true));
+    assert!(unweighted_graph.iter_complete_walks(&parameters).is_err());
+    Ok(())
+}
@@ -0,0 +1,55 @@
+extern crate graph;
+use graph::*;
+
+#[test]
+fn test_write_edge_iterator() -> Result<()> {
+    let path = std::env::temp_dir().join("test_write_edge_iterator.csv");
+    let path = path.to_str().unwrap();
+    write_edge_iterator(
+        path,
+        Some(','),
+        Some(true),
+        Some("edge_type".to_string()),
+        Some("weight".to_string()),
+        (0..3).map(|src| {
+            (
+                src,
+                src + 1,
+                if src % 2 == 0 {
+                    Some("even".to_string())
+                } else {
+                    None
+                },
+                Some(src as WeightT + 0.5),
+            )
+        }),
+    )?;
+    assert_eq!(
+        std::fs::read_to_string(path).unwrap(),
+        "subject,object,edge_type,weight\n0,1,even,0.5\n1,2,,1.5\n2,3,even,2.5\n"
+    );
+
+    assert!(write_edge_iterator(
+        path,
+        None,
+        None,
+        None,
+        None,
+        vec![(0, 1, None, Some(1.0))].into_iter(),
+    )
+    .is_err());
+    // The partially written edge list is removed when writing fails.
+    assert!(!std::path::Path::new(path).exists());
+
+    assert!(write_edge_iterator(
+        path,
+        None,
+        None,
+        None,
+        None,
+        vec![(0, 1, Some("even".to_string()), None)].into_iter(),
+    )
+    .is_err());
+    assert!(!std::path::Path::new(path).exists());
+    Ok(())
+}